@@ -0,0 +1,70 @@
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_core::{
+    combined_database::DbSyncPolicy,
+    database::{
+        database_description::on_chain::OnChain,
+        Database,
+    },
+    state::rocks_db::{
+        RocksDb,
+        ShallowTempDir,
+    },
+};
+use fuel_core_storage::{
+    tables::FuelBlocks,
+    transactional::WriteTransaction,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    blockchain::block::CompressedBlock,
+    fuel_types::BlockHeight,
+};
+use std::{
+    num::NonZeroU64,
+    sync::Arc,
+};
+
+// Use Jemalloc during benchmarks
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
+/// Measures the latency of the block commit for different sync policies.
+fn commit_block(c: &mut Criterion) {
+    let mut group = c.benchmark_group("commit_block");
+
+    let policies = [
+        DbSyncPolicy::Always,
+        DbSyncPolicy::EveryNBlocks(NonZeroU64::new(10).unwrap()),
+        DbSyncPolicy::Never,
+    ];
+    for policy in policies {
+        let tmp_dir = ShallowTempDir::new();
+        let rocks_db =
+            RocksDb::<OnChain>::default_open(tmp_dir.path(), None, policy).unwrap();
+        let mut database = Database::new(Arc::new(rocks_db));
+        let mut height = BlockHeight::new(0);
+
+        group.bench_function(format!("sync_policy_{policy}"), |b| {
+            b.iter(|| {
+                let mut block = CompressedBlock::default();
+                block.header_mut().set_block_height(height);
+                let mut transaction = database.write_transaction();
+                transaction
+                    .storage_as_mut::<FuelBlocks>()
+                    .insert(&height, &block)
+                    .unwrap();
+                transaction.commit().unwrap();
+                height = height.succ().expect("The height doesn't overflow");
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, commit_block);
+criterion_main!(benches);
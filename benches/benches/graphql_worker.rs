@@ -0,0 +1,199 @@
+//! Measures the time the GraphQL worker needs to catch up with the imported blocks
+//! for different levels of parallelism.
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_core::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    fuel_core_graphql_api::{
+        ports::worker,
+        worker_service,
+    },
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    Service,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        SealedBlock,
+    },
+    fuel_tx::{
+        Bytes32,
+        ConsensusParameters,
+        Input,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+    services::{
+        block_importer::{
+            ImportResult,
+            SharedImportResult,
+        },
+        executor::{
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
+        },
+        txpool::TransactionStatus,
+    },
+};
+use futures::StreamExt;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::{
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::{
+    runtime::Runtime,
+    sync::Notify,
+};
+
+const BLOCKS: u32 = 100;
+const TXS_PER_BLOCK: usize = 100;
+
+/// Notifies when the statuses of all transactions are completed.
+#[derive(Clone)]
+struct CompletedTxs {
+    remaining: Arc<AtomicUsize>,
+    notify: Arc<Notify>,
+}
+
+impl worker::TxPool for CompletedTxs {
+    fn send_complete(&self, _: Bytes32, _: &BlockHeight, _: TransactionStatus) {
+        if self.remaining.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.notify.notify_one();
+        }
+    }
+}
+
+/// Imports all blocks at once, like after the downtime of the worker.
+struct ImportedBlocks(Vec<SharedImportResult>);
+
+impl worker::BlockImporter for ImportedBlocks {
+    fn block_events(&self) -> BoxStream<SharedImportResult> {
+        Box::pin(futures::stream::iter(self.0.clone()).chain(futures::stream::pending()))
+    }
+}
+
+fn make_blocks(rng: &mut StdRng, chain_id: &ChainId) -> Vec<SharedImportResult> {
+    (1..=BLOCKS)
+        .map(|height| {
+            let txs = (0..TXS_PER_BLOCK)
+                .map(|_| {
+                    TransactionBuilder::script(vec![], vec![])
+                        .add_input(Input::coin_signed(
+                            rng.gen(),
+                            rng.gen(),
+                            1000,
+                            Default::default(),
+                            Default::default(),
+                            Default::default(),
+                        ))
+                        .add_output(Output::coin(rng.gen(), 1000, Default::default()))
+                        .add_witness(Default::default())
+                        .finalize_as_transaction()
+                })
+                .collect::<Vec<Transaction>>();
+            let tx_status = txs
+                .iter()
+                .map(|tx| TransactionExecutionStatus {
+                    id: tx.id(chain_id),
+                    result: TransactionExecutionResult::Success {
+                        result: None,
+                        receipts: vec![],
+                    },
+                    storage_access: None,
+                    gas_breakdown: None,
+                })
+                .collect();
+
+            let mut block = Block::default();
+            *block.transactions_mut() = txs;
+            block.header_mut().set_block_height(height.into());
+            block.header_mut().recalculate_metadata();
+            let sealed_block = SealedBlock {
+                entity: block,
+                consensus: Default::default(),
+            };
+            let result: SharedImportResult = Arc::new(ImportResult::new_from_network(
+                sealed_block,
+                tx_status,
+                vec![],
+            ));
+            result
+        })
+        .collect()
+}
+
+fn bench_catch_up(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let mut rng = StdRng::seed_from_u64(2322);
+    let consensus_parameters = ConsensusParameters::default();
+    let blocks = make_blocks(&mut rng, &consensus_parameters.chain_id());
+
+    let mut group = c.benchmark_group("graphql_worker_catch_up");
+    group.sample_size(10);
+    for parallelism in [1, 2, 4, 8] {
+        let blocks = blocks.clone();
+        group.bench_function(format!("parallelism {parallelism}"), |b| {
+            b.to_async(&rt).iter_custom(|iters| {
+                let blocks = blocks.clone();
+                let consensus_parameters = consensus_parameters.clone();
+                async move {
+                    let mut elapsed_time = Duration::default();
+                    for _ in 0..iters {
+                        let completed = CompletedTxs {
+                            remaining: Arc::new(AtomicUsize::new(
+                                blocks.len() * TXS_PER_BLOCK,
+                            )),
+                            notify: Arc::new(Notify::new()),
+                        };
+                        let service = worker_service::new_service(
+                            completed.clone(),
+                            ImportedBlocks(blocks.clone()),
+                            Database::<OffChain>::in_memory(),
+                            consensus_parameters.clone(),
+                            parallelism,
+                            Default::default(),
+                        );
+
+                        let start = std::time::Instant::now();
+                        service.start_and_await().await.unwrap();
+                        completed.notify.notified().await;
+                        elapsed_time += start.elapsed();
+
+                        service.stop_and_await().await.unwrap();
+                    }
+                    elapsed_time
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_catch_up);
+criterion_main!(benches);
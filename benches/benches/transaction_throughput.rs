@@ -41,6 +41,7 @@ use rand::{
     SeedableRng,
 };
 use std::{
+    num::NonZeroUsize,
     sync::Arc,
     time::Duration,
 };
@@ -53,8 +54,12 @@ use test_helpers::builder::{
 #[global_allocator]
 static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
-fn bench_txs<F>(group_id: &str, c: &mut Criterion, f: F)
-where
+fn bench_txs<F>(
+    group_id: &str,
+    c: &mut Criterion,
+    parallel_execution_workers: Option<NonZeroUsize>,
+    f: F,
+) where
     F: Fn(&mut StdRng) -> Script,
 {
     let inner_bench = |c: &mut BenchmarkGroup<WallTime>, n: u64| {
@@ -124,6 +129,8 @@ where
                         };
 
                         // start the validator node
+                        test_builder.parallel_execution_workers =
+                            parallel_execution_workers;
                         let TestContext { srv, .. } = test_builder.finalize().await;
 
                         let start = std::time::Instant::now();
@@ -154,29 +161,40 @@ where
     group.finish();
 }
 
+fn signed_transfer(rng: &mut StdRng) -> Script {
+    TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1000,
+            Default::default(),
+            Default::default(),
+        )
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1000,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(rng.gen(), 50, AssetId::default()))
+        .add_output(Output::change(rng.gen(), 0, AssetId::default()))
+        .finalize()
+}
+
 fn signed_transfers(c: &mut Criterion) {
-    let generator = |rng: &mut StdRng| {
-        TransactionBuilder::script(vec![], vec![])
-            .script_gas_limit(10000)
-            .add_unsigned_coin_input(
-                SecretKey::random(rng),
-                rng.gen(),
-                1000,
-                Default::default(),
-                Default::default(),
-            )
-            .add_unsigned_coin_input(
-                SecretKey::random(rng),
-                rng.gen(),
-                1000,
-                Default::default(),
-                Default::default(),
-            )
-            .add_output(Output::coin(rng.gen(), 50, AssetId::default()))
-            .add_output(Output::change(rng.gen(), 0, AssetId::default()))
-            .finalize()
-    };
-    bench_txs("signed transfers", c, generator);
+    bench_txs("signed transfers", c, None, signed_transfer);
+}
+
+fn signed_transfers_parallel_validation(c: &mut Criterion) {
+    let workers = std::thread::available_parallelism().ok();
+    bench_txs(
+        "signed transfers parallel validation",
+        c,
+        workers,
+        signed_transfer,
+    );
 }
 
 fn predicate_transfers(c: &mut Criterion) {
@@ -213,7 +231,7 @@ fn predicate_transfers(c: &mut Criterion) {
             .expect("Predicate check failed");
         tx
     };
-    bench_txs("predicate transfers", c, generator);
+    bench_txs("predicate transfers", c, None, generator);
 }
 
 fn predicate_transfers_eck1(c: &mut Criterion) {
@@ -279,12 +297,13 @@ fn predicate_transfers_eck1(c: &mut Criterion) {
             .expect("Predicate check failed");
         tx
     };
-    bench_txs("predicate transfers eck1", c, generator);
+    bench_txs("predicate transfers eck1", c, None, generator);
 }
 
 criterion_group!(
     benches,
     signed_transfers,
+    signed_transfers_parallel_validation,
     predicate_transfers,
     predicate_transfers_eck1
 );
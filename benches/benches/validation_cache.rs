@@ -0,0 +1,159 @@
+//! Compares the block validation with and without the cache of transactions
+//! already validated by the TxPool.
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    measurement::WallTime,
+    BenchmarkGroup,
+    Criterion,
+    SamplingMode,
+};
+use fuel_core::service::config::Trigger;
+use fuel_core_types::{
+    fuel_crypto::*,
+    fuel_tx::{
+        Finalizable,
+        Output,
+        Script,
+        Transaction,
+        TransactionBuilder,
+    },
+    fuel_types::AssetId,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+use test_helpers::builder::{
+    TestContext,
+    TestSetupBuilder,
+};
+
+// Use Jemalloc during benchmarks
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
+fn signed_transfer(rng: &mut StdRng) -> Script {
+    TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1000,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(rng.gen(), 50, AssetId::default()))
+        .add_output(Output::change(rng.gen(), 0, AssetId::default()))
+        .finalize()
+}
+
+fn bench_validation(c: &mut BenchmarkGroup<WallTime>, n: u64, tx_validation_cache: bool) {
+    let id = format!(
+        "{n} {}",
+        if tx_validation_cache {
+            "cached"
+        } else {
+            "uncached"
+        }
+    );
+    c.bench_function(id.as_str(), |b| {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let _drop = rt.enter();
+
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let transactions: Vec<Script> =
+            (0..n).map(|_| signed_transfer(&mut rng)).collect();
+
+        let mut test_builder = TestSetupBuilder::new(2322);
+        test_builder.config_coin_inputs_from_transactions(
+            &transactions.iter().collect::<Vec<_>>(),
+        );
+        test_builder.trigger = Trigger::Never;
+        test_builder.utxo_validation = true;
+
+        let transactions: Vec<Transaction> =
+            transactions.into_iter().map(|tx| tx.into()).collect();
+        let transactions = Arc::new(transactions);
+
+        b.to_async(&rt).iter_custom(|iters| {
+            let mut elapsed_time = Duration::default();
+            let test_builder = test_builder.clone();
+            let transactions = transactions.clone();
+
+            async move {
+                for _ in 0..iters {
+                    let mut test_builder = test_builder.clone();
+                    let pool_transactions = || {
+                        transactions
+                            .iter()
+                            .map(|tx| Arc::new(tx.clone()))
+                            .collect::<Vec<_>>()
+                    };
+
+                    let sealed_block = {
+                        let TestContext { srv, client, .. } =
+                            test_builder.finalize().await;
+                        srv.shared
+                            .txpool_shared_state
+                            .insert(pool_transactions())
+                            .await;
+                        let _ = client.produce_blocks(1, None).await;
+                        srv.shared
+                            .database
+                            .on_chain()
+                            .get_sealed_block_by_height(&1.into())
+                            .unwrap()
+                            .unwrap()
+                    };
+
+                    // The validator receives the same transactions via gossip
+                    // before it receives the block.
+                    test_builder.tx_validation_cache = tx_validation_cache;
+                    let TestContext { srv, .. } = test_builder.finalize().await;
+                    srv.shared
+                        .txpool_shared_state
+                        .insert(pool_transactions())
+                        .await;
+
+                    let start = std::time::Instant::now();
+
+                    srv.shared
+                        .block_importer
+                        .execute_and_commit(sealed_block)
+                        .await
+                        .expect("Should validate the block");
+
+                    elapsed_time += start.elapsed();
+                }
+                elapsed_time
+            }
+        });
+    });
+}
+
+fn validation_cache(c: &mut Criterion) {
+    let mut group = c.benchmark_group("block validation");
+
+    for n in [100, 500, 1000] {
+        group.throughput(criterion::Throughput::Elements(n));
+        group.sampling_mode(SamplingMode::Flat);
+        group.sample_size(10);
+        bench_validation(&mut group, n, false);
+        bench_validation(&mut group, n, true);
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, validation_cache);
+criterion_main!(benches);
@@ -11,13 +11,33 @@ use crate::{
     FuelService,
 };
 use anyhow::Context;
-use clap::Parser;
+use clap::{
+    Parser,
+    ValueEnum,
+};
 use fuel_core::{
     chain_config::default_consensus_dev_key,
-    combined_database::CombinedDatabaseConfig,
+    combined_database::{
+        CombinedDatabaseConfig,
+        DbSyncPolicy,
+    },
+    fuel_core_graphql_api::{
+        AssetMetadata,
+        CorsConfig,
+        RateLimitConfig,
+        SimulationLimitConfig,
+        WebSocketKeepAlive,
+        WorkerRetryConfig,
+    },
     producer::Config as ProducerConfig,
     service::{
-        config::Trigger,
+        config::{
+            CompactionWindow,
+            PeriodicCompactionConfig,
+            PeriodicSnapshotConfig,
+            Trigger,
+        },
+        genesis::DuplicatePolicy,
         Config,
         DbType,
         RelayerConsensusConfig,
@@ -29,10 +49,19 @@ use fuel_core::{
         Config as TxPoolConfig,
     },
     types::{
-        blockchain::primitives::SecretKeyWrapper,
-        fuel_tx::ContractId,
+        blockchain::primitives::{
+            BlockId,
+            SecretKeyWrapper,
+        },
+        fuel_tx::{
+            Address,
+            AssetId,
+            ContractId,
+        },
         fuel_vm::SecretKey,
         secrecy::Secret,
+        services::txpool::PackingStrategy,
+        tai64::Tai64,
     },
 };
 use fuel_core_chain_config::{
@@ -48,9 +77,17 @@ use pyroscope_pprofrs::{
     PprofConfig,
 };
 use std::{
+    collections::HashMap,
     env,
     net,
-    path::PathBuf,
+    num::{
+        NonZeroU32,
+        NonZeroUsize,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
     str::FromStr,
 };
 use tracing::{
@@ -111,19 +148,80 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
+    /// Defines when the database syncs the committed blocks to the disk:
+    /// `always` after each block, `never`, or a number `N` to sync every N blocks.
+    /// Rarer syncs speed up the commits, but on a crash of the OS or a power loss
+    /// the node loses the blocks committed after the last sync. By default, the node
+    /// never syncs and leaves it to the OS.
+    #[clap(long = "db-sync-policy", default_value = "never", env)]
+    pub db_sync_policy: DbSyncPolicy,
+
+    /// Stores identical contract bytecode only once, reducing the size of the database
+    /// for networks with many copies of the same contract.
+    /// The mode is recorded when the database is created and can't be changed later.
+    #[clap(long = "db-deduplicate-bytecode", env)]
+    pub db_deduplicate_bytecode: bool,
+
     /// Snapshot from which to do (re)genesis. Defaults to local testnet configuration.
     #[arg(name = "SNAPSHOT", long = "snapshot", env)]
     pub snapshot: Option<PathBuf>,
 
+    /// The UNIX timestamp in seconds of the genesis block.
+    /// If not set, the genesis block uses the UNIX epoch.
+    #[clap(long = "genesis-timestamp", env)]
+    pub genesis_timestamp: Option<i64>,
+
+    /// The number of threads used to import the genesis state.
+    /// Defaults to the number of available CPUs.
+    #[clap(long = "genesis-workers", env)]
+    pub genesis_workers: Option<NonZeroUsize>,
+
+    /// The expected id of the genesis block. The node fails to start if the genesis block
+    /// of the database or of the snapshot has a different id.
+    #[clap(long = "expected-genesis-id", env)]
+    pub expected_genesis_id: Option<BlockId>,
+
+    /// Dry-runs a minimal transaction after the genesis and before serving.
+    /// The node fails to start if the executor can't execute it, which catches
+    /// the misconfiguration of the executor early.
+    #[clap(long = "executor-self-test", env)]
+    pub executor_self_test: bool,
+
+    /// Imports only the coins owned by these addresses from the snapshot.
+    /// It is used to create the reduced databases for test networks.
+    /// If not set, all coins are imported.
+    #[clap(long = "genesis-coin-owners", value_delimiter = ',', env)]
+    pub genesis_coin_owners: Option<Vec<Address>>,
+
+    /// Defines how the genesis import treats the entries of the snapshot that already
+    /// exist in the database: `error` fails the import, `skip` keeps the existing entry,
+    /// and `overwrite` replaces it. It is used to merge the snapshots.
+    #[clap(long = "genesis-duplicate-policy", default_value = "error", env)]
+    pub genesis_duplicate_policy: DuplicatePolicy,
+
+    /// The maximum length of the data of the messages imported from the snapshot.
+    /// Defaults to the limit of the consensus parameters.
+    #[clap(long = "max-message-data-length", env)]
+    pub max_message_data_length: Option<u64>,
+
     /// Prunes the db. Genesis is done from the provided snapshot or the local testnet
     /// configuration.
     #[arg(name = "DB_PRUNE", long = "db-prune", env, default_value = "false")]
     pub db_prune: bool,
 
+    /// Prunes the relayer database, keeping the rest of the databases.
+    /// The relayer re-syncs the events from the DA layer starting at
+    /// `relayer-da-deploy-height`. Until the relayer catches up, the node doesn't
+    /// produce or import blocks that depend on the events from the DA layer.
+    #[arg(long = "relayer-db-prune", env, default_value = "false")]
+    pub relayer_db_prune: bool,
+
     /// Should be used for local development only. Enabling debug mode:
     /// - Allows GraphQL Endpoints to arbitrarily advance blocks.
     /// - Enables debugger GraphQL Endpoints.
     /// - Allows setting `utxo_validation` to `false`.
+    /// - Allows overriding the max call depth of the VM.
+    /// - Allows overriding the max number of inputs and outputs of the transaction.
     #[arg(long = "debug", env)]
     pub debug: bool,
 
@@ -131,6 +229,51 @@ pub struct Command {
     #[arg(long = "vm-backtrace", env)]
     pub vm_backtrace: bool,
 
+    /// Overrides the maximum depth of nested contract calls.
+    /// Transactions exceeding it are rejected. Requires `debug` to be enabled.
+    #[arg(long = "debug-max-call-depth", requires = "debug", env)]
+    pub debug_max_call_depth: Option<u64>,
+
+    /// Overrides the maximum number of inputs of the transaction.
+    /// Transactions exceeding it are rejected. Requires `debug` to be enabled.
+    #[arg(long = "debug-max-inputs", requires = "debug", env)]
+    pub debug_max_inputs: Option<u16>,
+
+    /// Overrides the maximum number of outputs of the transaction.
+    /// Transactions exceeding it are rejected. Requires `debug` to be enabled.
+    #[arg(long = "debug-max-outputs", requires = "debug", env)]
+    pub debug_max_outputs: Option<u16>,
+
+    /// The maximum wall-clock time of the execution of the transaction.
+    /// Transactions exceeding it fail, and their changes are reverted. It is not checked
+    /// during the validation of blocks. Requires `debug` to be enabled.
+    #[arg(long = "debug-max-tx-execution-time", requires = "debug", env)]
+    pub debug_max_tx_execution_time: Option<humantime::Duration>,
+
+    /// The maximum bytes of the VM memory occupied by the stack and the heap of the transaction.
+    /// Transactions exceeding it fail, and their changes are reverted. It is not checked
+    /// during the validation of blocks. Requires `debug` to be enabled.
+    #[arg(long = "debug-max-tx-memory", requires = "debug", env)]
+    pub debug_max_tx_memory: Option<u64>,
+
+    /// Records the storage keys read and written by each transaction during the dry run
+    /// and returns them in the `storageAccess` of the dry run result.
+    /// It slows down the dry run.
+    #[arg(long = "dry-run-storage-access", env)]
+    pub dry_run_storage_access: bool,
+
+    /// The number of workers used to execute independent transactions
+    /// of the imported block in parallel.
+    /// If not set, transactions are executed sequentially.
+    #[arg(long = "parallel-execution-workers", env)]
+    pub parallel_execution_workers: Option<NonZeroUsize>,
+
+    /// The number of threads dedicated to the execution of produced and imported blocks.
+    /// It isolates the execution from the GraphQL queries and dry runs.
+    /// If not set, the execution shares the threads with other workloads.
+    #[arg(long = "dedicated-execution-threads", env)]
+    pub dedicated_execution_threads: Option<NonZeroUsize>,
+
     /// Enable full utxo stateful validation
     /// disabled by default until downstream consumers stabilize
     #[arg(long = "utxo-validation", env)]
@@ -174,12 +317,22 @@ pub struct Command {
     #[arg(long = "metrics", env)]
     pub metrics: bool,
 
+    /// The address of the dedicated listener for Prometheus metrics.
+    /// If not set, metrics are served by the API at `/v1/metrics`.
+    #[arg(long = "metrics-addr", env)]
+    pub metrics_addr: Option<net::SocketAddr>,
+
     #[clap(long = "verify-max-da-lag", default_value = "10", env)]
     pub max_da_lag: u64,
 
     #[clap(long = "verify-max-relayer-wait", default_value = "30s", env)]
     pub max_wait_time: humantime::Duration,
 
+    /// The maximum time the timestamp of the imported block can be ahead of the local time.
+    /// If not set, the timestamp is not checked against the local time.
+    #[clap(long = "verify-max-timestamp-drift", env)]
+    pub max_timestamp_drift: Option<humantime::Duration>,
+
     /// The number of reserved peers to connect to before starting to sync.
     #[clap(long = "min-connected-reserved-peers", default_value = "0", env)]
     pub min_connected_reserved_peers: usize,
@@ -188,18 +341,271 @@ pub struct Command {
     #[clap(long = "time-until-synced", default_value = "0s", env)]
     pub time_until_synced: humantime::Duration,
 
+    /// The number of connected peers required to produce blocks.
+    /// The production is paused while the node is connected to fewer peers.
+    /// The manual production requests are rejected while it is paused.
+    #[cfg(feature = "p2p")]
+    #[clap(long = "min-peers-for-production", default_value = "0", env)]
+    pub min_peers_for_production: usize,
+
+    /// The maximum number of blocks the node may revert to switch to a fork.
+    /// Deeper forks are rejected and require manual intervention.
+    #[clap(long = "max-reorg-depth", env)]
+    pub max_reorg_depth: Option<u32>,
+
+    /// The maximum size of the on-chain database in bytes.
+    /// When the database exceeds it, the node enters the read-only mode and rejects new blocks.
+    /// The size is measured after the database is pruned by the `--db-prune`.
+    #[clap(long = "max-db-size", env)]
+    pub max_db_size: Option<u64>,
+
+    /// The policy for transactions that don't fit into the remaining gas of the produced block.
+    /// `skip` packs smaller transactions instead, `stop` preserves the strict priority order.
+    #[clap(
+        long = "block-packing-strategy",
+        default_value = "skip",
+        value_enum,
+        env
+    )]
+    pub block_packing_strategy: BlockPackingStrategy,
+
+    /// The number of times the `skip` strategy can skip a transaction
+    /// before forcing it into the next block.
+    #[clap(
+        long = "block-packing-max-skips",
+        default_value_t = PackingStrategy::DEFAULT_MAX_SKIPS,
+        env
+    )]
+    pub block_packing_max_skips: u32,
+
+    /// The max number of transactions in the produced block besides the `Mint` transaction.
+    /// The block is closed at this number even if it has gas left.
+    /// If not set, the number is limited only by the block gas limit.
+    #[clap(long = "max-tx-per-block", env)]
+    pub max_tx_per_block: Option<u16>,
+
+    /// The max number of outputs created by the transactions of the produced block
+    /// besides the `Mint` transaction. The block is closed at the first transaction
+    /// exceeding it. If not set, the number of outputs is not limited.
+    #[clap(long = "max-outputs-per-block", env)]
+    pub max_outputs_per_block: Option<u32>,
+
+    /// The max total serialized size of the transactions of the block besides the `Mint`
+    /// transaction. The produced block is closed at the first transaction exceeding it,
+    /// and the imported blocks exceeding it are rejected. If not set, the size is not limited.
+    #[clap(long = "max-block-size-bytes", env)]
+    pub max_block_size_bytes: Option<u64>,
+
+    /// Skips the production of empty blocks in the interval mode. A block is still
+    /// produced if no blocks were produced during this time.
+    #[clap(long = "poa-max-idle-time", env)]
+    pub max_idle_time: Option<humantime::Duration>,
+
     /// Time to wait after submitting a query before debug info will be logged about query.
     #[clap(long = "query-log-threshold-time", default_value = "2s", env)]
     pub query_log_threshold_time: humantime::Duration,
 
+    /// Exports the state snapshot after importing each block with the height divisible
+    /// by the interval. If not set, the snapshots are not created.
+    #[clap(long = "snapshot-interval", requires = "snapshot_directory", env)]
+    pub snapshot_interval: Option<NonZeroU32>,
+
+    /// The directory of the periodic snapshots.
+    /// Each snapshot is written into its own `snapshot_<height>` sub-directory.
+    #[clap(long = "snapshot-directory", env)]
+    pub snapshot_directory: Option<PathBuf>,
+
+    /// The number of the most recent periodic snapshots to keep.
+    #[clap(long = "snapshot-retention", default_value = "3", env)]
+    pub snapshot_retention: NonZeroUsize,
+
+    /// Compacts the database after importing every `N` blocks to reduce
+    /// the read amplification. With the `--db-compaction-window`, the compaction
+    /// waits for the window.
+    /// Each compaction rewrites every column of every database, which takes
+    /// a lot of disk IO and time on the big databases.
+    #[clap(long = "db-compaction-interval", env)]
+    pub db_compaction_interval: Option<NonZeroU32>,
+
+    /// The window of the UTC hours `<start_hour>-<end_hour>`, like `22-4`, for the
+    /// compaction of the database. Without the `--db-compaction-interval`, the
+    /// database is compacted once per window.
+    #[clap(long = "db-compaction-window", env)]
+    pub db_compaction_window: Option<CompactionWindow>,
+
     /// Timeout before drop the request.
     #[clap(long = "api-request-timeout", default_value = "30m", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The number of requests per second allowed for each client IP.
+    /// If not set, requests are not limited.
+    #[clap(long = "api-rate-limit", env)]
+    pub api_rate_limit: Option<NonZeroU32>,
+
+    /// The number of requests each client IP can do at once before being limited.
+    /// Defaults to the `api-rate-limit`.
+    #[clap(long = "api-rate-limit-burst", requires = "api_rate_limit", env)]
+    pub api_rate_limit_burst: Option<NonZeroU32>,
+
+    /// The list of client IPs exempt from the rate limiting.
+    #[clap(long = "api-rate-limit-allowlist", value_delimiter = ',', env)]
+    pub api_rate_limit_allowlist: Vec<net::IpAddr>,
+
+    /// Rejects introspection queries of the GraphQL schema.
+    /// It is recommended for production nodes.
+    #[clap(long = "api-disable-introspection", env)]
+    pub api_disable_introspection: bool,
+
+    /// Rejects all GraphQL mutations and the subscriptions submitting transactions,
+    /// leaving only queries and other subscriptions.
+    /// Useful for public read-only endpoints.
+    #[clap(long = "api-disable-mutations", env)]
+    pub api_disable_mutations: bool,
+
+    /// The interval of TCP keep-alive probes used to detect dead API clients.
+    /// If not set, the probes are disabled.
+    #[clap(long = "api-keep-alive", env)]
+    pub api_keep_alive: Option<humantime::Duration>,
+
+    /// Closes API connections that don't send a new request during this time.
+    /// If not set, idle connections are kept open.
+    #[clap(long = "api-idle-timeout", env)]
+    pub api_idle_timeout: Option<humantime::Duration>,
+
+    /// The interval of the pings sent to the clients of the WebSocket subscriptions.
+    /// If not set, the WebSockets stay open until the client closes them.
+    #[clap(long = "api-websocket-ping-interval", env)]
+    pub api_websocket_ping_interval: Option<humantime::Duration>,
+
+    /// Closes the WebSocket of the client that doesn't answer the ping during this time.
+    #[clap(
+        long = "api-websocket-pong-timeout",
+        default_value = "10s",
+        requires = "api_websocket_ping_interval",
+        env
+    )]
+    pub api_websocket_pong_timeout: humantime::Duration,
+
+    /// The time given to the in-flight API requests to complete during the shutdown.
+    /// The API stops accepting new connections during this time,
+    /// and the requests still running after it are cancelled.
+    #[clap(long = "api-shutdown-grace-period", default_value = "5s", env)]
+    pub api_shutdown_grace_period: humantime::Duration,
+
+    /// The number of confirmations after which the API reports the transaction as finalized.
+    /// The block including the transaction is the first confirmation.
+    #[clap(long = "api-finality-depth", default_value = "1", env)]
+    pub api_finality_depth: u32,
+
+    /// The maximum number of distinct assets processed by one coin selection or balances query.
+    #[clap(long = "api-max-assets-per-query", default_value = "1000", env)]
+    pub api_max_assets_per_query: usize,
+
+    /// The maximum number of receipts of one transaction returned by the transaction status.
+    /// The receipts of transactions above the limit can be fetched with the paginated
+    /// `receipts` query. If not set, the receipts are not limited.
+    #[clap(long = "api-max-receipts-per-transaction", env)]
+    pub api_max_receipts_per_transaction: Option<usize>,
+
+    /// The origins allowed to make cross-origin requests to the API.
+    /// The `*` allows any origin. If not set, cross-origin requests are not allowed.
+    #[clap(long = "api-cors-allowed-origins", value_delimiter = ',', env)]
+    pub api_cors_allowed_origins: Vec<String>,
+
+    /// The HTTP methods allowed for cross-origin requests to the API.
+    #[clap(
+        long = "api-cors-allowed-methods",
+        value_delimiter = ',',
+        default_value = "GET,POST",
+        env
+    )]
+    pub api_cors_allowed_methods: Vec<String>,
+
+    /// The headers allowed in cross-origin requests to the API.
+    #[clap(
+        long = "api-cors-allowed-headers",
+        value_delimiter = ',',
+        default_value = "content-type",
+        env
+    )]
+    pub api_cors_allowed_headers: Vec<String>,
+
+    /// Allows cross-origin requests with credentials to the API.
+    /// Can't be used together with the `*` wildcard.
+    #[clap(long = "api-cors-allow-credentials", env)]
+    pub api_cors_allow_credentials: bool,
+
+    /// The number of dry-runs and block simulations the API executes at once.
+    /// If not set, simulations are not limited.
+    #[clap(long = "api-max-concurrent-simulations", env)]
+    pub api_max_concurrent_simulations: Option<NonZeroUsize>,
+
+    /// How long a simulation above the `api-max-concurrent-simulations` waits
+    /// for a free slot before it is rejected.
+    #[clap(
+        long = "api-simulation-queue-timeout",
+        default_value = "0s",
+        requires = "api_max_concurrent_simulations",
+        env
+    )]
+    pub api_simulation_queue_timeout: humantime::Duration,
+
+    /// The path to the JSON file with the display metadata of the assets returned by
+    /// the `assets` query, e.g. `{"<asset id>": {"symbol": "USDC", "name": "USD Coin", "decimals": 6}}`.
+    #[clap(long = "api-asset-metadata", env)]
+    pub api_asset_metadata: Option<PathBuf>,
+
+    /// The symbol of the base asset of the chain returned by the `assets` query.
+    /// Requires the name and the decimals of the base asset.
+    /// Overrides the base asset in the `api-asset-metadata` file.
+    #[clap(long = "api-base-asset-symbol", requires = "api_base_asset_name", env)]
+    pub api_base_asset_symbol: Option<String>,
+
+    /// The name of the base asset of the chain returned by the `assets` query.
+    #[clap(
+        long = "api-base-asset-name",
+        requires = "api_base_asset_decimals",
+        env
+    )]
+    pub api_base_asset_name: Option<String>,
+
+    /// The number of decimals of the base asset of the chain returned by the `assets` query.
+    #[clap(
+        long = "api-base-asset-decimals",
+        requires = "api_base_asset_symbol",
+        env
+    )]
+    pub api_base_asset_decimals: Option<u8>,
+
+    /// The number of imported blocks the GraphQL worker prepares for indexing concurrently.
+    /// Speeds up the catch-up of the off-chain indexes after downtime.
+    #[clap(long = "graphql-worker-parallelism", default_value = "1", env)]
+    pub graphql_worker_parallelism: usize,
+
+    /// The number of retries of the failed write of the off-chain indexes by the GraphQL worker.
+    /// The worker halts if all retries fail, to not leave a gap in the indexes.
+    #[clap(long = "graphql-worker-write-retries", default_value = "5", env)]
+    pub graphql_worker_write_retries: u32,
+
+    /// The delay before the first retry of the failed write of the off-chain indexes.
+    /// The delay doubles after each retry.
+    #[clap(
+        long = "graphql-worker-write-retry-backoff",
+        default_value = "100ms",
+        env
+    )]
+    pub graphql_worker_write_retry_backoff: humantime::Duration,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum BlockPackingStrategy {
+    Skip,
+    Stop,
+}
+
 impl Command {
     pub fn get_config(self) -> anyhow::Result<Config> {
         let Command {
@@ -209,9 +615,27 @@ impl Command {
             max_database_cache_size,
             database_path,
             database_type,
+            db_sync_policy,
+            db_deduplicate_bytecode,
             db_prune,
+            relayer_db_prune: _,
             snapshot,
+            genesis_timestamp,
+            genesis_workers,
+            expected_genesis_id,
+            executor_self_test,
+            genesis_coin_owners,
+            genesis_duplicate_policy,
+            max_message_data_length,
             vm_backtrace,
+            debug_max_call_depth,
+            debug_max_inputs,
+            debug_max_outputs,
+            debug_max_tx_execution_time,
+            debug_max_tx_memory,
+            dry_run_storage_access,
+            parallel_execution_workers,
+            dedicated_execution_threads,
             debug,
             utxo_validation,
             min_gas_price,
@@ -225,18 +649,77 @@ impl Command {
             #[cfg(feature = "p2p")]
             sync_args,
             metrics,
+            metrics_addr,
             max_da_lag,
             max_wait_time,
+            max_timestamp_drift,
             tx_pool,
             min_connected_reserved_peers,
             time_until_synced,
+            #[cfg(feature = "p2p")]
+            min_peers_for_production,
+            max_reorg_depth,
+            max_db_size,
+            block_packing_strategy,
+            block_packing_max_skips,
+            max_tx_per_block,
+            max_outputs_per_block,
+            max_block_size_bytes,
+            max_idle_time,
             query_log_threshold_time,
+            snapshot_interval,
+            snapshot_directory,
+            snapshot_retention,
+            db_compaction_interval,
+            db_compaction_window,
             api_request_timeout,
+            api_rate_limit,
+            api_rate_limit_burst,
+            api_rate_limit_allowlist,
+            api_disable_introspection,
+            api_disable_mutations,
+            api_keep_alive,
+            api_idle_timeout,
+            api_websocket_ping_interval,
+            api_websocket_pong_timeout,
+            api_shutdown_grace_period,
+            api_finality_depth,
+            api_max_assets_per_query,
+            api_max_receipts_per_transaction,
+            api_cors_allowed_origins,
+            api_cors_allowed_methods,
+            api_cors_allowed_headers,
+            api_cors_allow_credentials,
+            api_max_concurrent_simulations,
+            api_simulation_queue_timeout,
+            api_asset_metadata,
+            api_base_asset_symbol,
+            api_base_asset_name,
+            api_base_asset_decimals,
+            graphql_worker_parallelism,
+            graphql_worker_write_retries,
+            graphql_worker_write_retry_backoff,
             profiling: _,
         } = self;
 
         let addr = net::SocketAddr::new(ip, port);
 
+        let periodic_snapshot = match (snapshot_interval, snapshot_directory) {
+            (Some(interval), Some(directory)) => Some(PeriodicSnapshotConfig {
+                interval,
+                directory,
+                retention: snapshot_retention,
+            }),
+            _ => None,
+        };
+
+        let periodic_compaction = (db_compaction_interval.is_some()
+            || db_compaction_window.is_some())
+        .then_some(PeriodicCompactionConfig {
+            every_blocks: db_compaction_interval,
+            window: db_compaction_window,
+        });
+
         let snapshot_reader = match snapshot.as_ref() {
             None => crate::cli::local_testnet_reader(),
             Some(path) => {
@@ -295,10 +778,13 @@ impl Command {
             database_path,
             database_type,
             max_database_cache_size,
+            sync_policy: db_sync_policy,
+            deduplicate_bytecode: db_deduplicate_bytecode,
         };
 
-        let block_importer =
+        let mut block_importer =
             fuel_core::service::config::fuel_core_importer::Config::new(&chain_config);
+        block_importer.max_db_size = max_db_size;
 
         let TxPoolArgs {
             tx_pool_ttl,
@@ -309,6 +795,15 @@ impl Command {
             tx_blacklist_coins,
             tx_blacklist_messages,
             tx_blacklist_contracts,
+            tx_reject_unknown_contracts,
+            tx_max_gas_per_predicate,
+            tx_max_predicate_gas_per_tx,
+            tx_relay_rejected_transactions,
+            tx_persist_transactions,
+            tx_min_replacement_tip_bump,
+            tx_unrelayed_message_hold_time,
+            tx_max_predicate_inputs,
+            tx_validation_cache,
         } = tx_pool;
 
         let blacklist = BlackList::new(
@@ -318,18 +813,101 @@ impl Command {
             tx_blacklist_contracts,
         );
         let block_gas_limit = chain_config.consensus_parameters.block_gas_limit();
+        let packing_strategy = match block_packing_strategy {
+            BlockPackingStrategy::Skip => PackingStrategy::Skip {
+                max_skips: block_packing_max_skips,
+            },
+            BlockPackingStrategy::Stop => PackingStrategy::Stop,
+        };
+
+        let api_rate_limit = api_rate_limit.map(|requests_per_second| RateLimitConfig {
+            requests_per_second,
+            burst: api_rate_limit_burst.unwrap_or(requests_per_second),
+            allowlist: api_rate_limit_allowlist,
+        });
+
+        let api_base_asset_metadata = match (
+            api_base_asset_symbol,
+            api_base_asset_name,
+            api_base_asset_decimals,
+        ) {
+            (Some(symbol), Some(name), Some(decimals)) => Some(AssetMetadata {
+                symbol,
+                name,
+                decimals,
+            }),
+            _ => None,
+        };
+
+        let api_simulation_limit =
+            api_max_concurrent_simulations.map(|max_concurrent| SimulationLimitConfig {
+                max_concurrent,
+                queue_timeout: api_simulation_queue_timeout.into(),
+            });
+
+        let api_cors = CorsConfig {
+            allowed_origins: api_cors_allowed_origins,
+            allowed_methods: api_cors_allowed_methods,
+            allowed_headers: api_cors_allowed_headers,
+            allow_credentials: api_cors_allow_credentials,
+        };
 
         let config = Config {
             addr,
+            metrics_addr,
             api_request_timeout: api_request_timeout.into(),
+            api_rate_limit,
+            api_enable_introspection: !api_disable_introspection,
+            api_disable_mutations,
+            api_keep_alive: api_keep_alive.map(Into::into),
+            api_idle_timeout: api_idle_timeout.map(Into::into),
+            api_websocket_keep_alive: api_websocket_ping_interval.map(|ping_interval| {
+                WebSocketKeepAlive {
+                    ping_interval: ping_interval.into(),
+                    pong_timeout: api_websocket_pong_timeout.into(),
+                }
+            }),
+            api_shutdown_grace_period: api_shutdown_grace_period.into(),
+            api_finality_depth,
+            api_max_assets_per_query,
+            api_max_receipts_per_transaction,
+            api_cors,
+            api_simulation_limit,
+            api_asset_metadata: api_asset_metadata
+                .map(|path| load_asset_metadata(&path))
+                .transpose()?
+                .unwrap_or_default(),
+            api_base_asset_metadata,
+            graphql_worker_parallelism,
+            graphql_worker_retry: WorkerRetryConfig {
+                max_retries: graphql_worker_write_retries,
+                initial_backoff: graphql_worker_write_retry_backoff.into(),
+            },
             combined_db_config,
             snapshot_reader,
+            genesis_timestamp: genesis_timestamp.map(Tai64::from_unix),
+            genesis_workers,
+            expected_genesis_id,
+            executor_self_test,
+            genesis_coin_owners: genesis_coin_owners
+                .map(|owners| owners.into_iter().collect()),
+            genesis_transformations: Default::default(),
+            genesis_duplicate_policy,
+            max_message_data_length,
             debug,
             utxo_validation,
             block_production: trigger,
             vm: VMConfig {
                 backtrace: vm_backtrace,
+                max_call_depth: debug_max_call_depth,
+                max_inputs: debug_max_inputs,
+                max_outputs: debug_max_outputs,
+                max_tx_execution_time: debug_max_tx_execution_time.map(Into::into),
+                max_tx_memory: debug_max_tx_memory,
+                record_storage_access: dry_run_storage_access,
             },
+            parallel_execution_workers,
+            dedicated_execution_threads,
             txpool: TxPoolConfig::new(
                 tx_max_number,
                 tx_max_depth,
@@ -339,15 +917,30 @@ impl Command {
                 tx_pool_ttl.into(),
                 tx_number_active_subscriptions,
                 blacklist,
+                tx_reject_unknown_contracts,
+                tx_max_gas_per_predicate,
+                tx_max_predicate_gas_per_tx,
+                tx_relay_rejected_transactions,
+                tx_persist_transactions,
+                tx_min_replacement_tip_bump,
+                tx_unrelayed_message_hold_time.map(Into::into),
+                tx_max_predicate_inputs,
             ),
+            tx_validation_cache,
             block_producer: ProducerConfig {
                 utxo_validation,
                 coinbase_recipient,
                 metrics,
                 block_gas_limit,
+                packing_strategy,
+                max_tx_per_block,
+                max_outputs_per_block,
+                max_block_size_bytes,
+                execution_pool: None,
             },
             static_gas_price: min_gas_price,
             block_importer,
+            max_reorg_depth,
             #[cfg(feature = "relayer")]
             relayer: relayer_cfg,
             #[cfg(feature = "p2p")]
@@ -357,9 +950,16 @@ impl Command {
             consensus_key,
             name,
             relayer_consensus_config: verifier,
+            max_timestamp_drift: max_timestamp_drift.map(Into::into),
+            max_block_size_bytes,
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
+            #[cfg(feature = "p2p")]
+            min_peers_for_production,
+            max_idle_time: max_idle_time.map(Into::into),
             query_log_threshold_time: query_log_threshold_time.into(),
+            periodic_snapshot,
+            periodic_compaction,
         };
         Ok(config)
     }
@@ -371,6 +971,13 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
         fuel_core::combined_database::CombinedDatabase::prune(&command.database_path)?;
     }
 
+    #[cfg(any(feature = "rocks-db", feature = "rocksdb-production"))]
+    if command.relayer_db_prune && command.database_path.exists() {
+        fuel_core::combined_database::CombinedDatabase::prune_relayer(
+            &command.database_path,
+        )?;
+    }
+
     let profiling = command.profiling.clone();
     let config = command.get_config()?;
 
@@ -395,6 +1002,13 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn load_asset_metadata(path: &Path) -> anyhow::Result<HashMap<AssetId, AssetMetadata>> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("failed to open the asset metadata file {path:?}"))?;
+    serde_json::from_reader(std::io::BufReader::new(file))
+        .with_context(|| format!("failed to parse the asset metadata file {path:?}"))
+}
+
 // Attempt to load the consensus key from cli arg first, otherwise check the env.
 fn load_consensus_key(
     cli_arg: Option<String>,
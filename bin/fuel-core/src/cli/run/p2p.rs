@@ -5,6 +5,7 @@ use clap::{
 };
 use fuel_core::{
     p2p::{
+        codecs::postcard::MessageExchangePostcardProtocol,
         config::{
             convert_to_libp2p_keypair,
             Config,
@@ -71,6 +72,15 @@ pub struct P2PArgs {
     #[clap(long = "bootstrap-nodes", value_delimiter = ',', env)]
     pub bootstrap_nodes: Vec<Multiaddr>,
 
+    /// DNS seeds in the `host:port` format
+    /// They are resolved into the addresses of the peers to dial at startup and periodically after
+    #[clap(long = "dns-seeds", value_delimiter = ',', env)]
+    pub dns_seeds: Vec<String>,
+
+    /// The interval between resolutions of the DNS seeds in seconds
+    #[clap(long = "dns-seeds-refresh-interval", default_value = "300", env)]
+    pub dns_seeds_refresh_interval: u64,
+
     /// Addresses of the reserved nodes
     /// They should contain PeerId within their `Multiaddr`
     #[clap(long = "reserved-nodes", value_delimiter = ',', env)]
@@ -80,6 +90,24 @@ pub struct P2PArgs {
     #[clap(long = "reserved-nodes-only-mode", env)]
     pub reserved_nodes_only_mode: bool,
 
+    /// The delay between the first and the second attempts to reconnect to the disconnected
+    /// reserved node. The first attempt happens right after the disconnect.
+    #[clap(
+        long = "reserved-nodes-reconnect-initial-backoff",
+        default_value = "1s",
+        env
+    )]
+    pub reserved_nodes_reconnect_initial_backoff: humantime::Duration,
+
+    /// The delay between the attempts to reconnect to the disconnected reserved node
+    /// doubles after each attempt until it reaches this value.
+    #[clap(
+        long = "reserved-nodes-reconnect-max-backoff",
+        default_value = "60s",
+        env
+    )]
+    pub reserved_nodes_reconnect_max_backoff: humantime::Duration,
+
     /// Allow nodes to be discoverable on the local network
     #[clap(long = "enable-mdns", env)]
     pub enable_mdns: bool,
@@ -95,6 +123,12 @@ pub struct P2PArgs {
     #[clap(long = "max-connections-per-peer", default_value = "3", env)]
     pub max_connections_per_peer: u32,
 
+    /// Max number of peers connected in total, including the reserved nodes.
+    /// New inbound connections are refused past the limit, and a reserved node
+    /// connecting past the limit replaces the non-reserved peer with the lowest score.
+    #[clap(long = "max-connections", env)]
+    pub max_connections: Option<u32>,
+
     /// Set the delay between random walks for p2p node discovery in seconds.
     /// If it's not set the random walk will be disabled.
     /// Also if `reserved_nodes_only_mode` is set to `true`,
@@ -156,6 +190,11 @@ pub struct P2PArgs {
     #[clap(long = "connection-keep-alive", default_value = "20", env)]
     pub connection_keep_alive: u64,
 
+    /// Prefers the length-delimited format of the RequestResponse messages (`/fuel/req_res/0.0.2`).
+    /// The peers that don't support it fall back to the original format.
+    #[clap(long = "req-res-length-delimited", env)]
+    pub req_res_length_delimited: bool,
+
     /// Sending of `BlockHeight` should not take longer than this duration, in seconds.
     #[clap(long = "heartbeat-send-duration", default_value = "2", env)]
     pub heartbeat_send_duration: u64,
@@ -181,6 +220,11 @@ pub struct P2PArgs {
     /// For peer reputations, the maximum time since last heartbeat before penalty
     #[clap(long = "heartbeat-max-time-since-last", default_value = "40", env)]
     pub heartbeat_max_time_since_last: u64,
+
+    /// For peer reputations, the maximum number of transactions gossiped by a peer per second before penalty.
+    /// The transactions above the limit are ignored.
+    #[clap(long = "max-gossip-transactions-per-peer", default_value = "1000", env)]
+    pub max_gossip_transactions_per_peer: u32,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -191,6 +235,18 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// Keeps the most recent blocks rejected during the sync in memory.
+    /// Rejected blocks are logged regardless of this flag.
+    #[clap(long = "sync-record-rejected-blocks", env)]
+    pub record_rejected_blocks: bool,
+    /// The maximum number of blocks received ahead of their parent, kept until the parent
+    /// is imported, so they are not requested again. `0` disables the buffer.
+    #[clap(long = "sync-orphan-buffer-size", default_value = "100", env)]
+    pub orphan_buffer_size: usize,
+    /// The maximum age of the blocks accepted from the peers once the node is synced.
+    /// The age is not checked during the deep sync. If not set, the age is not checked.
+    #[clap(long = "sync-max-block-age", env)]
+    pub max_block_age: Option<humantime::Duration>,
 }
 
 #[derive(Clone, Debug)]
@@ -223,6 +279,9 @@ impl From<SyncArgs> for fuel_core::sync::Config {
         Self {
             block_stream_buffer_size: value.block_stream_buffer_size,
             header_batch_size: value.header_batch_size as usize,
+            record_rejected_blocks: value.record_rejected_blocks,
+            orphan_buffer_size: value.orphan_buffer_size,
+            max_block_age: value.max_block_age.map(Into::into),
         }
     }
 }
@@ -283,6 +342,15 @@ impl P2PArgs {
             )
         };
 
+        let req_res_protocols = if self.req_res_length_delimited {
+            vec![
+                MessageExchangePostcardProtocol::V2,
+                MessageExchangePostcardProtocol::V1,
+            ]
+        } else {
+            vec![MessageExchangePostcardProtocol::V1]
+        };
+
         let config = Config {
             keypair: local_keypair,
             network_name,
@@ -295,11 +363,22 @@ impl P2PArgs {
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
             bootstrap_nodes: self.bootstrap_nodes,
+            dns_seeds: self.dns_seeds,
+            dns_seeds_refresh_interval: Duration::from_secs(
+                self.dns_seeds_refresh_interval,
+            ),
             reserved_nodes: self.reserved_nodes,
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
+            reserved_nodes_reconnect_initial_backoff: self
+                .reserved_nodes_reconnect_initial_backoff
+                .into(),
+            reserved_nodes_reconnect_max_backoff: self
+                .reserved_nodes_reconnect_max_backoff
+                .into(),
             enable_mdns: self.enable_mdns,
             max_peers_connected: self.max_peers_connected,
             max_connections_per_peer: self.max_connections_per_peer,
+            max_connections: self.max_connections,
             allow_private_addresses: self.allow_private_addresses,
             random_walk,
             connection_idle_timeout: Some(Duration::from_secs(
@@ -309,6 +388,7 @@ impl P2PArgs {
             heartbeat_config,
             set_request_timeout: Duration::from_secs(self.request_timeout),
             set_connection_keep_alive: Duration::from_secs(self.connection_keep_alive),
+            req_res_protocols,
             heartbeat_check_interval: Duration::from_secs(self.heartbeat_check_interval),
             heartbeat_max_avg_interval: Duration::from_secs(
                 self.heartbeat_max_avg_interval,
@@ -316,6 +396,7 @@ impl P2PArgs {
             heartbeat_max_time_since_last: Duration::from_secs(
                 self.heartbeat_max_time_since_last,
             ),
+            max_gossip_transactions_per_peer: self.max_gossip_transactions_per_peer,
             info_interval: Some(Duration::from_secs(self.info_interval)),
             identify_interval: Some(Duration::from_secs(self.identify_interval)),
             metrics,
@@ -40,6 +40,11 @@ pub struct RelayerArgs {
     #[clap(long = "relayer-log-page-size", default_value_t = Config::DEFAULT_LOG_PAGE_SIZE, env)]
     pub log_page_size: u64,
 
+    /// The maximum number of blocks covered by a single log query to the da layer.
+    /// Useful for providers that limit the block range of `eth_getLogs`.
+    #[clap(long = "relayer-max-log-query-range", default_value_t = Config::DEFAULT_MAX_LOG_QUERY_RANGE, env)]
+    pub max_log_query_range: u64,
+
     /// The minimum number of seconds that the relayer polling loop
     /// will take before running again. If this is too low the DA layer
     /// risks being spammed.
@@ -69,6 +74,7 @@ impl RelayerArgs {
             relayer: self.relayer,
             eth_v2_listening_contracts: self.eth_v2_listening_contracts,
             log_page_size: self.log_page_size,
+            max_log_query_range: self.max_log_query_range,
             sync_minimum_duration: Duration::from_secs(self.sync_minimum_duration_secs),
             syncing_call_frequency: Duration::from_secs(self.syncing_call_frequency_secs),
             syncing_log_frequency: Duration::from_secs(self.syncing_log_frequency_secs),
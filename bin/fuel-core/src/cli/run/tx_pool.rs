@@ -42,6 +42,53 @@ pub struct TxPoolArgs {
     /// The list of banned contracts ignored by the `TxPool`.
     #[clap(long = "tx-blacklist-contracts", value_delimiter = ',', env)]
     pub tx_blacklist_contracts: Vec<ContractId>,
+
+    /// Rejects transactions that use contracts not yet deployed on chain,
+    /// including contracts created by other transactions in the `TxPool`.
+    #[clap(long = "tx-reject-unknown-contracts", env)]
+    pub tx_reject_unknown_contracts: bool,
+
+    /// The max gas that a single predicate of the transaction may use.
+    /// By default, the limit from the consensus parameters is used.
+    #[clap(long = "tx-max-gas-per-predicate", env)]
+    pub tx_max_gas_per_predicate: Option<u64>,
+
+    /// The max gas that all predicates of the transaction may use together.
+    /// By default, the limit from the consensus parameters is used.
+    #[clap(long = "tx-max-predicate-gas-per-tx", env)]
+    pub tx_max_predicate_gas_per_tx: Option<u64>,
+
+    /// The max number of predicate inputs of the transaction.
+    /// By default, the limit of inputs from the consensus parameters is used.
+    #[clap(long = "tx-max-predicate-inputs", env)]
+    pub tx_max_predicate_inputs: Option<u16>,
+
+    /// Gossips the valid transactions rejected by the `TxPool` only because it is full.
+    /// It allows relay nodes to forward transactions that they can't store.
+    #[clap(long = "tx-relay-rejected-transactions", env)]
+    pub tx_relay_rejected_transactions: bool,
+
+    /// Stores the pending transactions on shutdown and re-admits the still valid ones
+    /// on the next start of the node.
+    #[clap(long = "tx-persist-transactions", env)]
+    pub tx_persist_transactions: bool,
+
+    /// The minimum increase of the tip required from the transaction to replace
+    /// the pending transaction spending the same inputs.
+    #[clap(long = "tx-min-replacement-tip-bump", default_value = "0", env)]
+    pub tx_min_replacement_tip_bump: u64,
+
+    /// The max time the transaction spending a message not yet imported by the relayer
+    /// is held before it is dropped. Only the transactions submitted to this node
+    /// are held, the gossiped ones are rejected. If not set, such transactions are rejected.
+    #[clap(long = "tx-unrelayed-message-hold-time", env)]
+    pub tx_unrelayed_message_hold_time: Option<humantime::Duration>,
+
+    /// Caches the transactions validated at the admission into the `TxPool`,
+    /// so their predicates and signatures are not checked again when the node
+    /// validates the block that includes them.
+    #[clap(long = "tx-validation-cache", env)]
+    pub tx_validation_cache: bool,
 }
 
 #[cfg(test)]
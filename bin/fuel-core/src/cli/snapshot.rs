@@ -7,39 +7,19 @@ use clap::{
 use fuel_core::{
     chain_config::ChainConfig,
     combined_database::CombinedDatabase,
-    database::{
-        database_description::{
-            off_chain::OffChain,
-            on_chain::OnChain,
-            DatabaseDescription,
-        },
-        Database,
-    },
-    fuel_core_graphql_api::storage::transactions::{
-        OwnedTransactions,
-        TransactionStatuses,
-    },
     types::fuel_types::ContractId,
 };
 use fuel_core_chain_config::{
-    AddTable,
     SnapshotWriter,
-    StateConfigBuilder,
-    TableEntry,
     MAX_GROUP_SIZE,
 };
 use fuel_core_storage::{
-    blueprint::BlueprintInspect,
     iter::IterDirection,
-    structured_storage::TableWithBlueprint,
     tables::{
-        Coins,
         ContractsAssets,
         ContractsLatestUtxo,
         ContractsRawCode,
         ContractsState,
-        Messages,
-        Transactions,
     },
 };
 use itertools::Itertools;
@@ -236,40 +216,8 @@ fn full_snapshot(
     let prev_chain_config = load_chain_config(prev_chain_config)?;
     writer.write_chain_config(&prev_chain_config)?;
 
-    fn write<T, DbDesc>(
-        db: &Database<DbDesc>,
-        group_size: usize,
-        writer: &mut SnapshotWriter,
-    ) -> anyhow::Result<()>
-    where
-        T: TableWithBlueprint<Column = <DbDesc as DatabaseDescription>::Column>,
-        T::Blueprint: BlueprintInspect<T, Database<DbDesc>>,
-        TableEntry<T>: serde::Serialize,
-        StateConfigBuilder: AddTable<T>,
-        DbDesc: DatabaseDescription,
-    {
-        db.entries::<T>(None, IterDirection::Forward)
-            .chunks(group_size)
-            .into_iter()
-            .try_for_each(|chunk| writer.write(chunk.try_collect()?))
-    }
     let group_size = encoding.group_size().unwrap_or(MAX_GROUP_SIZE);
-
-    let db = combined_db.on_chain();
-    write::<Coins, OnChain>(db, group_size, &mut writer)?;
-    write::<Messages, OnChain>(db, group_size, &mut writer)?;
-    write::<ContractsRawCode, OnChain>(db, group_size, &mut writer)?;
-    write::<ContractsLatestUtxo, OnChain>(db, group_size, &mut writer)?;
-    write::<ContractsState, OnChain>(db, group_size, &mut writer)?;
-    write::<ContractsAssets, OnChain>(db, group_size, &mut writer)?;
-    write::<Transactions, OnChain>(db, group_size, &mut writer)?;
-
-    let db = combined_db.off_chain();
-    write::<TransactionStatuses, OffChain>(db, group_size, &mut writer)?;
-    write::<OwnedTransactions, OffChain>(db, group_size, &mut writer)?;
-
-    let block = combined_db.on_chain().latest_block()?;
-    writer.write_block_data(*block.header().height(), block.header().da_height)?;
+    combined_db.write_snapshot(&mut writer, group_size)?;
 
     writer.close()?;
 
@@ -288,9 +236,13 @@ fn load_chain_config(
 }
 
 fn open_db(path: &Path, capacity: Option<usize>) -> anyhow::Result<CombinedDatabase> {
-    CombinedDatabase::open(path, capacity.unwrap_or(1024 * 1024 * 1024))
-        .map_err(Into::<anyhow::Error>::into)
-        .context(format!("failed to open combined database at path {path:?}",))
+    CombinedDatabase::open(
+        path,
+        capacity.unwrap_or(1024 * 1024 * 1024),
+        Default::default(),
+    )
+    .map_err(Into::<anyhow::Error>::into)
+    .context(format!("failed to open combined database at path {path:?}",))
 }
 
 #[cfg(test)]
@@ -298,7 +250,11 @@ mod tests {
 
     use std::iter::repeat_with;
 
-    use fuel_core::fuel_core_graphql_api::storage::transactions::OwnedTransactionIndexKey;
+    use fuel_core::fuel_core_graphql_api::storage::transactions::{
+        OwnedTransactionIndexKey,
+        OwnedTransactions,
+        TransactionStatuses,
+    };
     use fuel_core_chain_config::{
         AddTable,
         AsTable,
@@ -318,6 +274,7 @@ mod tests {
             ContractsState,
             FuelBlocks,
             Messages,
+            Transactions,
         },
         ContractsAssetKey,
         ContractsStateKey,
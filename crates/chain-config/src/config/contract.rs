@@ -49,6 +49,16 @@ pub struct ContractStateConfig {
     pub value: Vec<u8>,
 }
 
+/// The expected root of the state of the contract after the import of the
+/// group of the `ContractsState` table with the `group_index`.
+/// It allows detecting the corruption of large contract states early.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ContractStateChunkRoot {
+    pub contract_id: ContractId,
+    pub group_index: usize,
+    pub root: Bytes32,
+}
+
 impl TryFrom<ContractStateConfig> for StorageSlot {
     type Error = anyhow::Error;
 
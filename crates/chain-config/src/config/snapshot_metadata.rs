@@ -1,3 +1,5 @@
+use crate::ContractStateChunkRoot;
+use fuel_core_types::fuel_types::Bytes32;
 use std::path::{
     Path,
     PathBuf,
@@ -64,6 +66,14 @@ impl TableEncoding {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
 pub struct SnapshotMetadata {
     pub chain_config: PathBuf,
+    /// The expected root of the state after the genesis import.
+    /// If set, the node verifies the imported state against it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_root: Option<Bytes32>,
+    /// The expected roots of the contract states after the import of the chunks
+    /// of the `ContractsState` table. The node verifies each chunk against them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contract_state_roots: Vec<ContractStateChunkRoot>,
     pub table_encoding: TableEncoding,
 }
 
@@ -112,6 +122,8 @@ mod tests {
             let dir = temp_dir.path();
             let data = SnapshotMetadata {
                 chain_config: "some_chain_config.json".into(),
+                state_root: None,
+                contract_state_roots: vec![],
                 table_encoding: TableEncoding::Json {
                     filepath: "some_state_file.json".into(),
                 },
@@ -130,6 +142,8 @@ mod tests {
                 snapshot,
                 SnapshotMetadata {
                     chain_config: dir.join("some_chain_config.json"),
+                    state_root: None,
+                    contract_state_roots: vec![],
                     table_encoding: TableEncoding::Json {
                         filepath: temp_dir.path().join("some_state_file.json"),
                     }
@@ -144,6 +158,8 @@ mod tests {
             let dir = temp_dir.path();
             let snapshot = SnapshotMetadata {
                 chain_config: dir.join("some_chain_config.json"),
+                state_root: None,
+                contract_state_roots: vec![],
                 table_encoding: TableEncoding::Json {
                     filepath: dir.join("some_state_file.json"),
                 },
@@ -161,6 +177,8 @@ mod tests {
                 data,
                 SnapshotMetadata {
                     chain_config: "some_chain_config.json".into(),
+                    state_root: None,
+                    contract_state_roots: vec![],
                     table_encoding: TableEncoding::Json {
                         filepath: "some_state_file.json".into(),
                     }
@@ -179,6 +197,8 @@ mod tests {
             let dir = temp_dir.path();
             let data = SnapshotMetadata {
                 chain_config: "some_chain_config.json".into(),
+                state_root: None,
+                contract_state_roots: vec![],
                 table_encoding: TableEncoding::Parquet {
                     tables: std::collections::HashMap::from_iter(vec![(
                         "coins".into(),
@@ -203,6 +223,8 @@ mod tests {
                 snapshot,
                 SnapshotMetadata {
                     chain_config: dir.join("some_chain_config.json"),
+                    state_root: None,
+                    contract_state_roots: vec![],
                     table_encoding: TableEncoding::Parquet {
                         tables: std::collections::HashMap::from_iter(vec![(
                             "coins".into(),
@@ -223,6 +245,8 @@ mod tests {
             let dir = temp_dir.path();
             let snapshot = SnapshotMetadata {
                 chain_config: dir.join("some_chain_config.json"),
+                state_root: None,
+                contract_state_roots: vec![],
                 table_encoding: TableEncoding::Parquet {
                     tables: std::collections::HashMap::from_iter([(
                         "coins".into(),
@@ -246,6 +270,8 @@ mod tests {
                 data,
                 SnapshotMetadata {
                     chain_config: "some_chain_config.json".into(),
+                    state_root: None,
+                    contract_state_roots: vec![],
                     table_encoding: TableEncoding::Parquet {
                         tables: std::collections::HashMap::from_iter([(
                             "coins".into(),
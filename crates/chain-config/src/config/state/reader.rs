@@ -3,7 +3,10 @@ use std::fmt::Debug;
 use fuel_core_storage::structured_storage::TableWithBlueprint;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
 };
 use itertools::Itertools;
 
@@ -11,6 +14,7 @@ use crate::{
     config::table_entry::TableEntry,
     AsTable,
     ChainConfig,
+    ContractStateChunkRoot,
     Group,
     GroupResult,
     StateConfig,
@@ -84,6 +88,8 @@ enum DataSource {
 pub struct SnapshotReader {
     chain_config: ChainConfig,
     data_source: DataSource,
+    expected_state_root: Option<Bytes32>,
+    expected_contract_state_roots: Vec<ContractStateChunkRoot>,
 }
 
 impl SnapshotReader {
@@ -94,6 +100,8 @@ impl SnapshotReader {
                 state,
                 group_size: MAX_GROUP_SIZE,
             },
+            expected_state_root: None,
+            expected_contract_state_roots: vec![],
         }
     }
 
@@ -107,6 +115,8 @@ impl SnapshotReader {
                 group_size: MAX_GROUP_SIZE,
             },
             chain_config,
+            expected_state_root: None,
+            expected_contract_state_roots: vec![],
         }
     }
 
@@ -117,6 +127,23 @@ impl SnapshotReader {
         }
     }
 
+    pub fn with_expected_state_root(self, expected_state_root: Option<Bytes32>) -> Self {
+        Self {
+            expected_state_root,
+            ..self
+        }
+    }
+
+    pub fn with_expected_contract_state_roots(
+        self,
+        expected_contract_state_roots: Vec<ContractStateChunkRoot>,
+    ) -> Self {
+        Self {
+            expected_contract_state_roots,
+            ..self
+        }
+    }
+
     pub fn with_state_config(self, state_config: StateConfig) -> Self {
         Self {
             data_source: DataSource::InMemory {
@@ -144,6 +171,8 @@ impl SnapshotReader {
         Ok(Self {
             data_source: DataSource::InMemory { state, group_size },
             chain_config,
+            expected_state_root: None,
+            expected_contract_state_roots: vec![],
         })
     }
 
@@ -163,6 +192,8 @@ impl SnapshotReader {
                 da_block_height,
             },
             chain_config,
+            expected_state_root: None,
+            expected_contract_state_roots: vec![],
         })
     }
 
@@ -206,7 +237,7 @@ impl SnapshotReader {
             serde_json::from_reader(&mut file)?
         };
 
-        match snapshot_metadata.table_encoding {
+        let reader = match snapshot_metadata.table_encoding {
             TableEncoding::Json { filepath } => {
                 Self::json(filepath, chain_config, json_group_size)
             }
@@ -217,7 +248,11 @@ impl SnapshotReader {
                 da_block_height,
                 ..
             } => Self::parquet(tables, block_height, da_block_height, chain_config),
-        }
+        }?;
+
+        Ok(reader
+            .with_expected_state_root(snapshot_metadata.state_root)
+            .with_expected_contract_state_roots(snapshot_metadata.contract_state_roots))
     }
 
     pub fn read<T>(&self) -> anyhow::Result<IntoIter<TableEntry<T>>>
@@ -268,6 +303,17 @@ impl SnapshotReader {
         &self.chain_config
     }
 
+    /// The root of the state expected after the genesis import, if known.
+    pub fn expected_state_root(&self) -> Option<Bytes32> {
+        self.expected_state_root
+    }
+
+    /// The roots of the contract states expected after the import of the chunks
+    /// of the `ContractsState` table.
+    pub fn expected_contract_state_roots(&self) -> &[ContractStateChunkRoot] {
+        &self.expected_contract_state_roots
+    }
+
     pub fn block_height(&self) -> BlockHeight {
         match &self.data_source {
             DataSource::InMemory { state, .. } => state.block_height,
@@ -317,6 +317,8 @@ impl SnapshotWriter {
     ) -> anyhow::Result<SnapshotMetadata> {
         let metadata = SnapshotMetadata {
             chain_config: dir.join(Self::CHAIN_CONFIG_FILENAME),
+            state_root: None,
+            contract_state_roots: vec![],
             table_encoding,
         };
         metadata.clone().write(dir)?;
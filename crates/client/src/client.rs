@@ -1,14 +1,27 @@
 use crate::client::{
     schema::{
-        block::BlockByHeightArgs,
+        block::{
+            BlockByHeightArgs,
+            BlockHeadersArgs,
+            TransactionInclusionProofArgs,
+        },
         coins::{
             ExcludeInput,
             SpendQueryElementInput,
         },
-        contract::ContractBalanceQueryArgs,
-        gas_price::EstimateGasPrice,
+        contract::{
+            ContractBalanceQueryArgs,
+            ContractStorageSlotArgs,
+        },
+        gas_price::{
+            EstimateGasPrice,
+            RequiredGasPrice,
+        },
         message::MessageStatusArgs,
-        tx::DryRunArg,
+        tx::{
+            DryRunArg,
+            SimulateBlockArg,
+        },
         Tai64Timestamp,
         TransactionId,
     },
@@ -19,6 +32,7 @@ use crate::client::{
             Address,
             AssetId,
             BlockId,
+            Bytes32,
             ContractId,
             UtxoId,
         },
@@ -363,6 +377,13 @@ impl FuelClient {
         self.query(query).await.map(|r| r.estimate_gas_price)
     }
 
+    /// Returns the gas price and the tip required
+    /// for the admission of a new transaction into the `TxPool`.
+    pub async fn required_gas_price(&self) -> io::Result<RequiredGasPrice> {
+        let query = schema::gas_price::QueryRequiredGasPrice::build(());
+        self.query(query).await.map(|r| r.required_gas_price)
+    }
+
     pub async fn connected_peers_info(&self) -> io::Result<Vec<PeerInfo>> {
         let query = schema::node_info::QueryPeersInfo::build(());
         self.query(query)
@@ -370,6 +391,24 @@ impl FuelClient {
             .map(|r| r.node_info.peers.into_iter().map(Into::into).collect())
     }
 
+    /// Returns the messages and forced transactions from the DA layer
+    /// that are not included into blocks yet.
+    pub async fn pending_da_events(&self) -> io::Result<Vec<types::PendingDaEvent>> {
+        let query = schema::relayer::QueryPendingDaEvents::build(());
+        self.query(query)
+            .await
+            .map(|r| r.pending_da_events.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the progress of the relayer syncing with the DA layer,
+    /// or `None` if the relayer is disabled.
+    pub async fn relayer_status(&self) -> io::Result<Option<types::RelayerStatus>> {
+        let query = schema::relayer::QueryRelayerStatus::build(());
+        self.query(query)
+            .await
+            .map(|r| r.relayer_status.map(Into::into))
+    }
+
     pub async fn chain_info(&self) -> io::Result<types::ChainInfo> {
         let query = schema::chain::ChainQuery::build(());
         self.query(query).await.and_then(|r| {
@@ -378,6 +417,24 @@ impl FuelClient {
         })
     }
 
+    /// Returns the consensus parameters along with the limits enforced by the node
+    /// after applying the local overrides.
+    pub async fn effective_consensus_parameters(
+        &self,
+    ) -> io::Result<types::EffectiveConsensusParameters> {
+        let query = schema::chain::EffectiveConsensusParametersQuery::build(());
+        self.query(query).await.and_then(|r| {
+            let result = r.effective_consensus_parameters.try_into()?;
+            Ok(result)
+        })
+    }
+
+    /// Returns the chain config the node started with, serialized as JSON.
+    pub async fn chain_config(&self) -> io::Result<String> {
+        let query = schema::chain::ChainConfigQuery::build(());
+        self.query(query).await.map(|r| r.chain_config)
+    }
+
     /// Default dry run, matching the exact configuration as the node
     pub async fn dry_run(
         &self,
@@ -409,6 +466,74 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry runs the transactions and returns the gas consumed by each opcode of the scripts.
+    /// The breakdown is `None` for non-script transactions or if the node
+    /// doesn't have `debug` and `vm_backtrace` enabled.
+    pub async fn dry_run_gas_breakdown(
+        &self,
+        txs: &[Transaction],
+    ) -> io::Result<Vec<Option<Vec<types::OpcodeGas>>>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query = schema::tx::DryRunWithGasBreakdown::build(DryRunArg {
+            txs,
+            utxo_validation: None,
+        });
+        let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
+        let breakdowns = tx_statuses
+            .into_iter()
+            .map(|tx_status| {
+                tx_status
+                    .gas_breakdown
+                    .map(|breakdown| breakdown.into_iter().map(Into::into).collect())
+            })
+            .collect();
+        Ok(breakdowns)
+    }
+
+    /// Dry runs the transactions and returns the storage keys accessed by each of them.
+    /// The storage access is `None` if the node doesn't record it.
+    pub async fn dry_run_storage_access(
+        &self,
+        txs: &[Transaction],
+    ) -> io::Result<Vec<Option<types::StorageAccess>>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query = schema::tx::DryRunWithStorageAccess::build(DryRunArg {
+            txs,
+            utxo_validation: None,
+        });
+        let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
+        let storage_access = tx_statuses
+            .into_iter()
+            .map(|tx_status| tx_status.storage_access.map(Into::into))
+            .collect();
+        Ok(storage_access)
+    }
+
+    /// Simulates the block with `txs` on top of the latest block without committing it.
+    /// The `block_time` is the timestamp of the block in seconds; the current time if not set.
+    pub async fn simulate_block(
+        &self,
+        txs: &[Transaction],
+        block_time: Option<u64>,
+    ) -> io::Result<types::SimulatedBlock> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query = schema::tx::SimulateBlock::build(SimulateBlockArg {
+            txs,
+            block_time: block_time.map(|time| Tai64Timestamp::from(Tai64(time))),
+        });
+        let simulated_block = self.query(query).await.map(|r| r.simulate_block)?;
+        simulated_block.try_into().map_err(Into::into)
+    }
+
     /// Estimate predicates for the transaction
     pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
         let serialized_tx = tx.to_bytes();
@@ -580,6 +705,19 @@ impl FuelClient {
         Ok(transaction.map(|tx| tx.try_into()).transpose()?)
     }
 
+    /// Get the confirmations and the finality of a transaction
+    pub async fn transaction_finality(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<types::TransactionFinality>> {
+        let query =
+            schema::tx::TransactionFinalityQuery::build(TxIdArgs { id: (*id).into() });
+
+        let transaction = self.query(query).await?.transaction;
+
+        Ok(transaction.map(Into::into))
+    }
+
     /// Get the status of a transaction
     pub async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
@@ -761,6 +899,29 @@ impl FuelClient {
         Ok(block)
     }
 
+    /// Retrieve the proof of inclusion of the transaction into the transactions root
+    /// of the block at the `height`.
+    pub async fn transaction_inclusion_proof(
+        &self,
+        height: BlockHeight,
+        transaction_id: &TxId,
+    ) -> io::Result<Option<types::MerkleProof>> {
+        let query = schema::block::TransactionInclusionProofQuery::build(
+            TransactionInclusionProofArgs {
+                block_height: U32(height.into()),
+                transaction_id: (*transaction_id).into(),
+            },
+        );
+
+        let proof = self
+            .query(query)
+            .await?
+            .transaction_inclusion_proof
+            .map(Into::into);
+
+        Ok(proof)
+    }
+
     /// Retrieve multiple blocks
     pub async fn blocks(
         &self,
@@ -773,6 +934,29 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    /// Retrieve the headers of `count` consecutive blocks starting at the `start` height
+    /// without the transactions of the blocks.
+    pub async fn block_headers(
+        &self,
+        start: BlockHeight,
+        count: u32,
+    ) -> io::Result<Vec<types::block::Header>> {
+        let query = schema::block::BlockHeadersQuery::build(BlockHeadersArgs {
+            start: U32(start.into()),
+            count: U32(count),
+        });
+
+        let headers = self
+            .query(query)
+            .await?
+            .block_headers
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(headers)
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -799,6 +983,25 @@ impl FuelClient {
         Ok(coins)
     }
 
+    /// Retrieve a page of the unspent coins of the asset across all owners.
+    /// The coins are ordered by their id and only the forward direction is supported.
+    pub async fn all_coins(
+        &self,
+        asset_id: &AssetId,
+        after: Option<String>,
+        first: i32,
+    ) -> io::Result<PaginatedResult<types::Coin, String>> {
+        let query =
+            schema::coins::AllCoinsQuery::build(schema::coins::AllCoinsConnectionArgs {
+                asset_id: (*asset_id).into(),
+                after,
+                first: Some(first),
+            });
+
+        let coins = self.query(query).await?.all_coins.into();
+        Ok(coins)
+    }
+
     /// Retrieve coins to spend in a transaction
     pub async fn coins_to_spend(
         &self,
@@ -913,6 +1116,40 @@ impl FuelClient {
         Ok(balances)
     }
 
+    pub async fn contract_storage_slot(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> io::Result<Option<types::ContractStorageSlot>> {
+        let query =
+            schema::contract::ContractStorageSlotQuery::build(ContractStorageSlotArgs {
+                contract_id: (*contract).into(),
+                key: (*key).into(),
+            });
+        let slot = self
+            .query(query)
+            .await?
+            .contract_storage_slot
+            .map(Into::into);
+        Ok(slot)
+    }
+
+    // Retrieve a page of storage slots of the contract ordered by the key
+    pub async fn contract_storage_slots(
+        &self,
+        contract: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractStorageSlot, String>> {
+        let contract_id: schema::ContractId = (*contract).into();
+        let query = schema::contract::ContractStorageSlotsQuery::build(
+            (contract_id, request).into(),
+        );
+
+        let slots = self.query(query).await?.contract_storage_slots.into();
+
+        Ok(slots)
+    }
+
     // Retrieve a message by its nonce
     pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
@@ -33,6 +33,7 @@ pub mod coins;
 pub mod contract;
 pub mod message;
 pub mod node_info;
+pub mod relayer;
 
 pub mod gas_price;
 pub mod primitives;
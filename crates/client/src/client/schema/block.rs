@@ -1,10 +1,12 @@
 use crate::client::schema::{
+    message::MerkleProof,
     schema,
     BlockId,
     ConnectionArgs,
     PageInfo,
     Signature,
     Tai64Timestamp,
+    TransactionId,
     U32,
     U64,
 };
@@ -61,6 +63,40 @@ pub struct BlocksQuery {
     pub blocks: BlockConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlockHeadersArgs {
+    pub start: U32,
+    pub count: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockHeadersArgs"
+)]
+pub struct BlockHeadersQuery {
+    #[arguments(start: $start, count: $count)]
+    pub block_headers: Vec<Header>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionInclusionProofArgs {
+    pub block_height: U32,
+    pub transaction_id: TransactionId,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionInclusionProofArgs"
+)]
+pub struct TransactionInclusionProofQuery {
+    #[arguments(blockHeight: $block_height, transactionId: $transaction_id)]
+    pub transaction_inclusion_proof: Option<MerkleProof>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct BlockConnection {
@@ -200,6 +236,17 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn transaction_inclusion_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TransactionInclusionProofQuery::build(TransactionInclusionProofArgs {
+                block_height: U32(0),
+                transaction_id: TransactionId::default(),
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn block_mutation_query_gql_output() {
         use cynic::MutationBuilder;
@@ -221,4 +268,14 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn block_headers_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlockHeadersQuery::build(BlockHeadersArgs {
+            start: U32(0),
+            count: U32(10),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -544,6 +544,36 @@ pub struct ChainInfo {
     pub consensus_parameters: ConsensusParameters,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct EffectiveConsensusParametersQuery {
+    pub effective_consensus_parameters: EffectiveConsensusParameters,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct EffectiveConsensusParameters {
+    pub consensus_parameters: ConsensusParameters,
+    pub max_call_depth: EffectiveValue,
+    pub max_inputs: EffectiveValue,
+    pub max_outputs: EffectiveValue,
+    pub max_gas_per_predicate: EffectiveValue,
+    pub max_predicate_gas_per_tx: EffectiveValue,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct ChainConfigQuery {
+    pub chain_config: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct EffectiveValue {
+    pub value: Option<U64>,
+    pub overridden: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -554,4 +584,18 @@ mod tests {
         let operation = ChainQuery::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn effective_consensus_parameters_gql_query_output() {
+        use cynic::QueryBuilder;
+        let operation = EffectiveConsensusParametersQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn chain_config_gql_query_output() {
+        use cynic::QueryBuilder;
+        let operation = ChainConfigQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
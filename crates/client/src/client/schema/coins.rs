@@ -92,6 +92,27 @@ pub struct CoinsQuery {
     pub coins: CoinConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AllCoinsConnectionArgs {
+    /// The asset id of the coins
+    pub asset_id: AssetId,
+    /// Skip until coin id (forward pagination)
+    pub after: Option<String>,
+    /// Retrieve the first n coins in order (forward pagination)
+    pub first: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AllCoinsConnectionArgs"
+)]
+pub struct AllCoinsQuery {
+    #[arguments(assetId: $ asset_id, after: $ after, first: $ first)]
+    pub all_coins: CoinConnection,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct CoinConnection {
@@ -241,4 +262,15 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn all_coins_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AllCoinsQuery::build(AllCoinsConnectionArgs {
+            asset_id: AssetId::default(),
+            after: None,
+            first: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -2,6 +2,7 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
@@ -135,6 +136,93 @@ impl From<(ContractId, PaginationRequest<String>)> for ContractBalancesConnectio
     }
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlot {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub value: HexString,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageSlotArgs {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotArgs"
+)]
+pub struct ContractStorageSlotQuery {
+    #[arguments(contractId: $contract_id, key: $key)]
+    pub contract_storage_slot: Option<ContractStorageSlot>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageSlotsConnectionArgs {
+    pub contract_id: ContractId,
+    /// Skip until the key (forward pagination)
+    pub after: Option<String>,
+    /// Skip until the key (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n slots in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n slots in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotEdge {
+    pub cursor: String,
+    pub node: ContractStorageSlot,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotConnection {
+    pub edges: Vec<ContractStorageSlotEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotsConnectionArgs"
+)]
+pub struct ContractStorageSlotsQuery {
+    #[arguments(contractId: $contract_id, after: $after, before: $before, first: $first, last: $last)]
+    pub contract_storage_slots: ContractStorageSlotConnection,
+}
+
+impl From<(ContractId, PaginationRequest<String>)>
+    for ContractStorageSlotsConnectionArgs
+{
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => ContractStorageSlotsConnectionArgs {
+                contract_id: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => ContractStorageSlotsConnectionArgs {
+                contract_id: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
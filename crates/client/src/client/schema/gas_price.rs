@@ -47,6 +47,19 @@ pub struct QueryEstimateGasPrice {
     pub estimate_gas_price: EstimateGasPrice,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RequiredGasPrice {
+    pub gas_price: U64,
+    pub min_tip: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryRequiredGasPrice {
+    pub required_gas_price: RequiredGasPrice,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,4 +78,11 @@ mod tests {
         let operation = QueryEstimateGasPrice::build(arbitrary_horizon.into());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn required_gas_price_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryRequiredGasPrice::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -113,6 +113,7 @@ fuel_type_scalar!(ContractId, ContractId);
 fuel_type_scalar!(Salt, Salt);
 fuel_type_scalar!(TransactionId, Bytes32);
 fuel_type_scalar!(Signature, Bytes64);
+fuel_type_scalar!(PublicKey, Bytes64);
 fuel_type_scalar!(Nonce, Nonce);
 
 impl LowerHex for Nonce {
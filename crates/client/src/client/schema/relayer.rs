@@ -0,0 +1,60 @@
+use crate::client::schema::{
+    schema,
+    Bytes32,
+    U64,
+};
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum DaEventKind {
+    Message,
+    Transaction,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingDaEvent {
+    pub da_height: U64,
+    pub kind: DaEventKind,
+    pub id: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryPendingDaEvents {
+    pub pending_da_events: Vec<PendingDaEvent>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RelayerStatus {
+    pub synced_da_height: U64,
+    pub da_deploy_height: U64,
+    pub latest_known_da_height: Option<U64>,
+    pub synced: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryRelayerStatus {
+    pub relayer_status: Option<RelayerStatus>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_da_events_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryPendingDaEvents::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn relayer_status_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryRelayerStatus::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+}
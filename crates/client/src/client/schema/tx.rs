@@ -1,4 +1,7 @@
-use super::block::BlockHeightFragment;
+use super::block::{
+    BlockHeightFragment,
+    Header,
+};
 use crate::client::{
     schema::{
         schema,
@@ -10,6 +13,8 @@ use crate::client::{
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        U32,
+        U64,
     },
     types::TransactionResponse,
     PageDirection,
@@ -53,6 +58,24 @@ pub struct TransactionQuery {
     pub transaction: Option<OpaqueTransaction>,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(graphql_type = "Transaction", schema_path = "./assets/schema.sdl")]
+pub struct TransactionFinality {
+    pub confirmations: Option<U32>,
+    pub finalized: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionFinalityQuery {
+    #[arguments(id: $id)]
+    pub transaction: Option<TransactionFinality>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -273,7 +296,12 @@ impl TryFrom<DryRunTransactionExecutionStatus> for TransactionExecutionStatus {
         let id = schema.id.into();
         let status = schema.status.try_into()?;
 
-        Ok(TransactionExecutionStatus { id, result: status })
+        Ok(TransactionExecutionStatus {
+            id,
+            result: status,
+            storage_access: None,
+            gas_breakdown: None,
+        })
     }
 }
 
@@ -370,6 +398,102 @@ pub struct DryRun {
     pub dry_run: Vec<DryRunTransactionExecutionStatus>,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OpcodeGas {
+    pub opcode: String,
+    pub count: U64,
+    pub gas: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "DryRunTransactionExecutionStatus"
+)]
+pub struct DryRunGasBreakdown {
+    pub id: TransactionId,
+    pub gas_breakdown: Option<Vec<OpcodeGas>>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunWithGasBreakdown {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation)]
+    pub dry_run: Vec<DryRunGasBreakdown>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageAccessKey {
+    pub column: String,
+    pub key: HexString,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageAccess {
+    pub reads: Vec<StorageAccessKey>,
+    pub writes: Vec<StorageAccessKey>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "DryRunTransactionExecutionStatus"
+)]
+pub struct DryRunStorageAccess {
+    pub id: TransactionId,
+    pub storage_access: Option<StorageAccess>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunWithStorageAccess {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation)]
+    pub dry_run: Vec<DryRunStorageAccess>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SkippedTransaction {
+    pub id: TransactionId,
+    pub reason: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SimulatedBlock {
+    pub header: Header,
+    pub transactions: Vec<DryRunTransactionExecutionStatus>,
+    pub skipped_transactions: Vec<SkippedTransaction>,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct SimulateBlockArg {
+    pub txs: Vec<HexString>,
+    pub block_time: Option<Tai64Timestamp>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SimulateBlockArg"
+)]
+pub struct SimulateBlock {
+    #[arguments(txs: $txs, blockTime: $block_time)]
+    pub simulate_block: SimulatedBlock,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -413,6 +537,15 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn transaction_finality_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionFinalityQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn opaque_transaction_by_id_query_gql_output() {
         use cynic::QueryBuilder;
@@ -459,6 +592,39 @@ pub mod tests {
         insta::assert_snapshot!(query.query)
     }
 
+    #[test]
+    fn dry_run_with_gas_breakdown_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = DryRunWithGasBreakdown::build(DryRunArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn dry_run_with_storage_access_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = DryRunWithStorageAccess::build(DryRunArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn simulate_block_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = SimulateBlock::build(SimulateBlockArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            block_time: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
     #[test]
     fn submit_tx_gql_output() {
         use cynic::MutationBuilder;
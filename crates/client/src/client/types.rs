@@ -9,13 +9,20 @@ pub mod gas_price;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod relayer;
 
 pub use balance::Balance;
 pub use block::{
     Block,
     Consensus,
+    SimulatedBlock,
+    SkippedTransaction,
+};
+pub use chain_info::{
+    ChainInfo,
+    EffectiveConsensusParameters,
+    EffectiveValue,
 };
-pub use chain_info::ChainInfo;
 pub use coins::{
     Coin,
     CoinType,
@@ -24,6 +31,7 @@ pub use coins::{
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractStorageSlot,
 };
 pub use gas_costs::{
     DependentCost,
@@ -35,10 +43,18 @@ pub use message::{
     MessageProof,
 };
 pub use node_info::NodeInfo;
+pub use relayer::{
+    PendingDaEvent,
+    RelayerStatus,
+};
 
 use crate::client::schema::{
     tx::{
         OpaqueTransaction,
+        OpcodeGas as SchemaOpcodeGas,
+        StorageAccess as SchemaStorageAccess,
+        StorageAccessKey as SchemaStorageAccessKey,
+        TransactionFinality as SchemaTxFinality,
         TransactionStatus as SchemaTxStatus,
     },
     ConversionError,
@@ -89,6 +105,76 @@ pub struct TransactionResponse {
     pub status: TransactionStatus,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TransactionFinality {
+    /// The number of blocks since the inclusion of the transaction,
+    /// including the block with the transaction. `None` if the transaction is not included.
+    pub confirmations: Option<u32>,
+    pub finalized: bool,
+}
+
+/// The gas consumed by the executions of an opcode during the dry run.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OpcodeGas {
+    pub opcode: String,
+    /// The number of the executions of the opcode.
+    pub count: u64,
+    pub gas: u64,
+}
+
+impl From<SchemaOpcodeGas> for OpcodeGas {
+    fn from(value: SchemaOpcodeGas) -> Self {
+        Self {
+            opcode: value.opcode,
+            count: value.count.into(),
+            gas: value.gas.into(),
+        }
+    }
+}
+
+/// The storage keys accessed by the transaction during the dry run.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StorageAccess {
+    /// The keys whose values are read from the state preceding the transaction.
+    pub reads: Vec<StorageAccessKey>,
+    /// The keys inserted or removed by the transaction.
+    pub writes: Vec<StorageAccessKey>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StorageAccessKey {
+    /// The name of the storage column.
+    pub column: String,
+    pub key: Vec<u8>,
+}
+
+impl From<SchemaStorageAccess> for StorageAccess {
+    fn from(value: SchemaStorageAccess) -> Self {
+        Self {
+            reads: value.reads.into_iter().map(Into::into).collect(),
+            writes: value.writes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<SchemaStorageAccessKey> for StorageAccessKey {
+    fn from(value: SchemaStorageAccessKey) -> Self {
+        Self {
+            column: value.column,
+            key: value.key.0 .0,
+        }
+    }
+}
+
+impl From<SchemaTxFinality> for TransactionFinality {
+    fn from(value: SchemaTxFinality) -> Self {
+        Self {
+            confirmations: value.confirmations.map(Into::into),
+            finalized: value.finalized,
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum TransactionStatus {
     Submitted {
@@ -1,5 +1,6 @@
 use crate::client::{
     schema,
+    schema::ConversionError,
     types::primitives::{
         BlockId,
         Hash,
@@ -10,6 +11,7 @@ use crate::client::{
     },
     PaginatedResult,
 };
+use fuel_core_types::services::executor::TransactionExecutionStatus;
 use tai64::Tai64;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -151,3 +153,43 @@ impl From<schema::block::BlockConnection> for PaginatedResult<Block, String> {
         }
     }
 }
+
+/// The block that would be produced with the given transactions.
+#[derive(Clone, Debug)]
+pub struct SimulatedBlock {
+    pub header: Header,
+    /// The execution statuses of the included transactions, ending with the `Mint`.
+    pub transactions: Vec<TransactionExecutionStatus>,
+    pub skipped_transactions: Vec<SkippedTransaction>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkippedTransaction {
+    pub id: TransactionId,
+    pub reason: String,
+}
+
+impl TryFrom<schema::tx::SimulatedBlock> for SimulatedBlock {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::tx::SimulatedBlock) -> Result<Self, Self::Error> {
+        let transactions = value
+            .transactions
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+        let skipped_transactions = value
+            .skipped_transactions
+            .into_iter()
+            .map(|skipped| SkippedTransaction {
+                id: skipped.id.into(),
+                reason: skipped.reason,
+            })
+            .collect();
+        Ok(Self {
+            header: value.header.into(),
+            transactions,
+            skipped_transactions,
+        })
+    }
+}
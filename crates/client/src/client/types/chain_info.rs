@@ -16,6 +16,25 @@ pub struct ChainInfo {
     pub consensus_parameters: ConsensusParameters,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EffectiveConsensusParameters {
+    /// The consensus parameters from the chain configuration.
+    pub consensus_parameters: ConsensusParameters,
+    pub max_call_depth: EffectiveValue,
+    pub max_inputs: EffectiveValue,
+    pub max_outputs: EffectiveValue,
+    pub max_gas_per_predicate: EffectiveValue,
+    pub max_predicate_gas_per_tx: EffectiveValue,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EffectiveValue {
+    /// The enforced value. `None` if the node doesn't limit it.
+    pub value: Option<u64>,
+    /// Whether the value differs from the chain configuration because of the override.
+    pub overridden: bool,
+}
+
 // GraphQL Translation
 
 impl TryFrom<schema::chain::ChainInfo> for ChainInfo {
@@ -30,3 +49,31 @@ impl TryFrom<schema::chain::ChainInfo> for ChainInfo {
         })
     }
 }
+
+impl TryFrom<schema::chain::EffectiveConsensusParameters>
+    for EffectiveConsensusParameters
+{
+    type Error = ConversionError;
+
+    fn try_from(
+        value: schema::chain::EffectiveConsensusParameters,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            consensus_parameters: value.consensus_parameters.try_into()?,
+            max_call_depth: value.max_call_depth.into(),
+            max_inputs: value.max_inputs.into(),
+            max_outputs: value.max_outputs.into(),
+            max_gas_per_predicate: value.max_gas_per_predicate.into(),
+            max_predicate_gas_per_tx: value.max_predicate_gas_per_tx.into(),
+        })
+    }
+}
+
+impl From<schema::chain::EffectiveValue> for EffectiveValue {
+    fn from(value: schema::chain::EffectiveValue) -> Self {
+        Self {
+            value: value.value.map(Into::into),
+            overridden: value.overridden,
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::client::{
     types::primitives::{
         AssetId,
         Bytes,
+        Bytes32,
         ContractId,
         Salt,
     },
@@ -22,6 +23,13 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractStorageSlot {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub value: Bytes,
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -56,3 +64,26 @@ impl From<schema::contract::ContractBalanceConnection>
         }
     }
 }
+
+impl From<schema::contract::ContractStorageSlot> for ContractStorageSlot {
+    fn from(value: schema::contract::ContractStorageSlot) -> Self {
+        Self {
+            contract: value.contract.into(),
+            key: value.key.into(),
+            value: value.value.into(),
+        }
+    }
+}
+
+impl From<schema::contract::ContractStorageSlotConnection>
+    for PaginatedResult<ContractStorageSlot, String>
+{
+    fn from(conn: schema::contract::ContractStorageSlotConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
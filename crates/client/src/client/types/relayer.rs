@@ -0,0 +1,51 @@
+use crate::client::{
+    schema::{
+        self,
+        relayer::DaEventKind,
+    },
+    types::primitives::Bytes32,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingDaEvent {
+    pub da_height: u64,
+    pub kind: DaEventKind,
+    /// The id of the message or of the forced transaction.
+    pub id: Bytes32,
+}
+
+/// The progress of the relayer syncing with the DA layer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RelayerStatus {
+    /// The DA height up to which the relayer downloaded the events.
+    pub synced_da_height: u64,
+    /// The DA height of the bridge contracts deployment.
+    pub da_deploy_height: u64,
+    /// The latest finalized DA height known to the relayer.
+    pub latest_known_da_height: Option<u64>,
+    /// Whether the synced DA height reached the latest known DA height.
+    pub synced: bool,
+}
+
+// GraphQL Translation
+
+impl From<schema::relayer::PendingDaEvent> for PendingDaEvent {
+    fn from(value: schema::relayer::PendingDaEvent) -> Self {
+        Self {
+            da_height: value.da_height.into(),
+            kind: value.kind,
+            id: value.id.into(),
+        }
+    }
+}
+
+impl From<schema::relayer::RelayerStatus> for RelayerStatus {
+    fn from(value: schema::relayer::RelayerStatus) -> Self {
+        Self {
+            synced_da_height: value.synced_da_height.into(),
+            da_deploy_height: value.da_deploy_height.into(),
+            latest_known_da_height: value.latest_known_da_height.map(Into::into),
+            synced: value.synced,
+        }
+    }
+}
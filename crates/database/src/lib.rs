@@ -58,6 +58,16 @@ pub enum Error {
         /// The old height known by the database.
         prev_height: u64,
     },
+    /// The database was created with another mode of the bytecode deduplication.
+    #[display(
+        fmt = "The database was created with the bytecode deduplication set to {stored}, but {requested} is requested"
+    )]
+    BytecodeDeduplicationMismatch {
+        /// The mode the database was created with.
+        stored: bool,
+        /// The mode requested by the node.
+        requested: bool,
+    },
 
     /// Not related to database error.
     #[from]
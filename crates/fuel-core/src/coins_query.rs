@@ -40,6 +40,8 @@ pub enum CoinsQueryError {
     MaxCoinsReached,
     #[error("the query contains duplicate assets")]
     DuplicateAssets(AssetId),
+    #[error("too many assets in the query, the maximum is {max}")]
+    TooManyAssets { max: usize },
 }
 
 #[cfg(test)]
@@ -58,14 +60,19 @@ pub struct SpendQuery {
 }
 
 impl SpendQuery {
-    // TODO: Check that number of `queries` is not too high(to prevent attacks).
-    //  https://github.com/FuelLabs/fuel-core/issues/588#issuecomment-1240074551
+    /// Prepares the spend query. The query is rejected if it contains
+    /// more than `max_assets` assets or duplicate assets.
     pub fn new(
         owner: Address,
         query_per_asset: &[AssetSpendTarget],
         exclude_vec: Option<Vec<CoinId>>,
         base_asset_id: AssetId,
+        max_assets: usize,
     ) -> Result<Self, CoinsQueryError> {
+        if query_per_asset.len() > max_assets {
+            return Err(CoinsQueryError::TooManyAssets { max: max_assets })
+        }
+
         let mut duplicate_checker = HashSet::new();
 
         for query in query_per_asset {
@@ -504,7 +511,13 @@ mod tests {
         ) -> Result<Vec<(AssetId, u64)>, CoinsQueryError> {
             let coins = random_improve(
                 &db.view(),
-                &SpendQuery::new(owner, &query_per_asset, None, base_asset_id)?,
+                &SpendQuery::new(
+                    owner,
+                    &query_per_asset,
+                    None,
+                    base_asset_id,
+                    usize::MAX,
+                )?,
             );
 
             // Transform result for convenience
@@ -700,6 +713,7 @@ mod tests {
                     &query_per_asset,
                     Some(excluded_ids),
                     base_asset_id,
+                    usize::MAX,
                 )?;
                 let coins = random_improve(&db.service_database().view(), &spend_query);
 
@@ -869,6 +883,7 @@ mod tests {
                 }],
                 None,
                 base_asset_id,
+                usize::MAX,
             )?,
         )?;
 
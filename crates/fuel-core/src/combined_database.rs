@@ -5,34 +5,333 @@ use crate::{
             off_chain::OffChain,
             on_chain::OnChain,
             relayer::Relayer,
+            DatabaseDescription,
         },
         Database,
         Result as DatabaseResult,
     },
     service::DbType,
 };
+use anyhow::anyhow;
 #[cfg(feature = "test-helpers")]
+use fuel_core_chain_config::StateConfig;
 use fuel_core_chain_config::{
-    StateConfig,
+    AddTable,
+    SnapshotWriter,
     StateConfigBuilder,
+    TableEntry,
 };
-#[cfg(feature = "test-helpers")]
-use fuel_core_storage::tables::{
-    Coins,
-    ContractsAssets,
-    ContractsLatestUtxo,
-    ContractsRawCode,
-    ContractsState,
-    Messages,
+use fuel_core_storage::{
+    blueprint::BlueprintInspect,
+    codec::Decode,
+    column::Column,
+    iter::{
+        IterDirection,
+        IterableStore,
+        IteratorOverTable,
+    },
+    kv_store::{
+        StorageColumn,
+        Value,
+    },
+    not_found,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        merkle::{
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleMetadata,
+        },
+        Coins,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        Transactions,
+    },
+    transactional::WriteTransaction,
+    Error as StorageError,
+    IsNotFound,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_merkle::{
+        binary::root_calculator::MerkleRootCalculator,
+        sparse::{
+            in_memory,
+            MerkleTreeKey,
+        },
+    },
+    fuel_tx::UtxoId,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+        ContractId,
+        Nonce,
+    },
+};
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    fmt,
+    num::NonZeroU64,
+    path::PathBuf,
+    str::FromStr,
+    sync::atomic::AtomicBool,
 };
-use fuel_core_storage::Result as StorageResult;
-use std::path::PathBuf;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CombinedDatabaseConfig {
     pub database_path: PathBuf,
     pub database_type: DbType,
     pub max_database_cache_size: usize,
+    pub sync_policy: DbSyncPolicy,
+    /// Stores identical contract bytecode only once in the on-chain database.
+    /// The database refuses to open with another mode than the one it was created with.
+    pub deduplicate_bytecode: bool,
+}
+
+/// Defines when the database syncs its write-ahead log to the disk after
+/// committing a new block. Syncing makes the commit durable, but it waits
+/// for the disk and increases the commit latency.
+///
+/// Regardless of the policy, the committed blocks survive a crash of the process,
+/// because the write-ahead log is already handed over to the OS. The policy only
+/// matters for a crash of the OS or a power loss. After such a crash, the database
+/// recovers to an earlier height, and the lost blocks are synced again from peers.
+/// The block producer can't re-sync its own blocks and could produce different ones
+/// at the same heights, so it should opt into [`DbSyncPolicy::Always`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DbSyncPolicy {
+    /// Syncs each block commit. No committed block is lost on a power loss.
+    Always,
+    /// Syncs every N-th block commit. Up to the last N - 1 committed blocks
+    /// can be lost on a power loss.
+    EveryNBlocks(NonZeroU64),
+    /// Never syncs, and the OS decides when to write the data to the disk.
+    /// Any number of recently committed blocks can be lost on a power loss.
+    #[default]
+    Never,
+}
+
+impl DbSyncPolicy {
+    /// Returns `true` if the commit of the `block_number`-th block
+    /// since opening of the database should be synced.
+    pub fn should_sync(&self, block_number: u64) -> bool {
+        match self {
+            DbSyncPolicy::Always => true,
+            DbSyncPolicy::EveryNBlocks(n) => block_number.checked_rem(n.get()) == Some(0),
+            DbSyncPolicy::Never => false,
+        }
+    }
+}
+
+impl fmt::Display for DbSyncPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DbSyncPolicy::Always => f.write_str("always"),
+            DbSyncPolicy::EveryNBlocks(n) => write!(f, "{n}"),
+            DbSyncPolicy::Never => f.write_str("never"),
+        }
+    }
+}
+
+impl FromStr for DbSyncPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(DbSyncPolicy::Always),
+            "never" => Ok(DbSyncPolicy::Never),
+            n => {
+                let n = n.parse::<NonZeroU64>().map_err(|_| {
+                    anyhow!(
+                        "The sync policy should be `always`, `never` or \
+                        the non-zero number of blocks between syncs, got `{n}`"
+                    )
+                })?;
+                Ok(DbSyncPolicy::EveryNBlocks(n))
+            }
+        }
+    }
+}
+
+/// The code, state, balances and latest UTXO of a single contract.
+/// It can be exported from one node and imported into another one.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContractSnapshot {
+    pub code: TableEntry<ContractsRawCode>,
+    pub states: Vec<TableEntry<ContractsState>>,
+    pub balances: Vec<TableEntry<ContractsAssets>>,
+    pub latest_utxo: TableEntry<ContractsLatestUtxo>,
+}
+
+/// An inconsistency found by [`CombinedDatabase::check_integrity`].
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::Display)]
+pub enum IntegrityIssue {
+    #[display(fmt = "Failed to read an entry of the `{table}` table: {error}")]
+    CorruptedEntry { table: &'static str, error: String },
+    #[display(
+        fmt = "Coin {utxo_id} points to the block {block_height} after the latest block {latest_height}"
+    )]
+    CoinFromFutureBlock {
+        utxo_id: UtxoId,
+        block_height: BlockHeight,
+        latest_height: BlockHeight,
+    },
+    #[display(fmt = "Message {nonce} is stored under the key {key}")]
+    MessageUnderWrongKey { key: Nonce, nonce: Nonce },
+    #[display(
+        fmt = "Message {nonce} is from the DA block {da_height} after the latest DA block {latest_da_height}"
+    )]
+    MessageFromFutureDaBlock {
+        nonce: Nonce,
+        da_height: DaBlockHeight,
+        latest_da_height: DaBlockHeight,
+    },
+    #[display(fmt = "Contract {_0} has the latest UTXO but no code")]
+    ContractWithoutCode(ContractId),
+    #[display(fmt = "Contract {_0} has code but no latest UTXO")]
+    ContractWithoutUtxo(ContractId),
+    #[display(
+        fmt = "UTXO of the contract {contract_id} points to the block {block_height} after the latest block {latest_height}"
+    )]
+    ContractUtxoFromFutureBlock {
+        contract_id: ContractId,
+        block_height: BlockHeight,
+        latest_height: BlockHeight,
+    },
+    #[display(fmt = "Contract {_0} has state slots but no code")]
+    StateOfUnknownContract(ContractId),
+    #[display(fmt = "Contract {_0} has balances but no code")]
+    BalancesOfUnknownContract(ContractId),
+}
+
+/// A commitment that doesn't match the table contents,
+/// found by [`CombinedDatabase::verify_state_commitment`].
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::Display)]
+enum CommitmentMismatch {
+    #[display(
+        fmt = "The header of the block {height} commits to the previous blocks root {committed}, but the computed root is {computed}"
+    )]
+    PreviousBlocksRoot {
+        height: BlockHeight,
+        committed: Bytes32,
+        computed: Bytes32,
+    },
+    #[display(
+        fmt = "The stored root of the blocks up to {height} is {stored}, but the computed root is {computed}"
+    )]
+    BlocksRoot {
+        height: BlockHeight,
+        stored: Bytes32,
+        computed: Bytes32,
+    },
+    #[display(
+        fmt = "The stored state root of the contract {contract_id} is {stored}, but the computed root is {computed}"
+    )]
+    ContractStateRoot {
+        contract_id: ContractId,
+        stored: Bytes32,
+        computed: Bytes32,
+    },
+    #[display(
+        fmt = "The stored balances root of the contract {contract_id} is {stored}, but the computed root is {computed}"
+    )]
+    ContractBalancesRoot {
+        contract_id: ContractId,
+        stored: Bytes32,
+        computed: Bytes32,
+    },
+}
+
+/// The result of [`CombinedDatabase::check_integrity`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    pub issues: Vec<IntegrityIssue>,
+}
+
+impl IntegrityReport {
+    /// Returns `true` if no issues were found.
+    pub fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn corrupted_entry<T>(&mut self, error: StorageError)
+    where
+        T: TableWithBlueprint,
+    {
+        self.issues.push(IntegrityIssue::CorruptedEntry {
+            table: T::column().name(),
+            error: error.to_string(),
+        });
+    }
+}
+
+/// The difference of one entry of the table between two databases,
+/// found by [`CombinedDatabase::diff`].
+pub enum TableDiff<T>
+where
+    T: Mappable,
+{
+    /// The entry exists only in the other database.
+    Added(TableEntry<T>),
+    /// The entry exists only in this database.
+    Removed(TableEntry<T>),
+    /// The entry has the same key but different values, as `(this, other)`.
+    Changed(TableEntry<T>, TableEntry<T>),
+}
+
+impl<T> fmt::Debug for TableDiff<T>
+where
+    T: Mappable,
+    TableEntry<T>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableDiff::Added(entry) => f.debug_tuple("Added").field(entry).finish(),
+            TableDiff::Removed(entry) => f.debug_tuple("Removed").field(entry).finish(),
+            TableDiff::Changed(this, other) => {
+                f.debug_tuple("Changed").field(this).field(other).finish()
+            }
+        }
+    }
+}
+
+impl<T> PartialEq for TableDiff<T>
+where
+    T: Mappable,
+    TableEntry<T>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TableDiff::Added(a), TableDiff::Added(b))
+            | (TableDiff::Removed(a), TableDiff::Removed(b)) => a == b,
+            (
+                TableDiff::Changed(a_this, a_other),
+                TableDiff::Changed(b_this, b_other),
+            ) => a_this == b_this && a_other == b_other,
+            _ => false,
+        }
+    }
+}
+
+/// The difference of the on-chain state between two databases,
+/// found by [`CombinedDatabase::diff`].
+#[derive(Debug, PartialEq)]
+pub enum StateDiff {
+    Coins(TableDiff<Coins>),
+    Messages(TableDiff<Messages>),
+    ContractsRawCode(TableDiff<ContractsRawCode>),
+    ContractsState(TableDiff<ContractsState>),
+    ContractsAssets(TableDiff<ContractsAssets>),
+    ContractsLatestUtxo(TableDiff<ContractsLatestUtxo>),
+    Transactions(TableDiff<Transactions>),
 }
 
 /// A database that combines the on-chain, off-chain and relayer databases into one entity.
@@ -64,15 +363,24 @@ impl CombinedDatabase {
         Ok(())
     }
 
+    /// Prunes only the relayer database. The relayer re-syncs the events
+    /// from the DA layer starting at the deploy height, while the on-chain and
+    /// off-chain data stay untouched.
+    #[cfg(feature = "rocksdb")]
+    pub fn prune_relayer(path: &std::path::Path) -> database::Result<()> {
+        crate::state::rocks_db::RocksDb::<Relayer>::prune(path)
+    }
+
     #[cfg(feature = "rocksdb")]
     pub fn open(
         path: &std::path::Path,
         capacity: usize,
+        sync_policy: DbSyncPolicy,
     ) -> crate::database::Result<Self> {
         // TODO: Use different cache sizes for different databases
-        let on_chain = Database::open_rocksdb(path, capacity)?;
-        let off_chain = Database::open_rocksdb(path, capacity)?;
-        let relayer = Database::open_rocksdb(path, capacity)?;
+        let on_chain = Database::open_rocksdb(path, capacity, sync_policy)?;
+        let off_chain = Database::open_rocksdb(path, capacity, sync_policy)?;
+        let relayer = Database::open_rocksdb(path, capacity, sync_policy)?;
         Ok(Self {
             on_chain,
             off_chain,
@@ -92,13 +400,15 @@ impl CombinedDatabase {
                     CombinedDatabase::default()
                 } else {
                     tracing::info!(
-                        "Opening database {:?} with cache size \"{}\"",
+                        "Opening database {:?} with cache size \"{}\" and sync policy \"{}\"",
                         config.database_path,
-                        config.max_database_cache_size
+                        config.max_database_cache_size,
+                        config.sync_policy,
                     );
                     CombinedDatabase::open(
                         &config.database_path,
                         config.max_database_cache_size,
+                        config.sync_policy,
                     )?
                 }
             }
@@ -107,6 +417,23 @@ impl CombinedDatabase {
             _ => CombinedDatabase::in_memory(),
         };
 
+        combined_database
+            .on_chain
+            .check_bytecode_deduplication(config.deduplicate_bytecode)?;
+        if config.deduplicate_bytecode {
+            tracing::info!("The identical contract bytecode is stored only once");
+            let CombinedDatabase {
+                on_chain,
+                off_chain,
+                relayer,
+            } = combined_database;
+            return Ok(CombinedDatabase::new(
+                on_chain.with_deduplicated_bytecode(),
+                off_chain,
+                relayer,
+            ))
+        }
+
         Ok(combined_database)
     }
 
@@ -125,6 +452,28 @@ impl CombinedDatabase {
         Ok(())
     }
 
+    /// Returns the read-only copies of all databases. The copies are taken
+    /// one after another, so the heights of the databases may differ.
+    pub fn checkpoint(&self) -> StorageResult<Self> {
+        Ok(Self::new(
+            self.on_chain.checkpoint()?,
+            self.off_chain.checkpoint()?,
+            self.relayer.checkpoint()?,
+        ))
+    }
+
+    /// Compacts all databases one after another.
+    /// The concurrent reads and writes are not blocked.
+    /// Every column of every database is rewritten, so it may take a long time
+    /// on the big databases. The compaction stops before the next column once
+    /// the `stop` flag is set.
+    pub fn compact(&self, stop: &AtomicBool) -> StorageResult<()> {
+        self.on_chain.compact(stop)?;
+        self.off_chain.compact(stop)?;
+        self.relayer.compact(stop)?;
+        Ok(())
+    }
+
     pub fn on_chain(&self) -> &Database<OnChain> {
         &self.on_chain
     }
@@ -147,9 +496,524 @@ impl CombinedDatabase {
         &self.relayer
     }
 
+    /// Collects all on-chain entries of the contract into a [`ContractSnapshot`].
+    pub fn export_contract(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<ContractSnapshot> {
+        let code = self
+            .on_chain()
+            .storage::<ContractsRawCode>()
+            .get(contract_id)?
+            .ok_or(not_found!(ContractsRawCode))?
+            .into_owned();
+        let latest_utxo = self
+            .on_chain()
+            .storage::<ContractsLatestUtxo>()
+            .get(contract_id)?
+            .ok_or(not_found!(ContractsLatestUtxo))?
+            .into_owned();
+        let states = self
+            .on_chain()
+            .entries::<ContractsState>(Some(contract_id.as_ref()), IterDirection::Forward)
+            .collect::<StorageResult<Vec<_>>>()?;
+        let balances = self
+            .on_chain()
+            .entries::<ContractsAssets>(
+                Some(contract_id.as_ref()),
+                IterDirection::Forward,
+            )
+            .collect::<StorageResult<Vec<_>>>()?;
+
+        Ok(ContractSnapshot {
+            code: TableEntry {
+                key: *contract_id,
+                value: code,
+            },
+            states,
+            balances,
+            latest_utxo: TableEntry {
+                key: *contract_id,
+                value: latest_utxo,
+            },
+        })
+    }
+
+    /// Inserts the contract from the [`ContractSnapshot`] into the on-chain database.
+    /// Fails if the contract already exists.
+    pub fn import_contract(&mut self, snapshot: ContractSnapshot) -> anyhow::Result<()> {
+        use crate::database::{
+            balances::BalancesInitializer,
+            state::StateInitializer,
+        };
+
+        let ContractSnapshot {
+            code,
+            states,
+            balances,
+            latest_utxo,
+        } = snapshot;
+        let contract_id = code.key;
+
+        if latest_utxo.key != contract_id
+            || states.iter().any(|s| *s.key.contract_id() != contract_id)
+            || balances.iter().any(|b| *b.key.contract_id() != contract_id)
+        {
+            return Err(anyhow!(
+                "Contract snapshot contains entries of other contracts"
+            ));
+        }
+
+        let mut tx = self.on_chain.write_transaction();
+        if tx
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, code.value.as_ref())?
+            .is_some()
+        {
+            return Err(anyhow!("Contract code should not exist"));
+        }
+        tx.storage_as_mut::<ContractsLatestUtxo>()
+            .insert(&contract_id, &latest_utxo.value)?;
+        tx.update_contract_states(states)?;
+        tx.update_contract_balances(balances)?;
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Writes the state of the on-chain and off-chain tables along with the latest
+    /// block data into the `writer`, `group_size` entries per group.
+    /// The chain config is not written and the `writer` is not closed.
+    ///
+    /// The tables are read as they are, so the database of the running node
+    /// should be exported from the [`Self::checkpoint`] to not mix the states
+    /// of different heights.
+    pub fn write_snapshot(
+        &self,
+        writer: &mut SnapshotWriter,
+        group_size: usize,
+    ) -> anyhow::Result<()> {
+        use crate::graphql_api::storage::transactions::{
+            OwnedTransactions,
+            TransactionStatuses,
+        };
+        use itertools::Itertools;
+
+        fn write<T, Description>(
+            db: &Database<Description>,
+            group_size: usize,
+            writer: &mut SnapshotWriter,
+        ) -> anyhow::Result<()>
+        where
+            T: TableWithBlueprint<Column = Description::Column>,
+            T::Blueprint: BlueprintInspect<T, Database<Description>>,
+            TableEntry<T>: serde::Serialize,
+            StateConfigBuilder: AddTable<T>,
+            Description: DatabaseDescription,
+        {
+            db.entries::<T>(None, IterDirection::Forward)
+                .chunks(group_size)
+                .into_iter()
+                .try_for_each(|chunk| writer.write(chunk.try_collect()?))
+        }
+
+        let db = self.on_chain();
+        write::<Coins, OnChain>(db, group_size, writer)?;
+        write::<Messages, OnChain>(db, group_size, writer)?;
+        write::<ContractsRawCode, OnChain>(db, group_size, writer)?;
+        write::<ContractsLatestUtxo, OnChain>(db, group_size, writer)?;
+        write::<ContractsState, OnChain>(db, group_size, writer)?;
+        write::<ContractsAssets, OnChain>(db, group_size, writer)?;
+        write::<Transactions, OnChain>(db, group_size, writer)?;
+
+        let db = self.off_chain();
+        write::<TransactionStatuses, OffChain>(db, group_size, writer)?;
+        write::<OwnedTransactions, OffChain>(db, group_size, writer)?;
+
+        let block = self.on_chain().latest_block()?;
+        writer.write_block_data(*block.header().height(), block.header().da_height)?;
+
+        Ok(())
+    }
+
+    /// Scans the on-chain tables for entries violating the invariants enforced
+    /// during the genesis import and block execution. All found issues are
+    /// reported instead of failing on the first one.
+    pub fn check_integrity(&self) -> StorageResult<IntegrityReport> {
+        let mut report = IntegrityReport::default();
+        let latest_header = match self.on_chain().latest_block() {
+            Ok(block) => Some(block.header().clone()),
+            Err(err) if err.is_not_found() => None,
+            Err(err) => return Err(err),
+        };
+        let latest_height = latest_header.as_ref().map(|header| *header.height());
+        let latest_da_height = latest_header.as_ref().map(|header| header.da_height);
+
+        for entry in self.on_chain().iter_all::<Coins>(None) {
+            let (utxo_id, coin) = match entry {
+                Ok(entry) => entry,
+                Err(error) => {
+                    report.corrupted_entry::<Coins>(error);
+                    continue
+                }
+            };
+            let block_height = coin.tx_pointer().block_height();
+            if let Some(latest_height) = latest_height {
+                if block_height > latest_height {
+                    report.issues.push(IntegrityIssue::CoinFromFutureBlock {
+                        utxo_id,
+                        block_height,
+                        latest_height,
+                    });
+                }
+            }
+        }
+
+        for entry in self.on_chain().iter_all::<Messages>(None) {
+            let (key, message) = match entry {
+                Ok(entry) => entry,
+                Err(error) => {
+                    report.corrupted_entry::<Messages>(error);
+                    continue
+                }
+            };
+            let nonce = *message.id();
+            if key != nonce {
+                report
+                    .issues
+                    .push(IntegrityIssue::MessageUnderWrongKey { key, nonce });
+            }
+            let da_height = message.da_height();
+            if let Some(latest_da_height) = latest_da_height {
+                if da_height > latest_da_height {
+                    report
+                        .issues
+                        .push(IntegrityIssue::MessageFromFutureDaBlock {
+                            nonce,
+                            da_height,
+                            latest_da_height,
+                        });
+                }
+            }
+        }
+
+        let mut contracts_with_code = BTreeSet::new();
+        for entry in self.on_chain().iter_all::<ContractsRawCode>(None) {
+            match entry {
+                Ok((contract_id, _)) => {
+                    contracts_with_code.insert(contract_id);
+                }
+                Err(error) => report.corrupted_entry::<ContractsRawCode>(error),
+            }
+        }
+
+        let mut contracts_with_utxo = BTreeSet::new();
+        for entry in self.on_chain().iter_all::<ContractsLatestUtxo>(None) {
+            let (contract_id, utxo) = match entry {
+                Ok(entry) => entry,
+                Err(error) => {
+                    report.corrupted_entry::<ContractsLatestUtxo>(error);
+                    continue
+                }
+            };
+            contracts_with_utxo.insert(contract_id);
+            if !contracts_with_code.contains(&contract_id) {
+                report
+                    .issues
+                    .push(IntegrityIssue::ContractWithoutCode(contract_id));
+            }
+            let block_height = utxo.tx_pointer().block_height();
+            if let Some(latest_height) = latest_height {
+                if block_height > latest_height {
+                    report
+                        .issues
+                        .push(IntegrityIssue::ContractUtxoFromFutureBlock {
+                            contract_id,
+                            block_height,
+                            latest_height,
+                        });
+                }
+            }
+        }
+        report.issues.extend(
+            contracts_with_code
+                .difference(&contracts_with_utxo)
+                .copied()
+                .map(IntegrityIssue::ContractWithoutUtxo),
+        );
+
+        let mut unknown_contracts = BTreeSet::new();
+        for entry in self.on_chain().iter_all::<ContractsState>(None) {
+            match entry {
+                Ok((key, _)) => {
+                    let contract_id = *key.contract_id();
+                    if !contracts_with_code.contains(&contract_id)
+                        && unknown_contracts.insert(contract_id)
+                    {
+                        report
+                            .issues
+                            .push(IntegrityIssue::StateOfUnknownContract(contract_id));
+                    }
+                }
+                Err(error) => report.corrupted_entry::<ContractsState>(error),
+            }
+        }
+
+        let mut unknown_contracts = BTreeSet::new();
+        for entry in self.on_chain().iter_all::<ContractsAssets>(None) {
+            match entry {
+                Ok((key, _)) => {
+                    let contract_id = *key.contract_id();
+                    if !contracts_with_code.contains(&contract_id)
+                        && unknown_contracts.insert(contract_id)
+                    {
+                        report
+                            .issues
+                            .push(IntegrityIssue::BalancesOfUnknownContract(contract_id));
+                    }
+                }
+                Err(error) => report.corrupted_entry::<ContractsAssets>(error),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Recomputes the on-chain commitments from the table contents and compares them
+    /// with the committed values at the latest height:
+    /// - The root of the blocks before the latest one with the `prev_root` of the latest header.
+    /// - The root of all blocks with the stored root of the [`FuelBlocks`] table.
+    /// - The state and balances roots of each contract with their stored roots.
+    ///
+    /// It walks all blocks and contract entries, so it is expensive and should be
+    /// triggered explicitly. Returns `Ok(true)` if all commitments match and `Ok(false)`
+    /// if there are no blocks to verify. Otherwise, returns the error listing all mismatches.
+    pub fn verify_state_commitment(&self) -> anyhow::Result<bool> {
+        let mut mismatches = vec![];
+
+        let mut blocks_root = MerkleRootCalculator::new();
+        let mut previous_blocks_root = MerkleRootCalculator::new();
+        let mut previous_block_id: Option<Bytes32> = None;
+        let mut latest_header = None;
+        let mut blocks = 0usize;
+        for entry in self
+            .on_chain()
+            .iter_all::<FuelBlocks>(Some(IterDirection::Forward))
+        {
+            let (_, block) = entry?;
+            let block_id: Bytes32 = block.id().into();
+            if let Some(previous_block_id) = previous_block_id.replace(block_id) {
+                previous_blocks_root.push(previous_block_id.as_ref());
+            }
+            blocks_root.push(block_id.as_ref());
+            latest_header = Some(block.header().clone());
+            blocks = blocks.saturating_add(1);
+        }
+        let Some(latest_header) = latest_header else {
+            return Ok(false)
+        };
+        let latest_height = *latest_header.height();
+
+        // The first block doesn't commit to any previous blocks.
+        if blocks > 1 {
+            let committed = *latest_header.prev_root();
+            let computed = Bytes32::from(previous_blocks_root.root());
+            if committed != computed {
+                mismatches.push(CommitmentMismatch::PreviousBlocksRoot {
+                    height: latest_height,
+                    committed,
+                    computed,
+                });
+            }
+        }
+
+        let stored = Bytes32::from(
+            self.on_chain()
+                .storage::<FuelBlocks>()
+                .root(&latest_height)?,
+        );
+        let computed = Bytes32::from(blocks_root.root());
+        if stored != computed {
+            mismatches.push(CommitmentMismatch::BlocksRoot {
+                height: latest_height,
+                stored,
+                computed,
+            });
+        }
+
+        let mut contracts = BTreeSet::new();
+        for entry in self.on_chain().iter_all::<ContractsRawCode>(None) {
+            let (contract_id, _) = entry?;
+            contracts.insert(contract_id);
+        }
+        for entry in self
+            .on_chain()
+            .iter_all::<ContractsStateMerkleMetadata>(None)
+        {
+            let (contract_id, _) = entry?;
+            contracts.insert(contract_id);
+        }
+        for entry in self
+            .on_chain()
+            .iter_all::<ContractsAssetsMerkleMetadata>(None)
+        {
+            let (contract_id, _) = entry?;
+            contracts.insert(contract_id);
+        }
+
+        for contract_id in contracts {
+            let stored = Bytes32::from(
+                self.on_chain()
+                    .storage::<ContractsState>()
+                    .root(&contract_id)?,
+            );
+            let computed = self.contract_root::<ContractsState>(&contract_id)?;
+            if stored != computed {
+                mismatches.push(CommitmentMismatch::ContractStateRoot {
+                    contract_id,
+                    stored,
+                    computed,
+                });
+            }
+
+            let stored = Bytes32::from(
+                self.on_chain()
+                    .storage::<ContractsAssets>()
+                    .root(&contract_id)?,
+            );
+            let computed = self.contract_root::<ContractsAssets>(&contract_id)?;
+            if stored != computed {
+                mismatches.push(CommitmentMismatch::ContractBalancesRoot {
+                    contract_id,
+                    stored,
+                    computed,
+                });
+            }
+        }
+
+        if !mismatches.is_empty() {
+            let report = mismatches
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Err(anyhow!(
+                "The state commitment at the height {latest_height} doesn't match the table contents:\n{report}"
+            ))
+        }
+
+        Ok(true)
+    }
+
+    /// Computes the root of the sparse Merkle tree of the contract
+    /// from the encoded entries of the table `T`.
+    fn contract_root<T>(&self, contract_id: &ContractId) -> StorageResult<Bytes32>
+    where
+        T: TableWithBlueprint<Column = Column>,
+    {
+        let entries = self
+            .on_chain()
+            .iter_store(
+                T::column(),
+                Some(contract_id.as_ref()),
+                None,
+                IterDirection::Forward,
+            )
+            .collect::<StorageResult<Vec<_>>>()?;
+        let root = in_memory::MerkleTree::root_from_set(
+            entries
+                .iter()
+                .map(|(key, value)| (MerkleTreeKey::new(key), value.as_slice())),
+        );
+        Ok(root.into())
+    }
+
+    /// Compares the on-chain state of this database with the `other` one.
+    ///
+    /// Tables are walked in the key order by both databases at the same time,
+    /// and the differences are yielded one by one, so the memory usage
+    /// doesn't depend on the size of the state.
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> impl Iterator<Item = StorageResult<StateDiff>> + 'a {
+        self.diff_table(other)
+            .map(|diff| diff.map(StateDiff::Coins))
+            .chain(
+                self.diff_table(other)
+                    .map(|diff| diff.map(StateDiff::Messages)),
+            )
+            .chain(
+                self.diff_table(other)
+                    .map(|diff| diff.map(StateDiff::ContractsRawCode)),
+            )
+            .chain(
+                self.diff_table(other)
+                    .map(|diff| diff.map(StateDiff::ContractsState)),
+            )
+            .chain(
+                self.diff_table(other)
+                    .map(|diff| diff.map(StateDiff::ContractsAssets)),
+            )
+            .chain(
+                self.diff_table(other)
+                    .map(|diff| diff.map(StateDiff::ContractsLatestUtxo)),
+            )
+            .chain(
+                self.diff_table(other)
+                    .map(|diff| diff.map(StateDiff::Transactions)),
+            )
+    }
+
+    fn diff_table<'a, T>(
+        &'a self,
+        other: &'a Self,
+    ) -> impl Iterator<Item = StorageResult<TableDiff<T>>> + 'a
+    where
+        T: TableWithBlueprint<Column = Column> + 'a,
+        T::Blueprint: BlueprintInspect<T, Database<OnChain>>,
+    {
+        let mut this_iter = self
+            .on_chain()
+            .iter_store(T::column(), None, None, IterDirection::Forward)
+            .peekable();
+        let mut other_iter = other
+            .on_chain()
+            .iter_store(T::column(), None, None, IterDirection::Forward)
+            .peekable();
+
+        core::iter::from_fn(move || loop {
+            // Errors are yielded as soon as they are reached by any of iterators.
+            let ordering = match (this_iter.peek(), other_iter.peek()) {
+                (None, None) => return None,
+                (Some(Err(_)), _) | (Some(Ok(_)), None) => Ordering::Less,
+                (_, Some(Err(_))) | (None, Some(Ok(_))) => Ordering::Greater,
+                (Some(Ok(this)), Some(Ok(other))) => this.0.cmp(&other.0),
+            };
+
+            let diff = match ordering {
+                Ordering::Less => this_iter
+                    .next()?
+                    .and_then(decode_entry)
+                    .map(TableDiff::Removed),
+                Ordering::Greater => other_iter
+                    .next()?
+                    .and_then(decode_entry)
+                    .map(TableDiff::Added),
+                Ordering::Equal => match (this_iter.next()?, other_iter.next()?) {
+                    (Ok(this), Ok(other)) if this.1 == other.1 => continue,
+                    (Ok(this), Ok(other)) => decode_entry(this).and_then(|this| {
+                        Ok(TableDiff::Changed(this, decode_entry(other)?))
+                    }),
+                    (Err(error), _) | (_, Err(error)) => Err(error),
+                },
+            };
+            return Some(diff)
+        })
+    }
+
     #[cfg(feature = "test-helpers")]
     pub fn read_state_config(&self) -> StorageResult<StateConfig> {
-        use fuel_core_chain_config::AddTable;
         use itertools::Itertools;
         let mut builder = StateConfigBuilder::default();
 
@@ -183,3 +1047,346 @@ impl CombinedDatabase {
         Ok(state_config)
     }
 }
+
+fn decode_entry<T>((key, value): (Vec<u8>, Value)) -> StorageResult<TableEntry<T>>
+where
+    T: TableWithBlueprint,
+    T::Blueprint: BlueprintInspect<T, Database<OnChain>>,
+{
+    let key =
+        <T::Blueprint as BlueprintInspect<T, Database<OnChain>>>::KeyCodec::decode(&key)
+            .map_err(StorageError::Codec)?;
+    let value =
+        <T::Blueprint as BlueprintInspect<T, Database<OnChain>>>::ValueCodec::decode(
+            &value,
+        )
+        .map_err(StorageError::Codec)?;
+    Ok(TableEntry { key, value })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_chain_config::Randomize;
+    use fuel_core_storage::{
+        tables::merkle::SparseMerkleMetadata,
+        ContractsAssetKey,
+        ContractsStateKey,
+    };
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        entities::{
+            coins::coin::CompressedCoin,
+            contract::ContractUtxoInfo,
+            Message,
+        },
+        fuel_tx::TxPointer,
+        fuel_types::{
+            AssetId,
+            Bytes32,
+        },
+        fuel_vm::Contract,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn insert_random_contract(
+        db: &mut CombinedDatabase,
+        rng: &mut StdRng,
+        contract_id: &ContractId,
+        slots: usize,
+    ) {
+        let mut tx = db.on_chain_mut().write_transaction();
+        let code: Contract = Randomize::randomize(&mut *rng);
+        tx.storage_as_mut::<ContractsRawCode>()
+            .insert(contract_id, code.as_ref())
+            .unwrap();
+        let utxo: ContractUtxoInfo = Randomize::randomize(&mut *rng);
+        tx.storage_as_mut::<ContractsLatestUtxo>()
+            .insert(contract_id, &utxo)
+            .unwrap();
+        for _ in 0..slots {
+            let state_key = ContractsStateKey::new(contract_id, &rng.gen::<Bytes32>());
+            tx.storage_as_mut::<ContractsState>()
+                .insert(&state_key, &rng.gen::<[u8; 32]>())
+                .unwrap();
+            let asset_key = ContractsAssetKey::new(contract_id, &rng.gen::<AssetId>());
+            tx.storage_as_mut::<ContractsAssets>()
+                .insert(&asset_key, &rng.gen())
+                .unwrap();
+        }
+        tx.commit().unwrap();
+    }
+
+    #[test]
+    fn exported_contract_can_be_imported_into_fresh_database() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(1234);
+        let contract_id = ContractId::new([1; 32]);
+        let other_contract_id = ContractId::new([2; 32]);
+        let mut source = CombinedDatabase::in_memory();
+        insert_random_contract(&mut source, &mut rng, &contract_id, 10);
+        insert_random_contract(&mut source, &mut rng, &other_contract_id, 10);
+
+        // When
+        let snapshot = source.export_contract(&contract_id).unwrap();
+        let mut destination = CombinedDatabase::in_memory();
+        destination.import_contract(snapshot.clone()).unwrap();
+
+        // Then
+        assert_eq!(snapshot.states.len(), 10);
+        assert_eq!(snapshot.balances.len(), 10);
+        assert_eq!(destination.export_contract(&contract_id).unwrap(), snapshot);
+        assert!(destination.export_contract(&other_contract_id).is_err());
+    }
+
+    #[test]
+    fn import_contract_fails_if_contract_already_exists() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(1234);
+        let contract_id = ContractId::new([1; 32]);
+        let mut db = CombinedDatabase::in_memory();
+        insert_random_contract(&mut db, &mut rng, &contract_id, 1);
+        let snapshot = db.export_contract(&contract_id).unwrap();
+
+        // When
+        let result = db.import_contract(snapshot);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn check_integrity_reports_no_issues_for_empty_database() {
+        let db = CombinedDatabase::in_memory();
+
+        let report = db.check_integrity().unwrap();
+
+        assert!(report.is_healthy());
+    }
+
+    #[test]
+    fn check_integrity_reports_all_issues_of_corrupted_database() {
+        // Given
+        let mut db = CombinedDatabase::in_memory();
+        let mut tx = db.on_chain_mut().write_transaction();
+        let healthy_contract = ContractId::new([1; 32]);
+        tx.storage_as_mut::<ContractsRawCode>()
+            .insert(&healthy_contract, &[1, 2, 3])
+            .unwrap();
+        tx.storage_as_mut::<ContractsLatestUtxo>()
+            .insert(&healthy_contract, &ContractUtxoInfo::default())
+            .unwrap();
+        tx.storage_as_mut::<FuelBlocks>()
+            .insert(&BlockHeight::new(0), &CompressedBlock::default())
+            .unwrap();
+
+        let future_coin_id = UtxoId::new([2; 32].into(), 0);
+        let mut coin = CompressedCoin::default();
+        coin.set_tx_pointer(TxPointer::new(5.into(), 0));
+        tx.storage_as_mut::<Coins>()
+            .insert(&future_coin_id, &coin)
+            .unwrap();
+
+        let mut message = Message::default();
+        message.set_nonce(Nonce::new([3; 32]));
+        message.set_da_height(DaBlockHeight(7));
+        let wrong_key = Nonce::new([4; 32]);
+        tx.storage_as_mut::<Messages>()
+            .insert(&wrong_key, &message)
+            .unwrap();
+
+        let contract_without_code = ContractId::new([5; 32]);
+        tx.storage_as_mut::<ContractsLatestUtxo>()
+            .insert(&contract_without_code, &ContractUtxoInfo::default())
+            .unwrap();
+
+        let contract_without_utxo = ContractId::new([6; 32]);
+        tx.storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_without_utxo, &[1, 2, 3])
+            .unwrap();
+
+        let unknown_contract = ContractId::new([7; 32]);
+        for slot in 0..2u8 {
+            let state_key =
+                ContractsStateKey::new(&unknown_contract, &Bytes32::new([slot; 32]));
+            tx.storage_as_mut::<ContractsState>()
+                .insert(&state_key, &[slot; 32])
+                .unwrap();
+        }
+        let asset_key = ContractsAssetKey::new(&unknown_contract, &AssetId::zeroed());
+        tx.storage_as_mut::<ContractsAssets>()
+            .insert(&asset_key, &1)
+            .unwrap();
+        tx.commit().unwrap();
+
+        // When
+        let report = db.check_integrity().unwrap();
+
+        // Then
+        assert_eq!(
+            report.issues,
+            vec![
+                IntegrityIssue::CoinFromFutureBlock {
+                    utxo_id: future_coin_id,
+                    block_height: 5.into(),
+                    latest_height: 0.into(),
+                },
+                IntegrityIssue::MessageUnderWrongKey {
+                    key: wrong_key,
+                    nonce: Nonce::new([3; 32]),
+                },
+                IntegrityIssue::MessageFromFutureDaBlock {
+                    nonce: Nonce::new([3; 32]),
+                    da_height: DaBlockHeight(7),
+                    latest_da_height: DaBlockHeight(0),
+                },
+                IntegrityIssue::ContractWithoutCode(contract_without_code),
+                IntegrityIssue::ContractWithoutUtxo(contract_without_utxo),
+                IntegrityIssue::StateOfUnknownContract(unknown_contract),
+                IntegrityIssue::BalancesOfUnknownContract(unknown_contract),
+            ]
+        );
+    }
+
+    fn insert_chained_blocks(db: &mut CombinedDatabase, count: u32) {
+        for height in 0..count {
+            let height = BlockHeight::new(height);
+            let mut block = CompressedBlock::default();
+            block.header_mut().set_block_height(height);
+            if let Some(previous_height) = height.pred() {
+                let root = db
+                    .on_chain()
+                    .storage::<FuelBlocks>()
+                    .root(&previous_height)
+                    .unwrap();
+                block.header_mut().set_previous_root(root.into());
+            }
+            db.on_chain_mut()
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height, &block)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn verify_state_commitment_has_nothing_to_verify_without_blocks() {
+        let db = CombinedDatabase::in_memory();
+
+        let verified = db.verify_state_commitment().unwrap();
+
+        assert!(!verified);
+    }
+
+    #[test]
+    fn verify_state_commitment_succeeds_for_clean_database() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut db = CombinedDatabase::in_memory();
+        insert_chained_blocks(&mut db, 3);
+        insert_random_contract(&mut db, &mut rng, &ContractId::new([1; 32]), 10);
+        insert_random_contract(&mut db, &mut rng, &ContractId::new([2; 32]), 10);
+
+        // When
+        let verified = db.verify_state_commitment().unwrap();
+
+        // Then
+        assert!(verified);
+    }
+
+    #[test]
+    fn verify_state_commitment_fails_for_tampered_database() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut db = CombinedDatabase::in_memory();
+        insert_chained_blocks(&mut db, 3);
+        let tampered_contract = ContractId::new([1; 32]);
+        insert_random_contract(&mut db, &mut rng, &tampered_contract, 10);
+        insert_random_contract(&mut db, &mut rng, &ContractId::new([2; 32]), 10);
+        db.on_chain_mut()
+            .storage_as_mut::<ContractsStateMerkleMetadata>()
+            .insert(&tampered_contract, &SparseMerkleMetadata::new([9; 32]))
+            .unwrap();
+
+        // When
+        let result = db.verify_state_commitment();
+
+        // Then
+        let report = result.unwrap_err().to_string();
+        assert!(
+            report.contains(&format!(
+                "The stored state root of the contract {tampered_contract}"
+            )),
+            "{report}"
+        );
+        assert_eq!(report.lines().count(), 2, "{report}");
+    }
+
+    #[test]
+    fn diff_reports_coin_missing_in_one_of_databases() {
+        // Given
+        let shared_coin_id = UtxoId::new([1; 32].into(), 0);
+        let extra_coin_id = UtxoId::new([2; 32].into(), 0);
+        let mut coin = CompressedCoin::default();
+        coin.set_amount(100);
+
+        let mut this = CombinedDatabase::in_memory();
+        let mut other = CombinedDatabase::in_memory();
+        for db in [&mut this, &mut other] {
+            db.on_chain_mut()
+                .storage_as_mut::<Coins>()
+                .insert(&shared_coin_id, &coin)
+                .unwrap();
+        }
+        other
+            .on_chain_mut()
+            .storage_as_mut::<Coins>()
+            .insert(&extra_coin_id, &coin)
+            .unwrap();
+
+        // When
+        let diff = this
+            .diff(&other)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        let expected = vec![StateDiff::Coins(TableDiff::Added(TableEntry {
+            key: extra_coin_id,
+            value: coin,
+        }))];
+        assert_eq!(diff, expected);
+        assert!(this.diff(&this).next().is_none());
+    }
+
+    #[test]
+    fn sync_policy_decides_which_block_commits_are_synced() {
+        let every_third = DbSyncPolicy::EveryNBlocks(NonZeroU64::new(3).unwrap());
+
+        let synced = |policy: DbSyncPolicy| {
+            (1..=6)
+                .filter(|block_number| policy.should_sync(*block_number))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(synced(DbSyncPolicy::Always), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(synced(every_third), vec![3, 6]);
+        assert_eq!(synced(DbSyncPolicy::Never), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn sync_policy_can_be_parsed_from_its_display() {
+        for policy in [
+            DbSyncPolicy::Always,
+            DbSyncPolicy::EveryNBlocks(NonZeroU64::new(10).unwrap()),
+            DbSyncPolicy::Never,
+        ] {
+            assert_eq!(policy.to_string().parse::<DbSyncPolicy>().unwrap(), policy);
+        }
+        assert!("0".parse::<DbSyncPolicy>().is_err());
+        assert!("sometimes".parse::<DbSyncPolicy>().is_err());
+    }
+}
@@ -12,6 +12,10 @@ use crate::{
     },
     graphql_api::storage::blocks::FuelBlockIdsToHeights,
     state::{
+        deduplicated_bytecode::{
+            self,
+            DeduplicatedBytecode,
+        },
         in_memory::memory_store::MemoryStore,
         ChangesIterator,
         DataSource,
@@ -59,7 +63,10 @@ use fuel_core_types::{
 use itertools::Itertools;
 use std::{
     fmt::Debug,
-    sync::Arc,
+    sync::{
+        atomic::AtomicBool,
+        Arc,
+    },
 };
 
 pub use fuel_core_database::Error;
@@ -95,6 +102,23 @@ where
 }
 
 impl Database<OnChain> {
+    /// Stores identical contract bytecode only once.
+    /// See [`DeduplicatedBytecode`] for details.
+    pub fn with_deduplicated_bytecode(self) -> Self {
+        Self {
+            height: self.height,
+            data: Arc::new(DeduplicatedBytecode::new(self.data)),
+        }
+    }
+
+    /// Checks that the database is opened with the bytecode deduplication mode
+    /// it was created with. See [`deduplicated_bytecode::check_mode`] for details.
+    pub fn check_bytecode_deduplication(&self, deduplicate: bool) -> Result<()> {
+        let is_new_database =
+            self.latest_height().map_err(anyhow::Error::from)?.is_none();
+        deduplicated_bytecode::check_mode(&self.data, deduplicate, is_new_database)
+    }
+
     pub fn latest_block(&self) -> StorageResult<CompressedBlock> {
         self.iter_all::<FuelBlocks>(Some(IterDirection::Reverse))
             .next()
@@ -143,10 +167,20 @@ where
         database
     }
 
+    /// Returns the read-only copy of the database at the current height.
+    /// See [`crate::state::TransactableStorage::checkpoint`].
+    pub fn checkpoint(&self) -> StorageResult<Self> {
+        Ok(Self::new(self.data.checkpoint()?))
+    }
+
     #[cfg(feature = "rocksdb")]
-    pub fn open_rocksdb(path: &Path, capacity: impl Into<Option<usize>>) -> Result<Self> {
+    pub fn open_rocksdb(
+        path: &Path,
+        capacity: impl Into<Option<usize>>,
+        sync_policy: crate::combined_database::DbSyncPolicy,
+    ) -> Result<Self> {
         use anyhow::Context;
-        let db = RocksDb::<Description>::default_open(path, capacity.into()).map_err(Into::<anyhow::Error>::into).with_context(|| format!("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db e.g. `rm -rf {path:?}`"))?;
+        let db = RocksDb::<Description>::default_open(path, capacity.into(), sync_policy).map_err(Into::<anyhow::Error>::into).with_context(|| format!("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db e.g. `rm -rf {path:?}`"))?;
 
         Ok(Database::new(Arc::new(db)))
     }
@@ -156,6 +190,16 @@ impl<Description> Database<Description>
 where
     Description: DatabaseDescription,
 {
+    /// Returns the approximate size of the stored data in bytes.
+    pub fn size(&self) -> StorageResult<u64> {
+        self.data.size()
+    }
+
+    /// Compacts the stored data. See [`crate::state::TransactableStorage::compact`].
+    pub fn compact(&self, stop: &AtomicBool) -> StorageResult<()> {
+        self.data.compact(stop)
+    }
+
     pub fn in_memory() -> Self {
         let data = Arc::<MemoryStore<Description>>::new(MemoryStore::default());
         Self {
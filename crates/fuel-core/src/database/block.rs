@@ -62,6 +62,16 @@ impl Database {
         self.latest_compressed_block()
     }
 
+    /// Get the first block of the chain known to the database, the genesis block.
+    pub fn get_genesis_block(&self) -> StorageResult<Option<CompressedBlock>> {
+        let pair = self
+            .iter_all::<FuelBlocks>(Some(IterDirection::Forward))
+            .next()
+            .transpose()?;
+
+        Ok(pair.map(|(_, compressed_block)| compressed_block))
+    }
+
     /// Retrieve the full block and all associated transactions
     pub(crate) fn get_full_block(
         &self,
@@ -4,7 +4,9 @@ use crate::{
         Database,
     },
     fuel_core_graphql_api::storage::coins::{
+        asset_coin_id_key,
         owner_coin_id_key,
+        AssetCoins,
         OwnedCoins,
     },
 };
@@ -24,6 +26,7 @@ use fuel_core_types::{
     entities::coins::coin::CompressedCoin,
     fuel_tx::{
         Address,
+        AssetId,
         UtxoId,
     },
 };
@@ -53,6 +56,31 @@ impl Database<OffChain> {
             })
         })
     }
+
+    pub fn asset_coins_ids(
+        &self,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<UtxoId>> + '_ {
+        let start_coin = start_coin.map(|b| asset_coin_id_key(asset_id, &b));
+        self.iter_all_filtered::<AssetCoins, _>(
+            Some(*asset_id),
+            start_coin.as_ref(),
+            direction,
+        )
+        // Safety: key is always 66 bytes
+        .map(|res| {
+            res.map(|(key, _)| {
+                UtxoId::new(
+                    TxId::try_from(&key[32..64]).expect("The slice has size 32"),
+                    u16::from_be_bytes(
+                        key[64..].try_into().expect("The slice has size 2"),
+                    ),
+                )
+            })
+        })
+    }
 }
 
 impl Database {
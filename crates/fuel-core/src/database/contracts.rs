@@ -13,11 +13,13 @@ use fuel_core_storage::{
         ContractsState,
     },
     ContractsAssetKey,
+    ContractsStateKey,
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_types::fuel_types::{
     AssetId,
+    Bytes32,
     ContractId,
 };
 use itertools::Itertools;
@@ -100,6 +102,21 @@ impl Database {
         self.iter_all_by_prefix::<ContractsState, _>(Some(contract_id))
             .map_ok(|(key, value)| TableEntry { key, value })
     }
+
+    pub fn filter_contract_states_from(
+        &self,
+        contract_id: ContractId,
+        start_key: Option<Bytes32>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<TableEntry<ContractsState>>> + '_ {
+        let start_key = start_key.map(|key| ContractsStateKey::new(&contract_id, &key));
+        self.iter_all_filtered::<ContractsState, _>(
+            Some(contract_id),
+            start_key.as_ref(),
+            direction,
+        )
+        .map_ok(|(key, value)| TableEntry { key, value })
+    }
 }
 
 #[cfg(test)]
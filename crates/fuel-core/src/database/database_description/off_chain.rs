@@ -27,8 +27,9 @@ impl DatabaseDescription for OffChain {
         match column {
             Self::Column::OwnedCoins
             | Self::Column::TransactionsByOwnerBlockIdx
-            | Self::Column::OwnedMessageIds => {
-                // prefix is address length
+            | Self::Column::OwnedMessageIds
+            | Self::Column::AssetCoins => {
+                // prefix is address or asset id length
                 Some(32)
             }
             _ => None,
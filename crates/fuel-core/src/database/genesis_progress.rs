@@ -138,4 +138,14 @@ impl Database {
 
         Ok(root_calculator.root())
     }
+
+    /// The Binary Merkle Tree root of the coins, messages and contracts roots.
+    pub fn genesis_state_root(&self) -> Result<MerkleRoot> {
+        let mut root_calculator = MerkleRootCalculator::new();
+        root_calculator.push(self.genesis_coins_root()?.as_slice());
+        root_calculator.push(self.genesis_messages_root()?.as_slice());
+        root_calculator.push(self.genesis_contracts_root()?.as_slice());
+
+        Ok(root_calculator.root())
+    }
 }
@@ -7,6 +7,8 @@ use crate::{
         OwnedTransactionIndexCursor,
         OwnedTransactionIndexKey,
         OwnedTransactions,
+        PendingTransactions,
+        TransactionLabels,
         TransactionStatuses,
     },
 };
@@ -17,13 +19,19 @@ use fuel_core_storage::{
         IteratorOverTable,
     },
     tables::Transactions,
+    transactional::{
+        ConflictPolicy,
+        StorageTransaction,
+    },
     Result as StorageResult,
+    StorageAsMut,
 };
 use fuel_core_types::{
     self,
     fuel_tx::{
         Bytes32,
         Transaction,
+        TxId,
         TxPointer,
     },
     fuel_types::Address,
@@ -74,4 +82,78 @@ impl Database<OffChain> {
             .get(id)
             .map(|v| v.map(|v| v.into_owned()))
     }
+
+    /// Appends the transaction to the transactions submitted with the `label`.
+    ///
+    /// The labels are not a part of any block, so they are committed
+    /// without the update of the database height.
+    pub fn add_transaction_label(&self, label: &str, id: &Bytes32) -> StorageResult<()> {
+        // Holding the height lock serializes the concurrent updates of the same label.
+        let _guard = self.height.lock();
+        let mut transaction = StorageTransaction::transaction(
+            self,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        let mut ids = transaction
+            .storage::<TransactionLabels>()
+            .get(label)?
+            .map(|ids| ids.into_owned())
+            .unwrap_or_default();
+        ids.push(*id);
+        transaction
+            .storage_as_mut::<TransactionLabels>()
+            .insert(label, &ids)?;
+
+        self.data
+            .as_ref()
+            .commit_changes(None, transaction.into_changes())
+    }
+
+    /// Returns the pending transactions of the `TxPool` persisted along with their ids.
+    pub fn pending_transactions(&self) -> StorageResult<Vec<(TxId, Transaction)>> {
+        self.iter_all::<PendingTransactions>(None).collect()
+    }
+
+    /// Persists the pending transactions of the `TxPool` under their ids.
+    ///
+    /// The pending transactions are not a part of any block, so they are committed
+    /// without the update of the database height.
+    pub fn store_pending_transactions(
+        &self,
+        transactions: Vec<(TxId, Transaction)>,
+    ) -> StorageResult<()> {
+        let mut transaction = StorageTransaction::transaction(
+            self,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        for (id, tx) in transactions {
+            transaction
+                .storage_as_mut::<PendingTransactions>()
+                .insert(&id, &tx)?;
+        }
+
+        self.data
+            .as_ref()
+            .commit_changes(None, transaction.into_changes())
+    }
+
+    /// Removes the persisted pending transactions with the `tx_ids`.
+    pub fn remove_pending_transactions(&self, tx_ids: &[TxId]) -> StorageResult<()> {
+        let mut transaction = StorageTransaction::transaction(
+            self,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        for id in tx_ids {
+            transaction
+                .storage_as_mut::<PendingTransactions>()
+                .remove(id)?;
+        }
+
+        self.data
+            .as_ref()
+            .commit_changes(None, transaction.into_changes())
+    }
 }
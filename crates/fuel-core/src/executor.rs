@@ -47,6 +47,7 @@ mod tests {
         fuel_asm::{
             op,
             GTFArgs,
+            PanicReason,
             RegId,
         },
         fuel_crypto::SecretKey,
@@ -104,6 +105,7 @@ mod tests {
             checked_transaction::{
                 CheckError,
                 EstimatePredicates,
+                IntoChecked,
             },
             interpreter::ExecutableTransaction,
             script_with_data_offset,
@@ -124,6 +126,10 @@ mod tests {
                 TransactionValidityError,
             },
             relayer::Event,
+            txpool::{
+                PoolTransaction,
+                ValidationCache,
+            },
         },
         tai64::Tai64,
     };
@@ -134,6 +140,11 @@ mod tests {
         Rng,
         SeedableRng,
     };
+    use std::{
+        num::NonZeroUsize,
+        sync::Arc,
+        time::Duration,
+    };
 
     #[derive(Clone, Debug, Default)]
     struct Config {
@@ -145,6 +156,24 @@ mod tests {
         pub backtrace: bool,
         /// Default mode for utxo_validation
         pub utxo_validation_default: bool,
+        /// The number of workers used to validate independent transactions in parallel.
+        pub parallel_execution_workers: Option<NonZeroUsize>,
+        /// Overrides the maximum depth of nested contract calls.
+        pub max_call_depth: Option<u64>,
+        /// Overrides the maximum number of inputs of the transaction.
+        pub max_inputs: Option<u16>,
+        /// Overrides the maximum number of outputs of the transaction.
+        pub max_outputs: Option<u16>,
+        /// The maximum wall-clock time of the execution of the transaction.
+        pub max_tx_execution_time: Option<Duration>,
+        /// The maximum bytes of the VM memory occupied by the transaction.
+        pub max_tx_memory: Option<u64>,
+        /// The transactions checked at the admission into the `TxPool`.
+        pub validation_cache: Option<ValidationCache>,
+        /// Records the storage keys accessed by each transaction during the dry run.
+        pub record_storage_access: bool,
+        /// Records the gas consumed by each opcode of the script during the dry run.
+        pub record_gas_breakdown: bool,
     }
 
     #[derive(Clone, Debug)]
@@ -197,6 +226,15 @@ mod tests {
         let executor_config = fuel_core_upgradable_executor::config::Config {
             backtrace: config.backtrace,
             utxo_validation_default: config.utxo_validation_default,
+            parallel_execution_workers: config.parallel_execution_workers,
+            max_call_depth: config.max_call_depth,
+            max_inputs: config.max_inputs,
+            max_outputs: config.max_outputs,
+            max_tx_execution_time: config.max_tx_execution_time,
+            max_tx_memory: config.max_tx_memory,
+            validation_cache: config.validation_cache,
+            record_storage_access: config.record_storage_access,
+            record_gas_breakdown: config.record_gas_breakdown,
         };
 
         let database = add_consensus_parameters(database, &config.consensus_parameters);
@@ -344,6 +382,471 @@ mod tests {
         assert!(skipped_transactions.is_empty());
     }
 
+    #[test]
+    fn parallel_validation_produces_the_same_result_as_sequential() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let mut producer = create_executor(Default::default(), Default::default());
+        let sequential_verifier = create_executor(Default::default(), Default::default());
+        let parallel_verifier = create_executor(
+            Default::default(),
+            Config {
+                parallel_execution_workers: NonZeroUsize::new(4),
+                ..Default::default()
+            },
+        );
+
+        // Given
+        let (create, script) = setup_executable_script();
+        let mut transactions: Vec<Transaction> = (0..20)
+            .map(|i| {
+                TransactionBuilder::script(vec![], vec![])
+                    .script_gas_limit(10)
+                    .add_unsigned_coin_input(
+                        SecretKey::random(&mut rng),
+                        rng.gen(),
+                        (i + 1) * 100,
+                        AssetId::default(),
+                        Default::default(),
+                    )
+                    .add_output(Output::coin(rng.gen(), i * 10, AssetId::default()))
+                    .add_output(Output::change(rng.gen(), 0, AssetId::default()))
+                    .finalize_as_transaction()
+            })
+            .collect();
+        // The script calls the contract deployed by the `create` transaction,
+        // so they must be executed sequentially.
+        transactions.insert(5, create.into());
+        transactions.insert(15, script.into());
+
+        let mut block = Block::default();
+        block.header_mut().set_block_height(1u32.into());
+        *block.transactions_mut() = transactions;
+
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            ..
+        } = producer
+            .execute_and_commit(ExecutionTypes::Production(block.into()))
+            .unwrap();
+        assert!(skipped_transactions.is_empty());
+
+        // When
+        let (sequential_result, sequential_changes) = sequential_verifier
+            .execute_without_commit(ExecutionTypes::Validation(block.clone()))
+            .unwrap()
+            .into();
+        let (parallel_result, parallel_changes) = parallel_verifier
+            .execute_without_commit(ExecutionTypes::Validation(block))
+            .unwrap()
+            .into();
+
+        // Then
+        assert_eq!(sequential_changes, parallel_changes);
+        assert_eq!(sequential_result.block.id(), parallel_result.block.id());
+        assert_eq!(
+            sequential_result
+                .tx_status
+                .iter()
+                .map(|status| status.id)
+                .collect_vec(),
+            parallel_result
+                .tx_status
+                .iter()
+                .map(|status| status.id)
+                .collect_vec()
+        );
+        assert_eq!(
+            format!("{:?}", sequential_result.events),
+            format!("{:?}", parallel_result.events)
+        );
+    }
+
+    #[test]
+    fn call_chain_exceeding_max_call_depth_is_rejected() {
+        let mut rng = StdRng::seed_from_u64(2322);
+
+        // Given
+        // The contract calls itself until the transaction runs out of gas.
+        let (create, contract_id) = create_contract(
+            vec![
+                // Allocate the `Call` structure: the contract id and two words.
+                op::movi(0x10, Call::LEN as u32),
+                op::aloc(0x10),
+                // The contract id of the current call frame is stored at `$fp`.
+                op::mcpi(RegId::HP, RegId::FP, ContractId::LEN as u16),
+                op::call(RegId::HP, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ]
+            .into_iter()
+            .collect::<Vec<u8>>(),
+            &mut rng,
+        );
+        let (script, data_offset) = script_with_data_offset!(
+            data_offset,
+            vec![
+                op::movi(0x10, data_offset),
+                op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ],
+            TxParameters::DEFAULT.tx_offset()
+        );
+        let script_data = Call::new(contract_id, 0, 0).to_bytes();
+        let script = TxBuilder::new(2322)
+            .script_gas_limit(TxParameters::DEFAULT.max_gas_per_tx() >> 1)
+            .start_script(script, script_data)
+            .contract_input(contract_id)
+            .fee_input()
+            .contract_output(&contract_id)
+            .build()
+            .transaction()
+            .clone();
+        let script_id = script.id(&ChainId::default());
+
+        let max_call_depth = 2;
+        let mut executor = create_executor(
+            Default::default(),
+            Config {
+                max_call_depth: Some(max_call_depth),
+                ..Default::default()
+            },
+        );
+
+        let block = PartialFuelBlock {
+            header: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: 1.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            transactions: vec![create.into(), script.into()],
+        };
+
+        // When
+        let ExecutionResult {
+            skipped_transactions,
+            ..
+        } = executor
+            .execute_and_commit(ExecutionBlock::Production(block))
+            .unwrap();
+
+        // Then
+        assert_eq!(skipped_transactions.len(), 1);
+        let (tx_id, error) = &skipped_transactions[0];
+        assert_eq!(tx_id, &script_id);
+        assert!(matches!(
+            error,
+            ExecutorError::CallDepthExceeded {
+                transaction_id,
+                depth,
+                max_call_depth: max,
+            } if transaction_id == &script_id && *depth > max_call_depth && *max == max_call_depth
+        ));
+    }
+
+    #[test]
+    fn call_chain_within_max_call_depth_is_executed() {
+        // Given
+        let (create, script) = setup_executable_script();
+        let mut executor = create_executor(
+            Default::default(),
+            Config {
+                max_call_depth: Some(1),
+                ..Default::default()
+            },
+        );
+
+        let block = PartialFuelBlock {
+            header: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: 1.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            transactions: vec![create.into(), script.into()],
+        };
+
+        // When
+        let ExecutionResult {
+            skipped_transactions,
+            tx_status,
+            ..
+        } = executor
+            .execute_and_commit(ExecutionBlock::Production(block))
+            .unwrap();
+
+        // Then
+        assert!(skipped_transactions.is_empty());
+        assert!(matches!(
+            tx_status[1].result,
+            TransactionExecutionResult::Success { .. }
+        ));
+    }
+
+    fn slow_script() -> Script {
+        // The loop decrements the register until it reaches zero.
+        let script = vec![
+            op::movi(0x10, 100_000),
+            op::subi(0x10, 0x10, 1),
+            op::jnzb(0x10, RegId::ZERO, 0),
+            op::ret(RegId::ONE),
+        ];
+        TxBuilder::new(2322)
+            .script_gas_limit(TxParameters::DEFAULT.max_gas_per_tx() >> 1)
+            .start_script(script, vec![])
+            .fee_input()
+            .build()
+            .transaction()
+            .clone()
+    }
+
+    fn execute_with_max_tx_execution_time(
+        script: Script,
+        max_tx_execution_time: Duration,
+    ) -> ExecutionResult {
+        let mut executor = create_executor(
+            Default::default(),
+            Config {
+                max_tx_execution_time: Some(max_tx_execution_time),
+                ..Default::default()
+            },
+        );
+        let block = PartialFuelBlock {
+            header: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: 1.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            transactions: vec![script.into()],
+        };
+
+        executor
+            .execute_and_commit(ExecutionBlock::Production(block))
+            .unwrap()
+    }
+
+    fn is_out_of_gas(result: &TransactionExecutionResult) -> bool {
+        let TransactionExecutionResult::Failed { receipts, .. } = result else {
+            return false
+        };
+        receipts.iter().any(|receipt| {
+            matches!(
+                receipt,
+                Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::OutOfGas
+            )
+        })
+    }
+
+    #[test]
+    fn transaction_exceeding_max_tx_execution_time_fails() {
+        // Given
+        let script = slow_script();
+        let max_tx_execution_time = Duration::from_nanos(1);
+
+        // When
+        let ExecutionResult {
+            skipped_transactions,
+            tx_status,
+            ..
+        } = execute_with_max_tx_execution_time(script, max_tx_execution_time);
+
+        // Then
+        assert!(skipped_transactions.is_empty());
+        assert!(is_out_of_gas(&tx_status[0].result));
+    }
+
+    #[test]
+    fn max_tx_execution_time_is_not_checked_during_validation() {
+        // Given
+        let script = slow_script();
+        let ExecutionResult {
+            block: produced_block,
+            ..
+        } = execute_with_max_tx_execution_time(script, Duration::from_secs(3600));
+        let mut validator = create_executor(
+            Default::default(),
+            Config {
+                max_tx_execution_time: Some(Duration::from_nanos(1)),
+                ..Default::default()
+            },
+        );
+
+        // When
+        let ExecutionResult {
+            skipped_transactions,
+            tx_status,
+            ..
+        } = validator
+            .execute_and_commit(ExecutionBlock::Validation(produced_block))
+            .unwrap();
+
+        // Then
+        assert!(skipped_transactions.is_empty());
+        assert!(matches!(
+            tx_status[0].result,
+            TransactionExecutionResult::Success { .. }
+        ));
+    }
+
+    #[test]
+    fn transaction_within_max_tx_execution_time_is_executed() {
+        // Given
+        let script = slow_script();
+        let max_tx_execution_time = Duration::from_secs(3600);
+
+        // When
+        let ExecutionResult {
+            skipped_transactions,
+            tx_status,
+            ..
+        } = execute_with_max_tx_execution_time(script, max_tx_execution_time);
+
+        // Then
+        assert!(skipped_transactions.is_empty());
+        assert!(matches!(
+            tx_status[0].result,
+            TransactionExecutionResult::Success { .. }
+        ));
+    }
+
+    fn memory_hungry_script() -> Script {
+        // Allocates 1 MiB on the heap.
+        let script = vec![
+            op::movi(0x10, 1),
+            op::slli(0x10, 0x10, 20),
+            op::aloc(0x10),
+            op::ret(RegId::ONE),
+        ];
+        TxBuilder::new(2322)
+            .script_gas_limit(TxParameters::DEFAULT.max_gas_per_tx() >> 1)
+            .start_script(script, vec![])
+            .fee_input()
+            .build()
+            .transaction()
+            .clone()
+    }
+
+    fn execute_with_max_tx_memory(script: Script, max_tx_memory: u64) -> ExecutionResult {
+        let mut executor = create_executor(
+            Default::default(),
+            Config {
+                max_tx_memory: Some(max_tx_memory),
+                ..Default::default()
+            },
+        );
+        let block = PartialFuelBlock {
+            header: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: 1.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            transactions: vec![script.into()],
+        };
+
+        executor
+            .execute_and_commit(ExecutionBlock::Production(block))
+            .unwrap()
+    }
+
+    #[test]
+    fn transaction_exceeding_max_tx_memory_fails() {
+        // Given
+        let script = memory_hungry_script();
+        let max_tx_memory = 64 * 1024;
+
+        // When
+        let ExecutionResult {
+            skipped_transactions,
+            tx_status,
+            ..
+        } = execute_with_max_tx_memory(script, max_tx_memory);
+
+        // Then
+        assert!(skipped_transactions.is_empty());
+        assert!(is_out_of_gas(&tx_status[0].result));
+    }
+
+    #[test]
+    fn transaction_within_max_tx_memory_is_executed() {
+        // Given
+        let script = memory_hungry_script();
+        let max_tx_memory = 4 * 1024 * 1024;
+
+        // When
+        let ExecutionResult {
+            skipped_transactions,
+            tx_status,
+            ..
+        } = execute_with_max_tx_memory(script, max_tx_memory);
+
+        // Then
+        assert!(skipped_transactions.is_empty());
+        assert!(matches!(
+            tx_status[0].result,
+            TransactionExecutionResult::Success { .. }
+        ));
+    }
+
+    fn script_with_coin_inputs(number_of_inputs: usize) -> Script {
+        let mut builder = TxBuilder::new(2322);
+        builder.script_gas_limit(10);
+        for _ in 0..number_of_inputs {
+            builder.coin_input(AssetId::default(), 100);
+        }
+        builder
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone()
+    }
+
+    #[test_case::test_case(Some(2), None => matches Some(ExecutorError::TooManyInputs { inputs: 3, max_inputs: 2, .. }); "too many inputs")]
+    #[test_case::test_case(None, Some(0) => matches Some(ExecutorError::TooManyOutputs { outputs: 1, max_outputs: 0, .. }); "too many outputs")]
+    #[test_case::test_case(Some(3), Some(1) => matches None; "within limits")]
+    #[test_case::test_case(None, None => matches None; "without overrides")]
+    fn max_inputs_and_outputs_overrides_limit_transactions(
+        max_inputs: Option<u16>,
+        max_outputs: Option<u16>,
+    ) -> Option<ExecutorError> {
+        // Given
+        let script = script_with_coin_inputs(3);
+        let mut executor = create_executor(
+            Default::default(),
+            Config {
+                max_inputs,
+                max_outputs,
+                ..Default::default()
+            },
+        );
+        let block = PartialFuelBlock {
+            header: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: 1.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            transactions: vec![script.into()],
+        };
+
+        // When
+        let ExecutionResult {
+            mut skipped_transactions,
+            ..
+        } = executor
+            .execute_and_commit(ExecutionBlock::Production(block))
+            .unwrap();
+
+        // Then
+        skipped_transactions.pop().map(|(_, error)| error)
+    }
+
     // Ensure transaction commitment != default after execution
     #[test]
     fn executor_commits_transactions_to_block() {
@@ -1165,6 +1668,172 @@ mod tests {
         ));
     }
 
+    fn signed_transfer(secret: SecretKey, utxo_id: UtxoId, to: Address) -> Script {
+        TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            100,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::Change {
+            to,
+            amount: 0,
+            asset_id: Default::default(),
+        })
+        .finalize()
+    }
+
+    fn insert_coin(db: &mut Database, tx: &Script) {
+        if let Input::CoinSigned(CoinSigned {
+            utxo_id,
+            owner,
+            amount,
+            asset_id,
+            ..
+        }) = tx.inputs()[0]
+        {
+            let mut coin = CompressedCoin::default();
+            coin.set_owner(owner);
+            coin.set_amount(amount);
+            coin.set_asset_id(asset_id);
+            db.storage::<Coins>().insert(&utxo_id, &coin).unwrap();
+        }
+    }
+
+    fn cached(
+        tx: &Script,
+        consensus_parameters: &ConsensusParameters,
+    ) -> ValidationCache {
+        let checked = tx
+            .clone()
+            .into_checked(1u32.into(), consensus_parameters)
+            .expect("The transaction should be valid");
+        let cache = ValidationCache::default();
+        cache.insert(Arc::new(PoolTransaction::from(checked)));
+        cache
+    }
+
+    #[test]
+    fn validation_with_cache_accepts_cached_transaction() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let tx = signed_transfer(SecretKey::random(&mut rng), rng.gen(), rng.gen());
+        let mut db = Database::default();
+        insert_coin(&mut db, &tx);
+
+        // Given
+        let config = Config {
+            utxo_validation_default: true,
+            ..Default::default()
+        };
+        let cache = cached(&tx, &config.consensus_parameters);
+        let producer = create_executor(db.clone(), config.clone());
+        let block = PartialFuelBlock {
+            header: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: 1.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            transactions: vec![tx.into()],
+        };
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            ..
+        } = producer
+            .execute_without_commit(ExecutionTypes::Production(block))
+            .unwrap()
+            .into_result();
+        assert!(skipped_transactions.is_empty());
+
+        // When
+        let verifier = create_executor(
+            db,
+            Config {
+                validation_cache: Some(cache),
+                ..config
+            },
+        );
+        let verify_result =
+            verifier.execute_without_commit(ExecutionTypes::Validation(block));
+
+        // Then
+        assert!(verify_result.is_ok());
+    }
+
+    #[test]
+    fn validation_with_cache_rejects_cached_transaction_after_reorg_spent_its_inputs() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let secret = SecretKey::random(&mut rng);
+        let utxo_id = rng.gen();
+        let winning_tx = signed_transfer(secret, utxo_id, rng.gen());
+        let orphaned_tx = signed_transfer(secret, utxo_id, rng.gen());
+        let mut db = Database::default();
+        insert_coin(&mut db, &winning_tx);
+
+        let config = Config {
+            utxo_validation_default: true,
+            ..Default::default()
+        };
+        let header = PartialBlockHeader {
+            consensus: ConsensusHeader {
+                height: 1.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        // The block of the abandoned fork, produced while the coin was unspent.
+        let ExecutionResult {
+            block: orphaned_block,
+            ..
+        } = create_executor(db.clone(), config.clone())
+            .execute_without_commit(ExecutionTypes::Production(PartialFuelBlock {
+                header,
+                transactions: vec![orphaned_tx.clone().into()],
+            }))
+            .unwrap()
+            .into_result();
+
+        // Given
+        let cache = cached(&orphaned_tx, &config.consensus_parameters);
+        let ExecutionResult {
+            block: winning_block,
+            ..
+        } = create_executor(db.clone(), config.clone())
+            .execute_and_commit(ExecutionTypes::Production(PartialFuelBlock {
+                header,
+                transactions: vec![winning_tx.into()],
+            }))
+            .unwrap();
+
+        // When
+        let verifier = create_executor(
+            db,
+            Config {
+                validation_cache: Some(cache.clone()),
+                ..config
+            },
+        );
+        let verify_result =
+            verifier.execute_without_commit(ExecutionTypes::Validation(orphaned_block));
+
+        // Then
+        assert!(matches!(
+            verify_result,
+            Err(ExecutorError::TransactionValidity(
+                TransactionValidityError::CoinDoesNotExist(_)
+            ))
+        ));
+        cache.remove_spending(winning_block.transactions());
+        assert!(cache.is_empty());
+    }
+
     // corrupt a produced block by randomizing change amount
     // and verify that the executor invalidates the tx
     #[test]
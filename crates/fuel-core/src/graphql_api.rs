@@ -1,3 +1,4 @@
+use fuel_core_chain_config::ChainConfig;
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
@@ -5,21 +6,39 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_tx::ConsensusParameters,
+    fuel_types::AssetId,
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use std::{
+    collections::HashMap,
+    net::{
+        IpAddr,
+        SocketAddr,
+    },
+    num::{
+        NonZeroU32,
+        NonZeroUsize,
+    },
+    time::Duration,
+};
 
 pub mod api_service;
 pub mod database;
 pub(crate) mod metrics_extension;
 pub mod ports;
+pub(crate) mod rate_limit;
+pub(crate) mod read_only_extension;
+pub(crate) mod simulation_limit;
 pub mod storage;
 pub(crate) mod view_extension;
+pub(crate) mod websocket;
 pub mod worker_service;
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub addr: SocketAddr,
+    /// Serve metrics at `/v1/metrics` of the API.
+    pub expose_metrics: bool,
     pub utxo_validation: bool,
     pub debug: bool,
     pub vm_backtrace: bool,
@@ -27,7 +46,143 @@ pub struct Config {
     pub max_depth: usize,
     pub chain_name: String,
     pub consensus_parameters: ConsensusParameters,
+    /// The chain config the node started with.
+    pub chain_config: ChainConfig,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// Limits the rate of requests from each client IP.
+    /// If `None`, requests are not limited.
+    pub rate_limit: Option<RateLimitConfig>,
+    /// Allows introspection queries of the schema.
+    /// It is recommended to disable it for production nodes.
+    pub enable_introspection: bool,
+    /// Rejects all mutation operations and the subscriptions submitting transactions,
+    /// leaving only queries and other subscriptions.
+    pub disable_mutations: bool,
+    /// The interval of TCP keep-alive probes used to detect dead clients.
+    /// If `None`, the probes are disabled.
+    pub keep_alive: Option<Duration>,
+    /// Closes connections that don't send a new request during this time.
+    /// If `None`, idle connections are kept open.
+    /// The upgraded WebSocket connections are closed by the `websocket_keep_alive`.
+    pub idle_timeout: Option<Duration>,
+    /// Pings the clients of the WebSocket subscriptions and closes the connections
+    /// of the clients not answering in time. If `None`, the WebSockets stay open
+    /// until the client closes them.
+    pub websocket_keep_alive: Option<WebSocketKeepAlive>,
+    /// The time given to the in-flight requests to complete during the shutdown.
+    /// New connections are not accepted during this time.
+    /// Requests still running after it are cancelled.
+    pub shutdown_grace_period: Duration,
+    /// The number of confirmations after which the transaction is finalized.
+    /// The block including the transaction is the first confirmation.
+    pub finality_depth: u32,
+    /// The maximum number of distinct assets processed by one coin selection
+    /// or balances query. Queries above the limit are rejected.
+    pub max_assets_per_query: usize,
+    /// The maximum number of receipts of one transaction returned by the transaction status.
+    /// Statuses with more receipts are rejected, the receipts of such transactions
+    /// can be fetched page by page with the `receipts` query.
+    /// If `None`, the receipts are not limited.
+    pub max_receipts_per_transaction: Option<usize>,
+    /// The max call depth override of the VM.
+    pub max_call_depth: Option<u64>,
+    /// The max inputs override of the executor.
+    pub max_inputs: Option<u16>,
+    /// The max outputs override of the executor.
+    pub max_outputs: Option<u16>,
+    /// The max gas per predicate enforced by the `TxPool`.
+    pub max_gas_per_predicate: Option<u64>,
+    /// The max gas of all predicates per transaction enforced by the `TxPool`.
+    pub max_predicate_gas_per_tx: Option<u64>,
+    /// The CORS policy applied to the responses of the API.
+    pub cors: CorsConfig,
+    /// Limits the number of dry-runs and block simulations executed at once.
+    /// If `None`, simulations are not limited.
+    pub simulation_limit: Option<SimulationLimitConfig>,
+    /// The display metadata of the assets returned by the `assets` query.
+    pub asset_metadata: HashMap<AssetId, AssetMetadata>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// The number of requests per second allowed for one IP.
+    pub requests_per_second: NonZeroU32,
+    /// The number of requests one IP can do at once before being limited.
+    pub burst: NonZeroU32,
+    /// The IPs exempt from the rate limiting.
+    pub allowlist: Vec<IpAddr>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WebSocketKeepAlive {
+    /// The interval of the pings sent to the client.
+    pub ping_interval: Duration,
+    /// How long the client has to answer the ping before the connection is closed.
+    pub pong_timeout: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulationLimitConfig {
+    /// The number of dry-runs and block simulations executed at once.
+    pub max_concurrent: NonZeroUsize,
+    /// How long a simulation waits for a free slot before it is rejected.
+    pub queue_timeout: Duration,
+}
+
+/// The CORS policy of the API. The `*` value allows any origin, method, or header.
+///
+/// The default policy doesn't allow cross-origin requests from any origin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CorsConfig {
+    /// The origins allowed to make cross-origin requests.
+    pub allowed_origins: Vec<String>,
+    /// The HTTP methods allowed for cross-origin requests.
+    pub allowed_methods: Vec<String>,
+    /// The headers allowed in cross-origin requests.
+    pub allowed_headers: Vec<String>,
+    /// Allows cross-origin requests with credentials.
+    /// Can't be used together with any wildcard.
+    pub allow_credentials: bool,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allowed_origins: vec![],
+            allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            allowed_headers: vec!["content-type".to_string()],
+            allow_credentials: false,
+        }
+    }
+}
+
+/// The display metadata of the asset, used by explorers and wallets
+/// to show the human-readable amounts. It is node-local and not a part of the consensus.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AssetMetadata {
+    pub symbol: String,
+    pub name: String,
+    /// The number of decimals of the human-readable amount.
+    pub decimals: u8,
+}
+
+/// The retries of the failed writes of the off-chain indexes by the GraphQL worker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WorkerRetryConfig {
+    /// The number of retries after the first failed write of the block.
+    /// The worker halts if all retries fail.
+    pub max_retries: u32,
+    /// The delay before the first retry. The delay doubles after each retry.
+    pub initial_backoff: Duration,
+}
+
+impl Default for WorkerRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(100),
+        }
+    }
 }
 
 pub trait IntoApiResult<T> {
@@ -8,10 +8,19 @@ use crate::{
             OffChainDatabase,
             OnChainDatabase,
             P2pPort,
+            RelayerPort,
             TxPoolPort,
         },
+        rate_limit::{
+            rate_limit,
+            RateLimiter,
+        },
+        read_only_extension::ReadOnlyExtension,
+        simulation_limit::SimulationLimiter,
         view_extension::ViewExtension,
+        websocket::graphql_websocket_handler,
         Config,
+        CorsConfig,
     },
     schema::{
         CoreSchema,
@@ -33,13 +42,11 @@ use axum::{
         Extension,
     },
     http::{
-        header::{
-            ACCESS_CONTROL_ALLOW_HEADERS,
-            ACCESS_CONTROL_ALLOW_METHODS,
-            ACCESS_CONTROL_ALLOW_ORIGIN,
-        },
+        HeaderName,
         HeaderValue,
+        Method,
     },
+    middleware,
     response::{
         sse::Event,
         Html,
@@ -69,11 +76,17 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    sync::Arc,
     time::Duration,
 };
 use tokio_stream::StreamExt;
 use tower_http::{
-    set_header::SetResponseHeaderLayer,
+    cors::{
+        AllowHeaders,
+        AllowMethods,
+        AllowOrigin,
+        CorsLayer,
+    },
     timeout::TimeoutLayer,
     trace::TraceLayer,
 };
@@ -88,6 +101,7 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
 pub type P2pService = Box<dyn P2pPort>;
+pub type Relayer = Box<dyn RelayerPort>;
 
 pub type GasPriceProvider = Box<dyn GasPriceEstimate>;
 
@@ -103,11 +117,15 @@ pub struct GraphqlService {
 pub struct ServerParams {
     router: Router,
     listener: TcpListener,
+    keep_alive: Option<Duration>,
+    idle_timeout: Option<Duration>,
+    shutdown_grace_period: Duration,
 }
 
 pub struct Task {
     // Ugly workaround because of https://github.com/hyperium/hyper/issues/2582
     server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send + 'static>>,
+    shutdown_grace_period: Duration,
 }
 
 #[async_trait::async_trait]
@@ -130,11 +148,25 @@ impl RunnableService for GraphqlService {
         params: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         let mut state = state.clone();
-        let ServerParams { router, listener } = params;
+        let ServerParams {
+            router,
+            listener,
+            keep_alive,
+            idle_timeout,
+            shutdown_grace_period,
+        } = params;
 
-        let server = axum::Server::from_tcp(listener)
+        let mut server = axum::Server::from_tcp(listener)
             .unwrap()
-            .serve(router.into_make_service())
+            .tcp_keepalive(keep_alive);
+        if let Some(idle_timeout) = idle_timeout {
+            // The timeout starts when the server waits for the next request,
+            // so connections staying idle for longer are closed. The upgraded
+            // WebSocket connections are closed by the ping timeout instead.
+            server = server.http1_header_read_timeout(idle_timeout);
+        }
+        let server = server
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .with_graceful_shutdown(async move {
                 state
                     .while_started()
@@ -144,23 +176,44 @@ impl RunnableService for GraphqlService {
 
         Ok(Task {
             server: Box::pin(server),
+            shutdown_grace_period,
         })
     }
 }
 
 #[async_trait::async_trait]
 impl RunnableTask for Task {
-    async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
-        self.server.as_mut().await?;
-        // The `axum::Server` has its internal loop. If `await` is finished, we get an internal
-        // error or stop signal.
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            result = self.server.as_mut() => {
+                // The `axum::Server` has its internal loop. If `await` is finished,
+                // we get an internal error.
+                result?;
+                return Ok(false /* should_continue */)
+            }
+            _ = watcher.while_started() => {}
+        }
+
+        // On the stop signal the `axum::Server` stops accepting new connections
+        // and waits for the in-flight requests. The requests still running
+        // after the grace period are cancelled by dropping the server.
+        let graceful_shutdown =
+            tokio::time::timeout(self.shutdown_grace_period, self.server.as_mut());
+        match graceful_shutdown.await {
+            Ok(result) => result?,
+            Err(_) => tracing::warn!(
+                "The in-flight requests didn't complete during the shutdown grace period \
+                of {:?}, cancelling them",
+                self.shutdown_grace_period
+            ),
+        }
         Ok(false /* should_continue */)
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
         // Nothing to shut down because we don't have any temporary state that should be dumped,
         // and we don't spawn any sub-tasks that we need to finish or await.
-        // The `axum::Server` was already gracefully shutdown at this point.
+        // The `axum::Server` was already gracefully shutdown or dropped at this point.
         Ok(())
     }
 }
@@ -176,6 +229,7 @@ pub fn new_service<OnChain, OffChain>(
     producer: BlockProducer,
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
+    relayer: Relayer,
     gas_price_provider: GasPriceProvider,
     log_threshold_ms: Duration,
     request_timeout: Duration,
@@ -187,8 +241,31 @@ where
     OffChain::View: OffChainDatabase,
 {
     let network_addr = config.addr;
+    let expose_metrics = config.expose_metrics;
+    let rate_limiter = config
+        .rate_limit
+        .as_ref()
+        .map(RateLimiter::new)
+        .map(Arc::new);
     let combined_read_database = ReadDatabase::new(on_database, off_database);
+    let simulation_limiter = SimulationLimiter::new(config.simulation_limit.as_ref());
 
+    let keep_alive = config.keep_alive;
+    let idle_timeout = config.idle_timeout;
+    let websocket_keep_alive = config.websocket_keep_alive;
+    let shutdown_grace_period = config.shutdown_grace_period;
+    let cors = cors_layer(&config.cors)?;
+
+    let schema = if config.enable_introspection {
+        schema
+    } else {
+        schema.disable_introspection()
+    };
+    let schema = if config.disable_mutations {
+        schema.extension(ReadOnlyExtension::new())
+    } else {
+        schema
+    };
     let schema = schema
         .data(config)
         .data(combined_read_database)
@@ -196,38 +273,45 @@ where
         .data(producer)
         .data(consensus_module)
         .data(p2p_service)
+        .data(relayer)
         .data(gas_price_provider)
+        .data(simulation_limiter)
         .extension(async_graphql::extensions::Tracing)
         .extension(MetricsExtension::new(log_threshold_ms))
         .extension(ViewExtension::new())
         .finish();
 
-    let router = Router::new()
+    let mut router = Router::new()
         .route("/v1/playground", get(graphql_playground))
         .route("/v1/graphql", post(graphql_handler).options(ok))
         .route(
             "/v1/graphql-sub",
             post(graphql_subscription_handler).options(ok),
         )
-        .route("/v1/metrics", get(metrics))
-        .route("/v1/health", get(health))
+        .route("/v1/graphql-ws", get(graphql_websocket_handler))
+        .route("/v1/health", get(health));
+
+    // Metrics are served by the dedicated listener if it is configured.
+    if expose_metrics {
+        router = router.route("/v1/metrics", get(metrics));
+    }
+
+    let router = router
         .layer(Extension(schema))
+        .layer(Extension(websocket_keep_alive))
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(request_timeout))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_ORIGIN,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_METHODS,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_HEADERS,
-            HeaderValue::from_static("*"),
-        ))
+        .layer(cors)
         .layer(DefaultBodyLimit::disable());
 
+    let router = if let Some(limiter) = rate_limiter {
+        router.layer(middleware::from_fn(move |req, next| {
+            rate_limit(limiter.clone(), req, next)
+        }))
+    } else {
+        router
+    };
+
     let listener = TcpListener::bind(network_addr)?;
     let bound_address = listener.local_addr()?;
 
@@ -235,10 +319,72 @@ where
 
     Ok(Service::new_with_params(
         GraphqlService { bound_address },
-        ServerParams { router, listener },
+        ServerParams {
+            router,
+            listener,
+            keep_alive,
+            idle_timeout,
+            shutdown_grace_period,
+        },
     ))
 }
 
+const WILDCARD: &str = "*";
+
+fn cors_layer(config: &CorsConfig) -> anyhow::Result<CorsLayer> {
+    let any_origin = config.allowed_origins.iter().any(|o| o == WILDCARD);
+    let any_method = config.allowed_methods.iter().any(|m| m == WILDCARD);
+    let any_header = config.allowed_headers.iter().any(|h| h == WILDCARD);
+
+    if config.allow_credentials && (any_origin || any_method || any_header) {
+        return Err(anyhow::anyhow!(
+            "CORS credentials can't be allowed together with the `{WILDCARD}` wildcard"
+        ))
+    }
+
+    let allow_origin = if any_origin {
+        AllowOrigin::any()
+    } else {
+        let origins = config
+            .allowed_origins
+            .iter()
+            .map(|origin| HeaderValue::from_str(origin))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Invalid CORS origin: {e}"))?;
+        AllowOrigin::list(origins)
+    };
+
+    let allow_methods = if any_method {
+        AllowMethods::any()
+    } else {
+        let methods = config
+            .allowed_methods
+            .iter()
+            .map(|method| Method::from_bytes(method.to_uppercase().as_bytes()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Invalid CORS method: {e}"))?;
+        AllowMethods::list(methods)
+    };
+
+    let allow_headers = if any_header {
+        AllowHeaders::any()
+    } else {
+        let headers = config
+            .allowed_headers
+            .iter()
+            .map(|header| HeaderName::from_bytes(header.as_bytes()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Invalid CORS header: {e}"))?;
+        AllowHeaders::list(headers)
+    };
+
+    Ok(CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods(allow_methods)
+        .allow_headers(allow_headers)
+        .allow_credentials(config.allow_credentials))
+}
+
 async fn graphql_playground() -> impl IntoResponse {
     Html(playground_source(GraphQLPlaygroundConfig::new(
         "/v1/graphql",
@@ -256,6 +402,11 @@ async fn graphql_handler(
     schema.execute(req.0).await.into()
 }
 
+/// Delivers the results of the subscriptions incrementally as server-sent events.
+///
+/// The `@stream` and `@defer` directives are not supported because `async-graphql`
+/// doesn't implement incremental delivery for queries. Large result sets should be
+/// fetched page by page with the connection arguments of the paginated queries.
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
     req: Json<Request>,
@@ -9,6 +9,10 @@ use crate::fuel_core_graphql_api::{
         OffChainDatabase,
         OnChainDatabase,
     },
+    storage::{
+        blocks::BlockSummary,
+        coins::CoinChanges,
+    },
 };
 use fuel_core_storage::{
     iter::{
@@ -46,10 +50,14 @@ use fuel_core_types::{
     },
     fuel_types::{
         BlockHeight,
+        Bytes32,
         Nonce,
     },
     services::{
-        graphql_api::ContractBalance,
+        graphql_api::{
+            ContractBalance,
+            ContractStorageSlot,
+        },
         txpool::TransactionStatus,
     },
 };
@@ -164,6 +172,16 @@ impl DatabaseContracts for ReadView {
         self.on_chain
             .contract_balances(contract, start_asset, direction)
     }
+
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractStorageSlot>> {
+        self.on_chain
+            .contract_storage_slots(contract, start_key, direction)
+    }
 }
 
 impl DatabaseChain for ReadView {
@@ -203,6 +221,16 @@ impl OffChainDatabase for ReadView {
         self.off_chain.owned_coins_ids(owner, start_coin, direction)
     }
 
+    fn asset_coins_ids(
+        &self,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>> {
+        self.off_chain
+            .asset_coins_ids(asset_id, start_coin, direction)
+    }
+
     fn owned_message_ids(
         &self,
         owner: &Address,
@@ -226,4 +254,28 @@ impl OffChainDatabase for ReadView {
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         self.off_chain.contract_salt(contract_id)
     }
+
+    fn block_gas_price(&self, height: &BlockHeight) -> StorageResult<u64> {
+        self.off_chain.block_gas_price(height)
+    }
+
+    fn block_gas_prices(
+        &self,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, u64)>> {
+        self.off_chain.block_gas_prices(start, direction)
+    }
+
+    fn block_coin_changes(&self, height: &BlockHeight) -> StorageResult<CoinChanges> {
+        self.off_chain.block_coin_changes(height)
+    }
+
+    fn block_summary(&self, height: &BlockHeight) -> StorageResult<BlockSummary> {
+        self.off_chain.block_summary(height)
+    }
+
+    fn transactions_by_label(&self, label: &str) -> StorageResult<Vec<TxId>> {
+        self.off_chain.transactions_by_label(label)
+    }
 }
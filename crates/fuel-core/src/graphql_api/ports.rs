@@ -1,3 +1,7 @@
+use crate::fuel_core_graphql_api::storage::{
+    blocks::BlockSummary,
+    coins::CoinChanges,
+};
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
@@ -9,6 +13,7 @@ use fuel_core_storage::{
         Coins,
         ContractsAssets,
         ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         SealedBlockConsensus,
@@ -42,15 +47,26 @@ use fuel_core_types::{
         Address,
         AssetId,
         BlockHeight,
+        Bytes32,
         ContractId,
         Nonce,
     },
     services::{
-        executor::TransactionExecutionStatus,
-        graphql_api::ContractBalance,
+        executor::{
+            ExecutionResult,
+            TransactionExecutionStatus,
+        },
+        graphql_api::{
+            ContractBalance,
+            ContractStorageSlot,
+        },
         p2p::PeerInfo,
+        relayer::{
+            Event,
+            RelayerStatus,
+        },
         txpool::{
-            InsertionResult,
+            InsertionOutcome,
             TransactionStatus,
         },
     },
@@ -70,6 +86,14 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<UtxoId>>;
 
+    /// Returns the ids of the unspent coins of the `asset_id` across all owners.
+    fn asset_coins_ids(
+        &self,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>>;
+
     fn owned_message_ids(
         &self,
         owner: &Address,
@@ -85,6 +109,25 @@ pub trait OffChainDatabase: Send + Sync {
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
 
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt>;
+
+    /// Returns the gas price used to produce the block at `height`.
+    fn block_gas_price(&self, height: &BlockHeight) -> StorageResult<u64>;
+
+    /// Returns the gas prices used to produce blocks, starting from `start` height.
+    fn block_gas_prices(
+        &self,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, u64)>>;
+
+    /// Returns the coins created and spent by the block at `height`.
+    fn block_coin_changes(&self, height: &BlockHeight) -> StorageResult<CoinChanges>;
+
+    /// Returns the aggregates over the transactions of the block at `height`.
+    fn block_summary(&self, height: &BlockHeight) -> StorageResult<BlockSummary>;
+
+    /// Returns the ids of the transactions submitted with the `label`.
+    fn transactions_by_label(&self, label: &str) -> StorageResult<Vec<TxId>>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -132,6 +175,7 @@ pub trait DatabaseMessages: StorageInspect<Messages, Error = StorageError> {
 pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
     + StorageInspect<ContractsAssets, Error = StorageError>
+    + StorageInspect<ContractsState, Error = StorageError>
 {
     fn contract_balances(
         &self,
@@ -139,6 +183,13 @@ pub trait DatabaseContracts:
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractStorageSlot>>;
 }
 
 /// Trait that specifies all the getters required for chain metadata.
@@ -152,10 +203,25 @@ pub trait TxPoolPort: Send + Sync {
 
     fn submission_time(&self, id: TxId) -> Option<Tai64>;
 
+    /// Returns the depth of the transaction in the chain of dependent transactions
+    /// inside of the `TxPool`, or `None` if the `TxPool` doesn't contain the transaction.
+    fn dependency_depth(&self, id: TxId) -> Option<usize>;
+
+    /// Returns the minimal tip required for the admission of a new transaction
+    /// into the full `TxPool`, or `None` if the `TxPool` has free space.
+    fn min_admission_tip(&self) -> Option<u64>;
+
+    /// Returns the number of the pending transactions per tip bucket.
+    /// The ascending `bounds` split the tips into `bounds.len() + 1` buckets.
+    fn tip_histogram(&self, bounds: &[u64]) -> Vec<usize>;
+
+    /// Returns the number of the pending transactions and their total size in bytes.
+    fn pending_stats(&self) -> (usize, usize);
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
-    ) -> Vec<anyhow::Result<InsertionResult>>;
+    ) -> Vec<anyhow::Result<InsertionOutcome>>;
 
     fn tx_update_subscribe(
         &self,
@@ -171,6 +237,14 @@ pub trait BlockProducerPort: Send + Sync {
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<TransactionExecutionStatus>>;
+
+    /// Executes the block with `transactions` on top of the latest block
+    /// without committing it.
+    async fn simulate_block(
+        &self,
+        transactions: Vec<Transaction>,
+        block_time: Option<Tai64>,
+    ) -> anyhow::Result<ExecutionResult>;
 }
 
 #[async_trait::async_trait]
@@ -198,6 +272,15 @@ pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
 }
 
+pub trait RelayerPort: Send + Sync {
+    /// Returns the events from the DA layer after the `da_height`
+    /// that are not included into blocks yet.
+    fn pending_events(&self, da_height: &DaBlockHeight) -> anyhow::Result<Vec<Event>>;
+
+    /// Returns the progress of the relayer, or `None` if the relayer is disabled.
+    fn status(&self) -> anyhow::Result<Option<RelayerStatus>>;
+}
+
 /// Trait for defining how to estimate gas price for future blocks
 #[async_trait::async_trait]
 pub trait GasPriceEstimate: Send + Sync {
@@ -206,9 +289,17 @@ pub trait GasPriceEstimate: Send + Sync {
 }
 
 pub mod worker {
-    use super::super::storage::blocks::FuelBlockIdsToHeights;
+    use super::super::storage::blocks::{
+        FuelBlockGasPrices,
+        FuelBlockIdsToHeights,
+        FuelBlockSummaries,
+    };
     use crate::fuel_core_graphql_api::storage::{
-        coins::OwnedCoins,
+        coins::{
+            AssetCoins,
+            BlockCoinChanges,
+            OwnedCoins,
+        },
         contracts::ContractsInfo,
         messages::OwnedMessageIds,
     };
@@ -242,8 +333,12 @@ pub mod worker {
     pub trait OffChainDatabase:
         StorageMutate<OwnedMessageIds, Error = StorageError>
         + StorageMutate<OwnedCoins, Error = StorageError>
+        + StorageMutate<AssetCoins, Error = StorageError>
         + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>
+        + StorageMutate<FuelBlockGasPrices, Error = StorageError>
         + StorageMutate<ContractsInfo, Error = StorageError>
+        + StorageMutate<BlockCoinChanges, Error = StorageError>
+        + StorageMutate<FuelBlockSummaries, Error = StorageError>
     {
         fn record_tx_id_owner(
             &mut self,
@@ -0,0 +1,176 @@
+use crate::fuel_core_graphql_api::RateLimitConfig;
+use axum::{
+    extract::ConnectInfo,
+    http::{
+        header::RETRY_AFTER,
+        Request,
+        StatusCode,
+    },
+    middleware::Next,
+    response::{
+        IntoResponse,
+        Response,
+    },
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    net::{
+        IpAddr,
+        SocketAddr,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The number of tracked clients after which the limiter forgets about
+/// clients that have fully replenished their burst.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Per-IP rate limiter based on the generic cell rate algorithm. Each client
+/// may do `burst` requests at once, after which it is limited to
+/// `requests_per_second`.
+pub(crate) struct RateLimiter {
+    /// The time between two requests of the same client.
+    emission_interval: Duration,
+    /// How far ahead of the current time a client is allowed to be.
+    tolerance: Duration,
+    allowlist: HashSet<IpAddr>,
+    /// The theoretical arrival time of the next request for each client.
+    clients: Mutex<HashMap<IpAddr, Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        let emission_interval = Duration::from_secs(1)
+            .checked_div(config.requests_per_second.get())
+            .expect("`requests_per_second` is not zero");
+        let tolerance = emission_interval
+            .checked_mul(config.burst.get().saturating_sub(1))
+            .unwrap_or(Duration::MAX);
+
+        Self {
+            emission_interval,
+            tolerance,
+            allowlist: config.allowlist.iter().copied().collect(),
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Registers the request from the `ip` at the `now` time.
+    /// Returns the time the client should wait if the request is throttled.
+    pub fn check(&self, ip: IpAddr, now: Instant) -> Result<(), Duration> {
+        if self.allowlist.contains(&ip) {
+            return Ok(())
+        }
+
+        let mut clients = self.clients.lock().expect("The lock is poisoned");
+        if clients.len() >= PRUNE_THRESHOLD {
+            clients.retain(|_, arrival| *arrival > now);
+        }
+
+        let arrival = clients.get(&ip).copied().unwrap_or(now).max(now);
+        let ahead = arrival.saturating_duration_since(now);
+        if ahead > self.tolerance {
+            return Err(ahead.saturating_sub(self.tolerance))
+        }
+
+        let next_arrival = arrival
+            .checked_add(self.emission_interval)
+            .unwrap_or(arrival);
+        clients.insert(ip, next_arrival);
+        Ok(())
+    }
+}
+
+/// The middleware rejects requests of clients that exceed the rate limit
+/// with `429 Too Many Requests`.
+pub(crate) async fn rate_limit<B>(
+    limiter: Arc<RateLimiter>,
+    req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let ip = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip());
+
+    if let Some(ip) = ip {
+        if let Err(retry_after) = limiter.check(ip, Instant::now()) {
+            // `Retry-After` is specified in whole seconds, so round up.
+            let retry_after_secs = retry_after
+                .as_secs()
+                .saturating_add(u64::from(retry_after.subsec_nanos() > 0));
+            return (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(RETRY_AFTER, retry_after_secs.to_string())],
+                "Too many requests",
+            )
+                .into_response()
+        }
+    }
+
+    next.run(req).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::NonZeroU32;
+
+    fn limiter(allowlist: Vec<IpAddr>) -> RateLimiter {
+        RateLimiter::new(&RateLimitConfig {
+            requests_per_second: NonZeroU32::new(2).unwrap(),
+            burst: NonZeroU32::new(3).unwrap(),
+            allowlist,
+        })
+    }
+
+    #[test]
+    fn check_throttles_client_after_burst() {
+        let limiter = limiter(vec![]);
+        let ip: IpAddr = [10, 0, 0, 1].into();
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert_eq!(limiter.check(ip, now), Ok(()));
+        }
+        assert_eq!(limiter.check(ip, now), Err(Duration::from_millis(500)));
+
+        // Other clients are not affected.
+        assert_eq!(limiter.check([10, 0, 0, 2].into(), now), Ok(()));
+    }
+
+    #[test]
+    fn check_allows_client_again_after_retry_after() {
+        let limiter = limiter(vec![]);
+        let ip: IpAddr = [10, 0, 0, 1].into();
+        let now = Instant::now();
+        for _ in 0..3 {
+            limiter.check(ip, now).unwrap();
+        }
+        let retry_after = limiter.check(ip, now).unwrap_err();
+
+        let later = now.checked_add(retry_after).unwrap();
+        assert_eq!(limiter.check(ip, later), Ok(()));
+    }
+
+    #[test]
+    fn check_never_throttles_allowlisted_client() {
+        let ip: IpAddr = [10, 0, 0, 1].into();
+        let limiter = limiter(vec![ip]);
+        let now = Instant::now();
+
+        for _ in 0..100 {
+            assert_eq!(limiter.check(ip, now), Ok(()));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextParseQuery,
+    },
+    parser::types::{
+        ExecutableDocument,
+        OperationType,
+        Selection,
+        SelectionSet,
+    },
+    Pos,
+    ServerError,
+    ServerResult,
+    Variables,
+};
+use std::sync::Arc;
+
+/// The subscriptions that submit the transaction before streaming its status.
+const SUBMITTING_SUBSCRIPTIONS: &[&str] = &["submitAndAwait"];
+
+/// The extension that rejects documents with mutation operations
+/// or subscriptions that submit transactions.
+/// Queries and other subscriptions are processed as usual.
+pub(crate) struct ReadOnlyExtension;
+
+impl ReadOnlyExtension {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ExtensionFactory for ReadOnlyExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ReadOnlyExtension::new())
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for ReadOnlyExtension {
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let document = next.run(ctx, query, variables).await?;
+        for (_, operation) in document.operations.iter() {
+            match operation.node.ty {
+                OperationType::Mutation => {
+                    return Err(ServerError::new(
+                        "Mutations are disabled on this node",
+                        Some(operation.pos),
+                    ))
+                }
+                OperationType::Subscription => {
+                    let mut visited_fragments = vec![];
+                    let submission = find_submitting_field(
+                        &document,
+                        &operation.node.selection_set.node,
+                        &mut visited_fragments,
+                    );
+                    if let Some(pos) = submission {
+                        return Err(ServerError::new(
+                            "Transaction submissions are disabled on this node",
+                            Some(pos),
+                        ))
+                    }
+                }
+                OperationType::Query => {}
+            }
+        }
+        Ok(document)
+    }
+}
+
+/// Returns the position of the root field submitting transactions, if any.
+/// The fragments are visited once because the document is not validated yet
+/// and may contain cycles.
+fn find_submitting_field<'a>(
+    document: &'a ExecutableDocument,
+    selection_set: &'a SelectionSet,
+    visited_fragments: &mut Vec<&'a str>,
+) -> Option<Pos> {
+    for selection in &selection_set.items {
+        let pos = match &selection.node {
+            Selection::Field(field) => SUBMITTING_SUBSCRIPTIONS
+                .contains(&field.node.name.node.as_str())
+                .then_some(field.pos),
+            Selection::InlineFragment(fragment) => find_submitting_field(
+                document,
+                &fragment.node.selection_set.node,
+                visited_fragments,
+            ),
+            Selection::FragmentSpread(spread) => {
+                let name = spread.node.fragment_name.node.as_str();
+                if visited_fragments.contains(&name) {
+                    None
+                } else {
+                    visited_fragments.push(name);
+                    document.fragments.get(name).and_then(|fragment| {
+                        find_submitting_field(
+                            document,
+                            &fragment.node.selection_set.node,
+                            visited_fragments,
+                        )
+                    })
+                }
+            }
+        };
+        if pos.is_some() {
+            return pos
+        }
+    }
+    None
+}
@@ -0,0 +1,48 @@
+use crate::fuel_core_graphql_api::SimulationLimitConfig;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::{
+    OwnedSemaphorePermit,
+    Semaphore,
+};
+
+/// Limits the number of dry-runs and block simulations executed at once,
+/// since each of them runs the VM and may starve the node.
+///
+/// The simulations above the limit wait for a free slot during the queue timeout,
+/// after which they are rejected.
+pub(crate) struct SimulationLimiter {
+    permits: Option<Arc<Semaphore>>,
+    queue_timeout: Duration,
+}
+
+impl SimulationLimiter {
+    pub fn new(config: Option<&SimulationLimitConfig>) -> Self {
+        Self {
+            permits: config
+                .map(|config| Arc::new(Semaphore::new(config.max_concurrent.get()))),
+            queue_timeout: config
+                .map(|config| config.queue_timeout)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Waits for a free slot for the simulation. The slot is released
+    /// when the returned permit is dropped.
+    pub async fn acquire(&self) -> async_graphql::Result<Option<OwnedSemaphorePermit>> {
+        let Some(permits) = &self.permits else {
+            return Ok(None)
+        };
+
+        match tokio::time::timeout(self.queue_timeout, permits.clone().acquire_owned())
+            .await
+        {
+            Ok(permit) => Ok(Some(permit.expect("The semaphore is never closed"))),
+            Err(_) => Err(async_graphql::Error::new(
+                "Too many concurrent simulations, try again later",
+            )),
+        }
+    }
+}
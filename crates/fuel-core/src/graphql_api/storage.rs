@@ -1,7 +1,15 @@
 use crate::{
     fuel_core_graphql_api::storage::{
-        blocks::FuelBlockIdsToHeights,
-        coins::OwnedCoins,
+        blocks::{
+            FuelBlockGasPrices,
+            FuelBlockIdsToHeights,
+            FuelBlockSummaries,
+        },
+        coins::{
+            AssetCoins,
+            BlockCoinChanges,
+            OwnedCoins,
+        },
         messages::OwnedMessageIds,
         transactions::{
             OwnedTransactionIndexKey,
@@ -79,6 +87,18 @@ pub enum Column {
     FuelBlockIdsToHeights = 7,
     /// See [`ContractsInfo`](contracts::ContractsInfo)
     ContractsInfo = 8,
+    /// See [`blocks::FuelBlockGasPrices`]
+    FuelBlockGasPrices = 9,
+    /// See [`coins::BlockCoinChanges`]
+    BlockCoinChanges = 10,
+    /// See [`blocks::FuelBlockSummaries`]
+    FuelBlockSummaries = 11,
+    /// The column of the table that stores `true` if `asset_id` has unspent `Coin` with `coin_id`
+    AssetCoins = 12,
+    /// See [`transactions::TransactionLabels`]
+    TransactionLabels = 13,
+    /// See [`transactions::PendingTransactions`]
+    PendingTransactions = 14,
 }
 
 impl Column {
@@ -106,7 +126,11 @@ where
     S: KeyValueInspect<Column = Column> + Modifiable,
     StorageTransaction<S>: StorageMutate<OwnedMessageIds, Error = StorageError>
         + StorageMutate<OwnedCoins, Error = StorageError>
-        + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>,
+        + StorageMutate<AssetCoins, Error = StorageError>
+        + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>
+        + StorageMutate<FuelBlockGasPrices, Error = StorageError>
+        + StorageMutate<BlockCoinChanges, Error = StorageError>
+        + StorageMutate<FuelBlockSummaries, Error = StorageError>,
 {
     fn record_tx_id_owner(
         &mut self,
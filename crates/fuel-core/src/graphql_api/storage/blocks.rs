@@ -1,6 +1,7 @@
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
+        postcard::Postcard,
         primitive::Primitive,
         raw::Raw,
     },
@@ -34,9 +35,79 @@ impl TableWithBlueprint for FuelBlockIdsToHeights {
     }
 }
 
+/// The table of the gas price used by each fuel block.
+/// It links the `BlockHeight` to the gas price from the `Mint` transaction of the block.
+pub struct FuelBlockGasPrices;
+
+impl Mappable for FuelBlockGasPrices {
+    /// Primary key - `BlockHeight`.
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    /// The gas price used to produce the block.
+    type Value = u64;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for FuelBlockGasPrices {
+    type Blueprint = Plain<Primitive<4>, Primitive<8>>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::FuelBlockGasPrices
+    }
+}
+
+/// The aggregated information about the transactions of the block.
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct BlockSummary {
+    /// The number of transactions in the block, including the `Mint` transaction.
+    pub transaction_count: u64,
+    /// The total gas used by the transactions of the block.
+    pub total_gas_used: u64,
+    /// The total fee paid by the transactions of the block.
+    pub total_fee: u64,
+}
+
+/// The table of the summaries of fuel blocks.
+/// It links the `BlockHeight` to the aggregates over the transactions of the block.
+pub struct FuelBlockSummaries;
+
+impl Mappable for FuelBlockSummaries {
+    /// Primary key - `BlockHeight`.
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = BlockSummary;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for FuelBlockSummaries {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::FuelBlockSummaries
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    FuelBlockGasPrices,
+    <FuelBlockGasPrices as Mappable>::Key::default(),
+    <FuelBlockGasPrices as Mappable>::Value::default()
+);
+
 #[cfg(test)]
 fuel_core_storage::basic_storage_tests!(
     FuelBlockIdsToHeights,
     <FuelBlockIdsToHeights as Mappable>::Key::default(),
     <FuelBlockIdsToHeights as Mappable>::Value::default()
 );
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    FuelBlockSummaries,
+    <FuelBlockSummaries as Mappable>::Key::default(),
+    <FuelBlockSummaries as Mappable>::Value::default()
+);
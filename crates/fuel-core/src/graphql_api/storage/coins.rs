@@ -2,16 +2,24 @@ use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
         postcard::Postcard,
-        primitive::utxo_id_to_bytes,
+        primitive::{
+            utxo_id_to_bytes,
+            Primitive,
+        },
         raw::Raw,
     },
     structured_storage::TableWithBlueprint,
     Mappable,
 };
 use fuel_core_txpool::types::TxId;
-use fuel_core_types::fuel_tx::{
-    Address,
-    UtxoId,
+use fuel_core_types::{
+    entities::coins::coin::Coin,
+    fuel_tx::{
+        Address,
+        AssetId,
+        UtxoId,
+    },
+    fuel_types::BlockHeight,
 };
 
 // TODO: Reuse `fuel_vm::storage::double_key` macro.
@@ -44,6 +52,73 @@ impl TableWithBlueprint for OwnedCoins {
     }
 }
 
+pub fn asset_coin_id_key(asset_id: &AssetId, coin_id: &UtxoId) -> AssetCoinKey {
+    let mut default = [0u8; AssetId::LEN + TxId::LEN + 2];
+    default[0..AssetId::LEN].copy_from_slice(asset_id.as_ref());
+    let utxo_id_bytes: [u8; TxId::LEN + 2] = utxo_id_to_bytes(coin_id);
+    default[AssetId::LEN..].copy_from_slice(utxo_id_bytes.as_ref());
+    default
+}
+
+/// The storage table of the unspent coin ids of each asset. Maps asset ids to coins of all owners.
+pub struct AssetCoins;
+/// The storage key for the coins of the asset: `AssetId ++ UtxoId`
+pub type AssetCoinKey = [u8; AssetId::LEN + TxId::LEN + 2];
+
+impl Mappable for AssetCoins {
+    type Key = Self::OwnedKey;
+    type OwnedKey = AssetCoinKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = ();
+}
+
+impl TableWithBlueprint for AssetCoins {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::AssetCoins
+    }
+}
+
+/// The coins created and spent by the transactions of the block.
+/// The `Mint` transaction credits the coinbase to the coinbase contract,
+/// so it doesn't create coins.
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CoinChanges {
+    /// The coins created by the outputs, in the order of creation.
+    pub created: Vec<Coin>,
+    /// The ids of the coins spent by the inputs, in the order of spending.
+    pub spent: Vec<UtxoId>,
+}
+
+/// The storage table of the coin changes of each block.
+pub struct BlockCoinChanges;
+
+impl Mappable for BlockCoinChanges {
+    /// Primary key - `BlockHeight`.
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = CoinChanges;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for BlockCoinChanges {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::BlockCoinChanges
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    BlockCoinChanges,
+    <BlockCoinChanges as Mappable>::Key::default(),
+    <BlockCoinChanges as Mappable>::Value::default()
+);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -61,4 +136,16 @@ mod test {
         <OwnedCoins as Mappable>::Value::default(),
         generate_key
     );
+
+    mod asset_coins {
+        use super::*;
+
+        fuel_core_storage::basic_storage_tests!(
+            AssetCoins,
+            [0u8; 66],
+            <AssetCoins as Mappable>::Value::default(),
+            <AssetCoins as Mappable>::Value::default(),
+            generate_key
+        );
+    }
 }
@@ -21,6 +21,8 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         Bytes32,
+        Transaction,
+        TxId,
     },
     fuel_types::BlockHeight,
     services::txpool::TransactionStatus,
@@ -92,6 +94,45 @@ impl AddTable<TransactionStatuses> for StateConfigBuilder {
     }
 }
 
+/// The table of the transactions submitted with the label in the debug mode.
+/// It links the label to the ids of the transactions in the order of the submission.
+pub struct TransactionLabels;
+
+impl Mappable for TransactionLabels {
+    type Key = str;
+    type OwnedKey = String;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Vec<Bytes32>;
+}
+
+impl TableWithBlueprint for TransactionLabels {
+    type Blueprint = Plain<Postcard, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionLabels
+    }
+}
+
+/// The table of the pending transactions of the `TxPool` persisted across restarts.
+pub struct PendingTransactions;
+
+impl Mappable for PendingTransactions {
+    type Key = TxId;
+    type OwnedKey = Self::Key;
+    type Value = Transaction;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for PendingTransactions {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::PendingTransactions
+    }
+}
+
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
 const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
 const INDEX_SIZE: usize = Address::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE;
@@ -235,6 +276,12 @@ mod test {
         generate_key
     );
 
+    fuel_core_storage::basic_storage_tests!(
+        PendingTransactions,
+        <PendingTransactions as Mappable>::Key::default(),
+        <PendingTransactions as Mappable>::Value::default()
+    );
+
     fuel_core_storage::basic_storage_tests!(
         TransactionStatuses,
         <TransactionStatuses as Mappable>::Key::default(),
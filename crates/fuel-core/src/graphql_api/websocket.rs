@@ -0,0 +1,177 @@
+use crate::{
+    fuel_core_graphql_api::WebSocketKeepAlive,
+    schema::CoreSchema,
+};
+use async_graphql::http::{
+    WebSocket as GraphQLWebSocket,
+    WebSocketProtocols,
+    WsMessage,
+    ALL_WEBSOCKET_PROTOCOLS,
+};
+use axum::{
+    extract::{
+        ws::{
+            CloseFrame,
+            Message,
+            WebSocket,
+            WebSocketUpgrade,
+        },
+        Extension,
+    },
+    http::{
+        header::SEC_WEBSOCKET_PROTOCOL,
+        HeaderMap,
+        StatusCode,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+};
+use futures::{
+    SinkExt,
+    StreamExt,
+};
+use std::str::FromStr;
+use tokio::time::{
+    Instant,
+    Interval,
+    MissedTickBehavior,
+};
+use tokio_stream::wrappers::ReceiverStream;
+
+/// The number of the client messages waiting for the processing by the schema.
+const INPUT_BUFFER: usize = 16;
+
+/// The close code of the WebSocket closed without errors.
+const NORMAL_CLOSURE: u16 = 1000;
+
+/// Serves the subscriptions over the WebSocket with the `graphql-transport-ws`
+/// or the `graphql-ws` protocol.
+pub(crate) async fn graphql_websocket_handler(
+    upgrade: WebSocketUpgrade,
+    Extension(schema): Extension<CoreSchema>,
+    Extension(keep_alive): Extension<Option<WebSocketKeepAlive>>,
+    headers: HeaderMap,
+) -> Response {
+    let protocol = headers
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|protocols| protocols.to_str().ok())
+        .and_then(|protocols| {
+            protocols
+                .split(',')
+                .find_map(|protocol| WebSocketProtocols::from_str(protocol.trim()).ok())
+        });
+    let Some(protocol) = protocol else {
+        return (StatusCode::BAD_REQUEST, "Unsupported WebSocket protocol").into_response()
+    };
+
+    upgrade
+        .protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |socket| serve(socket, schema, protocol, keep_alive))
+}
+
+/// Forwards the messages between the client and the schema until one of them
+/// closes the connection. With the `keep_alive`, the client is pinged every
+/// `ping_interval`, and the connection is closed if the client doesn't answer
+/// during the `pong_timeout`, so the dead and idle clients don't hold the sockets.
+async fn serve(
+    socket: WebSocket,
+    schema: CoreSchema,
+    protocol: WebSocketProtocols,
+    keep_alive: Option<WebSocketKeepAlive>,
+) {
+    let (mut sink, mut stream) = socket.split();
+    let (input_sender, input_receiver) = tokio::sync::mpsc::channel(INPUT_BUFFER);
+    let mut output = Box::pin(GraphQLWebSocket::new(
+        schema,
+        ReceiverStream::<Vec<u8>>::new(input_receiver),
+        protocol,
+    ));
+
+    let mut ping_interval = keep_alive.map(|keep_alive| {
+        let mut interval = tokio::time::interval_at(
+            Instant::now() + keep_alive.ping_interval,
+            keep_alive.ping_interval,
+        );
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        interval
+    });
+    let mut pong_deadline = None;
+
+    loop {
+        tokio::select! {
+            message = stream.next() => {
+                let data = match message {
+                    Some(Ok(Message::Text(text))) => text.into_bytes(),
+                    Some(Ok(Message::Binary(data))) => data,
+                    Some(Ok(Message::Pong(_))) => {
+                        pong_deadline = None;
+                        continue
+                    }
+                    // The pings of the client are answered by the WebSocket itself.
+                    Some(Ok(Message::Ping(_))) => continue,
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                };
+                if input_sender.send(data).await.is_err() {
+                    break
+                }
+            }
+
+            message = output.next() => {
+                match message {
+                    Some(WsMessage::Text(text)) => {
+                        if sink.send(Message::Text(text)).await.is_err() {
+                            break
+                        }
+                    }
+                    Some(WsMessage::Close(code, reason)) => {
+                        let frame = CloseFrame {
+                            code,
+                            reason: reason.into(),
+                        };
+                        let _ = sink.send(Message::Close(Some(frame))).await;
+                        break
+                    }
+                    None => break,
+                }
+            }
+
+            _ = tick(&mut ping_interval) => {
+                if sink.send(Message::Ping(vec![])).await.is_err() {
+                    break
+                }
+                if pong_deadline.is_none() {
+                    pong_deadline = keep_alive
+                        .map(|keep_alive| Instant::now() + keep_alive.pong_timeout);
+                }
+            }
+
+            _ = sleep_until(pong_deadline) => {
+                tracing::debug!("Closing the WebSocket of the client that didn't answer the ping");
+                let frame = CloseFrame {
+                    code: NORMAL_CLOSURE,
+                    reason: "The ping was not answered in time".into(),
+                };
+                let _ = sink.send(Message::Close(Some(frame))).await;
+                break
+            }
+        }
+    }
+}
+
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => futures::future::pending().await,
+    }
+}
+
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => futures::future::pending().await,
+    }
+}
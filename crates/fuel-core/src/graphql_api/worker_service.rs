@@ -2,9 +2,18 @@ use crate::fuel_core_graphql_api::{
     ports,
     ports::worker::OffChainDatabase,
     storage::{
-        blocks::FuelBlockIdsToHeights,
+        blocks::{
+            BlockSummary,
+            FuelBlockGasPrices,
+            FuelBlockIdsToHeights,
+            FuelBlockSummaries,
+        },
         coins::{
+            asset_coin_id_key,
             owner_coin_id_key,
+            AssetCoins,
+            BlockCoinChanges,
+            CoinChanges,
             OwnedCoins,
         },
         contracts::ContractsInfo,
@@ -13,6 +22,7 @@ use crate::fuel_core_graphql_api::{
             OwnedMessageKey,
         },
     },
+    WorkerRetryConfig,
 };
 use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_services::{
@@ -32,6 +42,8 @@ use fuel_core_types::{
     fuel_tx::{
         field::{
             Inputs,
+            MintAmount,
+            MintGasPrice,
             Outputs,
             Salt,
         },
@@ -39,8 +51,12 @@ use fuel_core_types::{
             CoinPredicate,
             CoinSigned,
         },
+        Address,
+        Chargeable,
+        ConsensusParameters,
         Input,
         Output,
+        Receipt,
         Transaction,
         UniqueIdentifier,
     },
@@ -50,15 +66,15 @@ use fuel_core_types::{
         ChainId,
     },
     services::{
-        block_importer::{
-            ImportResult,
-            SharedImportResult,
-        },
+        block_importer::SharedImportResult,
         executor::{
             Event,
             TransactionExecutionStatus,
         },
-        txpool::from_executor_to_status,
+        txpool::{
+            from_executor_to_status,
+            TransactionStatus,
+        },
     },
 };
 use futures::{
@@ -68,15 +84,54 @@ use futures::{
 use std::{
     borrow::Cow,
     ops::Deref,
+    time::Duration,
+};
+use tokio_rayon::{
+    rayon::{
+        iter::{
+            IntoParallelIterator,
+            ParallelIterator,
+        },
+        ThreadPool,
+        ThreadPoolBuilder,
+    },
+    AsyncThreadPool,
 };
 
+/// The upper bound of the delay between the retries of the failed write.
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(10);
+
 /// The off-chain GraphQL API worker task processes the imported blocks
 /// and actualize the information used by the GraphQL service.
 pub struct Task<TxPool, D> {
     tx_pool: TxPool,
     block_importer: BoxStream<SharedImportResult>,
     database: D,
-    chain_id: ChainId,
+    consensus_parameters: ConsensusParameters,
+    /// The maximum number of already imported blocks prepared for indexing concurrently.
+    parallelism: usize,
+    /// The pool used to prepare the blocks. If `None`, the blocks are prepared
+    /// sequentially by the task itself.
+    thread_pool: Option<ThreadPool>,
+    retry: WorkerRetryConfig,
+    /// Set when the block can't be indexed. The worker doesn't index
+    /// the following blocks to not leave a gap in the indexes.
+    halted: bool,
+}
+
+/// The indexing information of the block that doesn't depend on the state
+/// of the database, so it can be prepared for several blocks concurrently.
+struct PreparedBlock {
+    result: SharedImportResult,
+    tx_statuses: Vec<(Bytes32, TransactionStatus)>,
+    tx_owners: Vec<TransactionOwners>,
+}
+
+/// The owners of the inputs and outputs of the transaction.
+struct TransactionOwners {
+    tx_id: Bytes32,
+    tx_idx: u16,
+    owners: Vec<Address>,
 }
 
 impl<TxPool, D> Task<TxPool, D>
@@ -84,24 +139,124 @@ where
     TxPool: ports::worker::TxPool,
     D: ports::worker::Transactional,
 {
-    fn process_block(&mut self, result: SharedImportResult) -> anyhow::Result<()> {
+    /// Prepares the blocks concurrently and writes them to the database
+    /// one by one in the order of import.
+    async fn process_blocks(
+        &mut self,
+        blocks: Vec<SharedImportResult>,
+        watcher: &mut StateWatcher,
+    ) -> anyhow::Result<()> {
+        let chain_id = self.consensus_parameters.chain_id();
+        let prepared_blocks = match &self.thread_pool {
+            Some(thread_pool) => {
+                thread_pool
+                    .spawn_async(move || {
+                        blocks
+                            .into_par_iter()
+                            .map(|result| prepare_block(result, &chain_id))
+                            .collect::<anyhow::Result<Vec<_>>>()
+                    })
+                    .await?
+            }
+            None => blocks
+                .into_iter()
+                .map(|result| prepare_block(result, &chain_id))
+                .collect::<anyhow::Result<Vec<_>>>()?,
+        };
+
+        for prepared_block in prepared_blocks {
+            self.process_block(prepared_block, watcher).await?;
+        }
+        Ok(())
+    }
+
+    /// Writes the block into the database, retrying the failed writes
+    /// while the service is started, and notifies the `TxPool` about
+    /// the statuses of its transactions.
+    async fn process_block(
+        &mut self,
+        prepared_block: PreparedBlock,
+        watcher: &mut StateWatcher,
+    ) -> anyhow::Result<()> {
+        let height = *prepared_block.result.sealed_block.entity.header().height();
+        let mut backoff = self.retry.initial_backoff;
+        let mut retries = 0u32;
+        let total_tx_count = loop {
+            match self.write_block(&prepared_block) {
+                Ok(total_tx_count) => break total_tx_count,
+                Err(err) if retries < self.retry.max_retries => {
+                    tracing::warn!(
+                        "Failed to write the off-chain indexes of the block at the height \
+                        {height}, retrying in {backoff:?}: {err:?}"
+                    );
+                    tokio::select! {
+                        biased;
+
+                        _ = watcher.while_started() => {
+                            return Err(err.context(format!(
+                                "The service is stopped before the off-chain indexes \
+                                of the block at the height {height} are written"
+                            )))
+                        }
+
+                        _ = tokio::time::sleep(backoff) => {}
+                    }
+                    backoff = backoff.saturating_mul(2).min(MAX_RETRY_BACKOFF);
+                    retries = retries.saturating_add(1);
+                }
+                Err(err) => {
+                    return Err(err.context(format!(
+                        "Failed to write the off-chain indexes of the block \
+                        at the height {height} after {retries} retries"
+                    )))
+                }
+            }
+        };
+
+        for (tx_id, status) in prepared_block.tx_statuses {
+            self.tx_pool.send_complete(tx_id, &height, status);
+        }
+
+        // update the importer metrics after the block is successfully committed
+        graphql_metrics().total_txs_count.set(total_tx_count as i64);
+
+        Ok(())
+    }
+
+    /// Writes the indexes of the block in one database transaction.
+    /// Returns the total number of the transactions on the chain.
+    fn write_block(&mut self, prepared_block: &PreparedBlock) -> anyhow::Result<u64> {
+        let PreparedBlock {
+            result,
+            tx_statuses,
+            tx_owners,
+        } = prepared_block;
         let block = &result.sealed_block.entity;
         let mut transaction = self.database.transaction();
         // save the status for every transaction using the finalized block id
-        persist_transaction_status(&result, &mut transaction)?;
+        persist_transaction_status(tx_statuses, &mut transaction)?;
 
         // save the associated owner for each transaction in the block
-        index_tx_owners_for_block(block, &mut transaction, &self.chain_id)?;
+        let height = block.header().height();
+        for owners in tx_owners.iter() {
+            persist_owners_index(*height, owners, &mut transaction)?;
+        }
 
         // save the transaction related information
         process_transactions(block.transactions().iter(), &mut transaction)?;
 
-        let height = block.header().height();
         let block_id = block.id();
         transaction
             .storage::<FuelBlockIdsToHeights>()
             .insert(&block_id, height)?;
 
+        // save the gas price used to produce the block
+        if let Some(Transaction::Mint(mint)) = block.transactions().last() {
+            transaction
+                .storage::<FuelBlockGasPrices>()
+                .insert(height, mint.gas_price())?;
+        }
+
         let total_tx_count = transaction
             .increase_tx_count(block.transactions().len() as u64)
             .unwrap_or_default();
@@ -111,18 +266,21 @@ where
             &mut transaction,
         )?;
 
-        transaction.commit()?;
+        // save the coins created and spent by the block
+        let coin_changes = block_coin_changes(result.events.iter());
+        transaction
+            .storage::<BlockCoinChanges>()
+            .insert(height, &coin_changes)?;
 
-        for status in result.tx_status.iter() {
-            let tx_id = status.id;
-            let status = from_executor_to_status(block, status.result.clone());
-            self.tx_pool.send_complete(tx_id, height, status);
-        }
+        // save the aggregates over the transactions of the block
+        let summary = block_summary(block, &result.tx_status, &self.consensus_parameters);
+        transaction
+            .storage::<FuelBlockSummaries>()
+            .insert(height, &summary)?;
 
-        // update the importer metrics after the block is successfully committed
-        graphql_metrics().total_txs_count.set(total_tx_count as i64);
+        transaction.commit()?;
 
-        Ok(())
+        Ok(total_tx_count)
     }
 }
 
@@ -158,29 +316,110 @@ where
                 block_st_transaction
                     .storage_as_mut::<OwnedCoins>()
                     .insert(&coin_by_owner, &())?;
+                let coin_by_asset = asset_coin_id_key(&coin.asset_id, &coin.utxo_id);
+                block_st_transaction
+                    .storage_as_mut::<AssetCoins>()
+                    .insert(&coin_by_asset, &())?;
             }
             Event::CoinConsumed(coin) => {
                 let key = owner_coin_id_key(&coin.owner, &coin.utxo_id);
                 block_st_transaction
                     .storage_as_mut::<OwnedCoins>()
                     .remove(&key)?;
+                let key = asset_coin_id_key(&coin.asset_id, &coin.utxo_id);
+                block_st_transaction
+                    .storage_as_mut::<AssetCoins>()
+                    .remove(&key)?;
             }
         }
     }
     Ok(())
 }
 
-/// Associate all transactions within a block to their respective UTXO owners
-fn index_tx_owners_for_block<T>(
-    block: &Block,
-    block_st_transaction: &mut T,
+/// Prepares the statuses and the owners of all transactions within a block.
+fn prepare_block(
+    result: SharedImportResult,
     chain_id: &ChainId,
-) -> anyhow::Result<()>
+) -> anyhow::Result<PreparedBlock> {
+    let block = &result.sealed_block.entity;
+    let tx_statuses = result
+        .tx_status
+        .iter()
+        .map(|TransactionExecutionStatus { id, result, .. }| {
+            (*id, from_executor_to_status(block, result.clone()))
+        })
+        .collect();
+    let tx_owners = tx_owners_for_block(block, chain_id)?;
+
+    Ok(PreparedBlock {
+        result,
+        tx_statuses,
+        tx_owners,
+    })
+}
+
+/// Collects the coins created and spent by the executor events of the block.
+fn block_coin_changes<'a, Iter>(events: Iter) -> CoinChanges
 where
-    T: OffChainDatabase,
+    Iter: Iterator<Item = &'a Event>,
 {
+    let mut changes = CoinChanges::default();
+    for event in events {
+        match event {
+            Event::CoinCreated(coin) => changes.created.push(*coin),
+            Event::CoinConsumed(coin) => changes.spent.push(coin.utxo_id),
+            Event::MessageImported(_) | Event::MessageConsumed(_) => {}
+        }
+    }
+    changes
+}
+
+/// Aggregates the gas and the fee over the executed transactions of the block.
+/// The gas of the transaction is its minimal gas, charged for its size and
+/// the validation of its predicates and signatures, plus the gas used
+/// by the script from the `ScriptResult` receipt. The fee is the amount
+/// minted to the coinbase, since all fees of the block go to the block producer.
+fn block_summary(
+    block: &Block,
+    tx_status: &[TransactionExecutionStatus],
+    consensus_parameters: &ConsensusParameters,
+) -> BlockSummary {
+    let gas_costs = consensus_parameters.gas_costs();
+    let fee_params = consensus_parameters.fee_params();
+    let min_gas = block.transactions().iter().map(|tx| match tx {
+        Transaction::Script(script) => script.min_gas(gas_costs, fee_params),
+        Transaction::Create(create) => create.min_gas(gas_costs, fee_params),
+        Transaction::Mint(_) => 0,
+    });
+    let script_gas = tx_status
+        .iter()
+        .flat_map(|status| status.result.receipts())
+        .filter_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        });
+    let total_gas_used = min_gas
+        .chain(script_gas)
+        .fold(0u64, |total, gas| total.saturating_add(gas));
+    let total_fee = match block.transactions().last() {
+        Some(Transaction::Mint(mint)) => *mint.mint_amount(),
+        _ => 0,
+    };
+
+    BlockSummary {
+        transaction_count: block.transactions().len() as u64,
+        total_gas_used,
+        total_fee,
+    }
+}
+
+/// Associate all transactions within a block to their respective UTXO owners
+fn tx_owners_for_block(
+    block: &Block,
+    chain_id: &ChainId,
+) -> anyhow::Result<Vec<TransactionOwners>> {
+    let mut tx_owners = Vec::with_capacity(block.transactions().len());
     for (tx_idx, tx) in block.transactions().iter().enumerate() {
-        let block_height = *block.header().height();
         let inputs;
         let outputs;
         let tx_idx = u16::try_from(tx_idx).map_err(|e| {
@@ -198,36 +437,23 @@ where
             }
             Transaction::Mint(_) => continue,
         }
-        persist_owners_index(
-            block_height,
-            inputs,
-            outputs,
-            &tx_id,
+        tx_owners.push(TransactionOwners {
+            tx_id,
             tx_idx,
-            block_st_transaction,
-        )?;
+            owners: owners_of(inputs, outputs),
+        });
     }
-    Ok(())
+    Ok(tx_owners)
 }
 
-/// Index the tx id by owner for all of the inputs and outputs
-fn persist_owners_index<T>(
-    block_height: BlockHeight,
-    inputs: &[Input],
-    outputs: &[Output],
-    tx_id: &Bytes32,
-    tx_idx: u16,
-    db: &mut T,
-) -> StorageResult<()>
-where
-    T: OffChainDatabase,
-{
+/// Collects the unique owners of the inputs and outputs
+fn owners_of(inputs: &[Input], outputs: &[Output]) -> Vec<Address> {
     let mut owners = vec![];
     for input in inputs {
         if let Input::CoinSigned(CoinSigned { owner, .. })
         | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
         {
-            owners.push(owner);
+            owners.push(*owner);
         }
     }
 
@@ -236,7 +462,7 @@ where
             Output::Coin { to, .. }
             | Output::Change { to, .. }
             | Output::Variable { to, .. } => {
-                owners.push(to);
+                owners.push(*to);
             }
             Output::Contract(_) | Output::ContractCreated { .. } => {}
         }
@@ -245,26 +471,40 @@ where
     // dedupe owners from inputs and outputs prior to indexing
     owners.sort();
     owners.dedup();
+    owners
+}
+
+/// Index the tx id by owner for all of the inputs and outputs
+fn persist_owners_index<T>(
+    block_height: BlockHeight,
+    tx_owners: &TransactionOwners,
+    db: &mut T,
+) -> StorageResult<()>
+where
+    T: OffChainDatabase,
+{
+    let TransactionOwners {
+        tx_id,
+        tx_idx,
+        owners,
+    } = tx_owners;
 
     for owner in owners {
-        db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
+        db.record_tx_id_owner(owner, block_height, *tx_idx, tx_id)?;
     }
 
     Ok(())
 }
 
 fn persist_transaction_status<T>(
-    import_result: &ImportResult,
+    tx_statuses: &[(Bytes32, TransactionStatus)],
     db: &mut T,
 ) -> StorageResult<()>
 where
     T: OffChainDatabase,
 {
-    for TransactionExecutionStatus { id, result } in import_result.tx_status.iter() {
-        let status =
-            from_executor_to_status(&import_result.sealed_block.entity, result.clone());
-
-        if db.update_tx_status(id, status)?.is_some() {
+    for (id, status) in tx_statuses.iter() {
+        if db.update_tx_status(id, status.clone())?.is_some() {
             return Err(anyhow::anyhow!(
                 "Transaction status already exists for tx {}",
                 id
@@ -329,6 +569,14 @@ where
             graphql_metrics().total_txs_count.set(total_tx_count as i64);
         }
 
+        if self.parallelism > 1 {
+            let thread_pool = ThreadPoolBuilder::new()
+                .num_threads(self.parallelism)
+                .thread_name(|i| format!("graphql-worker-{i}"))
+                .build()?;
+            self.thread_pool = Some(thread_pool);
+        }
+
         // TODO: It is possible that the node was shut down before we processed all imported blocks.
         //  It could lead to some missed blocks and the database's inconsistent state.
         //  Because the result of block execution is not stored on the chain, it is impossible
@@ -357,9 +605,24 @@ where
 
             result = self.block_importer.next() => {
                 if let Some(block) = result {
-                    self.process_block(block)?;
-
-                    should_continue = true
+                    let mut blocks = vec![block];
+                    // index the blocks that are already imported in one batch
+                    while blocks.len() < self.parallelism {
+                        match self.block_importer.next().now_or_never() {
+                            Some(Some(block)) => blocks.push(block),
+                            _ => break,
+                        }
+                    }
+                    if let Err(err) = self.process_blocks(blocks, watcher).await {
+                        tracing::error!(
+                            "The GraphQL worker is halted, because it can't index \
+                            the imported block: {err:?}"
+                        );
+                        self.halted = true;
+                        should_continue = false
+                    } else {
+                        should_continue = true
+                    }
                 } else {
                     should_continue = false
                 }
@@ -369,12 +632,20 @@ where
     }
 
     async fn shutdown(mut self) -> anyhow::Result<()> {
+        if self.halted {
+            return Ok(())
+        }
+
         // Process all remaining blocks before shutdown to not lose any data.
+        // The watcher is not started, so the failed writes are not retried.
+        let mut watcher = StateWatcher::default();
         loop {
             let result = self.block_importer.next().now_or_never();
 
             if let Some(Some(block)) = result {
-                self.process_block(block)?;
+                let prepared_block =
+                    prepare_block(block, &self.consensus_parameters.chain_id())?;
+                self.process_block(prepared_block, &mut watcher).await?;
             } else {
                 break;
             }
@@ -387,7 +658,9 @@ pub fn new_service<TxPool, I, D>(
     tx_pool: TxPool,
     block_importer: I,
     database: D,
-    chain_id: ChainId,
+    consensus_parameters: ConsensusParameters,
+    parallelism: usize,
+    retry: WorkerRetryConfig,
 ) -> ServiceRunner<Task<TxPool, D>>
 where
     TxPool: ports::worker::TxPool,
@@ -399,6 +672,183 @@ where
         tx_pool,
         block_importer,
         database,
-        chain_id,
+        consensus_parameters,
+        parallelism,
+        thread_pool: None,
+        retry,
+        halted: false,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::fuel_core_graphql_api::storage::Column;
+    use fuel_core_services::{
+        stream::IntoBoxStream,
+        State,
+    };
+    use fuel_core_storage::{
+        kv_store::{
+            KeyValueInspect,
+            Value,
+        },
+        structured_storage::test::InMemoryStorage,
+        transactional::{
+            Changes,
+            IntoTransaction,
+            Modifiable,
+            ReadTransaction,
+            StorageTransaction,
+        },
+        Error as StorageError,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        blockchain::SealedBlock,
+        services::block_importer::ImportResult,
+    };
+    use std::sync::Arc;
+    use tokio::sync::watch;
+
+    /// The off-chain storage failing the first `failing_commits` commits.
+    #[derive(Default)]
+    struct FlakyStorage {
+        inner: InMemoryStorage<Column>,
+        failing_commits: u32,
+        commits: u32,
+    }
+
+    impl KeyValueInspect for FlakyStorage {
+        type Column = Column;
+
+        fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+            self.inner.get(key, column)
+        }
+    }
+
+    impl Modifiable for FlakyStorage {
+        fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+            self.commits = self.commits.saturating_add(1);
+            if self.commits <= self.failing_commits {
+                return Err(StorageError::Other(anyhow::anyhow!(
+                    "The storage is temporarily unavailable"
+                )))
+            }
+            self.inner.commit_changes(changes)
+        }
+    }
+
+    impl ports::worker::Transactional for FlakyStorage {
+        type Transaction<'a>
+            = StorageTransaction<&'a mut Self>
+        where
+            Self: 'a;
+
+        fn transaction(&mut self) -> Self::Transaction<'_> {
+            self.into_transaction()
+        }
+    }
+
+    struct NoopTxPool;
+
+    impl ports::worker::TxPool for NoopTxPool {
+        fn send_complete(&self, _: Bytes32, _: &BlockHeight, _: TransactionStatus) {}
+    }
+
+    fn task(failing_commits: u32, max_retries: u32) -> Task<NoopTxPool, FlakyStorage> {
+        let result: SharedImportResult = Arc::new(ImportResult::new_from_local(
+            SealedBlock::default(),
+            vec![],
+            vec![],
+        ));
+        Task {
+            tx_pool: NoopTxPool,
+            block_importer: tokio_stream::iter(vec![result]).into_boxed(),
+            database: FlakyStorage {
+                failing_commits,
+                ..Default::default()
+            },
+            consensus_parameters: ConsensusParameters::default(),
+            parallelism: 1,
+            thread_pool: None,
+            retry: WorkerRetryConfig {
+                max_retries,
+                initial_backoff: Duration::from_millis(1),
+            },
+            halted: false,
+        }
+    }
+
+    fn indexed_heights(task: &Task<NoopTxPool, FlakyStorage>) -> Vec<BlockHeight> {
+        let block_id = SealedBlock::default().entity.id();
+        task.database
+            .inner
+            .read_transaction()
+            .storage::<FuelBlockIdsToHeights>()
+            .get(&block_id)
+            .unwrap()
+            .map(|height| height.into_owned())
+            .into_iter()
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn run__block_is_indexed_after_transient_write_failures() {
+        // Given
+        let mut task = task(2, 3);
+        let (_sender, receiver) = watch::channel(State::Started);
+        let mut watcher = receiver.into();
+
+        // When
+        let should_continue = task.run(&mut watcher).await.unwrap();
+
+        // Then
+        assert!(should_continue);
+        assert_eq!(task.database.commits, 3);
+        assert_eq!(indexed_heights(&task), vec![BlockHeight::default()]);
+    }
+
+    #[tokio::test]
+    async fn run__worker_halts_when_retries_are_exhausted() {
+        // Given
+        let mut task = task(3, 2);
+        let (_sender, receiver) = watch::channel(State::Started);
+        let mut watcher = receiver.into();
+
+        // When
+        let should_continue = task.run(&mut watcher).await.unwrap();
+
+        // Then
+        assert!(!should_continue);
+        assert!(task.halted);
+        assert_eq!(task.database.commits, 3);
+        assert!(indexed_heights(&task).is_empty());
+    }
+
+    #[tokio::test]
+    async fn run__stop_interrupts_the_retry_backoff() {
+        // Given
+        let mut task = task(1, 1);
+        task.retry.initial_backoff = Duration::from_secs(3600);
+        let (sender, receiver) = watch::channel(State::Started);
+        let mut watcher = receiver.into();
+
+        // When
+        let run = tokio::time::timeout(Duration::from_secs(10), task.run(&mut watcher));
+        let stop = async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            sender.send(State::Stopping).unwrap();
+        };
+        let (result, _) = tokio::join!(run, stop);
+        let should_continue = result.expect("The stop should interrupt the backoff");
+
+        // Then
+        assert!(!should_continue.unwrap());
+        assert!(task.halted);
+        assert_eq!(task.database.commits, 1);
+        assert!(indexed_heights(&task).is_empty());
+    }
+}
@@ -48,7 +48,10 @@ use fuel_core_types::{
         ChainId,
     },
     secrecy::Secret,
-    services::p2p::GossipsubMessageAcceptance,
+    services::{
+        p2p::GossipsubMessageAcceptance,
+        txpool::InsertionOutcome,
+    },
 };
 use futures::StreamExt;
 use itertools::Itertools;
@@ -472,7 +475,7 @@ impl Node {
     pub async fn insert_txs(&self) -> HashMap<Bytes32, Transaction> {
         let mut expected = HashMap::new();
         for tx in &self.test_txs {
-            let tx_result = self
+            let outcome = self
                 .node
                 .shared
                 .txpool_shared_state
@@ -481,6 +484,9 @@ impl Node {
                 .pop()
                 .unwrap()
                 .unwrap();
+            let InsertionOutcome::Inserted(tx_result) = outcome else {
+                panic!("The test transaction should be inserted")
+            };
 
             let tx = Transaction::from(tx_result.inserted.as_ref());
             expected.insert(tx.id(&ChainId::default()), tx);
@@ -1,4 +1,7 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::{
+    coins_query::CoinsQueryError,
+    fuel_core_graphql_api::database::ReadView,
+};
 use asset_query::{
     AssetQuery,
     AssetSpendTarget,
@@ -35,11 +38,14 @@ pub trait BalanceQueryData: Send + Sync {
         base_asset_id: AssetId,
     ) -> StorageResult<AddressBalance>;
 
+    /// Returns the balances of all assets of the `owner`.
+    /// Fails if the `owner` has more than `max_assets` distinct assets.
     fn balances(
         &self,
         owner: Address,
         direction: IterDirection,
         base_asset_id: AssetId,
+        max_assets: usize,
     ) -> BoxedIter<StorageResult<AddressBalance>>;
 }
 
@@ -80,6 +86,7 @@ impl BalanceQueryData for ReadView {
         owner: Address,
         direction: IterDirection,
         base_asset_id: AssetId,
+        max_assets: usize,
     ) -> BoxedIter<StorageResult<AddressBalance>> {
         let mut amounts_per_asset = HashMap::new();
         let mut errors = vec![];
@@ -87,9 +94,16 @@ impl BalanceQueryData for ReadView {
         for coin in AssetsQuery::new(&owner, None, None, self, &base_asset_id).coins() {
             match coin {
                 Ok(coin) => {
-                    let amount: &mut u64 = amounts_per_asset
-                        .entry(*coin.asset_id(&base_asset_id))
-                        .or_default();
+                    let asset_id = *coin.asset_id(&base_asset_id);
+                    if !amounts_per_asset.contains_key(&asset_id)
+                        && amounts_per_asset.len() >= max_assets
+                    {
+                        let error = anyhow::Error::new(CoinsQueryError::TooManyAssets {
+                            max: max_assets,
+                        });
+                        return core::iter::once(Err(error.into())).into_boxed()
+                    }
+                    let amount: &mut u64 = amounts_per_asset.entry(asset_id).or_default();
                     *amount = amount.saturating_add(coin.amount());
                 }
                 Err(err) => {
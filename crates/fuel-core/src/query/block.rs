@@ -1,4 +1,7 @@
-use crate::fuel_core_graphql_api::ports::OnChainDatabase;
+use crate::{
+    fuel_core_graphql_api::ports::OnChainDatabase,
+    query::SimpleTransactionData,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -17,7 +20,13 @@ use fuel_core_types::{
         block::CompressedBlock,
         consensus::Consensus,
     },
-    fuel_types::BlockHeight,
+    entities::relayer::message::MerkleProof,
+    fuel_merkle::binary::in_memory::MerkleTree,
+    fuel_tx::TxId,
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+    },
 };
 
 pub trait SimpleBlockData: Send + Sync {
@@ -74,3 +83,39 @@ impl<D: OnChainDatabase + ?Sized> BlockQueryData for D {
             .ok_or(not_found!(SealedBlockConsensus))
     }
 }
+
+/// Builds the proof of inclusion of the transaction into the transactions root
+/// of the block at the `height`. Returns `None` if the block doesn't contain the transaction.
+pub fn transaction_inclusion_proof<T>(
+    database: &T,
+    height: &BlockHeight,
+    transaction_id: &TxId,
+) -> StorageResult<Option<MerkleProof>>
+where
+    T: SimpleBlockData + SimpleTransactionData + ?Sized,
+{
+    let block = database.block(height)?;
+
+    // The leaves of the tree are the serialized transactions in the order
+    // of the block, the same as during the calculation of the transactions root.
+    let mut tree = MerkleTree::new();
+    let mut proof_index = None;
+
+    for (index, id) in block.transactions().iter().enumerate() {
+        if id == transaction_id {
+            proof_index = Some(index as u64);
+        }
+
+        let tx = database.transaction(id)?;
+        tree.push(tx.to_bytes().as_slice());
+    }
+
+    let Some(proof_index) = proof_index else {
+        return Ok(None)
+    };
+
+    Ok(tree.prove(proof_index).map(|(_, proof_set)| MerkleProof {
+        proof_set,
+        proof_index,
+    }))
+}
@@ -1,6 +1,9 @@
-use crate::fuel_core_graphql_api::ports::{
-    OffChainDatabase,
-    OnChainDatabase,
+use crate::fuel_core_graphql_api::{
+    ports::{
+        OffChainDatabase,
+        OnChainDatabase,
+    },
+    storage::coins::CoinChanges,
 };
 use fuel_core_storage::{
     iter::{
@@ -16,8 +19,13 @@ use fuel_core_storage::{
 use fuel_core_types::{
     entities::coins::coin::Coin,
     fuel_tx::UtxoId,
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+    },
 };
+use std::collections::HashSet;
 
 pub trait CoinQueryData: Send + Sync {
     fn coin(&self, utxo_id: UtxoId) -> StorageResult<Coin>;
@@ -35,6 +43,22 @@ pub trait CoinQueryData: Send + Sync {
         start_coin: Option<UtxoId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<Coin>>;
+
+    fn asset_coins(
+        &self,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<Coin>>;
+
+    /// Returns the net coin changes of the blocks after the `from` height
+    /// up to the `to` height inclusive. The coins created and spent within
+    /// the range are not a part of the result.
+    fn coins_diff(
+        &self,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> StorageResult<CoinChanges>;
 }
 
 impl<D: OnChainDatabase + OffChainDatabase + ?Sized> CoinQueryData for D {
@@ -67,4 +91,40 @@ impl<D: OnChainDatabase + OffChainDatabase + ?Sized> CoinQueryData for D {
             .map(|res| res.and_then(|id| self.coin(id)))
             .into_boxed()
     }
+
+    fn asset_coins(
+        &self,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<Coin>> {
+        self.asset_coins_ids(asset_id, start_coin, direction)
+            .map(|res| res.and_then(|id| self.coin(id)))
+            .into_boxed()
+    }
+
+    fn coins_diff(
+        &self,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> StorageResult<CoinChanges> {
+        let mut changes = CoinChanges::default();
+        for height in u32::from(from).saturating_add(1)..=u32::from(to) {
+            let block_changes = self.block_coin_changes(&height.into())?;
+            changes.created.extend(block_changes.created);
+            changes.spent.extend(block_changes.spent);
+        }
+
+        // The coin can't be created twice, so the coin created and spent
+        // within the range doesn't affect the difference.
+        let created: HashSet<_> =
+            changes.created.iter().map(|coin| coin.utxo_id).collect();
+        let spent: HashSet<_> = changes.spent.iter().copied().collect();
+        changes
+            .created
+            .retain(|coin| !spent.contains(&coin.utxo_id));
+        changes.spent.retain(|utxo_id| !created.contains(utxo_id));
+
+        Ok(changes)
+    }
 }
@@ -11,6 +11,7 @@ use fuel_core_storage::{
     tables::{
         ContractsAssets,
         ContractsRawCode,
+        ContractsState,
     },
     Result as StorageResult,
     StorageAsRef,
@@ -18,10 +19,14 @@ use fuel_core_storage::{
 use fuel_core_types::{
     fuel_types::{
         AssetId,
+        Bytes32,
         ContractId,
     },
     fuel_vm::Salt,
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractStorageSlot,
+    },
 };
 
 pub trait ContractQueryData: Send + Sync {
@@ -43,6 +48,19 @@ pub trait ContractQueryData: Send + Sync {
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<ContractStorageSlot>;
+
+    fn contract_storage_slots(
+        &self,
+        contract_id: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractStorageSlot>>;
 }
 
 impl<D: OnChainDatabase + OffChainDatabase + ?Sized> ContractQueryData for D {
@@ -95,4 +113,31 @@ impl<D: OnChainDatabase + OffChainDatabase + ?Sized> ContractQueryData for D {
     ) -> BoxedIter<StorageResult<ContractBalance>> {
         self.contract_balances(contract_id, start_asset, direction)
     }
+
+    fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<ContractStorageSlot> {
+        let value = self
+            .storage::<ContractsState>()
+            .get(&(&contract_id, &key).into())?
+            .ok_or(not_found!(ContractsState))?
+            .into_owned();
+
+        Ok(ContractStorageSlot {
+            contract_id,
+            key,
+            value: value.0,
+        })
+    }
+
+    fn contract_storage_slots(
+        &self,
+        contract_id: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractStorageSlot>> {
+        self.contract_storage_slots(contract_id, start_key, direction)
+    }
 }
@@ -19,6 +19,7 @@ use fuel_core_storage::{
 };
 use itertools::Itertools;
 
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain;
@@ -28,6 +29,7 @@ pub mod dap;
 pub mod health;
 pub mod message;
 pub mod node_info;
+pub mod relayer;
 
 pub mod gas_price;
 pub mod scalars;
@@ -37,6 +39,7 @@ pub mod tx;
 pub struct Query(
     dap::DapQuery,
     balance::BalanceQuery,
+    asset::AssetQuery,
     block::BlockQuery,
     chain::ChainQuery,
     tx::TxQuery,
@@ -47,7 +50,11 @@ pub struct Query(
     node_info::NodeQuery,
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
+    gas_price::RequiredGasPriceQuery,
+    gas_price::BlockGasPriceQuery,
+    gas_price::GasPriceHistogramQuery,
     message::MessageQuery,
+    relayer::RelayerQuery,
 );
 
 #[derive(MergedObject, Default)]
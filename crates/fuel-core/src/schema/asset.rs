@@ -0,0 +1,81 @@
+use crate::{
+    fuel_core_graphql_api::{
+        self,
+        Config,
+    },
+    schema::scalars::{
+        AssetId,
+        U8,
+    },
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    Object,
+};
+
+pub struct Asset {
+    id: AssetId,
+    metadata: Option<fuel_core_graphql_api::AssetMetadata>,
+}
+
+#[Object]
+impl Asset {
+    async fn id(&self) -> AssetId {
+        self.id
+    }
+
+    /// The display metadata configured on the node, or `null` for the unknown asset.
+    async fn metadata(&self) -> Option<AssetMetadata> {
+        self.metadata.clone().map(AssetMetadata)
+    }
+}
+
+pub struct AssetMetadata(fuel_core_graphql_api::AssetMetadata);
+
+#[Object]
+impl AssetMetadata {
+    async fn symbol(&self) -> &str {
+        &self.0.symbol
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn decimals(&self) -> U8 {
+        self.0.decimals.into()
+    }
+}
+
+#[derive(Default)]
+pub struct AssetQuery;
+
+#[Object]
+impl AssetQuery {
+    /// Returns the assets with their display metadata, in the order of the `ids`.
+    /// The metadata is node-local and doesn't affect the consensus.
+    async fn assets(
+        &self,
+        ctx: &Context<'_>,
+        ids: Vec<AssetId>,
+    ) -> async_graphql::Result<Vec<Asset>> {
+        let config = ctx.data_unchecked::<Config>();
+        if ids.len() > config.max_assets_per_query {
+            return Err(anyhow!(
+                "too many assets requested, the maximum is {}",
+                config.max_assets_per_query
+            )
+            .into())
+        }
+
+        let assets = ids
+            .into_iter()
+            .map(|id| Asset {
+                id,
+                metadata: config.asset_metadata.get(&id.0).cloned(),
+            })
+            .collect();
+        Ok(assets)
+    }
+}
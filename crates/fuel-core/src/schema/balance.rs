@@ -85,12 +85,10 @@ impl BalanceQuery {
         let query: &ReadView = ctx.data_unchecked();
         crate::schema::query_pagination(after, before, first, last, |_, direction| {
             let owner = filter.owner.into();
-            let base_asset_id = *ctx
-                .data_unchecked::<Config>()
-                .consensus_parameters
-                .base_asset_id();
+            let config = ctx.data_unchecked::<Config>();
+            let base_asset_id = *config.consensus_parameters.base_asset_id();
             Ok(query
-                .balances(owner, direction, base_asset_id)
+                .balances(owner, direction, base_asset_id, config.max_assets_per_query)
                 .map(|result| {
                     result.map(|balance| (balance.asset_id.into(), balance.into()))
                 }))
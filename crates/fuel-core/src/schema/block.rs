@@ -7,18 +7,23 @@ use crate::{
         api_service::ConsensusModule,
         database::ReadView,
         ports::OffChainDatabase,
+        storage::blocks::BlockSummary,
         Config as GraphQLConfig,
         IntoApiResult,
     },
     query::{
+        transaction_inclusion_proof,
         BlockQueryData,
         SimpleBlockData,
         SimpleTransactionData,
     },
     schema::{
+        message::MerkleProof,
         scalars::{
             BlockId,
+            PublicKey,
             Signature,
+            TransactionId,
             U32,
             U64,
         },
@@ -55,6 +60,15 @@ use fuel_core_types::{
 
 pub struct Block(pub(crate) CompressedBlock);
 
+impl Block {
+    fn summary(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<BlockSummary>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .block_summary(self.0.header().height())
+            .into_api_result()
+    }
+}
+
 pub struct Header(pub(crate) BlockHeader);
 
 #[derive(Union)]
@@ -100,6 +114,31 @@ impl Block {
         self.0.header().clone().into()
     }
 
+    /// The id of the previous block on the canonical chain.
+    /// It is `null` for the first block known to the node.
+    async fn parent_id(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<BlockId>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let Some(parent_height) = self.0.header().height().pred() else {
+            return Ok(None)
+        };
+        let parent = query
+            .block(&parent_height)
+            .into_api_result::<CompressedBlock, async_graphql::Error>()?;
+        Ok(parent.map(|parent| {
+            let bytes: fuel_types::Bytes32 = parent.header().id().into();
+            bytes.into()
+        }))
+    }
+
+    /// The Binary Merkle Tree root of the transactions of the block.
+    /// It is the same as the `transactionsRoot` of the header.
+    async fn transactions_root(&self) -> Bytes32 {
+        self.0.header().transactions_root.into()
+    }
+
     async fn consensus(&self, ctx: &Context<'_>) -> async_graphql::Result<Consensus> {
         let query: &ReadView = ctx.data_unchecked();
         let height = self.0.header().height();
@@ -109,19 +148,66 @@ impl Block {
         Ok(my_consensus)
     }
 
+    /// The public key recovered from the block's consensus signature.
+    /// The genesis block is not signed, so it doesn't have a signer.
+    async fn signer(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<PublicKey>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height = self.0.header().height();
+        let core_consensus = query.consensus(height)?;
+
+        let signer = core_consensus.block_signer(&self.0.header().id())?;
+        Ok(signer.map(Into::into))
+    }
+
+    /// The transactions of the block. The `Mint` transaction is included
+    /// unless `includeMint` is `false`.
     async fn transactions(
         &self,
         ctx: &Context<'_>,
+        #[graphql(desc = "Include the `Mint` transaction, `true` by default")]
+        include_mint: Option<bool>,
     ) -> async_graphql::Result<Vec<Transaction>> {
         let query: &ReadView = ctx.data_unchecked();
-        self.0
-            .transactions()
-            .iter()
-            .map(|tx_id| {
-                let tx = query.transaction(tx_id)?;
-                Ok(Transaction::from_tx(*tx_id, tx))
-            })
-            .collect()
+        let include_mint = include_mint.unwrap_or(true);
+        let mut transactions = Vec::with_capacity(self.0.transactions().len());
+        for tx_id in self.0.transactions() {
+            let tx = query.transaction(tx_id)?;
+            if include_mint || !tx.is_mint() {
+                transactions.push(Transaction::from_tx(*tx_id, tx));
+            }
+        }
+        Ok(transactions)
+    }
+
+    /// The number of transactions in the block, including the `Mint` transaction.
+    /// It is `null` if the block is not indexed by the off-chain worker, e.g., the genesis block.
+    async fn transaction_count(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<U64>> {
+        Ok(self
+            .summary(ctx)?
+            .map(|summary| summary.transaction_count.into()))
+    }
+
+    /// The total gas used by the transactions of the block.
+    /// It is `null` if the block is not indexed by the off-chain worker, e.g., the genesis block.
+    async fn total_gas_used(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<U64>> {
+        Ok(self
+            .summary(ctx)?
+            .map(|summary| summary.total_gas_used.into()))
+    }
+
+    /// The total fee paid by the transactions of the block.
+    /// It is `null` if the block is not indexed by the off-chain worker, e.g., the genesis block.
+    async fn total_fee(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<U64>> {
+        Ok(self.summary(ctx)?.map(|summary| summary.total_fee.into()))
     }
 }
 
@@ -202,6 +288,9 @@ impl PoAConsensus {
     }
 }
 
+/// The maximum number of headers returned by the `blockHeaders` query.
+const MAX_BLOCK_HEADERS_PER_QUERY: u32 = 1000;
+
 #[derive(Default)]
 pub struct BlockQuery;
 
@@ -235,6 +324,29 @@ impl BlockQuery {
             .into_api_result()
     }
 
+    /// Returns `true` if the block with the `id` is on the canonical chain of the node.
+    /// The orphaned blocks and the unknown ids are not canonical.
+    async fn is_canonical_block(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the block")] id: BlockId,
+    ) -> async_graphql::Result<bool> {
+        let query: &ReadView = ctx.data_unchecked();
+        let id = id.0.into();
+        let Some(height) = query
+            .block_height(&id)
+            .into_api_result::<BlockHeight, async_graphql::Error>()?
+        else {
+            return Ok(false)
+        };
+        let canonical = query
+            .block(&height)
+            .into_api_result::<CompressedBlock, async_graphql::Error>()?;
+        Ok(canonical
+            .map(|canonical| canonical.header().id() == id)
+            .unwrap_or(false))
+    }
+
     async fn blocks(
         &self,
         ctx: &Context<'_>,
@@ -249,6 +361,49 @@ impl BlockQuery {
         })
         .await
     }
+
+    /// Returns the headers of `count` consecutive blocks starting at the `start` height.
+    /// The headers are loaded without the transactions of the blocks.
+    /// The query can't request more than 1000 headers.
+    async fn block_headers(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the first block")] start: U32,
+        #[graphql(desc = "Number of the headers")] count: U32,
+    ) -> async_graphql::Result<Vec<Header>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let count: u32 = count.into();
+        if count > MAX_BLOCK_HEADERS_PER_QUERY {
+            return Err(anyhow!(
+                "The query can't request more than {MAX_BLOCK_HEADERS_PER_QUERY} headers"
+            )
+            .into())
+        }
+        let start: u32 = start.into();
+        let count = usize::try_from(count)?;
+
+        let headers = query
+            .compressed_blocks(Some(start.into()), IterDirection::Forward)
+            .take(count)
+            .map(|result| result.map(|block| block.header().clone().into()))
+            .collect::<StorageResult<Vec<Header>>>()?;
+        Ok(headers)
+    }
+
+    /// Returns the proof of inclusion of the transaction into the `transactionsRoot`
+    /// of the block. Returns `null` if the block doesn't contain the transaction.
+    async fn transaction_inclusion_proof(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] block_height: U32,
+        #[graphql(desc = "ID of the transaction")] transaction_id: TransactionId,
+    ) -> async_graphql::Result<Option<MerkleProof>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height: u32 = block_height.into();
+        let proof =
+            transaction_inclusion_proof(query, &height.into(), &transaction_id.0)?;
+        Ok(proof.map(Into::into))
+    }
 }
 
 #[derive(Default)]
@@ -834,6 +834,114 @@ impl ChainInfo {
     }
 }
 
+/// The consensus parameters with the limits actually enforced by the node.
+pub struct EffectiveConsensusParameters;
+
+/// The value of the parameter enforced by the node.
+pub struct EffectiveValue {
+    value: Option<u64>,
+    overridden: bool,
+}
+
+impl EffectiveValue {
+    /// Returns the effective limit, where the override can only make
+    /// the limit from the consensus parameters stricter.
+    fn limit(consensus_value: Option<u64>, override_value: Option<u64>) -> Self {
+        match (consensus_value, override_value) {
+            (Some(consensus_value), Some(override_value))
+                if override_value < consensus_value =>
+            {
+                Self {
+                    value: Some(override_value),
+                    overridden: true,
+                }
+            }
+            (None, Some(override_value)) => Self {
+                value: Some(override_value),
+                overridden: true,
+            },
+            (consensus_value, _) => Self {
+                value: consensus_value,
+                overridden: false,
+            },
+        }
+    }
+}
+
+#[Object]
+impl EffectiveValue {
+    /// The enforced value. `null` if the node doesn't limit it.
+    async fn value(&self) -> Option<U64> {
+        self.value.map(Into::into)
+    }
+
+    /// Whether the value differs from the chain configuration because of the override.
+    async fn overridden(&self) -> bool {
+        self.overridden
+    }
+}
+
+#[Object]
+impl EffectiveConsensusParameters {
+    /// The consensus parameters from the chain configuration.
+    async fn consensus_parameters(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<ConsensusParameters> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        Ok(ConsensusParameters(config.consensus_parameters.clone()))
+    }
+
+    /// The max depth of nested contract calls enforced by the executor.
+    async fn max_call_depth(&self, ctx: &Context<'_>) -> EffectiveValue {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        EffectiveValue::limit(None, config.max_call_depth)
+    }
+
+    /// The max number of inputs of the transaction enforced by the executor.
+    async fn max_inputs(&self, ctx: &Context<'_>) -> EffectiveValue {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let consensus_value = config.consensus_parameters.tx_params().max_inputs();
+
+        EffectiveValue::limit(
+            Some(consensus_value.into()),
+            config.max_inputs.map(Into::into),
+        )
+    }
+
+    /// The max number of outputs of the transaction enforced by the executor.
+    async fn max_outputs(&self, ctx: &Context<'_>) -> EffectiveValue {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let consensus_value = config.consensus_parameters.tx_params().max_outputs();
+
+        EffectiveValue::limit(
+            Some(consensus_value.into()),
+            config.max_outputs.map(Into::into),
+        )
+    }
+
+    /// The max gas per predicate enforced by the `TxPool`.
+    async fn max_gas_per_predicate(&self, ctx: &Context<'_>) -> EffectiveValue {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let consensus_value = config
+            .consensus_parameters
+            .predicate_params()
+            .max_gas_per_predicate();
+
+        EffectiveValue::limit(Some(consensus_value), config.max_gas_per_predicate)
+    }
+
+    /// The max gas of all predicates per transaction enforced by the `TxPool`.
+    async fn max_predicate_gas_per_tx(&self, ctx: &Context<'_>) -> EffectiveValue {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let consensus_value = config.consensus_parameters.tx_params().max_gas_per_tx();
+
+        EffectiveValue::limit(Some(consensus_value), config.max_predicate_gas_per_tx)
+    }
+}
+
 #[derive(Default)]
 pub struct ChainQuery;
 
@@ -842,4 +950,16 @@ impl ChainQuery {
     async fn chain(&self) -> ChainInfo {
         ChainInfo
     }
+
+    /// Returns the consensus parameters along with the limits enforced by the node
+    /// after applying the local overrides.
+    async fn effective_consensus_parameters(&self) -> EffectiveConsensusParameters {
+        EffectiveConsensusParameters
+    }
+
+    /// Returns the chain config the node started with, serialized as JSON.
+    async fn chain_config(&self, ctx: &Context<'_>) -> async_graphql::Result<String> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        Ok(serde_json::to_string(&config.chain_config)?)
+    }
 }
@@ -5,6 +5,7 @@ use crate::{
     },
     fuel_core_graphql_api::{
         database::ReadView,
+        storage::coins::CoinChanges,
         Config as GraphQLConfig,
         IntoApiResult,
     },
@@ -22,6 +23,7 @@ use crate::{
         U64,
     },
 };
+use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -38,9 +40,16 @@ use fuel_core_types::{
         },
     },
     fuel_tx,
+    fuel_types::BlockHeight,
 };
 use itertools::Itertools;
 
+/// The maximum number of coins returned by one page of the `allCoins`.
+pub const MAX_ALL_COINS_PER_PAGE: i32 = 1000;
+
+/// The maximum number of blocks covered by one `coinsDiff` query.
+pub const MAX_COINS_DIFF_BLOCKS: u32 = 1000;
+
 pub struct Coin(pub(crate) CoinModel);
 
 #[async_graphql::Object]
@@ -72,6 +81,55 @@ impl Coin {
     }
 }
 
+pub struct BlockCoinChanges {
+    height: BlockHeight,
+    changes: CoinChanges,
+}
+
+#[async_graphql::Object]
+impl BlockCoinChanges {
+    async fn height(&self) -> U32 {
+        self.height.into()
+    }
+
+    /// The coins created by the outputs of the block's transactions.
+    async fn created(&self) -> Vec<Coin> {
+        self.changes.created.iter().copied().map(Coin).collect()
+    }
+
+    /// The ids of the coins spent by the inputs of the block's transactions.
+    async fn spent(&self) -> Vec<UtxoId> {
+        self.changes.spent.iter().copied().map(Into::into).collect()
+    }
+}
+
+pub struct CoinsDiff {
+    from: BlockHeight,
+    to: BlockHeight,
+    changes: CoinChanges,
+}
+
+#[async_graphql::Object]
+impl CoinsDiff {
+    async fn from_height(&self) -> U32 {
+        self.from.into()
+    }
+
+    async fn to_height(&self) -> U32 {
+        self.to.into()
+    }
+
+    /// The coins created in the range and still unspent at the `toHeight`.
+    async fn added(&self) -> Vec<Coin> {
+        self.changes.created.iter().copied().map(Coin).collect()
+    }
+
+    /// The ids of the coins existing at the `fromHeight` and spent in the range.
+    async fn removed(&self) -> Vec<UtxoId> {
+        self.changes.spent.iter().copied().map(Into::into).collect()
+    }
+}
+
 pub struct MessageCoin(pub(crate) MessageCoinModel);
 
 #[async_graphql::Object]
@@ -153,6 +211,51 @@ impl CoinQuery {
         query.coin(utxo_id.0).into_api_result()
     }
 
+    /// Gets the coins created and spent by the block at `height`.
+    /// The coinbase is credited to the coinbase contract, so it doesn't create coins.
+    async fn block_coin_changes(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<BlockCoinChanges>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height = height.into();
+        query
+            .block_coin_changes(&height)
+            .map(|changes| BlockCoinChanges { height, changes })
+            .into_api_result()
+    }
+
+    /// Gets the net difference of the UTXO set between the `from_height` and the `to_height`:
+    /// the coins added and removed by the blocks after the `from_height` up to
+    /// the `to_height` inclusive. The range can't cover more than 1000 blocks.
+    async fn coins_diff(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The height of the initial UTXO set")] from_height: U32,
+        #[graphql(desc = "The height of the final UTXO set")] to_height: U32,
+    ) -> async_graphql::Result<CoinsDiff> {
+        let (from, to) = (from_height.0, to_height.0);
+        let Some(blocks) = to.checked_sub(from) else {
+            return Err(anyhow!(
+                "The `fromHeight` `{from}` is above the `toHeight` `{to}`"
+            )
+            .into())
+        };
+        if blocks > MAX_COINS_DIFF_BLOCKS {
+            return Err(anyhow!(
+                "The range of `{blocks}` blocks exceeds the maximum \
+                of `{MAX_COINS_DIFF_BLOCKS}` blocks"
+            )
+            .into())
+        }
+
+        let query: &ReadView = ctx.data_unchecked();
+        let (from, to) = (from.into(), to.into());
+        let changes = query.coins_diff(from, to)?;
+        Ok(CoinsDiff { from, to, changes })
+    }
+
     /// Gets all unspent coins of some `owner` maybe filtered with by `asset_id` per page.
     async fn coins(
         &self,
@@ -185,6 +288,36 @@ impl CoinQuery {
         .await
     }
 
+    /// Gets all unspent coins of the `asset_id` across all owners per page.
+    /// The page can't contain more than 1000 coins.
+    async fn all_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The asset id of the coins")] asset_id: AssetId,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
+        if let Some(count) = first {
+            if count > MAX_ALL_COINS_PER_PAGE {
+                return Err(anyhow!(
+                    "The page size `{count}` exceeds the maximum \
+                    of `{MAX_ALL_COINS_PER_PAGE}` coins"
+                )
+                .into())
+            }
+        }
+        let query: &ReadView = ctx.data_unchecked();
+        crate::schema::query_pagination(after, None, first, None, |start, direction| {
+            let asset_id: fuel_tx::AssetId = asset_id.0;
+            let coins = query
+                .asset_coins(&asset_id, (*start).map(Into::into), direction)
+                .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
+
+            Ok(coins)
+        })
+        .await
+    }
+
     /// For each `query_per_asset`, get some spendable coins(of asset specified by the query) owned by
     /// `owner` that add up at least the query amount. The returned coins can be spent.
     /// The number of coins is optimized to prevent dust accumulation.
@@ -234,8 +367,13 @@ impl CoinQuery {
         });
 
         let base_asset_id = config.consensus_parameters.base_asset_id();
-        let spend_query =
-            SpendQuery::new(owner, &query_per_asset, excluded_ids, *base_asset_id)?;
+        let spend_query = SpendQuery::new(
+            owner,
+            &query_per_asset,
+            excluded_ids,
+            *base_asset_id,
+            config.max_assets_per_query,
+        )?;
 
         let query: &ReadView = ctx.data_unchecked();
 
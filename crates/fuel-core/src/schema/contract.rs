@@ -6,12 +6,14 @@ use crate::{
     query::ContractQueryData,
     schema::scalars::{
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         Salt,
         U64,
     },
 };
+use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -26,6 +28,9 @@ use fuel_core_types::{
     services::graphql_api,
 };
 
+/// The maximum number of storage slots returned by one page of the `contractStorageSlots`.
+pub const MAX_STORAGE_SLOTS_PER_PAGE: i32 = 1000;
+
 pub struct Contract(pub(crate) fuel_types::ContractId);
 
 impl From<fuel_types::ContractId> for Contract {
@@ -70,6 +75,84 @@ impl ContractQuery {
         let query: &ReadView = ctx.data_unchecked();
         query.contract_id(id.0).into_api_result()
     }
+
+    async fn contract_storage_slot(
+        &self,
+        ctx: &Context<'_>,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> async_graphql::Result<Option<ContractStorageSlot>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .contract_storage_slot(contract_id.into(), key.into())
+            .into_api_result()
+    }
+
+    /// Returns the storage slots of the contract ordered by the key.
+    /// The page can't contain more than 1000 slots.
+    async fn contract_storage_slots(
+        &self,
+        ctx: &Context<'_>,
+        contract_id: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<Bytes32, ContractStorageSlot, EmptyFields, EmptyFields>,
+    > {
+        if let Some(count) = first.or(last) {
+            if count > MAX_STORAGE_SLOTS_PER_PAGE {
+                return Err(anyhow!(
+                    "The page size `{count}` exceeds the maximum \
+                    of `{MAX_STORAGE_SLOTS_PER_PAGE}` storage slots"
+                )
+                .into())
+            }
+        }
+        let query: &ReadView = ctx.data_unchecked();
+
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let slots = query
+                .contract_storage_slots(
+                    contract_id.into(),
+                    (*start).map(Into::into),
+                    direction,
+                )
+                .map(move |slot| {
+                    let slot = slot?;
+                    let key = slot.key;
+
+                    Ok((key.into(), slot.into()))
+                });
+
+            Ok(slots)
+        })
+        .await
+    }
+}
+
+pub struct ContractStorageSlot(graphql_api::ContractStorageSlot);
+
+#[Object]
+impl ContractStorageSlot {
+    async fn contract(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+
+    async fn value(&self) -> HexString {
+        HexString(self.0.value.clone())
+    }
+}
+
+impl From<graphql_api::ContractStorageSlot> for ContractStorageSlot {
+    fn from(slot: graphql_api::ContractStorageSlot) -> Self {
+        ContractStorageSlot(slot)
+    }
 }
 
 pub struct ContractBalance(graphql_api::ContractBalance);
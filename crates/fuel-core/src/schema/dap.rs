@@ -3,7 +3,9 @@ use crate::{
         database_description::on_chain::OnChain,
         Database,
     },
+    fuel_core_graphql_api::Config as GraphQLConfig,
     schema::scalars::{
+        HexString,
         U32,
         U64,
     },
@@ -27,6 +29,8 @@ use fuel_core_storage::{
 use fuel_core_types::{
     fuel_asm::{
         Instruction,
+        Opcode,
+        RegId,
         RegisterId,
         Word,
     },
@@ -42,6 +46,7 @@ use fuel_core_types::{
         Script,
         Transaction,
     },
+    fuel_types::canonical::Deserialize,
     fuel_vm::{
         checked_transaction::{
             CheckedTransaction,
@@ -207,6 +212,72 @@ impl ConcreteStorage {
         Ok(vm_database)
     }
 
+    /// Executes the script on top of the most recent node state instruction
+    /// by instruction, recording the trace of the execution.
+    pub fn trace(
+        &self,
+        tx: Transaction,
+        storage: Database<OnChain>,
+    ) -> anyhow::Result<gql_types::TransactionTrace> {
+        let vm_database = Self::vm_database(storage)?;
+        let checked_tx: CheckedTransaction = tx
+            .into_checked_basic(vm_database.block_height()?, &self.params)
+            .map_err(|e| anyhow::anyhow!("{:?}", e))?
+            .into();
+        let CheckedTransaction::Script(script) = checked_tx else {
+            return Err(anyhow!("Only `Script` transactions can be traced"))
+        };
+
+        let gas_costs = self.params.gas_costs();
+        let fee_params = self.params.fee_params();
+
+        let ready_tx = script
+            .into_ready(GAS_PRICE, gas_costs, fee_params)
+            .map_err(|e| {
+                anyhow!("Failed to apply dynamic values to checked tx: {:?}", e)
+            })?;
+
+        let interpreter_params = InterpreterParams::new(GAS_PRICE, &self.params);
+        let mut vm = Interpreter::with_storage(vm_database, interpreter_params);
+        vm.set_single_stepping(true);
+
+        let mut steps = vec![];
+        let mut state = *vm.transact(ready_tx).map_err(|e| anyhow!(e))?.state();
+
+        // The VM stops before each instruction while single-stepping is enabled.
+        while let Some(DebugEval::Breakpoint(_)) = state.debug_ref() {
+            let pc = vm.registers()[RegId::PC];
+            let start = usize::try_from(pc)?;
+            let instruction = vm
+                .memory()
+                .get(start..start.saturating_add(Instruction::SIZE))
+                .and_then(|bytes| <[u8; Instruction::SIZE]>::try_from(bytes).ok())
+                .and_then(|bytes| Instruction::try_from(bytes).ok())
+                .ok_or_else(|| anyhow!("Invalid instruction at {pc}"))?;
+            let opcode = instruction.opcode();
+
+            // Registers are captured at key points of the execution:
+            // calls, returns, and reverts.
+            let registers = matches!(
+                opcode,
+                Opcode::CALL | Opcode::RET | Opcode::RETD | Opcode::RVRT
+            )
+            .then(|| vm.registers().iter().copied().map(U64).collect());
+
+            steps.push(gql_types::TraceStep {
+                pc: U64(pc),
+                opcode: format!("{opcode:?}"),
+                registers,
+            });
+
+            state = vm.resume().map_err(|e| anyhow!(e))?;
+        }
+
+        let receipts = vm.receipts().iter().map(Into::into).collect();
+
+        Ok(gql_types::TransactionTrace { steps, receipts })
+    }
+
     fn dummy_tx(gas_limit: u64) -> Script {
         // Create `Script` transaction with dummy coin
         let mut tx = Script::default();
@@ -286,6 +357,34 @@ impl DapQuery {
             .ok_or_else(|| async_graphql::Error::new("Invalid memory range"))
             .and_then(|mem| Ok(serde_json::to_string(mem)?))
     }
+
+    /// Executes the `Script` transaction on top of the most recent node state and
+    /// returns the executed instructions with register states at key points.
+    /// Requires enabled `debug` and `vm_backtrace` in the node config.
+    async fn transaction_trace(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<gql_types::TransactionTrace> {
+        require_debug(ctx)?;
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if !config.vm_backtrace {
+            return Err(async_graphql::Error::new(
+                "The `vm_backtrace` must be enabled to trace transactions",
+            ))
+        }
+
+        let tx = Transaction::from_bytes(&tx.0)?;
+        let db = ctx.data_unchecked::<Database>();
+
+        let trace = ctx
+            .data_unchecked::<GraphStorage>()
+            .lock()
+            .await
+            .trace(tx, db.clone())?;
+
+        Ok(trace)
+    }
 }
 
 #[Object]
@@ -549,9 +648,12 @@ mod gql_types {
     //! GraphQL type wrappers
     use async_graphql::*;
 
-    use crate::schema::scalars::{
-        ContractId,
-        U64,
+    use crate::schema::{
+        scalars::{
+            ContractId,
+            U64,
+        },
+        tx::receipt::Receipt,
     };
 
     use fuel_core_types::fuel_vm::Breakpoint as FuelBreakpoint;
@@ -609,4 +711,22 @@ mod gql_types {
         pub breakpoint: Option<OutputBreakpoint>,
         pub json_receipts: Vec<String>,
     }
+
+    /// The executed instruction of the traced transaction.
+    #[derive(Debug, Clone, SimpleObject)]
+    pub struct TraceStep {
+        /// The program counter of the instruction.
+        pub pc: U64,
+        /// The opcode of the instruction.
+        pub opcode: String,
+        /// The state of registers before the execution of the instruction.
+        /// It is only captured for calls, returns, and reverts.
+        pub registers: Option<Vec<U64>>,
+    }
+
+    #[derive(SimpleObject)]
+    pub struct TransactionTrace {
+        pub steps: Vec<TraceStep>,
+        pub receipts: Vec<Receipt>,
+    }
 }
@@ -3,23 +3,36 @@ use super::scalars::{
     U64,
 };
 use crate::{
-    fuel_core_graphql_api::database::ReadView,
-    graphql_api::api_service::GasPriceProvider,
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::OffChainDatabase,
+        IntoApiResult,
+    },
+    graphql_api::api_service::{
+        GasPriceProvider,
+        TxPool,
+    },
     query::{
         BlockQueryData,
         SimpleTransactionData,
     },
 };
 use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
     Context,
     Object,
 };
+use fuel_core_storage::iter::IntoBoxedIter;
 use fuel_core_types::{
     blockchain::block::Block,
     fuel_tx::{
         field::MintGasPrice,
         Transaction,
     },
+    fuel_types::BlockHeight,
 };
 
 pub struct LatestGasPrice {
@@ -107,3 +120,204 @@ impl EstimateGasPriceQuery {
         })
     }
 }
+
+pub struct RequiredGasPrice {
+    pub gas_price: U64,
+    pub min_tip: U64,
+}
+
+#[Object]
+impl RequiredGasPrice {
+    /// The minimal gas price accepted by the `TxPool` for the next block.
+    async fn gas_price(&self) -> U64 {
+        self.gas_price
+    }
+
+    /// The minimal tip required for the admission into the `TxPool`.
+    /// It is zero while the `TxPool` has free space. When the `TxPool` is full,
+    /// the transaction should pay more than the lowest tip in the pool.
+    async fn min_tip(&self) -> U64 {
+        self.min_tip
+    }
+}
+
+#[derive(Default)]
+pub struct RequiredGasPriceQuery {}
+
+#[Object]
+impl RequiredGasPriceQuery {
+    /// Returns the requirements that a new transaction should satisfy
+    /// to be accepted by the `TxPool` right now.
+    async fn required_gas_price(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<RequiredGasPrice> {
+        let query: &ReadView = ctx.data_unchecked();
+
+        let latest_block_height: u32 = query.latest_block_height()?.into();
+        let next_block_height = latest_block_height
+            .checked_add(1)
+            .ok_or(async_graphql::Error::new("The block height overflows"))?;
+
+        let gas_price_provider = ctx.data_unchecked::<GasPriceProvider>();
+        let gas_price = gas_price_provider
+            .worst_case_gas_price(next_block_height.into())
+            .await;
+
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let min_tip = txpool.min_admission_tip().unwrap_or_default();
+
+        Ok(RequiredGasPrice {
+            gas_price: gas_price.into(),
+            min_tip: min_tip.into(),
+        })
+    }
+}
+
+pub struct BlockGasPrice {
+    pub gas_price: U64,
+    pub block_height: U32,
+}
+
+#[Object]
+impl BlockGasPrice {
+    async fn gas_price(&self) -> U64 {
+        self.gas_price
+    }
+
+    async fn block_height(&self) -> U32 {
+        self.block_height
+    }
+}
+
+impl From<(BlockHeight, u64)> for BlockGasPrice {
+    fn from((block_height, gas_price): (BlockHeight, u64)) -> Self {
+        BlockGasPrice {
+            gas_price: gas_price.into(),
+            block_height: block_height.into(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct BlockGasPriceQuery {}
+
+#[Object]
+impl BlockGasPriceQuery {
+    /// Returns the gas price used to produce the block at `height`.
+    async fn block_gas_price(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<BlockGasPrice>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height: BlockHeight = height.into();
+
+        query
+            .block_gas_price(&height)
+            .map(|gas_price| (height, gas_price))
+            .into_api_result()
+    }
+
+    /// Returns the gas prices used to produce the blocks, ordered by the block height.
+    async fn block_gas_prices(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<U32, BlockGasPrice, EmptyFields, EmptyFields>>
+    {
+        let query: &ReadView = ctx.data_unchecked();
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            Ok(query
+                .block_gas_prices(start.map(Into::into), direction)
+                .map(|result| {
+                    result.map(|(height, gas_price)| {
+                        (height.into(), (height, gas_price).into())
+                    })
+                })
+                .into_boxed())
+        })
+        .await
+    }
+}
+
+/// The maximum number of bounds accepted by the `gasPriceHistogram` query.
+const MAX_GAS_PRICE_HISTOGRAM_BOUNDS: usize = 100;
+
+pub struct GasPriceBucket {
+    pub from: U64,
+    pub to: Option<U64>,
+    pub count: U32,
+}
+
+#[Object]
+impl GasPriceBucket {
+    /// The lowest gas price of the bucket, inclusive.
+    async fn from(&self) -> U64 {
+        self.from
+    }
+
+    /// The highest gas price of the bucket, exclusive. `null` for the last bucket.
+    async fn to(&self) -> Option<U64> {
+        self.to
+    }
+
+    /// The number of the pending transactions in the bucket.
+    async fn count(&self) -> U32 {
+        self.count
+    }
+}
+
+#[derive(Default)]
+pub struct GasPriceHistogramQuery {}
+
+#[Object]
+impl GasPriceHistogramQuery {
+    /// Returns the number of the pending transactions in the `TxPool` per gas price bucket.
+    /// The gas price of the pending transaction is its tip, by which the `TxPool`
+    /// orders the transactions. The ascending `bounds` split the gas prices
+    /// into one bucket more than the number of the bounds.
+    /// The query can't accept more than 100 bounds.
+    async fn gas_price_histogram(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ascending gas prices separating the buckets")] bounds: Vec<
+            U64,
+        >,
+    ) -> async_graphql::Result<Vec<GasPriceBucket>> {
+        if bounds.len() > MAX_GAS_PRICE_HISTOGRAM_BOUNDS {
+            return Err(async_graphql::Error::new(format!(
+                "The histogram can't have more than {MAX_GAS_PRICE_HISTOGRAM_BOUNDS} bounds"
+            )))
+        }
+        let bounds: Vec<u64> = bounds.into_iter().map(Into::into).collect();
+        if bounds.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(async_graphql::Error::new(
+                "The bounds of the histogram should be strictly ascending",
+            ))
+        }
+
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let counts = txpool.tip_histogram(&bounds);
+
+        let lower_bounds = core::iter::once(0).chain(bounds.iter().copied());
+        let upper_bounds = bounds
+            .iter()
+            .copied()
+            .map(Some)
+            .chain(core::iter::once(None));
+        let buckets = lower_bounds
+            .zip(upper_bounds)
+            .zip(counts)
+            .map(|((from, to), count)| GasPriceBucket {
+                from: from.into(),
+                to: to.map(Into::into),
+                count: u32::try_from(count).unwrap_or(u32::MAX).into(),
+            })
+            .collect();
+        Ok(buckets)
+    }
+}
@@ -148,6 +148,9 @@ impl MessageQuery {
         .map(MessageProof))
     }
 
+    /// Returns the status of the message with the `nonce`. The message relayed from
+    /// the DA layer is `UNSPENT` once it is imported into a block, and `SPENT` once
+    /// it is consumed by a transaction. Otherwise, the message is `NOT_FOUND`.
     async fn message_status(
         &self,
         ctx: &Context<'_>,
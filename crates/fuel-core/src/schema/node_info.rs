@@ -1,11 +1,16 @@
 use super::scalars::{
+    BlockId,
     U32,
     U64,
 };
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use crate::{
+    fuel_core_graphql_api::Config as GraphQLConfig,
+    service::StartupReport,
+};
 use async_graphql::{
     Context,
     Object,
+    SimpleObject,
 };
 use std::time::UNIX_EPOCH;
 
@@ -39,6 +44,72 @@ impl NodeInfo {
         self.node_version.to_owned()
     }
 
+    /// The time each sub-service of the node took to start, in the order of the start.
+    async fn service_startups(&self, ctx: &Context<'_>) -> Vec<ServiceStartup> {
+        let startup_report = ctx.data_unchecked::<StartupReport>();
+        let startups = startup_report.lock().clone();
+        startups.into_iter().map(ServiceStartup).collect()
+    }
+
+    /// The estimated time to sync with the best known peer.
+    /// It is `null` if the node doesn't sync with peers.
+    async fn sync_estimate(&self, _ctx: &Context<'_>) -> Option<SyncEstimate> {
+        #[cfg(feature = "p2p")]
+        {
+            use fuel_core_sync::progress::SyncEstimate as Estimate;
+
+            let sync: &Option<fuel_core_sync::service::SharedState> =
+                _ctx.data_unchecked();
+            let estimate = match sync.as_ref()?.estimate_time_to_sync() {
+                Estimate::Synced => SyncEstimate {
+                    synced: true,
+                    remaining_blocks: 0u32.into(),
+                    time_left_ms: Some(U64(0)),
+                },
+                Estimate::Syncing {
+                    remaining_blocks,
+                    time_left,
+                } => SyncEstimate {
+                    synced: false,
+                    remaining_blocks: remaining_blocks.into(),
+                    time_left_ms: time_left.map(|time_left| {
+                        U64(time_left.as_millis().try_into().unwrap_or(u64::MAX))
+                    }),
+                },
+            };
+            Some(estimate)
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            None
+        }
+    }
+
+    /// The most recent blocks received from peers and rejected during the sync,
+    /// from the oldest to the newest. It is empty unless the recording of rejected
+    /// blocks is enabled.
+    async fn rejected_blocks(&self, _ctx: &Context<'_>) -> Vec<RejectedBlock> {
+        #[cfg(feature = "p2p")]
+        {
+            let sync: &Option<fuel_core_sync::service::SharedState> =
+                _ctx.data_unchecked();
+            sync.iter()
+                .flat_map(|sync| sync.rejected_blocks.blocks())
+                .map(|block| RejectedBlock {
+                    peer_id: block.peer_id.to_string(),
+                    height: block.height.into(),
+                    block_id: fuel_core_types::fuel_types::Bytes32::from(block.block_id)
+                        .into(),
+                    reason: block.reason,
+                })
+                .collect()
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            vec![]
+        }
+    }
+
     async fn peers(&self, _ctx: &Context<'_>) -> async_graphql::Result<Vec<PeerInfo>> {
         #[cfg(feature = "p2p")]
         {
@@ -77,6 +148,31 @@ impl NodeQuery {
     }
 }
 
+/// The estimated time to sync with the best known peer.
+#[derive(SimpleObject)]
+struct SyncEstimate {
+    /// Whether the node has all blocks known to its peers.
+    synced: bool,
+    /// The number of blocks between the committed and the best known peer height.
+    remaining_blocks: U32,
+    /// The time in ms to import the remaining blocks at the recent import rate.
+    /// It is `null` until the rate is known.
+    time_left_ms: Option<U64>,
+}
+
+/// The block received from a peer and rejected during the sync.
+#[derive(SimpleObject)]
+struct RejectedBlock {
+    /// The libp2p peer id of the peer that sent the block.
+    peer_id: String,
+    /// The height of the block.
+    height: U32,
+    /// The id of the block.
+    block_id: BlockId,
+    /// The reason of the rejection.
+    reason: String,
+}
+
 struct PeerInfo(fuel_core_types::services::p2p::PeerInfo);
 
 #[Object]
@@ -119,3 +215,18 @@ impl PeerInfo {
         self.0.app_score
     }
 }
+
+struct ServiceStartup(crate::service::ServiceStartup);
+
+#[Object]
+impl ServiceStartup {
+    /// The name of the sub-service
+    async fn name(&self) -> &str {
+        self.0.name
+    }
+
+    /// The time it took the sub-service to start in ms
+    async fn duration_ms(&self) -> U64 {
+        U64(self.0.duration.as_millis().try_into().unwrap_or(u64::MAX))
+    }
+}
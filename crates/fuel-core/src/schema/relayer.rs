@@ -0,0 +1,127 @@
+use super::scalars::{
+    Bytes32,
+    U64,
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+};
+use fuel_core_types::services::relayer::{
+    Event,
+    RelayerStatus as RelayerStatusInfo,
+};
+
+pub struct PendingDaEvent(pub(crate) Event);
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum DaEventKind {
+    Message,
+    Transaction,
+}
+
+#[Object]
+impl PendingDaEvent {
+    /// The height of the DA block where the event happened.
+    async fn da_height(&self) -> U64 {
+        self.0.da_height().0.into()
+    }
+
+    async fn kind(&self) -> DaEventKind {
+        match &self.0 {
+            Event::Message(_) => DaEventKind::Message,
+            Event::Transaction(_) => DaEventKind::Transaction,
+        }
+    }
+
+    /// The id of the message or of the forced transaction.
+    async fn id(&self) -> Bytes32 {
+        self.0.hash().into()
+    }
+}
+
+pub struct RelayerStatus(pub(crate) RelayerStatusInfo);
+
+#[Object]
+impl RelayerStatus {
+    /// The DA height up to which the relayer downloaded the events.
+    async fn synced_da_height(&self) -> U64 {
+        self.0.synced_da_height.0.into()
+    }
+
+    /// The DA height of the bridge contracts deployment, the relayer starts from it.
+    async fn da_deploy_height(&self) -> U64 {
+        self.0.da_deploy_height.0.into()
+    }
+
+    /// The latest finalized DA height known to the relayer.
+    /// It is `null` until the relayer reaches the DA layer.
+    async fn latest_known_da_height(&self) -> Option<U64> {
+        self.0.latest_known_da_height.map(|height| height.0.into())
+    }
+
+    /// Whether the synced DA height reached the latest known DA height.
+    /// It becomes `false` again when the relayer falls behind the DA layer.
+    async fn synced(&self) -> bool {
+        self.0.synced
+    }
+}
+
+#[derive(Default)]
+pub struct RelayerQuery;
+
+#[Object]
+impl RelayerQuery {
+    /// Returns the messages and forced transactions from the DA layer
+    /// that are not included into blocks yet.
+    async fn pending_da_events(
+        &self,
+        _ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<PendingDaEvent>> {
+        #[cfg(feature = "relayer")]
+        {
+            use crate::{
+                fuel_core_graphql_api::{
+                    api_service::Relayer,
+                    database::ReadView,
+                },
+                query::ChainQueryData,
+            };
+
+            let query: &ReadView = _ctx.data_unchecked();
+            let relayer: &Relayer = _ctx.data_unchecked();
+            let da_height = query.da_height()?;
+            let events = relayer
+                .pending_events(&da_height)?
+                .into_iter()
+                .map(PendingDaEvent)
+                .collect();
+            Ok(events)
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            Err(async_graphql::Error::new(
+                "Relayer is disabled in this build, try using the `relayer` feature flag.",
+            ))
+        }
+    }
+
+    /// Returns the progress of the relayer syncing with the DA layer.
+    /// It is `null` if the relayer is disabled.
+    async fn relayer_status(
+        &self,
+        _ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<RelayerStatus>> {
+        #[cfg(feature = "relayer")]
+        {
+            use crate::fuel_core_graphql_api::api_service::Relayer;
+
+            let relayer: &Relayer = _ctx.data_unchecked();
+            Ok(relayer.status()?.map(RelayerStatus))
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            Ok(None)
+        }
+    }
+}
@@ -302,6 +302,7 @@ fuel_type_scalar!("TransactionId", TransactionId, Bytes32, 32);
 fuel_type_scalar!("MessageId", MessageId, MessageId, 32);
 fuel_type_scalar!("Nonce", Nonce, Nonce, 32);
 fuel_type_scalar!("Signature", Signature, Bytes64, 64);
+fuel_type_scalar!("PublicKey", PublicKey, Bytes64, 64);
 
 impl From<fuel_core_types::fuel_vm::Signature> for Signature {
     fn from(s: fuel_core_types::fuel_vm::Signature) -> Self {
@@ -309,6 +310,12 @@ impl From<fuel_core_types::fuel_vm::Signature> for Signature {
     }
 }
 
+impl From<fuel_core_types::fuel_crypto::PublicKey> for PublicKey {
+    fn from(pk: fuel_core_types::fuel_crypto::PublicKey) -> Self {
+        Self(fuel_types::Bytes64::new(*pk))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,8 @@
 use crate::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
     fuel_core_graphql_api::{
         api_service::{
             BlockProducer,
@@ -6,6 +10,7 @@ use crate::{
         },
         database::ReadView,
         ports::OffChainDatabase,
+        simulation_limit::SimulationLimiter,
         Config,
         IntoApiResult,
     },
@@ -20,8 +25,10 @@ use crate::{
             Address,
             HexString,
             SortedTxCursor,
+            Tai64Timestamp,
             TransactionId,
             TxPointer,
+            U32,
         },
         tx::types::TransactionStatus,
     },
@@ -70,7 +77,10 @@ use std::{
 use tokio_stream::StreamExt;
 use types::{
     DryRunTransactionExecutionStatus,
+    SimulatedBlock,
+    SubmissionResult,
     Transaction,
+    TxPoolStats,
 };
 
 pub mod input;
@@ -102,6 +112,66 @@ impl TxQuery {
         }
     }
 
+    /// Returns the depth of the transaction in the chain of dependent transactions
+    /// inside of the `TxPool`. The transaction that doesn't depend on other transactions
+    /// from the `TxPool` has the depth `1`.
+    /// Returns `null` if the `TxPool` doesn't contain the transaction.
+    async fn transaction_dependency_depth(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> Option<U32> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool
+            .dependency_depth(id.0)
+            .map(|depth| u32::try_from(depth).unwrap_or(u32::MAX).into())
+    }
+
+    /// Returns the transactions submitted with the `label` in the order of the submission.
+    /// The transactions removed from the `TxPool` without the inclusion into a block
+    /// are omitted. Only available in the debug mode.
+    async fn transactions_by_label(
+        &self,
+        ctx: &Context<'_>,
+        label: String,
+    ) -> async_graphql::Result<Vec<Transaction>> {
+        let config = ctx.data_unchecked::<Config>();
+        if !config.debug {
+            return Err(
+                anyhow::anyhow!("`debug` must be enabled to use this endpoint").into(),
+            )
+        }
+
+        let query: &ReadView = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let mut transactions = vec![];
+        for id in query.transactions_by_label(&label)? {
+            if let Some(transaction) = txpool.transaction(id) {
+                transactions.push(Transaction(transaction, id));
+            } else if let Some(transaction) = query
+                .transaction(&id)
+                .into_api_result::<FuelTx, StorageError>()?
+            {
+                transactions.push(Transaction::from_tx(id, transaction));
+            }
+        }
+        Ok(transactions)
+    }
+
+    /// Returns the statistics of the pending transactions in the `TxPool`
+    /// along with its configured limits.
+    async fn txpool_stats(&self, ctx: &Context<'_>) -> TxPoolStats {
+        let config = ctx.data_unchecked::<Config>();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let (pending_count, total_bytes) = txpool.pending_stats();
+        TxPoolStats {
+            pending_count,
+            total_bytes,
+            max_tx: config.max_tx,
+            max_depth: config.max_depth,
+        }
+    }
+
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -109,10 +179,13 @@ impl TxQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(desc = "Include the `Mint` transactions, `true` by default")]
+        include_mint: Option<bool>,
     ) -> async_graphql::Result<
         Connection<SortedTxCursor, Transaction, EmptyFields, EmptyFields>,
     > {
         let query: &ReadView = ctx.data_unchecked();
+        let include_mint = include_mint.unwrap_or(true);
         crate::schema::query_pagination(
             after,
             before,
@@ -156,6 +229,10 @@ impl TxQuery {
                         Ok((sorted, Transaction::from_tx(sorted.tx_id.0, tx)))
                     })
                 });
+                let all_txs = all_txs.filter(move |result| match result {
+                    Ok((_, tx)) => include_mint || !tx.0.is_mint(),
+                    Err(_) => true,
+                });
 
                 Ok(all_txs)
             },
@@ -200,6 +277,58 @@ impl TxQuery {
         .await
     }
 
+    /// Returns the receipts of the executed transaction page by page.
+    /// The cursor is the index of the receipt in the transaction.
+    async fn receipts(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<U32, receipt::Receipt, EmptyFields, EmptyFields>>
+    {
+        let query: &ReadView = ctx.data_unchecked();
+        let receipts = match query.tx_status(&id.0)? {
+            txpool::TransactionStatus::Success { receipts, .. }
+            | txpool::TransactionStatus::Failed { receipts, .. } => receipts,
+            txpool::TransactionStatus::Submitted { .. }
+            | txpool::TransactionStatus::SqueezedOut { .. } => vec![],
+        };
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<U32>, direction| {
+                let start = start.map(|index| index.0);
+                let mut receipts = receipts
+                    .iter()
+                    .enumerate()
+                    .map(|(index, receipt)| {
+                        let index = u32::try_from(index).unwrap_or(u32::MAX);
+                        (index, receipt)
+                    })
+                    .collect::<Vec<_>>();
+                if direction == IterDirection::Reverse {
+                    receipts.reverse();
+                }
+                let receipts = receipts
+                    .into_iter()
+                    .filter(move |(index, _)| match (start, direction) {
+                        (Some(start), IterDirection::Forward) => *index >= start,
+                        (Some(start), IterDirection::Reverse) => *index <= start,
+                        (None, _) => true,
+                    })
+                    .map(|(index, receipt)| Ok((index.into(), receipt.into())));
+                Ok(receipts)
+            },
+        )
+        .await
+    }
+
     /// Estimate the predicate gas for the provided transaction
     async fn estimate_predicates(
         &self,
@@ -258,27 +387,64 @@ impl TxMutation {
             transaction.precompute(&config.consensus_parameters.chain_id())?;
         }
 
+        let _permit = ctx.data_unchecked::<SimulationLimiter>().acquire().await?;
         let tx_statuses = block_producer
             .dry_run_txs(transactions, None, utxo_validation)
             .await?;
-        let tx_statuses = tx_statuses
-            .into_iter()
-            .map(DryRunTransactionExecutionStatus)
-            .collect();
+        let tx_statuses = tx_statuses.into_iter().map(Into::into).collect();
 
         Ok(tx_statuses)
     }
 
+    /// Simulates the block with `txs` on top of the latest block. The block is executed
+    /// the same way as the produced one, but no changes are committed to the chain.
+    /// The `block_time` is the timestamp of the block; the current time if not set.
+    /// The block keeps the DA height of the latest block.
+    async fn simulate_block(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        block_time: Option<Tai64Timestamp>,
+    ) -> async_graphql::Result<SimulatedBlock> {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let config = ctx.data_unchecked::<Config>();
+
+        let mut transactions = txs
+            .iter()
+            .map(|tx| FuelTx::from_bytes(&tx.0))
+            .collect::<Result<Vec<FuelTx>, _>>()?;
+        for transaction in &mut transactions {
+            transaction.precompute(&config.consensus_parameters.chain_id())?;
+        }
+
+        let _permit = ctx.data_unchecked::<SimulationLimiter>().acquire().await?;
+        let result = block_producer
+            .simulate_block(transactions, block_time.map(|time| time.0))
+            .await?;
+
+        Ok(SimulatedBlock(result))
+    }
+
     /// Submits transaction to the `TxPool`.
     ///
     /// Returns submitted transaction if the transaction is included in the `TxPool` without problems.
+    ///
+    /// In the debug mode, the transaction can be submitted with the `label` to query it later
+    /// by `transactionsByLabel`. The label is stored only by the node and is not a part of
+    /// the transaction.
     async fn submit(
         &self,
         ctx: &Context<'_>,
         tx: HexString,
+        label: Option<String>,
     ) -> async_graphql::Result<Transaction> {
         let txpool = ctx.data_unchecked::<TxPool>();
         let config = ctx.data_unchecked::<Config>();
+        if label.is_some() && !config.debug {
+            return Err(
+                anyhow::anyhow!("`debug` must be enabled to use the `label`").into(),
+            )
+        }
         let tx = FuelTx::from_bytes(&tx.0)?;
 
         let _: Vec<_> = txpool
@@ -287,10 +453,48 @@ impl TxMutation {
             .into_iter()
             .try_collect()?;
         let id = tx.id(&config.consensus_parameters.chain_id());
+        if let Some(label) = label {
+            ctx.data_unchecked::<Database<OffChain>>()
+                .add_transaction_label(&label, &id)?;
+        }
 
         let tx = Transaction(tx, id);
         Ok(tx)
     }
+
+    /// Submits transaction to the `TxPool` the same way as `submit`.
+    ///
+    /// The transaction spending the same inputs as the pending transaction replaces it
+    /// if its tip is higher at least by the minimum replacement bump of the `TxPool`.
+    /// Returns submitted transaction together with the replaced transactions.
+    async fn submit_with_result(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<SubmissionResult> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<Config>();
+        let tx = FuelTx::from_bytes(&tx.0)?;
+
+        let results: Vec<_> = txpool
+            .insert(vec![Arc::new(tx.clone())])
+            .await
+            .into_iter()
+            .try_collect()?;
+        let replaced = results
+            .into_iter()
+            .flat_map(|outcome| match outcome {
+                txpool::InsertionOutcome::Inserted(result) => result.replaced,
+                txpool::InsertionOutcome::Deferred(_) => vec![],
+            })
+            .collect();
+        let id = tx.id(&config.consensus_parameters.chain_id());
+
+        Ok(SubmissionResult {
+            transaction: Transaction(tx, id),
+            replaced,
+        })
+    }
 }
 
 #[derive(Default)]
@@ -11,11 +11,15 @@ use crate::{
         IntoApiResult,
     },
     query::{
+        BlockQueryData,
         SimpleBlockData,
         TransactionQueryData,
     },
     schema::{
-        block::Block,
+        block::{
+            Block,
+            Header,
+        },
         scalars::{
             AssetId,
             Bytes32,
@@ -39,9 +43,18 @@ use async_graphql::{
     Context,
     Enum,
     Object,
+    SimpleObject,
     Union,
 };
-use fuel_core_storage::Error as StorageError;
+use base64::prelude::{
+    Engine,
+    BASE64_STANDARD,
+};
+use fuel_core_storage::{
+    column::Column,
+    kv_store::StorageColumn,
+    Error as StorageError,
+};
 use fuel_core_types::{
     fuel_tx::{
         self,
@@ -73,6 +86,8 @@ use fuel_core_types::{
     fuel_vm::ProgramState as VmProgramState,
     services::{
         executor::{
+            self,
+            ExecutionResult,
             TransactionExecutionResult,
             TransactionExecutionStatus,
         },
@@ -174,8 +189,18 @@ impl SuccessStatus {
         self.result.map(Into::into)
     }
 
-    async fn receipts(&self) -> async_graphql::Result<Vec<Receipt>> {
-        Ok(self.receipts.iter().map(Into::into).collect())
+    async fn receipts(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Receipt>> {
+        let receipts = limited_receipts(ctx, &self.tx_id, &self.receipts)?;
+        Ok(receipts.iter().map(Into::into).collect())
+    }
+
+    /// The receipts in the canonical binary encoding, each one is base64-encoded.
+    async fn receipts_base64(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<String>> {
+        let receipts = limited_receipts(ctx, &self.tx_id, &self.receipts)?;
+        Ok(encode_receipts(receipts))
     }
 }
 
@@ -212,11 +237,48 @@ impl FailureStatus {
         self.state.map(Into::into)
     }
 
-    async fn receipts(&self) -> async_graphql::Result<Vec<Receipt>> {
-        Ok(self.receipts.iter().map(Into::into).collect())
+    async fn receipts(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Receipt>> {
+        let receipts = limited_receipts(ctx, &self.tx_id, &self.receipts)?;
+        Ok(receipts.iter().map(Into::into).collect())
+    }
+
+    /// The receipts in the canonical binary encoding, each one is base64-encoded.
+    async fn receipts_base64(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<String>> {
+        let receipts = limited_receipts(ctx, &self.tx_id, &self.receipts)?;
+        Ok(encode_receipts(receipts))
+    }
+}
+
+/// Returns the receipts of the transaction if their number is within
+/// the `max_receipts_per_transaction` limit of the API.
+fn limited_receipts<'a>(
+    ctx: &Context<'_>,
+    tx_id: &TxId,
+    receipts: &'a [fuel_tx::Receipt],
+) -> async_graphql::Result<&'a [fuel_tx::Receipt]> {
+    let config = ctx.data_unchecked::<Config>();
+    match config.max_receipts_per_transaction {
+        Some(max) if receipts.len() > max => {
+            let more = receipts.len().saturating_sub(max);
+            Err(async_graphql::Error::new(format!(
+                "The receipts of the transaction {tx_id} exceed the limit of {max}: \
+                truncated, {more} more. Use the `receipts` query to fetch them page by page"
+            )))
+        }
+        _ => Ok(receipts),
     }
 }
 
+fn encode_receipts(receipts: &[fuel_tx::Receipt]) -> Vec<String> {
+    receipts
+        .iter()
+        .map(|receipt| BASE64_STANDARD.encode(receipt.to_bytes()))
+        .collect()
+}
+
 #[derive(Debug)]
 pub struct SqueezedOutStatus {
     pub reason: String,
@@ -333,6 +395,24 @@ impl Transaction {
     pub fn from_tx(id: fuel_tx::TxId, tx: fuel_tx::Transaction) -> Self {
         Self(tx, id)
     }
+
+    /// Returns the number of blocks since the inclusion of the transaction,
+    /// including the block with the transaction itself.
+    fn confirmations_count(&self, query: &ReadView) -> Result<Option<u32>, StorageError> {
+        let status = query
+            .status(&self.1)
+            .into_api_result::<TxStatus, StorageError>()?;
+        let inclusion_height = match status {
+            Some(TxStatus::Success { block_height, .. })
+            | Some(TxStatus::Failed { block_height, .. }) => block_height,
+            _ => return Ok(None),
+        };
+        let latest_height: u32 = query.latest_block_height()?.into();
+        let confirmations = latest_height
+            .saturating_sub(*inclusion_height)
+            .saturating_add(1);
+        Ok(Some(confirmations))
+    }
 }
 
 #[Object]
@@ -521,6 +601,28 @@ impl Transaction {
         get_tx_status(id, query, txpool).map_err(Into::into)
     }
 
+    /// The number of blocks since the inclusion of the transaction, including the block
+    /// with the transaction itself. `null` if the transaction is not included yet.
+    async fn confirmations(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<U32>> {
+        let query: &ReadView = ctx.data_unchecked();
+        Ok(self.confirmations_count(query)?.map(Into::into))
+    }
+
+    /// Whether the transaction has at least the number of confirmations
+    /// required by the finality depth of the node.
+    async fn finalized(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        let query: &ReadView = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<Config>();
+        let finalized = matches!(
+            self.confirmations_count(query)?,
+            Some(confirmations) if confirmations >= config.finality_depth
+        );
+        Ok(finalized)
+    }
+
     async fn script(&self) -> Option<HexString> {
         match &self.0 {
             fuel_tx::Transaction::Script(script) => {
@@ -586,6 +688,11 @@ impl Transaction {
     async fn raw_payload(&self) -> HexString {
         HexString(self.0.clone().to_bytes())
     }
+
+    /// The transaction bytes using canonical encoding, base64-encoded.
+    async fn raw_payload_base64(&self) -> String {
+        BASE64_STANDARD.encode(self.0.to_bytes())
+    }
 }
 
 #[derive(Union, Debug)]
@@ -645,20 +752,217 @@ impl DryRunFailureStatus {
     }
 }
 
-pub struct DryRunTransactionExecutionStatus(pub TransactionExecutionStatus);
+pub struct DryRunTransactionExecutionStatus {
+    pub status: TransactionExecutionStatus,
+}
+
+impl From<TransactionExecutionStatus> for DryRunTransactionExecutionStatus {
+    fn from(status: TransactionExecutionStatus) -> Self {
+        Self { status }
+    }
+}
 
 #[Object]
 impl DryRunTransactionExecutionStatus {
     async fn id(&self) -> TransactionId {
-        TransactionId(self.0.id)
+        TransactionId(self.status.id)
     }
 
     async fn status(&self) -> DryRunTransactionStatus {
-        DryRunTransactionStatus::new(self.0.result.clone())
+        DryRunTransactionStatus::new(self.status.result.clone())
     }
 
     async fn receipts(&self) -> Vec<Receipt> {
-        self.0.result.receipts().iter().map(Into::into).collect()
+        self.status
+            .result
+            .receipts()
+            .iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// The gas consumed by each opcode of the script, ordered by the consumed gas.
+    /// It is only available for `Script` transactions when `debug` and `vm_backtrace`
+    /// are enabled in the node config.
+    async fn gas_breakdown(&self) -> Option<Vec<OpcodeGas>> {
+        self.status
+            .gas_breakdown
+            .as_ref()
+            .map(|gas_breakdown| gas_breakdown.iter().map(Into::into).collect())
+    }
+
+    /// The storage keys read and written by the transaction.
+    /// It is only available when the recording of the storage access
+    /// is enabled in the node config.
+    async fn storage_access(&self) -> Option<StorageAccess> {
+        self.status.storage_access.clone().map(StorageAccess)
+    }
+}
+
+/// The storage keys accessed by the transaction during the dry run.
+pub struct StorageAccess(executor::StorageAccess);
+
+#[Object]
+impl StorageAccess {
+    /// The keys whose values are read from the state preceding the transaction,
+    /// ordered by the column and the key.
+    async fn reads(&self) -> Vec<StorageAccessKey> {
+        self.0.reads.iter().map(StorageAccessKey::from).collect()
+    }
+
+    /// The keys inserted or removed by the transaction, ordered by the column and the key.
+    async fn writes(&self) -> Vec<StorageAccessKey> {
+        self.0.writes.iter().map(StorageAccessKey::from).collect()
+    }
+}
+
+/// The key of the storage column.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct StorageAccessKey {
+    /// The name of the column, like `ContractsState`.
+    pub column: String,
+    /// The raw key in the column.
+    pub key: HexString,
+}
+
+impl From<&(u32, Vec<u8>)> for StorageAccessKey {
+    fn from((column, key): &(u32, Vec<u8>)) -> Self {
+        let column = Column::try_from(*column)
+            .map(|column| column.name().to_string())
+            .unwrap_or_else(|_| column.to_string());
+        Self {
+            column,
+            key: HexString(key.clone()),
+        }
+    }
+}
+
+/// The gas consumed by the executions of an opcode.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct OpcodeGas {
+    /// The name of the opcode.
+    pub opcode: String,
+    /// The number of the executions of the opcode.
+    pub count: U64,
+    /// The gas consumed by all executions of the opcode.
+    pub gas: U64,
+}
+
+impl From<&executor::OpcodeGas> for OpcodeGas {
+    fn from(value: &executor::OpcodeGas) -> Self {
+        Self {
+            opcode: value.opcode.clone(),
+            count: value.count.into(),
+            gas: value.gas.into(),
+        }
+    }
+}
+
+/// The block that would be produced with the given transactions.
+pub struct SimulatedBlock(pub ExecutionResult);
+
+#[Object]
+impl SimulatedBlock {
+    async fn header(&self) -> Header {
+        self.0.block.header().clone().into()
+    }
+
+    /// The execution statuses of the transactions included into the block.
+    /// The last one is the status of the `Mint` transaction.
+    async fn transactions(&self) -> Vec<DryRunTransactionExecutionStatus> {
+        self.0.tx_status.iter().cloned().map(Into::into).collect()
+    }
+
+    /// The transactions that would be skipped by the block producer.
+    async fn skipped_transactions(&self) -> Vec<SkippedTransaction> {
+        self.0
+            .skipped_transactions
+            .iter()
+            .map(|(id, error)| SkippedTransaction {
+                id: *id,
+                reason: error.to_string(),
+            })
+            .collect()
+    }
+}
+
+pub struct SkippedTransaction {
+    id: TxId,
+    reason: String,
+}
+
+#[Object]
+impl SkippedTransaction {
+    async fn id(&self) -> TransactionId {
+        TransactionId(self.id)
+    }
+
+    async fn reason(&self) -> String {
+        self.reason.clone()
+    }
+}
+
+/// The result of the submission of the transaction into the `TxPool`.
+pub struct SubmissionResult {
+    pub(crate) transaction: Transaction,
+    pub(crate) replaced: Vec<TxId>,
+}
+
+#[Object]
+impl SubmissionResult {
+    /// The submitted transaction.
+    async fn transaction(&self) -> &Transaction {
+        &self.transaction
+    }
+
+    /// Whether the transaction replaced the pending transactions spending the same inputs.
+    async fn replaced(&self) -> bool {
+        !self.replaced.is_empty()
+    }
+
+    /// The ids of the replaced transactions, including the transactions depending on them.
+    async fn replaced_transaction_ids(&self) -> Vec<TransactionId> {
+        self.replaced.iter().copied().map(TransactionId).collect()
+    }
+}
+
+/// The statistics of the pending transactions in the `TxPool`.
+pub struct TxPoolStats {
+    pub(crate) pending_count: usize,
+    pub(crate) total_bytes: usize,
+    pub(crate) max_tx: usize,
+    pub(crate) max_depth: usize,
+}
+
+#[Object]
+impl TxPoolStats {
+    /// The number of the pending transactions.
+    async fn pending_count(&self) -> U64 {
+        (self.pending_count as u64).into()
+    }
+
+    /// The serialized size in bytes of all pending transactions combined,
+    /// including the witnesses.
+    async fn total_bytes(&self) -> U64 {
+        (self.total_bytes as u64).into()
+    }
+
+    /// The configured maximum number of the pending transactions.
+    async fn max_tx(&self) -> U64 {
+        (self.max_tx as u64).into()
+    }
+
+    /// The configured maximum depth of the chain of dependent transactions.
+    async fn max_depth(&self) -> U64 {
+        (self.max_depth as u64).into()
+    }
+
+    /// The percentage of the `max_tx` occupied by the pending transactions.
+    async fn utilization(&self) -> f64 {
+        if self.max_tx == 0 {
+            return 0.0
+        }
+        self.pending_count as f64 * 100.0 / self.max_tx as f64
     }
 }
 
@@ -1,4 +1,7 @@
-use self::adapters::BlockImporterAdapter;
+use self::adapters::{
+    BlockImporterAdapter,
+    BlockProducerAdapter,
+};
 use crate::{
     combined_database::CombinedDatabase,
     database::Database,
@@ -12,11 +15,18 @@ use fuel_core_services::{
     RunnableService,
     RunnableTask,
     ServiceRunner,
+    SharedMutex,
     State,
     StateWatcher,
 };
 use fuel_core_storage::IsNotFound;
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    time::{
+        Duration,
+        Instant,
+    },
+};
 
 use crate::service::adapters::StaticGasPrice;
 pub use config::{
@@ -31,7 +41,10 @@ pub mod adapters;
 pub mod config;
 pub mod genesis;
 pub mod metrics;
+pub mod periodic_compaction;
+pub mod periodic_snapshot;
 mod query;
+mod self_test;
 pub mod sub_services;
 
 #[derive(Clone)]
@@ -44,6 +57,10 @@ pub struct SharedState {
     /// The P2P network shared state.
     #[cfg(feature = "p2p")]
     pub network: Option<fuel_core_p2p::service::SharedState>,
+    /// The shared state of the sync with peers, like the estimated time to sync
+    /// and the rejected blocks.
+    #[cfg(feature = "p2p")]
+    pub sync: Option<fuel_core_sync::service::SharedState>,
     #[cfg(feature = "relayer")]
     /// The Relayer shared state.
     pub relayer: Option<
@@ -53,12 +70,30 @@ pub struct SharedState {
     >,
     /// The GraphQL shared state.
     pub graph_ql: crate::fuel_core_graphql_api::api_service::SharedState,
+    /// The shared state of the dedicated metrics listener, if it is enabled.
+    pub metrics: Option<metrics::SharedState>,
     /// The underlying database.
     pub database: CombinedDatabase,
     /// Subscribe to new block production.
     pub block_importer: BlockImporterAdapter,
+    /// The block producer, used to dry-run the transactions.
+    pub block_producer: BlockProducerAdapter,
     /// The config of the service.
     pub config: Config,
+    /// The startup durations of the sub-services, in the order of the start.
+    pub startup_report: StartupReport,
+}
+
+/// The startup durations of the sub-services.
+pub type StartupReport = SharedMutex<Vec<ServiceStartup>>;
+
+/// The time it took the sub-service to start.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServiceStartup {
+    /// The name of the sub-service.
+    pub name: &'static str,
+    /// The duration between the start signal and the started state.
+    pub duration: Duration,
 }
 
 pub struct FuelService {
@@ -148,6 +183,10 @@ impl FuelService {
 
 #[async_trait::async_trait]
 impl ServiceTrait for FuelService {
+    fn name(&self) -> &'static str {
+        Task::NAME
+    }
+
     fn start(&self) -> anyhow::Result<()> {
         self.runner.start()
     }
@@ -238,8 +277,27 @@ impl RunnableService for Task {
             }
         }
 
+        if self.shared.config.executor_self_test {
+            let base_asset_id = *self
+                .shared
+                .config
+                .snapshot_reader
+                .chain_config()
+                .consensus_parameters
+                .base_asset_id();
+            self_test::run(&self.shared.block_producer, base_asset_id).await?;
+            tracing::info!("The executor self-test passed");
+        }
+
         for service in &self.services {
+            let start = Instant::now();
             service.start_and_await().await?;
+            let duration = start.elapsed();
+            tracing::info!("The `{}` service started in {:?}", service.name(), duration);
+            self.shared.startup_report.lock().push(ServiceStartup {
+                name: service.name(),
+                duration,
+            });
         }
         Ok(self)
     }
@@ -340,6 +398,57 @@ mod tests {
         assert_eq!(i, expected_services);
     }
 
+    #[tokio::test]
+    async fn startup_report_contains_entry_per_started_service() {
+        // Given
+        let task = Task::new(Default::default(), Config::local_node()).unwrap();
+        let expected_names: Vec<_> = task.services.iter().map(|s| s.name()).collect();
+
+        // When
+        let task = task.into_task(&Default::default(), ()).await.unwrap();
+
+        // Then
+        let startup_report = task.shared.startup_report.lock().clone();
+        let names: Vec<_> = startup_report.iter().map(|s| s.name).collect();
+        assert_eq!(names, expected_names);
+        task.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn executor_self_test_passes_with_valid_executor_config() {
+        // Given
+        let mut config = Config::local_node();
+        config.executor_self_test = true;
+        let task = Task::new(Default::default(), config).unwrap();
+
+        // When
+        let result = task.into_task(&Default::default(), ()).await;
+
+        // Then
+        let task = result.expect("The self-test should pass");
+        task.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn executor_self_test_fails_startup_with_broken_executor_config() {
+        // Given
+        let mut config = Config::local_node();
+        config.executor_self_test = true;
+        // The executor rejects the transactions with any input.
+        config.vm.max_inputs = Some(0);
+        let task = Task::new(Default::default(), config).unwrap();
+
+        // When
+        let result = task.into_task(&Default::default(), ()).await;
+
+        // Then
+        let err = result.err().expect("The self-test should fail");
+        assert!(
+            format!("{err:?}").contains("The executor self-test failed"),
+            "unexpected error: {err:?}"
+        );
+    }
+
     #[tokio::test]
     async fn shutdown_stops_all_services() {
         let task = Task::new(Default::default(), Config::local_node()).unwrap();
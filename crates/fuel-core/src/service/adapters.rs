@@ -15,10 +15,20 @@ use fuel_core_txpool::service::SharedState as TxPoolSharedState;
 use fuel_core_types::services::p2p::peer_reputation::AppScore;
 use fuel_core_types::{
     fuel_types::BlockHeight,
-    services::block_importer::SharedImportResult,
+    services::{
+        block_importer::SharedImportResult,
+        txpool::PackingStrategy,
+    },
 };
 use fuel_core_upgradable_executor::executor::Executor;
-use std::sync::Arc;
+use std::sync::{
+    atomic::{
+        AtomicU16,
+        AtomicU32,
+        AtomicU64,
+    },
+    Arc,
+};
 
 pub mod block_importer;
 pub mod consensus_module;
@@ -63,17 +73,33 @@ impl TxPoolAdapter {
 #[derive(Clone)]
 pub struct TransactionsSource {
     txpool: TxPoolSharedState<P2PAdapter, Database, StaticGasPrice>,
-    _block_height: BlockHeight,
+    block_height: BlockHeight,
+    packing_strategy: PackingStrategy,
+    /// The number of transactions that still can be included into the block.
+    /// The executor requests transactions several times per block.
+    remaining_txs: Arc<AtomicU16>,
+    /// The number of outputs that still can be created by the transactions of the block.
+    remaining_outputs: Arc<AtomicU32>,
+    /// The number of bytes that still can be occupied by the transactions of the block.
+    remaining_bytes: Arc<AtomicU64>,
 }
 
 impl TransactionsSource {
     pub fn new(
         txpool: TxPoolSharedState<P2PAdapter, Database, StaticGasPrice>,
         block_height: BlockHeight,
+        packing_strategy: PackingStrategy,
+        max_txs: Option<u16>,
+        max_outputs: Option<u32>,
+        max_bytes: Option<u64>,
     ) -> Self {
         Self {
             txpool,
-            _block_height: block_height,
+            block_height,
+            packing_strategy,
+            remaining_txs: Arc::new(AtomicU16::new(max_txs.unwrap_or(u16::MAX))),
+            remaining_outputs: Arc::new(AtomicU32::new(max_outputs.unwrap_or(u32::MAX))),
+            remaining_bytes: Arc::new(AtomicU64::new(max_bytes.unwrap_or(u64::MAX))),
         }
     }
 }
@@ -139,6 +165,8 @@ pub struct BlockProducerAdapter {
 pub struct BlockImporterAdapter {
     pub block_importer:
         Arc<fuel_core_importer::Importer<Database, ExecutorAdapter, VerifierAdapter>>,
+    database: Database,
+    max_reorg_depth: Option<u32>,
 }
 
 impl BlockImporterAdapter {
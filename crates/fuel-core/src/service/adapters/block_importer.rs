@@ -51,14 +51,17 @@ use std::sync::Arc;
 impl BlockImporterAdapter {
     pub fn new(
         config: Config,
+        max_reorg_depth: Option<u32>,
         database: Database,
         executor: ExecutorAdapter,
         verifier: VerifierAdapter,
     ) -> Self {
-        let importer = Importer::new(config, database, executor, verifier);
+        let importer = Importer::new(config, database.clone(), executor, verifier);
         importer.init_metrics();
         Self {
             block_importer: Arc::new(importer),
+            database,
+            max_reorg_depth,
         }
     }
 
@@ -66,9 +69,38 @@ impl BlockImporterAdapter {
         &self,
         sealed_block: SealedBlock,
     ) -> anyhow::Result<()> {
+        self.check_reorg_depth(*sealed_block.entity.header().height())?;
         self.block_importer.execute_and_commit(sealed_block).await?;
         Ok(())
     }
+
+    /// The block at the already imported height belongs to a fork. Switching to it
+    /// requires reverting all blocks starting from its height. Forks deeper than
+    /// the `max_reorg_depth` are rejected, the shallower ones are passed to the importer.
+    fn check_reorg_depth(&self, height: BlockHeight) -> anyhow::Result<()> {
+        let Some(max_reorg_depth) = self.max_reorg_depth else {
+            return Ok(())
+        };
+        let Some(latest_height) = self.database.latest_block_height()? else {
+            return Ok(())
+        };
+
+        let depth = u32::from(latest_height)
+            .saturating_add(1)
+            .saturating_sub(u32::from(height));
+        if depth > max_reorg_depth {
+            tracing::error!(
+                "Rejected the fork at height {height} that requires reverting {depth} \
+                blocks, when the maximum reorg depth is {max_reorg_depth}. \
+                Manual intervention is required."
+            );
+            return Err(anyhow::anyhow!(
+                "The block at height {height} requires reverting {depth} blocks, \
+                when the maximum reorg depth is {max_reorg_depth}"
+            ))
+        }
+        Ok(())
+    }
 }
 
 impl BlockVerifier for VerifierAdapter {
@@ -96,6 +128,10 @@ impl ImporterDatabase for Database {
             .get(&DenseMetadataKey::Latest)?
             .map(|cow| *cow.root()))
     }
+
+    fn database_size(&self) -> StorageResult<u64> {
+        self.size()
+    }
 }
 
 impl Executor for ExecutorAdapter {
@@ -36,10 +36,12 @@ impl VerifierAdapter {
         let block_height = config.snapshot_reader.block_height();
         let da_block_height = config.snapshot_reader.da_block_height();
         let chain_config = config.snapshot_reader.chain_config();
-        let config =
+        let mut verifier_config =
             VerifierConfig::new(chain_config.clone(), block_height, da_block_height);
+        verifier_config.max_timestamp_drift = config.max_timestamp_drift;
+        verifier_config.max_block_size_bytes = config.max_block_size_bytes;
         Self {
-            block_verifier: Arc::new(Verifier::new(config, database)),
+            block_verifier: Arc::new(Verifier::new(verifier_config, database)),
         }
     }
 }
@@ -83,3 +85,49 @@ impl RelayerPort for MaybeRelayerAdapter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_importer::ports::BlockVerifier;
+    use fuel_core_types::{
+        blockchain::{
+            block::Block,
+            consensus::{
+                poa::PoAConsensus,
+                Consensus,
+            },
+            header::PartialBlockHeader,
+        },
+        fuel_tx::{
+            Mint,
+            Transaction,
+        },
+        fuel_types::canonical::Serialize,
+    };
+
+    #[test]
+    fn oversized_block_from_peer_is_rejected() {
+        // Given
+        let tx = Transaction::default();
+        let mut config = Config::local_node();
+        config.max_block_size_bytes = Some(u64::try_from(tx.size()).unwrap());
+        let verifier = VerifierAdapter::new(&config, Database::default());
+        // The `Mint` transaction is not counted, but two other transactions
+        // are above the limit.
+        let block = Block::new(
+            PartialBlockHeader::default(),
+            vec![tx.clone(), tx, Mint::default().into()],
+            &[],
+            Default::default(),
+        );
+        let consensus = Consensus::PoA(PoAConsensus::new(Default::default()));
+
+        // When
+        let result = verifier.verify_block_fields(&consensus, &block);
+
+        // Then
+        let err = result.expect_err("The oversized block should be rejected");
+        assert!(err.to_string().contains("exceeds the maximum"), "{err}");
+    }
+}
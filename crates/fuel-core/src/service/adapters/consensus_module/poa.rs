@@ -23,6 +23,8 @@ use fuel_core_poa::{
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::transactional::Changes;
+#[cfg(feature = "p2p")]
+use fuel_core_types::services::p2p::PeerConnectionEvent;
 use fuel_core_types::{
     fuel_tx::TxId,
     fuel_types::BlockHeight,
@@ -39,6 +41,8 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
+#[cfg(feature = "p2p")]
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 use tokio_stream::{
     wrappers::BroadcastStream,
     StreamExt,
@@ -155,6 +159,47 @@ impl P2pPort for P2PAdapter {
             Box::pin(tokio_stream::pending())
         }
     }
+
+    fn connected_peers_count(&self) -> BoxStream<usize> {
+        if let Some(service) = &self.service {
+            // The subscription is created before the start of the p2p service,
+            // so counting of the connection events starts from zero peers.
+            let events = BroadcastStream::new(service.subscribe_peer_connections());
+            let service = service.clone();
+            Box::pin(futures::stream::unfold(
+                (events, 0usize),
+                move |(mut events, connected_peers)| {
+                    let service = service.clone();
+                    async move {
+                        let connected_peers = match events.next().await? {
+                            Ok(PeerConnectionEvent::PeerConnected { .. }) => {
+                                connected_peers.saturating_add(1)
+                            }
+                            Ok(PeerConnectionEvent::PeerDisconnected { .. }) => {
+                                connected_peers.saturating_sub(1)
+                            }
+                            // Some events are missed, so the count is resynced
+                            // from the p2p service.
+                            Err(BroadcastStreamRecvError::Lagged(_)) => {
+                                match service.get_peer_ids().await {
+                                    Ok(peer_ids) => peer_ids.len(),
+                                    Err(err) => {
+                                        tracing::warn!(
+                                            "Failed to resync the number of connected peers: {err}"
+                                        );
+                                        connected_peers
+                                    }
+                                }
+                            }
+                        };
+                        Some((connected_peers, (events, connected_peers)))
+                    }
+                },
+            ))
+        } else {
+            Box::pin(tokio_stream::pending())
+        }
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
@@ -162,4 +207,8 @@ impl P2pPort for P2PAdapter {
     fn reserved_peers_count(&self) -> BoxStream<usize> {
         Box::pin(tokio_stream::pending())
     }
+
+    fn connected_peers_count(&self) -> BoxStream<usize> {
+        Box::pin(tokio_stream::pending())
+    }
 }
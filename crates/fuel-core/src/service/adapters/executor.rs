@@ -26,12 +26,44 @@ use fuel_core_types::{
         relayer::Event,
     },
 };
+use std::sync::atomic::Ordering;
 
 impl fuel_core_executor::ports::TransactionsSource for TransactionsSource {
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction> {
-        self.txpool
-            .select_transactions(gas_limit)
-            .into_iter()
+        let remaining_txs = self.remaining_txs.load(Ordering::Relaxed);
+        if remaining_txs == 0 {
+            return vec![]
+        }
+        let remaining_outputs = self.remaining_outputs.load(Ordering::Relaxed);
+        let remaining_bytes = self.remaining_bytes.load(Ordering::Relaxed);
+        let txs = self.txpool.select_transactions(
+            self.block_height,
+            gas_limit,
+            remaining_txs,
+            remaining_outputs,
+            remaining_bytes,
+            self.packing_strategy,
+        );
+        let selected = u16::try_from(txs.len()).unwrap_or(u16::MAX);
+        self.remaining_txs
+            .store(remaining_txs.saturating_sub(selected), Ordering::Relaxed);
+        let created_outputs = txs.iter().fold(0u32, |outputs, tx| {
+            let tx_outputs = u32::try_from(tx.outputs().len()).unwrap_or(u32::MAX);
+            outputs.saturating_add(tx_outputs)
+        });
+        self.remaining_outputs.store(
+            remaining_outputs.saturating_sub(created_outputs),
+            Ordering::Relaxed,
+        );
+        let used_bytes = txs.iter().fold(0u64, |bytes, tx| {
+            let tx_bytes = u64::try_from(tx.size()).unwrap_or(u64::MAX);
+            bytes.saturating_add(tx_bytes)
+        });
+        self.remaining_bytes.store(
+            remaining_bytes.saturating_sub(used_bytes),
+            Ordering::Relaxed,
+        );
+        txs.into_iter()
             .map(|tx| MaybeCheckedTransaction::CheckedTransaction(tx.as_ref().into()))
             .collect()
     }
@@ -11,9 +11,11 @@ use crate::{
         DatabaseMessageProof,
         GasPriceEstimate,
         P2pPort,
+        RelayerPort,
         TxPoolPort,
     },
     service::adapters::{
+        MaybeRelayerAdapter,
         P2PAdapter,
         TxPoolAdapter,
     },
@@ -26,6 +28,7 @@ use fuel_core_txpool::{
     types::TxId,
 };
 use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
     entities::relayer::message::MerkleProof,
     fuel_tx::{
         Bytes32,
@@ -34,10 +37,17 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::{
         block_importer::SharedImportResult,
-        executor::TransactionExecutionStatus,
+        executor::{
+            ExecutionResult,
+            TransactionExecutionStatus,
+        },
         p2p::PeerInfo,
+        relayer::{
+            Event,
+            RelayerStatus,
+        },
         txpool::{
-            InsertionResult,
+            InsertionOutcome,
             TransactionStatus,
         },
     },
@@ -65,10 +75,26 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
     }
 
+    fn dependency_depth(&self, id: TxId) -> Option<usize> {
+        self.service.dependency_depth(id)
+    }
+
+    fn min_admission_tip(&self) -> Option<u64> {
+        self.service.min_admission_tip()
+    }
+
+    fn tip_histogram(&self, bounds: &[u64]) -> Vec<usize> {
+        self.service.tip_histogram(bounds)
+    }
+
+    fn pending_stats(&self) -> (usize, usize) {
+        self.service.pending_stats()
+    }
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
-    ) -> Vec<anyhow::Result<InsertionResult>> {
+    ) -> Vec<anyhow::Result<InsertionOutcome>> {
         self.service
             .insert(txs)
             .await
@@ -107,6 +133,72 @@ impl BlockProducerPort for BlockProducerAdapter {
             .dry_run(transactions, height, utxo_validation)
             .await
     }
+
+    async fn simulate_block(
+        &self,
+        transactions: Vec<Transaction>,
+        block_time: Option<Tai64>,
+    ) -> anyhow::Result<ExecutionResult> {
+        self.block_producer
+            .simulate_block(transactions, block_time)
+            .await
+    }
+}
+
+impl RelayerPort for MaybeRelayerAdapter {
+    fn pending_events(&self, _da_height: &DaBlockHeight) -> anyhow::Result<Vec<Event>> {
+        #[cfg(feature = "relayer")]
+        {
+            use fuel_core_relayer::storage::EventsHistory;
+            use fuel_core_storage::iter::IteratorOverTable;
+
+            if let Some(relayer) = &self.relayer_synced {
+                let start = DaBlockHeight(_da_height.0.saturating_add(1));
+                let mut events = vec![];
+                for result in relayer
+                    .database()
+                    .iter_all_by_start::<EventsHistory>(Some(&start), None)
+                {
+                    let (_, events_at_height) = result?;
+                    events.extend(events_at_height);
+                }
+                Ok(events)
+            } else {
+                Ok(vec![])
+            }
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            Ok(vec![])
+        }
+    }
+
+    fn status(&self) -> anyhow::Result<Option<RelayerStatus>> {
+        #[cfg(feature = "relayer")]
+        {
+            if let Some(relayer) = &self.relayer_synced {
+                let synced_da_height = relayer.get_finalized_da_height()?;
+                let latest_known_da_height = relayer.latest_known_da_height();
+                // The relayer is synced only while there is no gap to the DA layer,
+                // so it stops being synced when it falls behind.
+                let synced = latest_known_da_height
+                    .map_or(false, |latest| synced_da_height >= latest);
+                let status = RelayerStatus {
+                    synced_da_height,
+                    da_deploy_height: self.da_deploy_height,
+                    latest_known_da_height,
+                    synced,
+                };
+                Ok(Some(status))
+            } else {
+                Ok(None)
+            }
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            Ok(None)
+        }
+    }
 }
 
 #[async_trait::async_trait]
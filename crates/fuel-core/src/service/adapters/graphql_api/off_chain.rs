@@ -9,8 +9,20 @@ use crate::{
             OffChainDatabase,
         },
         storage::{
+            blocks::{
+                BlockSummary,
+                FuelBlockGasPrices,
+                FuelBlockSummaries,
+            },
+            coins::{
+                BlockCoinChanges,
+                CoinChanges,
+            },
             contracts::ContractsInfo,
-            transactions::OwnedTransactionIndexCursor,
+            transactions::{
+                OwnedTransactionIndexCursor,
+                TransactionLabels,
+            },
         },
     },
 };
@@ -19,6 +31,7 @@ use fuel_core_storage::{
         BoxedIter,
         IntoBoxedIter,
         IterDirection,
+        IteratorOverTable,
     },
     not_found,
     transactional::{
@@ -37,6 +50,7 @@ use fuel_core_types::{
     blockchain::primitives::BlockId,
     fuel_tx::{
         Address,
+        AssetId,
         Salt,
         TxPointer,
         UtxoId,
@@ -71,6 +85,17 @@ impl OffChainDatabase for Database<OffChain> {
             .into_boxed()
     }
 
+    fn asset_coins_ids(
+        &self,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>> {
+        self.asset_coins_ids(asset_id, start_coin, Some(direction))
+            .map(|res| res.map_err(StorageError::from))
+            .into_boxed()
+    }
+
     fn owned_message_ids(
         &self,
         owner: &Address,
@@ -106,6 +131,52 @@ impl OffChainDatabase for Database<OffChain> {
 
         Ok(salt)
     }
+
+    fn block_gas_price(&self, height: &BlockHeight) -> StorageResult<u64> {
+        let gas_price = *self
+            .storage_as_ref::<FuelBlockGasPrices>()
+            .get(height)?
+            .ok_or(not_found!(FuelBlockGasPrices))?;
+
+        Ok(gas_price)
+    }
+
+    fn block_gas_prices(
+        &self,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, u64)>> {
+        self.iter_all_by_start::<FuelBlockGasPrices>(start.as_ref(), Some(direction))
+    }
+
+    fn block_coin_changes(&self, height: &BlockHeight) -> StorageResult<CoinChanges> {
+        let changes = self
+            .storage_as_ref::<BlockCoinChanges>()
+            .get(height)?
+            .ok_or(not_found!(BlockCoinChanges))?
+            .into_owned();
+
+        Ok(changes)
+    }
+
+    fn block_summary(&self, height: &BlockHeight) -> StorageResult<BlockSummary> {
+        let summary = *self
+            .storage_as_ref::<FuelBlockSummaries>()
+            .get(height)?
+            .ok_or(not_found!(FuelBlockSummaries))?;
+
+        Ok(summary)
+    }
+
+    fn transactions_by_label(&self, label: &str) -> StorageResult<Vec<TxId>> {
+        let ids = self
+            .storage_as_ref::<TransactionLabels>()
+            .get(label)?
+            .map(|ids| ids.into_owned())
+            .unwrap_or_default();
+
+        Ok(ids)
+    }
 }
 
 impl Transactional for Database<OffChain> {
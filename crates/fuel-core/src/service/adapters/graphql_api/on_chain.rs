@@ -31,9 +31,13 @@ use fuel_core_types::{
     fuel_tx::AssetId,
     fuel_types::{
         BlockHeight,
+        Bytes32,
         Nonce,
     },
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractStorageSlot,
+    },
 };
 use itertools::Itertools;
 
@@ -91,6 +95,21 @@ impl DatabaseContracts for Database {
             .map(|res| res.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractStorageSlot>> {
+        self.filter_contract_states_from(contract, start_key, Some(direction))
+            .map_ok(|entry| ContractStorageSlot {
+                contract_id: *entry.key.contract_id(),
+                key: *entry.key.state_key(),
+                value: entry.value.0,
+            })
+            .into_boxed()
+    }
 }
 
 impl DatabaseChain for Database {
@@ -58,6 +58,7 @@ use fuel_core_types::{
             TransactionExecutionStatus,
             UncommittedResult,
         },
+        txpool::PackingStrategy,
     },
 };
 use std::{
@@ -77,8 +78,22 @@ impl BlockProducerAdapter {
 impl TxPool for TxPoolAdapter {
     type TxSource = TransactionsSource;
 
-    fn get_source(&self, block_height: BlockHeight) -> Self::TxSource {
-        TransactionsSource::new(self.service.clone(), block_height)
+    fn get_source(
+        &self,
+        block_height: BlockHeight,
+        packing_strategy: PackingStrategy,
+        max_txs: Option<u16>,
+        max_outputs: Option<u32>,
+        max_bytes: Option<u64>,
+    ) -> Self::TxSource {
+        TransactionsSource::new(
+            self.service.clone(),
+            block_height,
+            packing_strategy,
+            max_txs,
+            max_outputs,
+            max_bytes,
+        )
     }
 }
 
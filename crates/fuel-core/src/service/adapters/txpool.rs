@@ -1,5 +1,8 @@
 use crate::{
-    database::Database,
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
     service::adapters::{
         BlockImporterAdapter,
         P2PAdapter,
@@ -28,6 +31,7 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -138,6 +142,23 @@ impl fuel_core_txpool::ports::TxPoolDb for Database {
     }
 }
 
+impl fuel_core_txpool::ports::TxPoolPersistence for Database<OffChain> {
+    fn load_transactions(&self) -> StorageResult<Vec<(TxId, Transaction)>> {
+        self.pending_transactions()
+    }
+
+    fn store_transactions(
+        &mut self,
+        transactions: Vec<(TxId, Transaction)>,
+    ) -> StorageResult<()> {
+        self.store_pending_transactions(transactions)
+    }
+
+    fn remove_transactions(&mut self, tx_ids: &[TxId]) -> StorageResult<()> {
+        self.remove_pending_transactions(tx_ids)
+    }
+}
+
 impl GasPriceProvider for StaticGasPrice {
     fn gas_price(&self, _block_height: BlockHeight) -> Option<u64> {
         Some(self.gas_price)
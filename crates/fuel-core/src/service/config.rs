@@ -1,11 +1,24 @@
 use clap::ValueEnum;
 use fuel_core_chain_config::SnapshotReader;
 use fuel_core_types::{
-    blockchain::primitives::SecretKeyWrapper,
+    blockchain::primitives::{
+        BlockId,
+        SecretKeyWrapper,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+    },
     secrecy::Secret,
+    tai64::Tai64,
 };
 use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
     net::SocketAddr,
+    num::NonZeroUsize,
     time::Duration,
 };
 use strum_macros::{
@@ -27,27 +40,125 @@ pub use fuel_core_consensus_module::RelayerConsensusConfig;
 pub use fuel_core_importer;
 pub use fuel_core_poa::Trigger;
 
-use crate::combined_database::CombinedDatabaseConfig;
+pub use crate::service::{
+    periodic_compaction::{
+        CompactionWindow,
+        Config as PeriodicCompactionConfig,
+    },
+    periodic_snapshot::Config as PeriodicSnapshotConfig,
+};
+
+use crate::{
+    combined_database::CombinedDatabaseConfig,
+    graphql_api::{
+        AssetMetadata,
+        CorsConfig,
+        RateLimitConfig,
+        SimulationLimitConfig,
+        WebSocketKeepAlive,
+        WorkerRetryConfig,
+    },
+    service::genesis::{
+        DuplicatePolicy,
+        GenesisTransformations,
+    },
+};
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub addr: SocketAddr,
+    /// The address of the dedicated listener for metrics.
+    /// If `None`, metrics are served by the API on the `addr`.
+    pub metrics_addr: Option<SocketAddr>,
     pub api_request_timeout: Duration,
+    /// Limits the rate of API requests from each client IP.
+    pub api_rate_limit: Option<RateLimitConfig>,
+    /// Allows introspection queries of the GraphQL schema.
+    pub api_enable_introspection: bool,
+    /// Rejects mutations of the API and the subscriptions submitting transactions.
+    pub api_disable_mutations: bool,
+    /// The interval of TCP keep-alive probes used to detect dead API clients.
+    pub api_keep_alive: Option<Duration>,
+    /// Closes API connections that don't send a new request during this time.
+    pub api_idle_timeout: Option<Duration>,
+    /// Closes the WebSocket subscriptions of the clients not answering the pings.
+    pub api_websocket_keep_alive: Option<WebSocketKeepAlive>,
+    /// The time given to the in-flight API requests to complete during the shutdown.
+    pub api_shutdown_grace_period: Duration,
+    /// The number of confirmations after which the API reports the transaction as finalized.
+    pub api_finality_depth: u32,
+    /// The maximum number of distinct assets processed by one coin selection or balances query.
+    pub api_max_assets_per_query: usize,
+    /// The maximum number of receipts of one transaction returned by the transaction status.
+    pub api_max_receipts_per_transaction: Option<usize>,
+    /// The CORS policy of the API.
+    pub api_cors: CorsConfig,
+    /// Limits the number of dry-runs and block simulations executed by the API at once.
+    pub api_simulation_limit: Option<SimulationLimitConfig>,
+    /// The display metadata of the assets returned by the `assets` query of the API.
+    pub api_asset_metadata: HashMap<AssetId, AssetMetadata>,
+    /// The display metadata of the base asset of the chain.
+    /// Overrides the metadata of the base asset in the `api_asset_metadata`.
+    pub api_base_asset_metadata: Option<AssetMetadata>,
+    /// The number of imported blocks the GraphQL worker prepares for indexing concurrently.
+    pub graphql_worker_parallelism: usize,
+    /// The retries of the failed writes of the off-chain indexes by the GraphQL worker.
+    pub graphql_worker_retry: WorkerRetryConfig,
     pub combined_db_config: CombinedDatabaseConfig,
     pub snapshot_reader: SnapshotReader,
+    /// The timestamp of the genesis block.
+    /// If `None`, the genesis block uses the UNIX epoch.
+    pub genesis_timestamp: Option<Tai64>,
+    /// The number of threads used to import the genesis state.
+    /// If `None`, a thread per available CPU is used.
+    pub genesis_workers: Option<NonZeroUsize>,
+    /// The id of the genesis block the node expects. The node fails to start
+    /// if the genesis block from the database or the snapshot differs.
+    pub expected_genesis_id: Option<BlockId>,
+    /// Dry-runs a minimal transaction after the genesis and before starting
+    /// the sub-services. The node fails to start if the executor can't execute it.
+    pub executor_self_test: bool,
+    /// If set, only the coins owned by these addresses are imported from the snapshot.
+    /// It is used to create the reduced databases for test networks.
+    pub genesis_coin_owners: Option<HashSet<Address>>,
+    /// The transformations of the entries of the snapshot applied before their import.
+    pub genesis_transformations: GenesisTransformations,
+    /// Defines how the genesis import treats the entries that already exist in the database.
+    pub genesis_duplicate_policy: DuplicatePolicy,
+    /// The maximum length of the data of the messages imported from the snapshot.
+    /// If `None`, the limit of the consensus parameters is used. The messages from
+    /// the DA layer are stored as they are, and the transactions spending
+    /// the oversized ones fail the checks.
+    pub max_message_data_length: Option<u64>,
     /// When `true`:
     /// - Enables manual block production.
     /// - Enables debugger endpoint.
     /// - Allows setting `utxo_validation` to `false`.
+    /// - Allows overriding the max call depth of the VM.
+    /// - Allows overriding the max number of inputs and outputs of the transaction.
     pub debug: bool,
     // default to false until downstream consumers stabilize
     pub utxo_validation: bool,
     pub block_production: Trigger,
     pub vm: VMConfig,
+    /// The number of workers used to execute independent transactions of the imported
+    /// block in parallel. If `None`, transactions are executed sequentially.
+    pub parallel_execution_workers: Option<NonZeroUsize>,
+    /// The number of threads dedicated to the execution of produced and imported blocks,
+    /// isolating it from the GraphQL queries and dry runs.
+    /// If `None`, the execution shares the threads with other workloads.
+    pub dedicated_execution_threads: Option<NonZeroUsize>,
     pub txpool: fuel_core_txpool::Config,
+    /// Caches the transactions checked at the admission into the `TxPool`, so the
+    /// executor doesn't check their predicates and signatures again.
+    pub tx_validation_cache: bool,
     pub block_producer: fuel_core_producer::Config,
     pub static_gas_price: u64,
     pub block_importer: fuel_core_importer::Config,
+    /// The maximum number of blocks the `BlockImporterAdapter` may revert to switch
+    /// to a fork. Deeper forks are rejected and require manual intervention.
+    /// `None` means no limit.
+    pub max_reorg_depth: Option<u32>,
     #[cfg(feature = "relayer")]
     pub relayer: Option<RelayerConfig>,
     #[cfg(feature = "p2p")]
@@ -57,12 +168,32 @@ pub struct Config {
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
     pub name: String,
     pub relayer_consensus_config: fuel_core_consensus_module::RelayerConsensusConfig,
+    /// The maximum time the timestamp of the imported block can be ahead of the local time.
+    /// If `None`, the timestamp is not checked against the local time.
+    pub max_timestamp_drift: Option<Duration>,
+    /// The maximum total serialized size of the block's transactions besides
+    /// the `Mint` transaction. The producer stops packing the block before
+    /// the limit, and the imported blocks exceeding it are rejected.
+    /// If `None`, the size is limited only by the consensus parameters.
+    pub max_block_size_bytes: Option<u64>,
     /// The number of reserved peers to connect to before starting to sync.
     pub min_connected_reserved_peers: usize,
     /// Time to wait after receiving the latest block before considered to be Synced.
     pub time_until_synced: Duration,
+    /// The number of connected peers required to produce blocks.
+    /// The production is paused while the node is connected to fewer peers.
+    /// The manual production requests are rejected while it is paused.
+    #[cfg(feature = "p2p")]
+    pub min_peers_for_production: usize,
+    /// If set, the interval block production skips empty blocks,
+    /// but still produces one block per `max_idle_time`.
+    pub max_idle_time: Option<Duration>,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// If set, the node periodically exports the state snapshot.
+    pub periodic_snapshot: Option<PeriodicSnapshotConfig>,
+    /// If set, the node periodically compacts the database.
+    pub periodic_compaction: Option<PeriodicCompactionConfig>,
 }
 
 impl Config {
@@ -83,16 +214,45 @@ impl Config {
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            sync_policy: Default::default(),
+            deduplicate_bytecode: false,
         };
 
         Self {
             addr: SocketAddr::new(std::net::Ipv4Addr::new(127, 0, 0, 1).into(), 0),
+            metrics_addr: None,
             api_request_timeout: Duration::from_secs(60),
+            api_rate_limit: None,
+            api_enable_introspection: true,
+            api_disable_mutations: false,
+            api_keep_alive: None,
+            api_idle_timeout: None,
+            api_websocket_keep_alive: None,
+            api_shutdown_grace_period: Duration::from_secs(5),
+            api_finality_depth: 1,
+            api_max_assets_per_query: 1000,
+            api_max_receipts_per_transaction: None,
+            api_cors: Default::default(),
+            api_simulation_limit: None,
+            api_asset_metadata: HashMap::new(),
+            api_base_asset_metadata: None,
+            graphql_worker_parallelism: 1,
+            graphql_worker_retry: Default::default(),
             combined_db_config,
             debug: true,
             snapshot_reader,
+            genesis_timestamp: None,
+            genesis_workers: None,
+            expected_genesis_id: None,
+            executor_self_test: false,
+            genesis_coin_owners: None,
+            genesis_transformations: GenesisTransformations::default(),
+            genesis_duplicate_policy: DuplicatePolicy::default(),
+            max_message_data_length: None,
             block_production: Trigger::Instant,
             vm: Default::default(),
+            parallel_execution_workers: None,
+            dedicated_execution_threads: None,
             utxo_validation,
             txpool: fuel_core_txpool::Config {
                 chain_config,
@@ -100,11 +260,13 @@ impl Config {
                 transaction_ttl: Duration::from_secs(60 * 100000000),
                 ..fuel_core_txpool::Config::default()
             },
+            tx_validation_cache: false,
             block_producer: fuel_core_producer::Config {
                 ..Default::default()
             },
             static_gas_price: min_gas_price,
             block_importer,
+            max_reorg_depth: None,
             #[cfg(feature = "relayer")]
             relayer: None,
             #[cfg(feature = "p2p")]
@@ -116,9 +278,16 @@ impl Config {
             )),
             name: String::default(),
             relayer_consensus_config: Default::default(),
+            max_timestamp_drift: None,
+            max_block_size_bytes: None,
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            #[cfg(feature = "p2p")]
+            min_peers_for_production: 0,
+            max_idle_time: None,
             query_log_threshold_time: Duration::from_secs(2),
+            periodic_snapshot: None,
+            periodic_compaction: None,
         }
     }
 
@@ -153,6 +322,17 @@ impl Config {
 
         self
     }
+
+    /// Returns the maximum length of the data of the imported messages.
+    pub fn max_message_data_length(&self) -> u64 {
+        self.max_message_data_length.unwrap_or_else(|| {
+            self.snapshot_reader
+                .chain_config()
+                .consensus_parameters
+                .predicate_params()
+                .max_message_data_length()
+        })
+    }
 }
 
 impl From<&Config> for fuel_core_poa::Config {
@@ -165,6 +345,11 @@ impl From<&Config> for fuel_core_poa::Config {
             consensus_params: chain_config.consensus_parameters.clone(),
             min_connected_reserved_peers: config.min_connected_reserved_peers,
             time_until_synced: config.time_until_synced,
+            max_idle_time: config.max_idle_time,
+            #[cfg(feature = "p2p")]
+            min_peers_for_production: config.min_peers_for_production,
+            #[cfg(not(feature = "p2p"))]
+            min_peers_for_production: 0,
         }
     }
 }
@@ -172,6 +357,26 @@ impl From<&Config> for fuel_core_poa::Config {
 #[derive(Clone, Debug, Default)]
 pub struct VMConfig {
     pub backtrace: bool,
+    /// Overrides the maximum depth of nested contract calls. Transactions exceeding it
+    /// are rejected by the executor. Requires `debug` to be enabled.
+    pub max_call_depth: Option<u64>,
+    /// Overrides the maximum number of inputs of the transaction. Transactions exceeding
+    /// it are rejected by the executor. Requires `debug` to be enabled.
+    pub max_inputs: Option<u16>,
+    /// Overrides the maximum number of outputs of the transaction. Transactions exceeding
+    /// it are rejected by the executor. Requires `debug` to be enabled.
+    pub max_outputs: Option<u16>,
+    /// The maximum wall-clock time of the execution of the transaction. Transactions
+    /// exceeding it fail, and their changes are reverted. It is not checked during
+    /// the validation of blocks. Requires `debug` to be enabled.
+    pub max_tx_execution_time: Option<Duration>,
+    /// The maximum bytes of the VM memory occupied by the stack and the heap of the
+    /// transaction. Transactions exceeding it fail, and their changes are reverted.
+    /// It is not checked during the validation of blocks. Requires `debug` to be enabled.
+    pub max_tx_memory: Option<u64>,
+    /// Records the storage keys read and written by each transaction during the dry run,
+    /// exposing them in the dry run result. It slows down the dry run.
+    pub record_storage_access: bool,
 }
 
 #[derive(
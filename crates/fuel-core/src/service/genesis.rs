@@ -46,23 +46,56 @@ use fuel_core_types::{
         ImportResult,
         UncommittedResult as UncommittedImportResult,
     },
+    tai64::Tai64,
 };
 use itertools::Itertools;
+use std::time::Duration;
 
 pub mod off_chain;
 pub mod on_chain;
 mod runner;
 mod workers;
 
-pub use runner::GenesisRunner;
+pub use on_chain::GenesisImportSummary;
+pub use runner::{
+    GenesisRunner,
+    TableImportSummary,
+};
+pub use workers::{
+    DuplicatePolicy,
+    GenesisTransformations,
+    Transformation,
+};
+
+/// The maximum time the genesis timestamp may be ahead of the local clock.
+pub const MAX_GENESIS_TIMESTAMP_SKEW: Duration = Duration::from_secs(60);
 
 /// Performs the importing of the genesis block from the snapshot.
 pub async fn execute_genesis_block(
     config: &Config,
     db: &CombinedDatabase,
 ) -> anyhow::Result<UncommittedImportResult<Changes>> {
-    on_chain::import_state(db.clone(), config.snapshot_reader.clone()).await?;
-    off_chain::import_state(db.clone(), config.snapshot_reader.clone()).await?;
+    validate_genesis_timestamp(config)?;
+    let summary = on_chain::import_state(
+        db.clone(),
+        config.snapshot_reader.clone(),
+        config.genesis_workers,
+        config.max_message_data_length(),
+        config.genesis_coin_owners.clone(),
+        config.genesis_transformations.clone(),
+        config.genesis_duplicate_policy,
+    )
+    .await?;
+    log_import_summary(&summary);
+    off_chain::import_state(
+        db.clone(),
+        config.snapshot_reader.clone(),
+        config.genesis_workers,
+        config.genesis_coin_owners.clone(),
+        config.genesis_transformations.clone(),
+        config.genesis_duplicate_policy,
+    )
+    .await?;
 
     let genesis_progress_on_chain: Vec<String> = db
         .on_chain()
@@ -146,6 +179,38 @@ pub async fn execute_and_commit_genesis_block(
     Ok(())
 }
 
+fn log_import_summary(summary: &GenesisImportSummary) {
+    for table in &summary.tables {
+        tracing::info!(
+            "Imported {} entries of {} in {:?} ({:.0} entries/sec)",
+            table.entries,
+            table.table,
+            table.duration,
+            table.entries_per_second()
+        );
+    }
+    tracing::info!(
+        "Imported {} on-chain entries of the snapshot in {:?}",
+        summary.entries(),
+        summary.duration
+    );
+}
+
+fn validate_genesis_timestamp(config: &Config) -> anyhow::Result<()> {
+    if let Some(genesis_timestamp) = config.genesis_timestamp {
+        let max_allowed = Tai64::now()
+            .0
+            .saturating_add(MAX_GENESIS_TIMESTAMP_SKEW.as_secs());
+        if genesis_timestamp.0 > max_allowed {
+            return Err(anyhow::anyhow!(
+                "The genesis timestamp {} is in the future",
+                genesis_timestamp.to_unix()
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub fn create_genesis_block(config: &Config) -> Block {
     let block_height = config.snapshot_reader.block_height();
     let da_block_height = config.snapshot_reader.da_block_height();
@@ -167,7 +232,7 @@ pub fn create_genesis_block(config: &Config) -> Block {
             consensus: ConsensusHeader::<Empty> {
                 prev_root: Bytes32::zeroed(),
                 height: block_height,
-                time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
+                time: config.genesis_timestamp.unwrap_or(Tai64::UNIX_EPOCH),
                 generated: Empty,
             },
         },
@@ -183,7 +248,10 @@ mod tests {
 
     use crate::{
         combined_database::CombinedDatabase,
-        database::Database,
+        database::{
+            database_description::DatabaseDescription,
+            Database,
+        },
         service::{
             config::Config,
             FuelService,
@@ -193,6 +261,8 @@ mod tests {
     use fuel_core_chain_config::{
         CoinConfig,
         ContractConfig,
+        ContractStateChunkRoot,
+        ContractStateConfig,
         MessageConfig,
         Randomize,
         SnapshotReader,
@@ -200,10 +270,21 @@ mod tests {
     };
     use fuel_core_services::RunnableService;
     use fuel_core_storage::{
+        iter::{
+            IterDirection,
+            IterableStore,
+        },
+        kv_store::{
+            StorageColumn,
+            Value,
+        },
         tables::{
+            merkle::ContractsStateMerkleMetadata,
             Coins,
             ContractsAssets,
+            ContractsRawCode,
             ContractsState,
+            Messages,
         },
         StorageAsRef,
     };
@@ -224,7 +305,11 @@ mod tests {
         RngCore,
         SeedableRng,
     };
-    use std::vec;
+    use std::{
+        collections::HashSet,
+        num::NonZeroUsize,
+        vec,
+    };
 
     #[tokio::test]
     async fn config_initializes_block_height() {
@@ -252,6 +337,483 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn config_initializes_genesis_timestamp() {
+        let genesis_timestamp = Tai64::from_unix(1_700_000_000);
+        let service_config = Config {
+            genesis_timestamp: Some(genesis_timestamp),
+            ..Config::local_node()
+        };
+
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        let genesis_block = db.latest_block().unwrap();
+        assert_eq!(genesis_block.header().time(), genesis_timestamp);
+    }
+
+    #[tokio::test]
+    async fn genesis_timestamp_in_the_future_is_rejected() {
+        let future = Tai64::now()
+            .0
+            .saturating_add(MAX_GENESIS_TIMESTAMP_SKEW.as_secs().saturating_mul(2));
+        let service_config = Config {
+            genesis_timestamp: Some(Tai64(future)),
+            ..Config::local_node()
+        };
+
+        let db = Database::default();
+        let result = FuelService::from_database(db, service_config).await;
+
+        assert!(result.is_err());
+    }
+
+    fn given_state_with_coins(rng: &mut StdRng) -> StateConfig {
+        let coins = std::iter::repeat_with(|| CoinConfig {
+            tx_pointer_block_height: 0.into(),
+            ..Randomize::randomize(&mut *rng)
+        })
+        .take(10)
+        .collect_vec();
+
+        StateConfig {
+            coins,
+            ..Default::default()
+        }
+    }
+
+    async fn imported_state_root(state: StateConfig) -> Bytes32 {
+        let service_config = Config {
+            snapshot_reader: SnapshotReader::local_testnet().with_state_config(state),
+            ..Config::local_node()
+        };
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        db.genesis_state_root().unwrap().into()
+    }
+
+    #[tokio::test]
+    async fn genesis_succeeds_if_state_root_matches_snapshot() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = given_state_with_coins(&mut rng);
+        let expected_state_root = imported_state_root(state.clone()).await;
+
+        let snapshot_reader = SnapshotReader::local_testnet()
+            .with_state_config(state)
+            .with_expected_state_root(Some(expected_state_root));
+        let service_config = Config {
+            snapshot_reader,
+            ..Config::local_node()
+        };
+        let result =
+            FuelService::from_database(Database::default(), service_config).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn genesis_fails_if_tampered_snapshot_produces_different_state_root() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = given_state_with_coins(&mut rng);
+        let expected_state_root = imported_state_root(state.clone()).await;
+
+        let mut tampered_state = state;
+        tampered_state.coins[0].amount = tampered_state.coins[0].amount.wrapping_add(1);
+        let snapshot_reader = SnapshotReader::local_testnet()
+            .with_state_config(tampered_state)
+            .with_expected_state_root(Some(expected_state_root));
+        let service_config = Config {
+            snapshot_reader,
+            ..Config::local_node()
+        };
+        let result =
+            FuelService::from_database(Database::default(), service_config).await;
+
+        let err = result.expect_err("Genesis should fail because of the root mismatch");
+        assert!(err
+            .to_string()
+            .contains("doesn't match the expected state root"));
+    }
+
+    fn given_state_with_contract_state(rng: &mut StdRng) -> StateConfig {
+        let states = std::iter::repeat_with(|| ContractStateConfig {
+            key: rng.gen(),
+            value: rng.gen::<[u8; 32]>().to_vec(),
+        })
+        .take(10)
+        .collect_vec();
+        let contract = ContractConfig {
+            states,
+            ..given_contract_config(rng)
+        };
+
+        StateConfig {
+            contracts: vec![contract],
+            ..Default::default()
+        }
+    }
+
+    async fn imported_contract_state_chunk_root(
+        state: StateConfig,
+    ) -> ContractStateChunkRoot {
+        let contract_id = state.contracts[0].contract_id;
+        let service_config = Config {
+            snapshot_reader: SnapshotReader::local_testnet().with_state_config(state),
+            ..Config::local_node()
+        };
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        let metadata = db
+            .storage::<ContractsStateMerkleMetadata>()
+            .get(&contract_id)
+            .unwrap()
+            .unwrap();
+        ContractStateChunkRoot {
+            contract_id,
+            group_index: 0,
+            root: (*metadata.root()).into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn genesis_succeeds_if_contract_state_chunk_root_matches_snapshot() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = given_state_with_contract_state(&mut rng);
+        let chunk_root = imported_contract_state_chunk_root(state.clone()).await;
+
+        let snapshot_reader = SnapshotReader::local_testnet()
+            .with_state_config(state)
+            .with_expected_contract_state_roots(vec![chunk_root]);
+        let service_config = Config {
+            snapshot_reader,
+            ..Config::local_node()
+        };
+        let result =
+            FuelService::from_database(Database::default(), service_config).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn genesis_fails_if_tampered_contract_state_chunk_produces_different_root() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = given_state_with_contract_state(&mut rng);
+        let chunk_root = imported_contract_state_chunk_root(state.clone()).await;
+
+        let mut tampered_state = state;
+        tampered_state.contracts[0].states[0].value[0] ^= 1;
+        let snapshot_reader = SnapshotReader::local_testnet()
+            .with_state_config(tampered_state)
+            .with_expected_contract_state_roots(vec![chunk_root]);
+        let service_config = Config {
+            snapshot_reader,
+            ..Config::local_node()
+        };
+        let result =
+            FuelService::from_database(Database::default(), service_config).await;
+
+        let err = result.expect_err("Genesis should fail because of the root mismatch");
+        assert!(err.to_string().contains("after the chunk 0 doesn't match"));
+    }
+
+    #[tokio::test]
+    async fn genesis_imports_only_coins_of_allowed_owners() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = given_state_with_coins(&mut rng);
+        let allowed_owners: HashSet<Address> =
+            state.coins.iter().take(2).map(|coin| coin.owner).collect();
+        let skipped_owner = state.coins[2].owner;
+
+        let service_config = Config {
+            snapshot_reader: SnapshotReader::local_testnet().with_state_config(state),
+            genesis_coin_owners: Some(allowed_owners.clone()),
+            ..Config::local_node()
+        };
+        let db = CombinedDatabase::default();
+        FuelService::from_combined_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        let imported_owners: HashSet<Address> = db
+            .on_chain()
+            .iter_all::<Coins>(None)
+            .map(|entry| *entry.unwrap().1.owner())
+            .collect();
+        assert_eq!(imported_owners, allowed_owners);
+        for owner in &allowed_owners {
+            assert_eq!(get_coins(&db, owner).len(), 1);
+        }
+        assert!(get_coins(&db, &skipped_owner).is_empty());
+    }
+
+    #[tokio::test]
+    async fn genesis_transformations_are_applied_to_imported_coins() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = given_state_with_coins(&mut rng);
+        let expected_amounts: HashSet<(UtxoId, u64)> = state
+            .coins
+            .iter()
+            .map(|coin| (coin.utxo_id(), coin.amount / 2))
+            .collect();
+
+        let transformations =
+            GenesisTransformations::default().with::<Coins, _>(|mut entry| {
+                let amount = *entry.value.amount();
+                entry.value.set_amount(amount / 2);
+                entry
+            });
+        let service_config = Config {
+            snapshot_reader: SnapshotReader::local_testnet().with_state_config(state),
+            genesis_transformations: transformations,
+            ..Config::local_node()
+        };
+        let db = CombinedDatabase::default();
+        FuelService::from_combined_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        let imported_amounts: HashSet<(UtxoId, u64)> = db
+            .on_chain()
+            .iter_all::<Coins>(None)
+            .map(|entry| {
+                let (utxo_id, coin) = entry.unwrap();
+                (utxo_id, *coin.amount())
+            })
+            .collect();
+        assert_eq!(imported_amounts, expected_amounts);
+    }
+
+    /// Returns the state with a duplicate of the first coin
+    /// with a different owner and amount.
+    fn given_state_with_duplicate_coin(rng: &mut StdRng) -> (StateConfig, UtxoId) {
+        let mut state = given_state_with_coins(rng);
+        let mut duplicate = state.coins[0].clone();
+        duplicate.owner = rng.gen();
+        duplicate.amount = duplicate.amount.wrapping_add(1);
+        let utxo_id = duplicate.utxo_id();
+        state.coins.push(duplicate);
+        (state, utxo_id)
+    }
+
+    async fn import_with_duplicate_policy(
+        state: StateConfig,
+        duplicate_policy: DuplicatePolicy,
+    ) -> anyhow::Result<CombinedDatabase> {
+        let service_config = Config {
+            snapshot_reader: SnapshotReader::local_testnet().with_state_config(state),
+            genesis_duplicate_policy: duplicate_policy,
+            ..Config::local_node()
+        };
+        let db = CombinedDatabase::in_memory();
+        execute_genesis_block(&service_config, &db).await?;
+        Ok(db)
+    }
+
+    fn imported_coin_amount(db: &CombinedDatabase, utxo_id: &UtxoId) -> u64 {
+        *db.on_chain()
+            .storage::<Coins>()
+            .get(utxo_id)
+            .unwrap()
+            .expect("The coin should be imported")
+            .amount()
+    }
+
+    fn indexed_coins(db: &CombinedDatabase, owner: &Address) -> Vec<UtxoId> {
+        db.off_chain()
+            .owned_coins_ids(owner, None, None)
+            .try_collect()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn genesis_fails_on_duplicate_coin_with_error_policy() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(10);
+        let (state, _) = given_state_with_duplicate_coin(&mut rng);
+
+        // When
+        let result = import_with_duplicate_policy(state, DuplicatePolicy::Error).await;
+
+        // Then
+        let err = result.expect_err("Genesis should fail because of the duplicate coin");
+        assert!(err.to_string().contains("Coin should not exist"));
+    }
+
+    #[tokio::test]
+    async fn genesis_keeps_the_first_coin_with_skip_policy() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(10);
+        let (state, utxo_id) = given_state_with_duplicate_coin(&mut rng);
+        let first_amount = state.coins[0].amount;
+        let first_owner = state.coins[0].owner;
+        let duplicate_owner = state.coins.last().unwrap().owner;
+        let coins_in_snapshot = state.coins.len();
+
+        // When
+        let db = import_with_duplicate_policy(state, DuplicatePolicy::Skip)
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(imported_coin_amount(&db, &utxo_id), first_amount);
+        let imported_coins = db.on_chain().iter_all::<Coins>(None).count();
+        assert_eq!(imported_coins, coins_in_snapshot.saturating_sub(1));
+        assert_eq!(indexed_coins(&db, &first_owner), vec![utxo_id]);
+        assert!(indexed_coins(&db, &duplicate_owner).is_empty());
+    }
+
+    #[tokio::test]
+    async fn genesis_replaces_the_first_coin_with_overwrite_policy() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(10);
+        let (state, utxo_id) = given_state_with_duplicate_coin(&mut rng);
+        let duplicate_amount = state.coins.last().unwrap().amount;
+        let first_owner = state.coins[0].owner;
+        let duplicate_owner = state.coins.last().unwrap().owner;
+        let coins_in_snapshot = state.coins.len();
+
+        // When
+        let db = import_with_duplicate_policy(state, DuplicatePolicy::Overwrite)
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(imported_coin_amount(&db, &utxo_id), duplicate_amount);
+        let imported_coins = db.on_chain().iter_all::<Coins>(None).count();
+        assert_eq!(imported_coins, coins_in_snapshot.saturating_sub(1));
+        assert!(indexed_coins(&db, &first_owner).is_empty());
+        assert_eq!(indexed_coins(&db, &duplicate_owner), vec![utxo_id]);
+    }
+
+    #[tokio::test]
+    async fn genesis_import_summary_reports_duration_and_entries_per_table() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(10);
+        let messages = std::iter::repeat_with(|| MessageConfig {
+            da_height: DaBlockHeight(0),
+            ..MessageConfig::randomize(&mut rng)
+        })
+        .take(5)
+        .collect_vec();
+        let state = StateConfig {
+            messages,
+            ..given_state_with_coins(&mut rng)
+        };
+        let config = Config {
+            snapshot_reader: SnapshotReader::local_testnet().with_state_config(state),
+            ..Config::local_node()
+        };
+
+        // When
+        let summary = on_chain::import_state(
+            CombinedDatabase::in_memory(),
+            config.snapshot_reader.clone(),
+            config.genesis_workers,
+            config.max_message_data_length(),
+            None,
+            GenesisTransformations::default(),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        // Then
+        assert!(summary.duration > Duration::ZERO);
+        let entries = |table: &str| {
+            summary
+                .tables
+                .iter()
+                .find(|summary| summary.table == table)
+                .unwrap_or_else(|| panic!("The summary of {table} is missing"))
+                .entries
+        };
+        assert_eq!(entries(Coins::column().name()), 10);
+        assert_eq!(entries(Messages::column().name()), 5);
+        assert_eq!(entries(ContractsRawCode::column().name()), 0);
+        assert_eq!(summary.entries(), 15);
+        for table in summary.tables.iter().filter(|table| table.entries > 0) {
+            assert!(table.duration > Duration::ZERO);
+            assert!(table.entries_per_second() > 0.0);
+        }
+    }
+
+    fn all_entries<Description>(db: &Database<Description>) -> Vec<(u32, Vec<u8>, Value)>
+    where
+        Description: DatabaseDescription,
+    {
+        enum_iterator::all::<Description::Column>()
+            .flat_map(|column| {
+                db.iter_store(column, None, None, IterDirection::Forward)
+                    .map(move |entry| {
+                        let (key, value) = entry.unwrap();
+                        (column.id(), key, value)
+                    })
+            })
+            .collect()
+    }
+
+    async fn imported_database(
+        state: StateConfig,
+        genesis_workers: usize,
+    ) -> CombinedDatabase {
+        let service_config = Config {
+            snapshot_reader: SnapshotReader::local_testnet().with_state_config(state),
+            genesis_workers: NonZeroUsize::new(genesis_workers),
+            ..Config::local_node()
+        };
+        let db = CombinedDatabase::in_memory();
+        execute_genesis_block(&service_config, &db).await.unwrap();
+        db
+    }
+
+    #[tokio::test]
+    async fn genesis_import_does_not_depend_on_the_number_of_workers() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let coins = std::iter::repeat_with(|| CoinConfig {
+            tx_pointer_block_height: 0.into(),
+            ..Randomize::randomize(&mut rng)
+        })
+        .take(100)
+        .collect_vec();
+        let messages = std::iter::repeat_with(|| MessageConfig {
+            da_height: DaBlockHeight(0),
+            ..MessageConfig::randomize(&mut rng)
+        })
+        .take(100)
+        .collect_vec();
+        let contracts = std::iter::repeat_with(|| given_contract_config(&mut rng))
+            .take(100)
+            .collect_vec();
+        let state = StateConfig {
+            coins,
+            messages,
+            contracts,
+            ..Default::default()
+        };
+
+        // When
+        let single_worker = imported_database(state.clone(), 1).await;
+        let multiple_workers = imported_database(state, 8).await;
+
+        // Then
+        assert_eq!(
+            all_entries(single_worker.on_chain()),
+            all_entries(multiple_workers.on_chain())
+        );
+        assert_eq!(
+            all_entries(single_worker.off_chain()),
+            all_entries(multiple_workers.off_chain())
+        );
+    }
+
     #[tokio::test]
     async fn genesis_columns_are_cleared_after_import() {
         let mut rng = StdRng::seed_from_u64(10);
@@ -426,8 +988,6 @@ mod tests {
     #[cfg(feature = "test-helpers")]
     #[tokio::test]
     async fn tests_init_da_msgs() {
-        use fuel_core_storage::tables::Messages;
-
         let mut rng = StdRng::seed_from_u64(32492);
 
         let msg = MessageConfig {
@@ -469,6 +1029,38 @@ mod tests {
         assert_eq!(expected_msg, ret_msg);
     }
 
+    #[tokio::test]
+    async fn genesis_rejects_message_with_data_above_max_length() {
+        let mut rng = StdRng::seed_from_u64(32492);
+        let max_message_data_length = 16;
+
+        let msg = MessageConfig {
+            sender: rng.gen(),
+            recipient: rng.gen(),
+            nonce: rng.gen(),
+            amount: rng.gen(),
+            data: vec![1; 17],
+            da_height: DaBlockHeight(0),
+        };
+        let state = StateConfig {
+            messages: vec![msg],
+            ..Default::default()
+        };
+        let snapshot_reader = SnapshotReader::local_testnet().with_state_config(state);
+
+        let config = Config {
+            snapshot_reader,
+            max_message_data_length: Some(max_message_data_length),
+            ..Config::local_node()
+        };
+
+        let db = CombinedDatabase::default();
+        let result = super::execute_genesis_block(&config, &db).await;
+
+        let error = result.expect_err("The oversized message should be rejected");
+        assert!(format!("{error:?}").contains("has 17 bytes"), "{error:?}");
+    }
+
     #[tokio::test]
     async fn config_state_initializes_contract_balance() {
         let mut rng = StdRng::seed_from_u64(10);
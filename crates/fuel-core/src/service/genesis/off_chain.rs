@@ -1,4 +1,8 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    num::NonZeroUsize,
+};
 
 use crate::{
     combined_database::CombinedDatabase,
@@ -32,12 +36,18 @@ use fuel_core_storage::{
     },
     transactional::StorageTransaction,
     StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_types::Address,
+    services::executor::Event,
 };
-use fuel_core_types::services::executor::Event;
 
 use super::{
     runner::ProcessState,
     workers::{
+        DuplicatePolicy,
+        GenesisTransformations,
         GenesisWorkers,
         Handler,
     },
@@ -46,8 +56,17 @@ use super::{
 pub async fn import_state(
     db: CombinedDatabase,
     snapshot_reader: SnapshotReader,
+    workers: Option<NonZeroUsize>,
+    coin_owners: Option<HashSet<Address>>,
+    transformations: GenesisTransformations,
+    duplicate_policy: DuplicatePolicy,
 ) -> anyhow::Result<()> {
-    let mut workers = GenesisWorkers::new(db, snapshot_reader);
+    let mut workers = GenesisWorkers::new(db, snapshot_reader, workers)?
+        .with_duplicate_policy(duplicate_policy)
+        .with_transformations(transformations);
+    if let Some(coin_owners) = coin_owners {
+        workers = workers.with_coin_owners(coin_owners);
+    }
     if let Err(e) = workers.run_off_chain_imports().await {
         workers.shutdown();
         workers.finished().await;
@@ -139,10 +158,26 @@ impl ProcessState for Handler<OwnedCoins> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
-        let events = group.into_iter().map(|TableEntry { value, key }| {
-            Cow::Owned(Event::CoinCreated(value.uncompress(key)))
-        });
-        worker_service::process_executor_events(events, tx)?;
+        let mut events = vec![];
+        for TableEntry { key, value } in group {
+            if !self.is_coin_imported(value.owner()) {
+                continue
+            }
+            let coin = match self.config.duplicate_policy {
+                DuplicatePolicy::Error => value,
+                // The duplicates are resolved during the on-chain import, so the index
+                // is built from the imported coin instead of the entry of the snapshot.
+                DuplicatePolicy::Skip | DuplicatePolicy::Overwrite => {
+                    let Some(coin) = self.config.on_chain.storage::<Coins>().get(&key)?
+                    else {
+                        continue
+                    };
+                    coin.into_owned()
+                }
+            };
+            events.push(Cow::Owned(Event::CoinCreated(coin.uncompress(key))));
+        }
+        worker_service::process_executor_events(events.into_iter(), tx)?;
         Ok(())
     }
 }
@@ -1,6 +1,11 @@
 use super::{
-    runner::ProcessState,
+    runner::{
+        ProcessState,
+        TableImportSummary,
+    },
     workers::{
+        DuplicatePolicy,
+        GenesisTransformations,
         GenesisWorkers,
         Handler,
     },
@@ -16,11 +21,13 @@ use crate::{
 };
 use anyhow::anyhow;
 use fuel_core_chain_config::{
+    Group,
     SnapshotReader,
     TableEntry,
 };
 use fuel_core_storage::{
     tables::{
+        merkle::ContractsStateMerkleMetadata,
         Coins,
         ContractsAssets,
         ContractsLatestUtxo,
@@ -30,7 +37,11 @@ use fuel_core_storage::{
         Transactions,
     },
     transactional::StorageTransaction,
+    Error as StorageError,
+    Mappable,
     StorageAsMut,
+    StorageAsRef,
+    StorageMutate,
 };
 use fuel_core_types::{
     self,
@@ -39,22 +50,74 @@ use fuel_core_types::{
         coins::coin::Coin,
         Message,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Bytes32,
+    },
+};
+use std::{
+    collections::HashSet,
+    fmt,
+    num::NonZeroUsize,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
+/// The summary of the import of the on-chain state of the snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenesisImportSummary {
+    /// The wall time of the import of all the on-chain tables.
+    pub duration: Duration,
+    /// The summaries of the imported tables.
+    pub tables: Vec<TableImportSummary>,
+}
+
+impl GenesisImportSummary {
+    /// Returns the total number of the entries processed by the import.
+    pub fn entries(&self) -> usize {
+        self.tables.iter().fold(0usize, |entries, table| {
+            entries.saturating_add(table.entries)
+        })
+    }
+}
+
 pub(crate) async fn import_state(
     db: CombinedDatabase,
     snapshot_reader: SnapshotReader,
-) -> anyhow::Result<()> {
-    let mut workers = GenesisWorkers::new(db, snapshot_reader);
-    if let Err(e) = workers.run_on_chain_imports().await {
-        workers.shutdown();
-        workers.finished().await;
-
-        return Err(e);
+    workers: Option<NonZeroUsize>,
+    max_message_data_length: u64,
+    coin_owners: Option<HashSet<Address>>,
+    transformations: GenesisTransformations,
+    duplicate_policy: DuplicatePolicy,
+) -> anyhow::Result<GenesisImportSummary> {
+    let mut workers = GenesisWorkers::new(db, snapshot_reader, workers)?
+        .with_max_message_data_length(max_message_data_length)
+        .with_duplicate_policy(duplicate_policy)
+        .with_transformations(transformations);
+    if let Some(coin_owners) = coin_owners {
+        workers = workers.with_coin_owners(coin_owners);
     }
+    let started_at = Instant::now();
+    let tables = match workers.run_on_chain_imports().await {
+        Ok(tables) => tables,
+        Err(e) => {
+            workers.shutdown();
+            workers.finished().await;
+
+            return Err(e);
+        }
+    };
+    let summary = GenesisImportSummary {
+        duration: started_at.elapsed(),
+        tables,
+    };
 
-    Ok(())
+    workers.verify_state_root()?;
+
+    Ok(summary)
 }
 
 impl ProcessState for Handler<Coins> {
@@ -67,10 +130,18 @@ impl ProcessState for Handler<Coins> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut Database>,
     ) -> anyhow::Result<()> {
-        group.into_iter().try_for_each(|coin| {
-            init_coin(tx, &coin, self.block_height)?;
-            Ok(())
-        })
+        group
+            .into_iter()
+            .filter(|coin| self.is_coin_imported(coin.value.owner()))
+            .try_for_each(|coin| {
+                init_coin(
+                    tx,
+                    &coin,
+                    self.config.block_height,
+                    self.config.duplicate_policy,
+                )?;
+                Ok(())
+            })
     }
 }
 
@@ -84,9 +155,15 @@ impl ProcessState for Handler<Messages> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut Database>,
     ) -> anyhow::Result<()> {
-        group
-            .into_iter()
-            .try_for_each(|message| init_da_message(tx, message, self.da_block_height))
+        group.into_iter().try_for_each(|message| {
+            init_da_message(
+                tx,
+                message,
+                self.config.da_block_height,
+                self.config.max_message_data_length,
+                self.config.duplicate_policy,
+            )
+        })
     }
 }
 
@@ -101,7 +178,7 @@ impl ProcessState for Handler<ContractsRawCode> {
         tx: &mut StorageTransaction<&mut Database>,
     ) -> anyhow::Result<()> {
         group.into_iter().try_for_each(|contract| {
-            init_contract_raw_code(tx, &contract)?;
+            init_contract_raw_code(tx, &contract, self.config.duplicate_policy)?;
             Ok::<(), anyhow::Error>(())
         })
     }
@@ -118,7 +195,12 @@ impl ProcessState for Handler<ContractsLatestUtxo> {
         tx: &mut StorageTransaction<&mut Database>,
     ) -> anyhow::Result<()> {
         group.into_iter().try_for_each(|contract| {
-            init_contract_latest_utxo(tx, &contract, self.block_height)?;
+            init_contract_latest_utxo(
+                tx,
+                &contract,
+                self.config.block_height,
+                self.config.duplicate_policy,
+            )?;
             Ok::<(), anyhow::Error>(())
         })
     }
@@ -137,6 +219,35 @@ impl ProcessState for Handler<ContractsState> {
         tx.update_contract_states(group)?;
         Ok(())
     }
+
+    fn process_group(
+        &mut self,
+        group: Group<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut Database>,
+    ) -> anyhow::Result<()> {
+        let index = group.index;
+        self.process(group.data, tx)?;
+
+        let Some(chunk_roots) = self.config.contract_state_roots.get(&index) else {
+            return Ok(())
+        };
+        for chunk_root in chunk_roots {
+            let contract_id = chunk_root.contract_id;
+            let actual = tx
+                .storage_as_ref::<ContractsStateMerkleMetadata>()
+                .get(&contract_id)?
+                .map(|metadata| Bytes32::from(*metadata.root()))
+                .unwrap_or_default();
+            if actual != chunk_root.root {
+                return Err(anyhow!(
+                    "The state root {actual} of the contract {contract_id} after the chunk \
+                    {index} doesn't match the expected root {} from the snapshot",
+                    chunk_root.root
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl ProcessState for Handler<ContractsAssets> {
@@ -176,6 +287,7 @@ fn init_coin(
     transaction: &mut StorageTransaction<&mut Database>,
     coin: &TableEntry<Coins>,
     height: BlockHeight,
+    duplicate_policy: DuplicatePolicy,
 ) -> anyhow::Result<()> {
     let utxo_id = coin.key;
 
@@ -196,21 +308,20 @@ fn init_coin(
         ));
     }
 
-    if transaction
-        .storage::<Coins>()
-        .insert(&utxo_id, &compressed_coin)?
-        .is_some()
-    {
-        return Err(anyhow!("Coin should not exist"));
-    }
-
-    Ok(())
+    insert_entry::<Coins, _>(
+        transaction,
+        &utxo_id,
+        &compressed_coin,
+        duplicate_policy,
+        "Coin",
+    )
 }
 
 fn init_contract_latest_utxo(
     transaction: &mut StorageTransaction<&mut Database>,
     entry: &TableEntry<ContractsLatestUtxo>,
     height: BlockHeight,
+    duplicate_policy: DuplicatePolicy,
 ) -> anyhow::Result<()> {
     let contract_id = entry.key;
 
@@ -220,40 +331,39 @@ fn init_contract_latest_utxo(
         ));
     }
 
-    if transaction
-        .storage::<ContractsLatestUtxo>()
-        .insert(&contract_id, &entry.value)?
-        .is_some()
-    {
-        return Err(anyhow!("Contract utxo should not exist"));
-    }
-
-    Ok(())
+    insert_entry::<ContractsLatestUtxo, _>(
+        transaction,
+        &contract_id,
+        &entry.value,
+        duplicate_policy,
+        "Contract utxo",
+    )
 }
 
 fn init_contract_raw_code(
     transaction: &mut StorageTransaction<&mut Database>,
     entry: &TableEntry<ContractsRawCode>,
+    duplicate_policy: DuplicatePolicy,
 ) -> anyhow::Result<()> {
     let contract = entry.value.as_ref();
     let contract_id = entry.key;
 
     // insert contract code
-    if transaction
-        .storage::<ContractsRawCode>()
-        .insert(&contract_id, contract)?
-        .is_some()
-    {
-        return Err(anyhow!("Contract code should not exist"));
-    }
-
-    Ok(())
+    insert_entry::<ContractsRawCode, _>(
+        transaction,
+        &contract_id,
+        contract,
+        duplicate_policy,
+        "Contract code",
+    )
 }
 
 fn init_da_message(
     transaction: &mut StorageTransaction<&mut Database>,
     msg: TableEntry<Messages>,
     da_height: DaBlockHeight,
+    max_data_length: u64,
+    duplicate_policy: DuplicatePolicy,
 ) -> anyhow::Result<()> {
     let message: Message = msg.value;
 
@@ -263,13 +373,55 @@ fn init_da_message(
         ));
     }
 
-    if transaction
-        .storage::<Messages>()
-        .insert(message.id(), &message)?
-        .is_some()
-    {
-        return Err(anyhow!("Message should not exist"));
+    let data_length = message.data().len() as u64;
+    if data_length > max_data_length {
+        return Err(anyhow!(
+            "message {} data has {data_length} bytes, but the maximum is {max_data_length} bytes",
+            message.id()
+        ));
     }
 
+    insert_entry::<Messages, _>(
+        transaction,
+        message.id(),
+        &message,
+        duplicate_policy,
+        "Message",
+    )
+}
+
+/// Inserts the genesis entry into the table `T`, treating the already existing entry
+/// according to the `duplicate_policy`.
+fn insert_entry<T, S>(
+    storage: &mut S,
+    key: &T::Key,
+    value: &T::Value,
+    duplicate_policy: DuplicatePolicy,
+    name: &str,
+) -> anyhow::Result<()>
+where
+    T: Mappable,
+    T::Key: fmt::Debug,
+    S: StorageMutate<T, Error = StorageError>,
+{
+    match duplicate_policy {
+        DuplicatePolicy::Error => {
+            if storage.insert(key, value)?.is_some() {
+                return Err(anyhow!("{name} should not exist"));
+            }
+        }
+        DuplicatePolicy::Skip => {
+            if storage.contains_key(key)? {
+                tracing::warn!("Skipped the duplicate of the {name} {key:?}");
+            } else {
+                storage.insert(key, value)?;
+            }
+        }
+        DuplicatePolicy::Overwrite => {
+            if storage.insert(key, value)?.is_some() {
+                tracing::warn!("Overwrote the {name} {key:?} with its duplicate");
+            }
+        }
+    }
     Ok(())
 }
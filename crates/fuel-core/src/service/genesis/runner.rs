@@ -14,7 +14,13 @@ use fuel_core_storage::{
     StorageInspect,
     StorageMutate,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
 use tokio::sync::Notify;
 use tokio_util::sync::CancellationToken;
 
@@ -27,6 +33,30 @@ use crate::database::{
     Database,
 };
 
+/// The summary of the import of a single table of the snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableImportSummary {
+    /// The name of the table being written.
+    pub table: String,
+    /// The number of the snapshot entries processed by the import.
+    /// The entries of the groups imported before the restart are not counted.
+    pub entries: usize,
+    /// The time spent importing the table.
+    pub duration: Duration,
+}
+
+impl TableImportSummary {
+    /// Returns the number of the entries processed per second.
+    pub fn entries_per_second(&self) -> f64 {
+        let seconds = self.duration.as_secs_f64();
+        if seconds > 0.0 {
+            self.entries as f64 / seconds
+        } else {
+            0.0
+        }
+    }
+}
+
 pub struct GenesisRunner<Handler, Groups, DbDesc>
 where
     DbDesc: DatabaseDescription,
@@ -49,6 +79,16 @@ pub trait ProcessState {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
     ) -> anyhow::Result<()>;
+
+    /// Processes the `group` of the snapshot. The default implementation ignores
+    /// the index of the group and [`ProcessState::process`]es its entries.
+    fn process_group(
+        &mut self,
+        group: Group<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        self.process(group.data, tx)
+    }
 }
 
 impl<Logic, GroupGenerator, DbDesc> GenesisRunner<Logic, GroupGenerator, DbDesc>
@@ -102,13 +142,15 @@ where
     for<'a> StorageTransaction<&'a mut Database<DbDesc>>:
         StorageMutate<GenesisMetadata<DbDesc>, Error = fuel_core_storage::Error>,
 {
-    pub fn run(mut self) -> anyhow::Result<()> {
+    pub fn run(mut self) -> anyhow::Result<TableImportSummary> {
+        let started_at = Instant::now();
         tracing::info!(
             "Starting genesis runner. Reading: {} writing into {}",
             Logic::TableInSnapshot::column().name(),
             Logic::TableBeingWritten::column().name()
         );
         let mut db = self.db;
+        let mut entries = 0usize;
         let result = self
             .groups
             .into_iter()
@@ -117,9 +159,10 @@ where
             .try_for_each(move |group| {
                 let group = group?;
                 let group_num = group.index;
+                let group_entries = group.data.len();
 
                 let mut tx = db.write_transaction();
-                self.handler.process(group.data, &mut tx)?;
+                self.handler.process_group(group, &mut tx)?;
 
                 GenesisProgressMutate::<DbDesc>::update_genesis_progress(
                     &mut tx,
@@ -127,6 +170,7 @@ where
                     group_num,
                 )?;
                 tx.commit()?;
+                entries = entries.saturating_add(group_entries);
                 Ok(())
             });
 
@@ -140,7 +184,11 @@ where
             Logic::TableBeingWritten::column().name()
         );
 
-        result
+        result.map(|_| TableImportSummary {
+            table: Logic::TableBeingWritten::column().name().to_string(),
+            entries,
+            duration: started_at.elapsed(),
+        })
     }
 }
 
@@ -149,6 +197,7 @@ mod tests {
     use crate::database::genesis_progress::GenesisProgressInspect;
     use std::{
         sync::{
+            atomic::AtomicBool,
             Arc,
             Mutex,
         },
@@ -612,6 +661,21 @@ mod tests {
         ) -> StorageResult<()> {
             Err(anyhow::anyhow!("I refuse to work!").into())
         }
+
+        fn size(&self) -> StorageResult<u64> {
+            unimplemented!()
+        }
+
+        fn compact(&self, _: &AtomicBool) -> StorageResult<()> {
+            unimplemented!()
+        }
+
+        fn checkpoint(
+            &self,
+        ) -> StorageResult<Arc<dyn TransactableStorage<BlockHeight, Column = Self::Column>>>
+        {
+            unimplemented!()
+        }
     }
 
     #[test]
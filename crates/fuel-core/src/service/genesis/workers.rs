@@ -1,18 +1,34 @@
 use super::{
-    runner::ProcessState,
+    runner::{
+        ProcessState,
+        TableImportSummary,
+    },
     GenesisRunner,
 };
 use std::{
-    collections::HashMap,
+    any::{
+        Any,
+        TypeId,
+    },
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    fmt,
     marker::PhantomData,
+    num::NonZeroUsize,
+    str::FromStr,
     sync::Arc,
 };
 
 use crate::{
     combined_database::CombinedDatabase,
-    database::database_description::{
-        off_chain::OffChain,
-        on_chain::OnChain,
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+        Database,
     },
     graphql_api::storage::{
         coins::OwnedCoins,
@@ -26,8 +42,11 @@ use crate::{
 };
 use fuel_core_chain_config::{
     AsTable,
+    ContractStateChunkRoot,
+    Group,
     SnapshotReader,
     StateConfig,
+    TableEntry,
 };
 use fuel_core_storage::{
     kv_store::StorageColumn,
@@ -41,41 +60,229 @@ use fuel_core_storage::{
         Messages,
         Transactions,
     },
+    Mappable,
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Bytes32,
+    },
 };
 use tokio::sync::Notify;
-use tokio_rayon::AsyncRayonHandle;
+use tokio_rayon::{
+    rayon::{
+        ThreadPool,
+        ThreadPoolBuilder,
+    },
+    AsyncRayonHandle,
+    AsyncThreadPool,
+};
 use tokio_util::sync::CancellationToken;
 
+/// The transformation of the entries of the table `T` applied before their import.
+pub type Transformation<T> = Arc<dyn Fn(TableEntry<T>) -> TableEntry<T> + Send + Sync>;
+
+/// The transformations of the genesis entries per table of the snapshot.
+/// The entries of the tables without a transformation are imported as they are.
+///
+/// The transformed entries are verified the same way as the entries of the snapshot,
+/// e.g., the coin can't point to the block from the future.
+#[derive(Default, Clone)]
+pub struct GenesisTransformations {
+    transformations: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl GenesisTransformations {
+    /// Sets the `transformation` of the entries of the table `T`,
+    /// replacing the previous one.
+    pub fn with<T, F>(mut self, transformation: F) -> Self
+    where
+        T: Mappable + 'static,
+        F: Fn(TableEntry<T>) -> TableEntry<T> + Send + Sync + 'static,
+    {
+        let transformation: Transformation<T> = Arc::new(transformation);
+        self.transformations
+            .insert(TypeId::of::<T>(), Arc::new(transformation));
+        self
+    }
+
+    /// Returns the transformation of the entries of the table `T`, if any.
+    pub fn get<T>(&self) -> Option<Transformation<T>>
+    where
+        T: Mappable + 'static,
+    {
+        self.transformations
+            .get(&TypeId::of::<T>())
+            .and_then(|transformation| transformation.downcast_ref::<Transformation<T>>())
+            .cloned()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transformations.is_empty()
+    }
+}
+
+impl fmt::Debug for GenesisTransformations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenesisTransformations")
+            .field("tables", &self.transformations.len())
+            .finish()
+    }
+}
+
+/// Defines how the genesis import treats the entries of the snapshot
+/// that already exist in the database, e.g., after merging of the snapshots.
+///
+/// The policy applies to the coins, messages, contract codes and contract utxos.
+/// The off-chain index of the coins is built from the coins imported on-chain,
+/// while the other off-chain indexes are built from all entries of the snapshot.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DuplicatePolicy {
+    /// Fails the genesis import.
+    #[default]
+    Error,
+    /// Keeps the existing entry and skips the duplicate with a warning.
+    Skip,
+    /// Replaces the existing entry with the duplicate with a warning.
+    Overwrite,
+}
+
+impl fmt::Display for DuplicatePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DuplicatePolicy::Error => f.write_str("error"),
+            DuplicatePolicy::Skip => f.write_str("skip"),
+            DuplicatePolicy::Overwrite => f.write_str("overwrite"),
+        }
+    }
+}
+
+impl FromStr for DuplicatePolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(DuplicatePolicy::Error),
+            "skip" => Ok(DuplicatePolicy::Skip),
+            "overwrite" => Ok(DuplicatePolicy::Overwrite),
+            _ => Err(anyhow::anyhow!(
+                "The duplicate policy should be `error`, `skip` or `overwrite`, got `{s}`"
+            )),
+        }
+    }
+}
+
 pub struct GenesisWorkers {
     db: CombinedDatabase,
     cancel_token: CancellationToken,
     block_height: BlockHeight,
     da_block_height: DaBlockHeight,
+    max_message_data_length: u64,
+    coin_owners: Option<Arc<HashSet<Address>>>,
+    contract_state_roots: Arc<ContractStateRoots>,
+    duplicate_policy: DuplicatePolicy,
+    transformations: GenesisTransformations,
     snapshot_reader: SnapshotReader,
     finished_signals: HashMap<String, Arc<Notify>>,
+    /// The dedicated pool for the workers. If `None`, the global rayon pool is used,
+    /// which has a thread per available CPU.
+    thread_pool: Option<ThreadPool>,
 }
 
 impl GenesisWorkers {
-    pub fn new(db: CombinedDatabase, snapshot_reader: SnapshotReader) -> Self {
+    /// Creates the workers that import the snapshot. Each table is imported
+    /// sequentially by its own worker, so the `workers` only limit how many
+    /// tables are imported in parallel.
+    pub fn new(
+        db: CombinedDatabase,
+        snapshot_reader: SnapshotReader,
+        workers: Option<NonZeroUsize>,
+    ) -> anyhow::Result<Self> {
         let block_height = snapshot_reader.block_height();
         let da_block_height = snapshot_reader.da_block_height();
-        Self {
+        let max_message_data_length = snapshot_reader
+            .chain_config()
+            .consensus_parameters
+            .predicate_params()
+            .max_message_data_length();
+        let thread_pool = workers
+            .map(|workers| {
+                ThreadPoolBuilder::new()
+                    .num_threads(workers.get())
+                    .thread_name(|index| format!("genesis-worker-{index}"))
+                    .build()
+            })
+            .transpose()?;
+        let mut contract_state_roots = ContractStateRoots::new();
+        for chunk_root in snapshot_reader.expected_contract_state_roots() {
+            contract_state_roots
+                .entry(chunk_root.group_index)
+                .or_default()
+                .push(chunk_root.clone());
+        }
+        Ok(Self {
             db,
             cancel_token: CancellationToken::new(),
             block_height,
             da_block_height,
+            max_message_data_length,
+            coin_owners: None,
+            contract_state_roots: Arc::new(contract_state_roots),
+            duplicate_policy: DuplicatePolicy::default(),
+            transformations: GenesisTransformations::default(),
             snapshot_reader,
             finished_signals: HashMap::default(),
+            thread_pool,
+        })
+    }
+
+    /// Overrides the maximum length of the data of the imported messages,
+    /// which defaults to the limit of the consensus parameters.
+    pub fn with_max_message_data_length(mut self, max_message_data_length: u64) -> Self {
+        self.max_message_data_length = max_message_data_length;
+        self
+    }
+
+    /// Imports only the coins owned by the `coin_owners`, skipping the rest.
+    /// It is used to create the reduced databases for test networks.
+    pub fn with_coin_owners(mut self, coin_owners: HashSet<Address>) -> Self {
+        self.coin_owners = Some(Arc::new(coin_owners));
+        self
+    }
+
+    /// Sets how the entries that already exist in the database are treated.
+    pub fn with_duplicate_policy(mut self, duplicate_policy: DuplicatePolicy) -> Self {
+        self.duplicate_policy = duplicate_policy;
+        self
+    }
+
+    /// Transforms the entries of the snapshot before their import.
+    /// It is used to modify the state for tests, e.g., to scale the balances.
+    pub fn with_transformations(
+        mut self,
+        transformations: GenesisTransformations,
+    ) -> Self {
+        if transformations.get::<ContractsState>().is_some()
+            && !self.contract_state_roots.is_empty()
+        {
+            tracing::warn!(
+                "Skipped the verification of the contract state chunks, \
+                since the contract states of the snapshot are transformed"
+            );
+            self.contract_state_roots = Default::default();
         }
+        self.transformations = transformations;
+        self
     }
 
-    pub async fn run_on_chain_imports(&mut self) -> anyhow::Result<()> {
+    /// Imports the on-chain tables of the snapshot and returns the summary of each table.
+    pub async fn run_on_chain_imports(
+        &mut self,
+    ) -> anyhow::Result<Vec<TableImportSummary>> {
         tracing::info!("Running on-chain imports");
-        tokio::try_join!(
+        let summaries = tokio::try_join!(
             self.spawn_worker_on_chain::<Coins>()?,
             self.spawn_worker_on_chain::<Messages>()?,
             self.spawn_worker_on_chain::<ContractsRawCode>()?,
@@ -83,8 +290,16 @@ impl GenesisWorkers {
             self.spawn_worker_on_chain::<ContractsState>()?,
             self.spawn_worker_on_chain::<ContractsAssets>()?,
             self.spawn_worker_on_chain::<Transactions>()?,
-        )
-        .map(|_| ())
+        )?;
+        Ok(vec![
+            summaries.0,
+            summaries.1,
+            summaries.2,
+            summaries.3,
+            summaries.4,
+            summaries.5,
+            summaries.6,
+        ])
     }
 
     pub async fn run_off_chain_imports(&mut self) -> anyhow::Result<()> {
@@ -100,6 +315,39 @@ impl GenesisWorkers {
         .map(|_| ())
     }
 
+    /// Compares the root of the imported on-chain state with the root expected by the
+    /// snapshot. Does nothing if the snapshot doesn't specify the expected root.
+    /// The verification is skipped if the coins are filtered by the owners
+    /// or the entries are transformed, since the imported state differs from the snapshot.
+    pub fn verify_state_root(&self) -> anyhow::Result<()> {
+        if let Some(expected) = self.snapshot_reader.expected_state_root() {
+            if self.coin_owners.is_some() {
+                tracing::warn!(
+                    "Skipped the verification of the genesis state root, \
+                    since the coins are filtered by the owners"
+                );
+                return Ok(())
+            }
+            if !self.transformations.is_empty() {
+                tracing::warn!(
+                    "Skipped the verification of the genesis state root, \
+                    since the entries of the snapshot are transformed"
+                );
+                return Ok(())
+            }
+            let actual: Bytes32 = self.db.on_chain().genesis_state_root()?.into();
+            if actual != expected {
+                return Err(anyhow::anyhow!(
+                    "The state root after the genesis import {actual} doesn't match the \
+                    expected state root {expected} from the snapshot"
+                ));
+            }
+            tracing::info!("Verified the genesis state root {actual}");
+        }
+
+        Ok(())
+    }
+
     pub async fn finished(&self) {
         for signal in self.finished_signals.values() {
             signal.notified().await;
@@ -112,7 +360,7 @@ impl GenesisWorkers {
 
     pub fn spawn_worker_on_chain<T>(
         &mut self,
-    ) -> anyhow::Result<AsyncRayonHandle<anyhow::Result<()>>>
+    ) -> anyhow::Result<AsyncRayonHandle<anyhow::Result<TableImportSummary>>>
     where
         T: TableWithBlueprint + Send + 'static,
         T::OwnedKey: serde::de::DeserializeOwned + Send,
@@ -120,23 +368,23 @@ impl GenesisWorkers {
         StateConfig: AsTable<T>,
         Handler<T>: ProcessState<TableInSnapshot = T, DbDesc = OnChain>,
     {
-        let groups = self.snapshot_reader.read::<T>()?;
+        let groups = self.transform::<T, _>(self.snapshot_reader.read::<T>()?);
         let finished_signal = self.get_signal(T::column().name());
 
         let runner = GenesisRunner::new(
             Some(finished_signal),
             self.cancel_token.clone(),
-            Handler::new(self.block_height, self.da_block_height),
+            Handler::new(self.handler_config()),
             groups,
             self.db.on_chain().clone(),
         );
-        Ok(tokio_rayon::spawn(move || runner.run()))
+        Ok(self.spawn(move || runner.run()))
     }
 
     // TODO: serde bounds can be written shorter
     pub fn spawn_worker_off_chain<TableInSnapshot, TableBeingWritten>(
         &mut self,
-    ) -> anyhow::Result<AsyncRayonHandle<anyhow::Result<()>>>
+    ) -> anyhow::Result<AsyncRayonHandle<anyhow::Result<TableImportSummary>>>
     where
         TableInSnapshot: TableWithBlueprint + Send + 'static,
         TableInSnapshot::OwnedKey: serde::de::DeserializeOwned + Send,
@@ -146,16 +394,64 @@ impl GenesisWorkers {
             ProcessState<TableInSnapshot = TableInSnapshot, DbDesc = OffChain>,
         TableBeingWritten: Send + 'static,
     {
-        let groups = self.snapshot_reader.read::<TableInSnapshot>()?;
+        let groups = self.transform::<TableInSnapshot, _>(
+            self.snapshot_reader.read::<TableInSnapshot>()?,
+        );
         let finished_signal = self.get_signal(TableInSnapshot::column().name());
         let runner = GenesisRunner::new(
             Some(finished_signal),
             self.cancel_token.clone(),
-            Handler::<TableBeingWritten>::new(self.block_height, self.da_block_height),
+            Handler::<TableBeingWritten>::new(self.handler_config()),
             groups,
             self.db.off_chain().clone(),
         );
-        Ok(tokio_rayon::spawn(move || runner.run()))
+        Ok(self.spawn(move || runner.run()))
+    }
+
+    fn handler_config(&self) -> HandlerConfig {
+        HandlerConfig {
+            block_height: self.block_height,
+            da_block_height: self.da_block_height,
+            max_message_data_length: self.max_message_data_length,
+            coin_owners: self.coin_owners.clone(),
+            contract_state_roots: self.contract_state_roots.clone(),
+            duplicate_policy: self.duplicate_policy,
+            on_chain: self.db.on_chain().clone(),
+        }
+    }
+
+    /// Applies the transformation of the table `T` to the entries of the `groups`.
+    fn transform<T, Groups>(
+        &self,
+        groups: Groups,
+    ) -> impl Iterator<Item = anyhow::Result<Group<TableEntry<T>>>> + Send
+    where
+        T: Mappable + 'static,
+        Groups: IntoIterator<Item = anyhow::Result<Group<TableEntry<T>>>>,
+        Groups::IntoIter: Send,
+    {
+        let transformation = self.transformations.get::<T>();
+        groups.into_iter().map(move |group| {
+            let mut group = group?;
+            if let Some(transformation) = &transformation {
+                group.data = group
+                    .data
+                    .into_iter()
+                    .map(transformation.as_ref())
+                    .collect();
+            }
+            Ok(group)
+        })
+    }
+
+    fn spawn<F>(&self, runner: F) -> AsyncRayonHandle<anyhow::Result<TableImportSummary>>
+    where
+        F: FnOnce() -> anyhow::Result<TableImportSummary> + Send + 'static,
+    {
+        match &self.thread_pool {
+            Some(thread_pool) => thread_pool.spawn_async(runner),
+            None => tokio_rayon::spawn(runner),
+        }
     }
 
     fn get_signal(&mut self, name: &str) -> Arc<Notify> {
@@ -166,19 +462,44 @@ impl GenesisWorkers {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct Handler<T> {
+/// The expected roots of the contract states per index of the group of the `ContractsState` table.
+pub type ContractStateRoots = HashMap<usize, Vec<ContractStateChunkRoot>>;
+
+/// The parameters of the genesis import shared by the handlers of all tables.
+#[derive(Debug, Clone)]
+pub struct HandlerConfig {
     pub block_height: BlockHeight,
     pub da_block_height: DaBlockHeight,
+    pub max_message_data_length: u64,
+    /// If set, only the coins owned by these addresses are imported.
+    pub coin_owners: Option<Arc<HashSet<Address>>>,
+    /// The contract state roots verified after the import of each group.
+    pub contract_state_roots: Arc<ContractStateRoots>,
+    pub duplicate_policy: DuplicatePolicy,
+    /// The on-chain database. The off-chain handlers read the on-chain entries
+    /// that have won over their duplicates from it.
+    pub on_chain: Database<OnChain>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Handler<T> {
+    pub config: HandlerConfig,
     pub phaton_data: PhantomData<T>,
 }
 
 impl<T> Handler<T> {
-    pub fn new(block_height: BlockHeight, da_block_height: DaBlockHeight) -> Self {
+    pub fn new(config: HandlerConfig) -> Self {
         Self {
-            block_height,
-            da_block_height,
+            config,
             phaton_data: PhantomData,
         }
     }
+
+    /// Returns `true` if the coin of the `owner` should be imported.
+    pub fn is_coin_imported(&self, owner: &Address) -> bool {
+        match &self.config.coin_owners {
+            Some(coin_owners) => coin_owners.contains(owner),
+            None => true,
+        }
+    }
 }
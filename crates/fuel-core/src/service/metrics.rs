@@ -2,9 +2,111 @@ use axum::{
     body::Body,
     http::Request,
     response::IntoResponse,
+    routing::get,
+    Router,
 };
 use fuel_core_metrics::response::encode_metrics_response;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use std::{
+    future::Future,
+    net::{
+        SocketAddr,
+        TcpListener,
+    },
+    pin::Pin,
+};
 
 pub async fn metrics(_req: Request<Body>) -> impl IntoResponse {
     encode_metrics_response()
 }
+
+/// The service that serves metrics on a dedicated listener, separately from the API.
+pub type Service = fuel_core_services::ServiceRunner<MetricsService>;
+
+#[derive(Clone)]
+pub struct SharedState {
+    pub bound_address: SocketAddr,
+}
+
+pub struct MetricsService {
+    bound_address: SocketAddr,
+}
+
+pub struct ServerParams {
+    router: Router,
+    listener: TcpListener,
+}
+
+pub struct Task {
+    server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send + 'static>>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for MetricsService {
+    const NAME: &'static str = "Metrics";
+
+    type SharedData = SharedState;
+    type Task = Task;
+    type TaskParams = ServerParams;
+
+    fn shared_data(&self) -> Self::SharedData {
+        SharedState {
+            bound_address: self.bound_address,
+        }
+    }
+
+    async fn into_task(
+        self,
+        state: &StateWatcher,
+        params: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let mut state = state.clone();
+        let ServerParams { router, listener } = params;
+
+        let server = axum::Server::from_tcp(listener)?
+            .serve(router.into_make_service())
+            .with_graceful_shutdown(async move {
+                state
+                    .while_started()
+                    .await
+                    .expect("The service is destroyed");
+            });
+
+        Ok(Task {
+            server: Box::pin(server),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
+        self.server.as_mut().await?;
+        // The `axum::Server` has its internal loop. If `await` is finished, we get an internal
+        // error or stop signal.
+        Ok(false /* should_continue */)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // The `axum::Server` was already gracefully shutdown at this point.
+        Ok(())
+    }
+}
+
+pub fn new_service(addr: SocketAddr) -> anyhow::Result<Service> {
+    let router = Router::new().route("/v1/metrics", get(metrics));
+
+    let listener = TcpListener::bind(addr)?;
+    let bound_address = listener.local_addr()?;
+
+    tracing::info!("Binding metrics provider to {}", bound_address);
+
+    Ok(Service::new_with_params(
+        MetricsService { bound_address },
+        ServerParams { router, listener },
+    ))
+}
@@ -0,0 +1,341 @@
+//! Periodically compacts the database to reduce the read amplification.
+//!
+//! The compaction is requested after every `every_blocks` imported blocks
+//! and/or runs once per day during the window of low traffic.
+//! It is done on a blocking thread and doesn't block the block production.
+//! Each compaction rewrites every column of every database, so it may take
+//! a long time on the big databases.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    service::adapters::BlockImporterAdapter,
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::services::block_importer::SharedImportResult;
+use futures::StreamExt;
+use std::{
+    num::NonZeroU32,
+    str::FromStr,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+    time::{
+        Duration,
+        Instant,
+        SystemTime,
+    },
+};
+use tokio::task::JoinHandle;
+
+/// How often the task checks whether it is inside the compaction window.
+const WINDOW_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+const SECONDS_PER_HOUR: u64 = 3600;
+const HOURS_PER_DAY: u64 = 24;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The compaction is requested after every `every_blocks` imported blocks.
+    pub every_blocks: Option<NonZeroU32>,
+    /// The compaction runs only inside the window. Without `every_blocks`,
+    /// it runs once per window.
+    pub window: Option<CompactionWindow>,
+}
+
+/// The window of the UTC hours `[start_hour, end_hour)`.
+/// The window wraps around midnight if `start_hour > end_hour`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompactionWindow {
+    pub start_hour: u8,
+    pub end_hour: u8,
+}
+
+impl CompactionWindow {
+    pub fn contains(&self, hour: u8) -> bool {
+        if self.start_hour <= self.end_hour {
+            self.start_hour <= hour && hour < self.end_hour
+        } else {
+            self.start_hour <= hour || hour < self.end_hour
+        }
+    }
+
+    /// Returns the day when the window containing the `time` has started.
+    fn started_on(&self, time: UtcTime) -> u64 {
+        let wrapped = self.start_hour > self.end_hour && time.hour < self.end_hour;
+        if wrapped {
+            time.day.saturating_sub(1)
+        } else {
+            time.day
+        }
+    }
+}
+
+impl FromStr for CompactionWindow {
+    type Err = anyhow::Error;
+
+    /// Parses the window in the `<start_hour>-<end_hour>` format, like `22-4`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| anyhow::anyhow!("expected `<start_hour>-<end_hour>`"))?;
+        let window = Self {
+            start_hour: start.trim().parse()?,
+            end_hour: end.trim().parse()?,
+        };
+        if u64::from(window.start_hour.max(window.end_hour)) >= HOURS_PER_DAY {
+            anyhow::bail!("the hours should be in the range 0-23");
+        }
+        if window.start_hour == window.end_hour {
+            anyhow::bail!("the window should not be empty");
+        }
+        Ok(window)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct UtcTime {
+    /// The number of days since the UNIX epoch.
+    day: u64,
+    hour: u8,
+}
+
+impl UtcTime {
+    fn now() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let hours = seconds.checked_div(SECONDS_PER_HOUR).unwrap_or_default();
+        Self {
+            day: hours.checked_div(HOURS_PER_DAY).unwrap_or_default(),
+            hour: hours
+                .checked_rem(HOURS_PER_DAY)
+                .and_then(|hour| u8::try_from(hour).ok())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+pub type Service = ServiceRunner<Task>;
+
+pub struct Task {
+    config: Config,
+    database: CombinedDatabase,
+    block_importer: BoxStream<SharedImportResult>,
+    /// The number of blocks imported since the last compaction has started.
+    blocks_since_compaction: u32,
+    /// The day when the window of the last compaction has started.
+    last_compaction_day: Option<u64>,
+    /// The compaction running in the background, if any.
+    compaction: Option<JoinHandle<()>>,
+    /// Stops the running compaction before the next column on the shutdown.
+    stop: Arc<AtomicBool>,
+}
+
+impl Task {
+    fn should_compact(&self, now: UtcTime) -> bool {
+        if let Some(window) = &self.config.window {
+            if !window.contains(now.hour) {
+                return false
+            }
+        }
+
+        match (self.config.every_blocks, &self.config.window) {
+            (Some(every_blocks), _) => self.blocks_since_compaction >= every_blocks.get(),
+            (None, Some(window)) => {
+                self.last_compaction_day != Some(window.started_on(now))
+            }
+            (None, None) => false,
+        }
+    }
+
+    fn try_compact(&mut self, now: UtcTime) {
+        if !self.should_compact(now) {
+            return
+        }
+
+        if let Some(compaction) = &self.compaction {
+            if !compaction.is_finished() {
+                return
+            }
+        }
+
+        self.blocks_since_compaction = 0;
+        self.last_compaction_day =
+            self.config.window.map(|window| window.started_on(now));
+
+        let database = self.database.clone();
+        let stop = self.stop.clone();
+        // The compaction rewrites the files of the database, so it is done
+        // on a blocking thread to not delay the import of the next blocks.
+        self.compaction = Some(tokio::task::spawn_blocking(move || {
+            let start = Instant::now();
+            match database.compact(&stop) {
+                Ok(()) if stop.load(Ordering::Relaxed) => {
+                    tracing::info!("Stopped the compaction of the database on shutdown")
+                }
+                Ok(()) => {
+                    tracing::info!("Compacted the database in {:?}", start.elapsed())
+                }
+                Err(err) => tracing::error!("Failed to compact the database: {err:?}"),
+            }
+        }));
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "PeriodicCompaction";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.block_importer.next() => {
+                if result.is_some() {
+                    self.blocks_since_compaction =
+                        self.blocks_since_compaction.saturating_add(1);
+                    self.try_compact(UtcTime::now());
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+
+            _ = tokio::time::sleep(WINDOW_CHECK_INTERVAL) => {
+                self.try_compact(UtcTime::now());
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // The compaction of the current column can't be interrupted, so the running
+        // compaction is not awaited. It stops before the next column, and leaves
+        // the database consistent even if the node exits earlier.
+        self.stop.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    config: Config,
+    database: CombinedDatabase,
+    block_importer: &BlockImporterAdapter,
+) -> Service {
+    ServiceRunner::new(Task {
+        config,
+        database,
+        block_importer: block_importer.events(),
+        blocks_since_compaction: 0,
+        last_compaction_day: None,
+        compaction: None,
+        stop: Arc::new(AtomicBool::new(false)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_services::stream::IntoBoxStream;
+
+    fn task(every_blocks: Option<u32>, window: Option<&str>) -> Task {
+        Task {
+            config: Config {
+                every_blocks: every_blocks.and_then(NonZeroU32::new),
+                window: window.map(|window| window.parse().unwrap()),
+            },
+            database: CombinedDatabase::in_memory(),
+            block_importer: futures::stream::empty().into_boxed(),
+            blocks_since_compaction: 0,
+            last_compaction_day: None,
+            compaction: None,
+            stop: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn at(day: u64, hour: u8) -> UtcTime {
+        UtcTime { day, hour }
+    }
+
+    #[test]
+    fn window_is_parsed_and_wraps_around_midnight() {
+        let window: CompactionWindow = "22-4".parse().unwrap();
+
+        assert!(window.contains(22));
+        assert!(window.contains(0));
+        assert!(window.contains(3));
+        assert!(!window.contains(4));
+        assert!(!window.contains(12));
+        assert!("4-4".parse::<CompactionWindow>().is_err());
+        assert!("22-24".parse::<CompactionWindow>().is_err());
+        assert!("22".parse::<CompactionWindow>().is_err());
+    }
+
+    #[test]
+    fn compaction_is_requested_after_every_blocks() {
+        let mut task = task(Some(10), None);
+
+        task.blocks_since_compaction = 9;
+        assert!(!task.should_compact(at(0, 12)));
+
+        task.blocks_since_compaction = 10;
+        assert!(task.should_compact(at(0, 12)));
+    }
+
+    #[test]
+    fn compaction_after_every_blocks_waits_for_the_window() {
+        let mut task = task(Some(10), Some("1-3"));
+        task.blocks_since_compaction = 10;
+
+        assert!(!task.should_compact(at(0, 12)));
+        assert!(task.should_compact(at(0, 2)));
+    }
+
+    #[test]
+    fn compaction_in_the_window_runs_once_per_window() {
+        let mut task = task(None, Some("22-4"));
+        assert!(!task.should_compact(at(0, 12)));
+        assert!(task.should_compact(at(0, 23)));
+
+        task.last_compaction_day =
+            Some(task.config.window.unwrap().started_on(at(0, 23)));
+        assert!(!task.should_compact(at(1, 1)));
+        assert!(task.should_compact(at(1, 22)));
+    }
+}
@@ -0,0 +1,268 @@
+//! Periodically exports the state snapshot of the node for backups.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    service::adapters::BlockImporterAdapter,
+};
+use fuel_core_chain_config::{
+    ChainConfig,
+    SnapshotWriter,
+    MAX_GROUP_SIZE,
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_storage::transactional::AtomicView;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::block_importer::SharedImportResult,
+};
+use futures::StreamExt;
+use std::{
+    num::{
+        NonZeroU32,
+        NonZeroUsize,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+use tokio::task::JoinHandle;
+
+/// The prefix of the snapshot directories, followed by the block height of the snapshot.
+pub const SNAPSHOT_DIR_PREFIX: &str = "snapshot_";
+
+/// The suffix of the snapshot directory while the snapshot is being written.
+const INCOMPLETE_SNAPSHOT_SUFFIX: &str = ".incomplete";
+
+/// How many times the checkpoint is taken until the on-chain and off-chain
+/// databases are at the same height.
+const CHECKPOINT_ATTEMPTS: usize = 10;
+
+/// The delay before retaking the checkpoint.
+const CHECKPOINT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The snapshot is created after the import of each block
+    /// with the height divisible by the interval.
+    pub interval: NonZeroU32,
+    /// The directory of the snapshots. Each snapshot is written into its own
+    /// `snapshot_<height>` sub-directory.
+    pub directory: PathBuf,
+    /// The number of the most recent snapshots to keep. Older snapshots are removed.
+    pub retention: NonZeroUsize,
+}
+
+pub type Service = ServiceRunner<Task>;
+
+pub struct Task {
+    config: Config,
+    chain_config: ChainConfig,
+    database: CombinedDatabase,
+    block_importer: BoxStream<SharedImportResult>,
+    /// The export running in the background, if any.
+    export: Option<JoinHandle<()>>,
+}
+
+impl Task {
+    fn on_block(&mut self, height: BlockHeight) {
+        if u32::from(height).checked_rem(self.config.interval.get()) != Some(0) {
+            return
+        }
+
+        if let Some(export) = &self.export {
+            if !export.is_finished() {
+                tracing::warn!(
+                    "Skipping the snapshot at the height {height}, \
+                    because the previous snapshot is still being written"
+                );
+                return
+            }
+        }
+
+        let config = self.config.clone();
+        let chain_config = self.chain_config.clone();
+        let database = self.database.clone();
+        // The export reads the whole database, so it is done on a blocking thread
+        // to not delay the import of the next blocks.
+        self.export = Some(tokio::task::spawn_blocking(move || {
+            match export_snapshot(&config, &chain_config, &database) {
+                Ok((snapshot_height, dir)) => {
+                    tracing::info!(
+                        "Created the snapshot at the height {snapshot_height} in {dir:?}"
+                    )
+                }
+                Err(err) => tracing::error!(
+                    "Failed to create the snapshot requested at the height {height}: {err:?}"
+                ),
+            }
+        }));
+    }
+}
+
+/// Writes the snapshot of the `database` into the `snapshot_<height>` directory
+/// and removes the snapshots above the retention.
+///
+/// The snapshot is read from the checkpoint of the `database`, so the blocks
+/// imported during the export don't affect it. The block height of the snapshot
+/// is the height of the checkpoint, which may be above the requested height
+/// if the next blocks were imported before the checkpoint was taken.
+fn export_snapshot(
+    config: &Config,
+    chain_config: &ChainConfig,
+    database: &CombinedDatabase,
+) -> anyhow::Result<(BlockHeight, PathBuf)> {
+    let (checkpoint, height) = checkpoint_at_same_height(database)?;
+
+    let name = format!("{SNAPSHOT_DIR_PREFIX}{height}");
+    let dir = config.directory.join(&name);
+    let incomplete_dir = config
+        .directory
+        .join(format!("{name}{INCOMPLETE_SNAPSHOT_SUFFIX}"));
+
+    if incomplete_dir.exists() {
+        std::fs::remove_dir_all(&incomplete_dir)?;
+    }
+    std::fs::create_dir_all(&incomplete_dir)?;
+
+    let mut writer = SnapshotWriter::json(&incomplete_dir);
+    writer.write_chain_config(chain_config)?;
+    checkpoint.write_snapshot(&mut writer, MAX_GROUP_SIZE)?;
+    writer.close()?;
+
+    // The snapshot becomes visible only when it is complete.
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    std::fs::rename(&incomplete_dir, &dir)?;
+
+    remove_old_snapshots(&config.directory, config.retention)?;
+
+    Ok((height, dir))
+}
+
+/// Takes the checkpoint of the `database` with the on-chain and off-chain
+/// databases at the same height. The off-chain database is updated after
+/// the on-chain one, so the checkpoint is retaken until it catches up.
+fn checkpoint_at_same_height(
+    database: &CombinedDatabase,
+) -> anyhow::Result<(CombinedDatabase, BlockHeight)> {
+    for _ in 0..CHECKPOINT_ATTEMPTS {
+        let checkpoint = database.checkpoint()?;
+        let on_chain_height = checkpoint.on_chain().latest_height();
+        let off_chain_height = checkpoint.off_chain().latest_height();
+        match (on_chain_height, off_chain_height) {
+            (Some(on_chain_height), Some(off_chain_height))
+                if on_chain_height == off_chain_height =>
+            {
+                return Ok((checkpoint, on_chain_height))
+            }
+            _ => std::thread::sleep(CHECKPOINT_RETRY_DELAY),
+        }
+    }
+    anyhow::bail!(
+        "The off-chain database didn't reach the height of the on-chain database \
+        after {CHECKPOINT_ATTEMPTS} attempts"
+    )
+}
+
+/// Removes the oldest complete snapshots in the `directory`, keeping the `retention` newest ones.
+fn remove_old_snapshots(directory: &Path, retention: NonZeroUsize) -> anyhow::Result<()> {
+    let mut snapshots = vec![];
+    for entry in std::fs::read_dir(directory)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        let height = file_name
+            .to_str()
+            .and_then(|name| name.strip_prefix(SNAPSHOT_DIR_PREFIX))
+            .and_then(|height| height.parse::<u32>().ok());
+        if let Some(height) = height {
+            snapshots.push((height, entry.path()));
+        }
+    }
+
+    snapshots.sort_by_key(|(height, _)| *height);
+    let outdated = snapshots.len().saturating_sub(retention.get());
+    for (_, path) in snapshots.into_iter().take(outdated) {
+        std::fs::remove_dir_all(&path)?;
+        tracing::info!("Removed the outdated snapshot {path:?}");
+    }
+
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "PeriodicSnapshot";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        std::fs::create_dir_all(&self.config.directory)?;
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.block_importer.next() => {
+                if let Some(result) = result {
+                    self.on_block(*result.sealed_block.entity.header().height());
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Let the snapshot in progress finish to not leave it incomplete.
+        if let Some(export) = self.export {
+            export.await?;
+        }
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    config: Config,
+    chain_config: ChainConfig,
+    database: CombinedDatabase,
+    block_importer: &BlockImporterAdapter,
+) -> Service {
+    ServiceRunner::new(Task {
+        config,
+        chain_config,
+        database,
+        block_importer: block_importer.events(),
+        export: None,
+    })
+}
@@ -7,7 +7,7 @@ use fuel_core_types::{
         UniqueIdentifier,
     },
     fuel_types::Bytes32,
-    services::txpool::InsertionResult,
+    services::txpool::InsertionOutcome,
 };
 use futures::{
     Stream,
@@ -23,7 +23,7 @@ use super::*;
 
 impl FuelService {
     /// Submit a transaction to the txpool.
-    pub async fn submit(&self, tx: Transaction) -> anyhow::Result<InsertionResult> {
+    pub async fn submit(&self, tx: Transaction) -> anyhow::Result<InsertionOutcome> {
         let results: Vec<_> = self
             .shared
             .txpool_shared_state
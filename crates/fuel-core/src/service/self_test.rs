@@ -0,0 +1,71 @@
+//! The self-test of the executor against the state of the node before serving.
+
+use crate::service::adapters::BlockProducerAdapter;
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        AssetId,
+        Input,
+        Transaction,
+        TransactionBuilder,
+        UtxoId,
+    },
+    services::executor::TransactionExecutionResult,
+};
+
+/// The gas limit of the script of the self-test transaction.
+const SELF_TEST_SCRIPT_GAS_LIMIT: u64 = 10_000;
+
+/// The amount of the coin paying for the self-test transaction.
+/// The coin doesn't exist, so the dry run is done without the UTXO validation.
+const SELF_TEST_COIN_AMOUNT: u64 = u32::MAX as u64;
+
+/// The minimal transaction that returns `1` from the script.
+fn self_test_transaction(base_asset_id: AssetId) -> Transaction {
+    let predicate: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+    let owner = Input::predicate_owner(&predicate);
+    let coin = Input::coin_predicate(
+        UtxoId::default(),
+        owner,
+        SELF_TEST_COIN_AMOUNT,
+        base_asset_id,
+        Default::default(),
+        Default::default(),
+        predicate,
+        vec![],
+    );
+
+    TransactionBuilder::script([op::ret(RegId::ONE)].into_iter().collect(), vec![])
+        .script_gas_limit(SELF_TEST_SCRIPT_GAS_LIMIT)
+        .max_fee_limit(SELF_TEST_COIN_AMOUNT)
+        .add_input(coin)
+        .finalize_as_transaction()
+}
+
+/// Dry-runs the minimal transaction on top of the latest block and fails
+/// if the executor can't execute it successfully. It catches the misconfiguration
+/// of the executor before the node starts serving.
+pub async fn run(
+    block_producer: &BlockProducerAdapter,
+    base_asset_id: AssetId,
+) -> anyhow::Result<()> {
+    let transaction = self_test_transaction(base_asset_id);
+    let statuses = block_producer
+        .block_producer
+        .dry_run(vec![transaction], None, Some(false))
+        .await
+        .map_err(|err| err.context("The executor self-test failed"))?;
+
+    match statuses.first().map(|status| &status.result) {
+        Some(TransactionExecutionResult::Success { .. }) => Ok(()),
+        Some(TransactionExecutionResult::Failed { result, .. }) => Err(anyhow::anyhow!(
+            "The executor self-test failed: the transaction failed with {result:?}"
+        )),
+        None => Err(anyhow::anyhow!(
+            "The executor self-test failed: the transaction wasn't executed"
+        )),
+    }
+}
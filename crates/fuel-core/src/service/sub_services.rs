@@ -21,12 +21,18 @@ use crate::{
         },
         Config,
         SharedState,
+        StartupReport,
         SubServices,
     },
 };
 use fuel_core_poa::Trigger;
+use fuel_core_types::{
+    fuel_types::ContractId,
+    services::txpool::ValidationCache,
+};
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tokio_rayon::rayon::ThreadPoolBuilder;
 
 #[cfg(feature = "relayer")]
 use crate::relayer::Config as RelayerConfig;
@@ -61,19 +67,71 @@ pub fn init_sub_services(
         });
     let last_height = *last_block_header.height();
 
+    if let Some(expected_genesis_id) = config.expected_genesis_id {
+        let genesis_id = database
+            .on_chain()
+            .get_genesis_block()?
+            .map(|block| block.header().id())
+            .unwrap_or_else(|| create_genesis_block(config).header().id());
+        if genesis_id != expected_genesis_id {
+            return Err(anyhow::anyhow!(
+                "The genesis block id {genesis_id:#x} doesn't match the expected \
+                genesis block id {expected_genesis_id:#x}. Check that the snapshot \
+                and the database belong to the expected network"
+            ))
+        }
+    }
+
+    if config.block_producer.coinbase_recipient == Some(ContractId::zeroed()) {
+        return Err(anyhow::anyhow!(
+            "The coinbase recipient can't be the zero `ContractId`, \
+            since the fees minted to it are burned"
+        ))
+    }
+
+    verify_vm_limit_overrides(config)?;
+
+    let validation_cache = config.tx_validation_cache.then(ValidationCache::default);
+
+    let execution_pool = config
+        .dedicated_execution_threads
+        .map(|threads| {
+            ThreadPoolBuilder::new()
+                .num_threads(threads.get())
+                .thread_name(|index| format!("execution-{index}"))
+                .build()
+                .map(Arc::new)
+        })
+        .transpose()?;
+
     let executor = ExecutorAdapter::new(
         database.on_chain().clone(),
         database.relayer().clone(),
         fuel_core_upgradable_executor::config::Config {
             backtrace: config.vm.backtrace,
             utxo_validation_default: config.utxo_validation,
+            parallel_execution_workers: config.parallel_execution_workers,
+            max_call_depth: config.vm.max_call_depth,
+            max_inputs: config.vm.max_inputs,
+            max_outputs: config.vm.max_outputs,
+            max_tx_execution_time: config.vm.max_tx_execution_time,
+            max_tx_memory: config.vm.max_tx_memory,
+            validation_cache: validation_cache.clone(),
+            record_storage_access: config.vm.record_storage_access,
+            // The breakdown is only recorded in the debug mode with backtraces,
+            // since it slows down the dry run.
+            record_gas_breakdown: config.debug && config.vm.backtrace,
         },
     );
 
     let verifier = VerifierAdapter::new(config, database.on_chain().clone());
 
     let importer_adapter = BlockImporterAdapter::new(
-        config.block_importer.clone(),
+        fuel_core_importer::Config {
+            execution_pool: execution_pool.clone(),
+            ..config.block_importer.clone()
+        },
+        config.max_reorg_depth,
         database.on_chain().clone(),
         executor.clone(),
         verifier.clone(),
@@ -134,17 +192,25 @@ pub fn init_sub_services(
 
     let gas_price_provider = StaticGasPrice::new(config.static_gas_price);
     let txpool = fuel_core_txpool::new_service(
-        config.txpool.clone(),
+        fuel_core_txpool::Config {
+            validation_cache,
+            ..config.txpool.clone()
+        },
         database.on_chain().clone(),
         importer_adapter.clone(),
         p2p_adapter.clone(),
         last_height,
         gas_price_provider.clone(),
+        database.off_chain().clone(),
     );
     let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
 
     let block_producer = fuel_core_producer::Producer {
-        config: config.block_producer.clone(),
+        config: fuel_core_producer::Config {
+            execution_pool,
+            max_block_size_bytes: config.max_block_size_bytes,
+            ..config.block_producer.clone()
+        },
         view_provider: database.on_chain().clone(),
         txpool: tx_pool_adapter.clone(),
         executor: Arc::new(executor),
@@ -182,30 +248,48 @@ pub fn init_sub_services(
         super::adapters::ConsensusAdapter::new(
             verifier.clone(),
             config.relayer_consensus_config.clone(),
-            relayer_adapter,
+            relayer_adapter.clone(),
         ),
         config.sync,
     )?;
+    // The sync only runs along with the P2P network.
+    #[cfg(feature = "p2p")]
+    let sync_shared = network.as_ref().map(|_| sync.shared.clone());
 
     // TODO: Figure out on how to move it into `fuel-core-graphql-api`.
     let chain_config = config.snapshot_reader.chain_config();
+    let startup_report = StartupReport::new(vec![]);
     let schema = crate::schema::dap::init(
         build_schema(),
         chain_config.consensus_parameters.clone(),
         config.debug,
     )
-    .data(database.on_chain().clone());
+    .data(database.on_chain().clone())
+    .data(database.off_chain().clone())
+    .data(startup_report.clone());
+    #[cfg(feature = "p2p")]
+    let schema = schema.data(sync_shared.clone());
 
     let graphql_worker = fuel_core_graphql_api::worker_service::new_service(
         tx_pool_adapter.clone(),
         importer_adapter.clone(),
         database.off_chain().clone(),
-        chain_config.consensus_parameters.chain_id(),
+        chain_config.consensus_parameters.clone(),
+        config.graphql_worker_parallelism,
+        config.graphql_worker_retry,
     );
 
     let chain_config = config.snapshot_reader.chain_config();
+    let mut asset_metadata = config.api_asset_metadata.clone();
+    if let Some(base_asset_metadata) = &config.api_base_asset_metadata {
+        asset_metadata.insert(
+            *chain_config.consensus_parameters.base_asset_id(),
+            base_asset_metadata.clone(),
+        );
+    }
     let graphql_config = GraphQLConfig {
         addr: config.addr,
+        expose_metrics: config.metrics_addr.is_none(),
         utxo_validation: config.utxo_validation,
         debug: config.debug,
         vm_backtrace: config.vm.backtrace,
@@ -213,7 +297,26 @@ pub fn init_sub_services(
         max_depth: config.txpool.max_depth,
         chain_name: chain_config.chain_name.clone(),
         consensus_parameters: chain_config.consensus_parameters.clone(),
+        chain_config: chain_config.clone(),
         consensus_key: config.consensus_key.clone(),
+        rate_limit: config.api_rate_limit.clone(),
+        enable_introspection: config.api_enable_introspection,
+        disable_mutations: config.api_disable_mutations,
+        keep_alive: config.api_keep_alive,
+        idle_timeout: config.api_idle_timeout,
+        websocket_keep_alive: config.api_websocket_keep_alive,
+        shutdown_grace_period: config.api_shutdown_grace_period,
+        finality_depth: config.api_finality_depth,
+        max_assets_per_query: config.api_max_assets_per_query,
+        max_receipts_per_transaction: config.api_max_receipts_per_transaction,
+        max_call_depth: config.vm.max_call_depth,
+        max_inputs: config.vm.max_inputs,
+        max_outputs: config.vm.max_outputs,
+        max_gas_per_predicate: config.txpool.max_gas_per_predicate,
+        max_predicate_gas_per_tx: config.txpool.max_predicate_gas_per_tx,
+        cors: config.api_cors.clone(),
+        simulation_limit: config.api_simulation_limit.clone(),
+        asset_metadata,
     };
 
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
@@ -222,25 +325,54 @@ pub fn init_sub_services(
         database.on_chain().clone(),
         database.off_chain().clone(),
         Box::new(tx_pool_adapter),
-        Box::new(producer_adapter),
+        Box::new(producer_adapter.clone()),
         Box::new(poa_adapter.clone()),
         Box::new(p2p_adapter),
+        Box::new(relayer_adapter),
         Box::new(gas_price_provider),
         config.query_log_threshold_time,
         config.api_request_timeout,
     )?;
 
+    let metrics = config
+        .metrics_addr
+        .map(crate::service::metrics::new_service)
+        .transpose()?;
+
+    let periodic_snapshot = config.periodic_snapshot.clone().map(|snapshot_config| {
+        crate::service::periodic_snapshot::new_service(
+            snapshot_config,
+            chain_config.clone(),
+            database.clone(),
+            &importer_adapter,
+        )
+    });
+
+    let periodic_compaction =
+        config.periodic_compaction.clone().map(|compaction_config| {
+            crate::service::periodic_compaction::new_service(
+                compaction_config,
+                database.clone(),
+                &importer_adapter,
+            )
+        });
+
     let shared = SharedState {
         poa_adapter,
         txpool_shared_state: txpool.shared.clone(),
         #[cfg(feature = "p2p")]
         network: network.as_ref().map(|n| n.shared.clone()),
+        #[cfg(feature = "p2p")]
+        sync: sync_shared,
         #[cfg(feature = "relayer")]
         relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
         graph_ql: graph_ql.shared.clone(),
+        metrics: metrics.as_ref().map(|m| m.shared.clone()),
         database,
         block_importer: importer_adapter,
+        block_producer: producer_adapter,
         config: config.clone(),
+        startup_report,
     };
 
     #[allow(unused_mut)]
@@ -251,6 +383,10 @@ pub fn init_sub_services(
         Box::new(txpool),
     ];
 
+    if let Some(metrics) = metrics {
+        services.push(Box::new(metrics));
+    }
+
     if let Some(poa) = poa {
         services.push(Box::new(poa));
     }
@@ -270,5 +406,51 @@ pub fn init_sub_services(
 
     services.push(Box::new(graphql_worker));
 
+    if let Some(periodic_snapshot) = periodic_snapshot {
+        services.push(Box::new(periodic_snapshot));
+    }
+
+    if let Some(periodic_compaction) = periodic_compaction {
+        services.push(Box::new(periodic_compaction));
+    }
+
     Ok((services, shared))
 }
+
+/// Fails if the limits of the VM are overridden without `debug` enabled.
+/// The overrides diverge from the consensus rules, so the node may produce
+/// blocks rejected by other nodes.
+fn verify_vm_limit_overrides(config: &Config) -> anyhow::Result<()> {
+    let vm = &config.vm;
+    let overrides = [
+        vm.max_call_depth
+            .map(|depth| format!("max call depth {depth}")),
+        vm.max_inputs.map(|inputs| format!("max inputs {inputs}")),
+        vm.max_outputs
+            .map(|outputs| format!("max outputs {outputs}")),
+        vm.max_tx_execution_time
+            .map(|time| format!("max execution time {time:?}")),
+        vm.max_tx_memory
+            .map(|memory| format!("max memory {memory} bytes")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    if overrides.is_empty() {
+        return Ok(())
+    }
+
+    let overrides = overrides.join(", ");
+    if !config.debug {
+        return Err(anyhow::anyhow!(
+            "The overrides of the VM limits ({overrides}) require `debug` \
+            to be enabled, since they diverge from the consensus rules"
+        ))
+    }
+    tracing::warn!(
+        "The limits of the VM are overridden ({overrides}), \
+        diverging from the consensus rules"
+    );
+    Ok(())
+}
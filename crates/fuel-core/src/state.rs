@@ -18,9 +18,13 @@ use fuel_core_storage::{
 };
 use std::{
     fmt::Debug,
-    sync::Arc,
+    sync::{
+        atomic::AtomicBool,
+        Arc,
+    },
 };
 
+pub mod deduplicated_bytecode;
 pub mod in_memory;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
@@ -38,6 +42,22 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
         height: Option<Height>,
         changes: Changes,
     ) -> StorageResult<()>;
+
+    /// Returns the approximate size of the stored data in bytes.
+    fn size(&self) -> StorageResult<u64>;
+
+    /// Compacts the stored data to reduce the number of reads per lookup.
+    /// It blocks the calling thread until the compaction is done,
+    /// but doesn't block the concurrent reads and writes.
+    /// The columns are compacted one by one, and the compaction stops
+    /// before the next column once the `stop` flag is set.
+    fn compact(&self, stop: &AtomicBool) -> StorageResult<()>;
+
+    /// Creates the read-only copy of the stored data at the moment of the call.
+    /// The commits done after the call are not visible in the copy.
+    fn checkpoint(
+        &self,
+    ) -> StorageResult<Arc<dyn TransactableStorage<Height, Column = Self::Column>>>;
 }
 
 // It is used only to allow conversion of the `StorageTransaction` into the `DataSource`.
@@ -50,6 +70,20 @@ where
     fn commit_changes(&self, _: Option<Height>, _: Changes) -> StorageResult<()> {
         unimplemented!()
     }
+
+    fn size(&self) -> StorageResult<u64> {
+        unimplemented!()
+    }
+
+    fn compact(&self, _: &AtomicBool) -> StorageResult<()> {
+        unimplemented!()
+    }
+
+    fn checkpoint(
+        &self,
+    ) -> StorageResult<Arc<dyn TransactableStorage<Height, Column = Self::Column>>> {
+        unimplemented!()
+    }
 }
 
 /// A type that allows to iterate over the `Changes`.
@@ -0,0 +1,280 @@
+use crate::{
+    database::{
+        database_description::on_chain::OnChain,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        DataSource,
+        TransactableStorage,
+    },
+};
+use fuel_core_storage::{
+    column::Column,
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    transactional::Changes,
+    Error as StorageError,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::BlockHeight,
+};
+use std::sync::{
+    atomic::AtomicBool,
+    Arc,
+};
+
+/// The key in the [`Column::Metadata`] of the bytecode deduplication mode
+/// the database was created with.
+const DEDUPLICATION_MODE_KEY: &[u8] = b"bytecode_deduplication";
+
+/// The tag of the values in the [`Column::ContractsRawCode`] that refer
+/// to the bytecode by its hash.
+const REFERENCE_TAG: u8 = 1;
+
+/// The storage that keeps identical contract bytecode only once.
+///
+/// The bytecode is stored in the [`Column::ContractsBytecode`] under its hash,
+/// while the [`Column::ContractsRawCode`] keeps the tagged hash of the bytecode
+/// per contract id. The hashes are resolved into the bytecode on reads, so
+/// the deduplication is transparent for the tables above.
+///
+/// The mode can't be changed for the existing database, see [`check_mode`].
+///
+/// The bytecode is not removed when the contract is removed, since other
+/// contracts may still refer to it.
+#[derive(Debug)]
+pub struct DeduplicatedBytecode {
+    inner: DataSource<OnChain>,
+}
+
+impl DeduplicatedBytecode {
+    pub fn new(inner: DataSource<OnChain>) -> Self {
+        Self { inner }
+    }
+
+    /// Resolves the reference stored in the [`Column::ContractsRawCode`] into the bytecode.
+    fn resolve(&self, value: Value) -> StorageResult<Value> {
+        let Some((&REFERENCE_TAG, hash)) = value.split_first() else {
+            return Err(StorageError::Other(anyhow::anyhow!(
+                "The contract bytecode is not a reference to the deduplicated bytecode"
+            )))
+        };
+        self.inner
+            .as_ref()
+            .get(hash, Column::ContractsBytecode)?
+            .ok_or(StorageError::NotFound(
+                "ContractsBytecode",
+                "DeduplicatedBytecode::resolve",
+            ))
+    }
+}
+
+impl KeyValueInspect for DeduplicatedBytecode {
+    type Column = Column;
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        self.inner.as_ref().exists(key, column)
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        let value = self.inner.as_ref().get(key, column)?;
+        match column {
+            Column::ContractsRawCode => {
+                value.map(|value| self.resolve(value)).transpose()
+            }
+            _ => Ok(value),
+        }
+    }
+}
+
+impl IterableStore for DeduplicatedBytecode {
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let iter = self
+            .inner
+            .as_ref()
+            .iter_store(column, prefix, start, direction);
+        match column {
+            Column::ContractsRawCode => iter
+                .map(|item| {
+                    let (key, value) = item?;
+                    Ok((key, self.resolve(value)?))
+                })
+                .into_boxed(),
+            _ => iter,
+        }
+    }
+}
+
+impl TransactableStorage<BlockHeight> for DeduplicatedBytecode {
+    fn commit_changes(
+        &self,
+        height: Option<BlockHeight>,
+        mut changes: Changes,
+    ) -> StorageResult<()> {
+        if let Some(raw_code) = changes.get_mut(&Column::ContractsRawCode.id()) {
+            let mut bytecode = std::collections::BTreeMap::new();
+            for operation in raw_code.values_mut() {
+                if let WriteOperation::Insert(code) = operation {
+                    let hash = Hasher::hash(code.as_slice());
+                    let mut reference = vec![REFERENCE_TAG];
+                    reference.extend_from_slice(hash.as_ref());
+                    let reference = Arc::new(reference);
+                    let code = core::mem::replace(code, reference);
+                    bytecode.insert(hash.to_vec(), WriteOperation::Insert(code));
+                }
+            }
+            changes
+                .entry(Column::ContractsBytecode.id())
+                .or_default()
+                .extend(bytecode);
+        }
+        self.inner.commit_changes(height, changes)
+    }
+
+    fn size(&self) -> StorageResult<u64> {
+        self.inner.size()
+    }
+
+    fn compact(&self, stop: &AtomicBool) -> StorageResult<()> {
+        self.inner.compact(stop)
+    }
+
+    fn checkpoint(
+        &self,
+    ) -> StorageResult<Arc<dyn TransactableStorage<BlockHeight, Column = Self::Column>>>
+    {
+        Ok(Arc::new(Self::new(self.inner.checkpoint()?)))
+    }
+}
+
+/// Checks that the database is opened with the bytecode deduplication mode it was
+/// created with, and records the mode on the first start. The databases created
+/// before the mode was recorded don't deduplicate the bytecode.
+pub fn check_mode(
+    data_source: &DataSource<OnChain>,
+    deduplicate: bool,
+    is_new_database: bool,
+) -> DatabaseResult<()> {
+    let stored = data_source
+        .as_ref()
+        .get(DEDUPLICATION_MODE_KEY, Column::Metadata)
+        .map_err(anyhow::Error::from)?
+        .map(|mode| mode.as_slice() == [u8::from(true)]);
+    let stored = match stored {
+        Some(stored) => stored,
+        None => {
+            let stored = deduplicate && is_new_database;
+            let mut changes = Changes::default();
+            changes.entry(Column::Metadata.id()).or_default().insert(
+                DEDUPLICATION_MODE_KEY.to_vec(),
+                WriteOperation::Insert(Arc::new(vec![u8::from(stored)])),
+            );
+            data_source
+                .commit_changes(None, changes)
+                .map_err(anyhow::Error::from)?;
+            stored
+        }
+    };
+
+    if stored != deduplicate {
+        return Err(DatabaseError::BytecodeDeduplicationMismatch {
+            stored,
+            requested: deduplicate,
+        })
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use fuel_core_storage::{
+        tables::ContractsRawCode,
+        transactional::WriteTransaction,
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        fuel_tx::Contract,
+        fuel_types::ContractId,
+    };
+
+    #[test]
+    fn identical_bytecode_is_stored_once() {
+        // Given
+        let mut db = Database::<OnChain>::in_memory().with_deduplicated_bytecode();
+        let code = vec![1u8; 100];
+        let first = ContractId::from([1; 32]);
+        let second = ContractId::from([2; 32]);
+
+        // When
+        let mut tx = db.write_transaction();
+        tx.storage_as_mut::<ContractsRawCode>()
+            .insert(&first, code.as_slice())
+            .unwrap();
+        tx.storage_as_mut::<ContractsRawCode>()
+            .insert(&second, code.as_slice())
+            .unwrap();
+        tx.commit().unwrap();
+
+        // Then
+        let stored_bytecode = db
+            .iter_store(
+                Column::ContractsBytecode,
+                None,
+                None,
+                IterDirection::Forward,
+            )
+            .collect::<Vec<_>>();
+        assert_eq!(stored_bytecode.len(), 1);
+        for contract_id in [first, second] {
+            let stored = db
+                .storage::<ContractsRawCode>()
+                .get(&contract_id)
+                .unwrap()
+                .unwrap();
+            assert_eq!(stored.into_owned(), Contract::from(code.clone()));
+        }
+    }
+
+    #[test]
+    fn database_refuses_another_deduplication_mode() {
+        // Given
+        let db = Database::<OnChain>::in_memory();
+        db.check_bytecode_deduplication(true).unwrap();
+
+        // When
+        let result = db.check_bytecode_deduplication(false);
+
+        // Then
+        assert!(matches!(
+            result,
+            Err(DatabaseError::BytecodeDeduplicationMismatch {
+                stored: true,
+                requested: false,
+            })
+        ));
+        db.check_bytecode_deduplication(true).unwrap();
+    }
+}
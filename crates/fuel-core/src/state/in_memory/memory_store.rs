@@ -28,7 +28,11 @@ use fuel_core_storage::{
 use std::{
     collections::BTreeMap,
     fmt::Debug,
-    sync::Mutex,
+    sync::{
+        atomic::AtomicBool,
+        Arc,
+        Mutex,
+    },
 };
 
 #[derive(Debug)]
@@ -137,6 +141,51 @@ where
         }
         Ok(())
     }
+
+    fn size(&self) -> StorageResult<u64> {
+        let mut size = 0u64;
+        for column in self.inner.iter() {
+            let lock = column
+                .lock()
+                .map_err(|e| anyhow::anyhow!("The lock is poisoned: {}", e))?;
+            for (key, value) in lock.iter() {
+                size = size
+                    .saturating_add(key.len() as u64)
+                    .saturating_add(value.len() as u64);
+            }
+        }
+        Ok(size)
+    }
+
+    fn compact(&self, _: &AtomicBool) -> StorageResult<()> {
+        // The in-memory storage has nothing to compact.
+        Ok(())
+    }
+
+    fn checkpoint(
+        &self,
+    ) -> StorageResult<
+        Arc<dyn TransactableStorage<Description::Height, Column = Self::Column>>,
+    > {
+        // All columns are locked before copying to not observe a partial commit.
+        let locks = self
+            .inner
+            .iter()
+            .map(|column| {
+                column
+                    .lock()
+                    .map_err(|e| anyhow::anyhow!("The lock is poisoned: {}", e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let inner = locks
+            .iter()
+            .map(|column| Mutex::new(BTreeMap::clone(column)))
+            .collect();
+        Ok(Arc::new(Self {
+            inner,
+            _marker: Default::default(),
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use crate::{
+    combined_database::DbSyncPolicy,
     database::{
         convert_to_rocksdb_direction,
         database_description::DatabaseDescription,
@@ -29,10 +30,12 @@ use fuel_core_storage::{
 };
 use rand::RngCore;
 use rocksdb::{
+    checkpoint::Checkpoint,
     BlockBasedOptions,
     BoundColumnFamily,
     Cache,
     ColumnFamilyDescriptor,
+    CompactOptions,
     DBCompressionType,
     DBWithThreadMode,
     IteratorMode,
@@ -41,6 +44,7 @@ use rocksdb::{
     ReadOptions,
     SliceTransform,
     WriteBatch,
+    WriteOptions,
 };
 use std::{
     cmp,
@@ -55,7 +59,14 @@ use std::{
         Path,
         PathBuf,
     },
-    sync::Arc,
+    sync::{
+        atomic::{
+            AtomicBool,
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+    },
 };
 use tempfile::TempDir;
 
@@ -77,9 +88,15 @@ impl Default for ShallowTempDir {
 impl ShallowTempDir {
     /// Creates a random directory.
     pub fn new() -> Self {
+        Self::new_in(env::temp_dir())
+    }
+
+    /// Creates a random directory inside of the `parent` directory.
+    pub fn new_in<P: AsRef<Path>>(parent: P) -> Self {
         let mut rng = rand::thread_rng();
-        let mut path = env::temp_dir();
-        path.push(format!("fuel-core-shallow-{}", rng.next_u64()));
+        let path = parent
+            .as_ref()
+            .join(format!("fuel-core-shallow-{}", rng.next_u64()));
         Self { path }
     }
 
@@ -128,6 +145,9 @@ impl Drop for DropResources {
 #[derive(Debug)]
 pub struct RocksDb<Description> {
     db: DB,
+    sync_policy: DbSyncPolicy,
+    /// The number of blocks committed since the database was opened.
+    committed_blocks: AtomicU64,
     // used for RAII
     _drop: DropResources,
     _marker: core::marker::PhantomData<Description>,
@@ -144,6 +164,8 @@ where
             path,
             enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
             capacity,
+            // The temporary database is removed on drop, so there is nothing to sync.
+            DbSyncPolicy::Never,
         );
         let mut db = result?;
 
@@ -161,11 +183,13 @@ where
     pub fn default_open<P: AsRef<Path>>(
         path: P,
         capacity: Option<usize>,
+        sync_policy: DbSyncPolicy,
     ) -> DatabaseResult<Self> {
         Self::open(
             path,
             enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
             capacity,
+            sync_policy,
         )
     }
 
@@ -180,6 +204,7 @@ where
         path: P,
         columns: Vec<Description::Column>,
         capacity: Option<usize>,
+        sync_policy: DbSyncPolicy,
     ) -> DatabaseResult<Self> {
         let path = path.as_ref().join(Description::name());
         let mut block_opts = BlockBasedOptions::default();
@@ -259,6 +284,8 @@ where
         .map_err(|e| DatabaseError::Other(e.into()))?;
         let rocks_db = RocksDb {
             db,
+            sync_policy,
+            committed_blocks: AtomicU64::new(0),
             _drop: Default::default(),
             _marker: Default::default(),
         };
@@ -515,7 +542,7 @@ where
 {
     fn commit_changes(
         &self,
-        _: Option<Description::Height>,
+        height: Option<Description::Height>,
         changes: Changes,
     ) -> StorageResult<()> {
         let mut batch = WriteBatch::default();
@@ -539,10 +566,94 @@ where
             .bytes_written
             .observe(batch.size_in_bytes() as f64);
 
+        // Only commits of new blocks are synced, other changes are
+        // persisted together with the next synced block.
+        let sync = height.is_some() && {
+            let block_number = self
+                .committed_blocks
+                .fetch_add(1, Ordering::Relaxed)
+                .wrapping_add(1);
+            self.sync_policy.should_sync(block_number)
+        };
+        let mut write_options = WriteOptions::default();
+        write_options.set_sync(sync);
+
         self.db
-            .write(batch)
+            .write_opt(batch, &write_options)
             .map_err(|e| DatabaseError::Other(e.into()).into())
     }
+
+    fn size(&self) -> StorageResult<u64> {
+        let mut size = 0u64;
+        for column in enum_iterator::all::<Description::Column>() {
+            let cf = self.cf(column);
+            for property in [
+                "rocksdb.total-sst-files-size",
+                "rocksdb.size-all-mem-tables",
+            ] {
+                let value = self
+                    .db
+                    .property_int_value_cf(&cf, property)
+                    .map_err(|e| DatabaseError::Other(e.into()))?
+                    .unwrap_or_default();
+                size = size.saturating_add(value);
+            }
+        }
+        Ok(size)
+    }
+
+    fn compact(&self, stop: &AtomicBool) -> StorageResult<()> {
+        let mut opts = CompactOptions::default();
+        // Let the automatic compactions run during the manual one
+        // to not stall the writes of the new blocks.
+        opts.set_exclusive_manual_compaction(false);
+        for column in enum_iterator::all::<Description::Column>() {
+            // The compaction of the column can't be interrupted,
+            // so the flag is checked between the columns.
+            if stop.load(Ordering::Relaxed) {
+                break
+            }
+            let cf = self.cf(column);
+            self.db
+                .compact_range_cf_opt(&cf, None::<&[u8]>, None::<&[u8]>, &opts);
+        }
+        Ok(())
+    }
+
+    fn checkpoint(
+        &self,
+    ) -> StorageResult<
+        Arc<dyn TransactableStorage<Description::Height, Column = Self::Column>>,
+    > {
+        // The checkpoint is created next to the database, so the files are
+        // hard-linked instead of being copied.
+        let parent = self.db.path().parent().unwrap_or(self.db.path());
+        let tmp_dir = ShallowTempDir::new_in(parent);
+        std::fs::create_dir_all(tmp_dir.path())
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Checkpoint::new(&self.db)
+            .and_then(|checkpoint| {
+                checkpoint.create_checkpoint(tmp_dir.path().join(Description::name()))
+            })
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+
+        let mut db = Self::open(
+            tmp_dir.path(),
+            enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
+            None,
+            // The checkpoint is removed on drop, so there is nothing to sync.
+            DbSyncPolicy::Never,
+        )?;
+        db._drop = {
+            move || {
+                // cleanup the checkpoint
+                drop(tmp_dir);
+            }
+        }
+        .into();
+
+        Ok(Arc::new(db))
+    }
 }
 
 /// The `None` means overflow, so there is not following prefix.
@@ -558,6 +669,8 @@ fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::arithmetic_side_effects)]
+
     use super::*;
     use crate::database::database_description::on_chain::OnChain;
     use fuel_core_storage::{
@@ -601,7 +714,7 @@ mod tests {
     fn create_db() -> (RocksDb<OnChain>, TempDir) {
         let tmp_dir = TempDir::new().unwrap();
         (
-            RocksDb::default_open(tmp_dir.path(), None).unwrap(),
+            RocksDb::default_open(tmp_dir.path(), None, DbSyncPolicy::Always).unwrap(),
             tmp_dir,
         )
     }
@@ -762,4 +875,127 @@ mod tests {
 
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
+
+    fn insert_and_flush(db: &RocksDb<OnChain>, keys: std::ops::Range<u32>) {
+        let ops = keys
+            .map(|i| {
+                (
+                    i.to_be_bytes().to_vec(),
+                    WriteOperation::Insert(Arc::new(i.to_le_bytes().to_vec())),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        let changes = HashMap::from_iter([(Column::Metadata.id(), ops)]);
+        db.commit_changes(Default::default(), changes).unwrap();
+        db.db.flush_cf(&db.cf(Column::Metadata)).unwrap();
+    }
+
+    fn files_at_level0(db: &RocksDb<OnChain>) -> u64 {
+        db.db
+            .property_int_value_cf(
+                &db.cf(Column::Metadata),
+                "rocksdb.num-files-at-level0",
+            )
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn compact_reduces_read_amplification() {
+        // Given
+        let (db, _tmp) = create_db();
+        // Each flush creates a new overlapping file at the level 0,
+        // and every lookup has to check all of them.
+        // Staying below the trigger of the automatic compaction.
+        for round in 0..3u32 {
+            insert_and_flush(&db, round * 100..(round + 2) * 100);
+        }
+        assert_eq!(files_at_level0(&db), 3);
+
+        // When
+        db.compact(&AtomicBool::new(false)).unwrap();
+
+        // Then
+        assert_eq!(files_at_level0(&db), 0);
+        for i in 0..400u32 {
+            assert_eq!(
+                db.get(&i.to_be_bytes(), Column::Metadata).unwrap(),
+                Some(Arc::new(i.to_le_bytes().to_vec()))
+            );
+        }
+    }
+
+    #[test]
+    fn compact_does_nothing_once_stopped() {
+        // Given
+        let (db, _tmp) = create_db();
+        for round in 0..3u32 {
+            insert_and_flush(&db, round * 100..(round + 2) * 100);
+        }
+        let stop = AtomicBool::new(true);
+
+        // When
+        db.compact(&stop).unwrap();
+
+        // Then
+        assert_eq!(files_at_level0(&db), 3);
+    }
+
+    #[test]
+    fn compact_does_not_interfere_with_concurrent_reads_and_writes() {
+        // Given
+        let (db, _tmp) = create_db();
+        insert_and_flush(&db, 0..1000);
+
+        // When
+        std::thread::scope(|scope| {
+            let compaction = scope.spawn(|| {
+                for _ in 0..10 {
+                    db.compact(&AtomicBool::new(false)).unwrap();
+                }
+            });
+            for round in 1..10u32 {
+                insert_and_flush(&db, round * 1000..(round + 1) * 1000);
+                for i in (0..(round + 1) * 1000).step_by(97) {
+                    assert_eq!(
+                        db.get(&i.to_be_bytes(), Column::Metadata).unwrap(),
+                        Some(Arc::new(i.to_le_bytes().to_vec()))
+                    );
+                }
+            }
+            compaction.join().unwrap();
+        });
+
+        // Then
+        for i in 0..10_000u32 {
+            assert_eq!(
+                db.get(&i.to_be_bytes(), Column::Metadata).unwrap(),
+                Some(Arc::new(i.to_le_bytes().to_vec()))
+            );
+        }
+    }
+
+    #[test]
+    fn checkpoint_is_not_affected_by_following_commits() {
+        // Given
+        let (mut db, tmp) = create_db();
+        let key = vec![0xA, 0xB, 0xC];
+        db.put(&key, Column::Metadata, Arc::new(vec![1])).unwrap();
+        let checkpoint = db.checkpoint().unwrap();
+
+        // When
+        db.put(&key, Column::Metadata, Arc::new(vec![2])).unwrap();
+
+        // Then
+        assert_eq!(
+            checkpoint.get(&key, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert_eq!(
+            db.get(&key, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![2]))
+        );
+        drop(checkpoint);
+        assert_eq!(std::fs::read_dir(tmp.path()).unwrap().count(), 1);
+    }
 }
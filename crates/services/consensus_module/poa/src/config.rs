@@ -13,6 +13,13 @@ pub struct Config {
     pub consensus_params: ConsensusParameters,
     pub min_connected_reserved_peers: usize,
     pub time_until_synced: Duration,
+    /// If set, the `Interval` trigger doesn't produce empty blocks, except one block
+    /// per `max_idle_time` to keep the block timestamps advancing.
+    pub max_idle_time: Option<Duration>,
+    /// The number of connected peers required to produce blocks.
+    /// The production is paused while the node is connected to fewer peers.
+    /// The manual production requests are rejected while it is paused.
+    pub min_peers_for_production: usize,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -25,6 +32,8 @@ impl Default for Config {
             consensus_params: ConsensusParameters::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            max_idle_time: None,
+            min_peers_for_production: 0,
         }
     }
 }
@@ -100,6 +100,9 @@ pub trait RelayerPort {
 pub trait P2pPort: Send + Sync + 'static {
     /// Subscribe to reserved peers connection updates.
     fn reserved_peers_count(&self) -> BoxStream<usize>;
+
+    /// Subscribe to the updates of the number of all connected peers.
+    fn connected_peers_count(&self) -> BoxStream<usize>;
 }
 
 #[async_trait::async_trait]
@@ -54,6 +54,7 @@ use fuel_core_types::{
     services::{
         block_importer::ImportResult,
         executor::{
+            Event,
             ExecutionResult,
             UncommittedResult as UncommittedExecutionResult,
         },
@@ -140,9 +141,13 @@ pub struct MainTask<T, B, I> {
     last_timestamp: Tai64,
     last_block_created: Instant,
     trigger: Trigger,
+    max_idle_time: Option<Duration>,
     /// Deadline clock, used by the triggers
     timer: DeadlineClock,
     sync_task_handle: ServiceRunner<SyncTask>,
+    min_peers_for_production: usize,
+    connected_peers: usize,
+    connected_peers_stream: BoxStream<usize>,
 }
 
 impl<T, B, I> MainTask<T, B, I>
@@ -165,12 +170,15 @@ where
 
         let block_stream = block_importer.block_stream();
         let peer_connections_stream = p2p_port.reserved_peers_count();
+        let connected_peers_stream = p2p_port.connected_peers_count();
 
         let Config {
             signing_key,
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
+            max_idle_time,
+            min_peers_for_production,
             ..
         } = config;
 
@@ -196,8 +204,12 @@ where
             last_timestamp,
             last_block_created,
             trigger,
+            max_idle_time,
             timer: DeadlineClock::new(),
             sync_task_handle,
+            min_peers_for_production,
+            connected_peers: 0,
+            connected_peers_stream,
         }
     }
 
@@ -346,6 +358,17 @@ where
         }
         self.txpool.remove_txs(tx_ids_to_remove);
 
+        if let (Trigger::Interval { block_time }, RequestType::Trigger) =
+            (self.trigger, &request_type)
+        {
+            if self.is_idle_block_suppressed(&block, &events, last_block_created) {
+                tracing::debug!("Skipping production of the empty block {}", height);
+                let deadline = last_block_created.checked_add(block_time).expect("It is impossible to overflow except in the case where we don't want to produce a block.");
+                self.timer.set_deadline(deadline, OnConflict::Min).await;
+                return Ok(())
+            }
+        }
+
         // Sign the block and seal it
         let seal = seal_block(&self.signing_key, &block)?;
         let block = SealedBlock {
@@ -387,6 +410,23 @@ where
         Ok(())
     }
 
+    /// Returns `true` if the block doesn't contain any transactions except the mint
+    /// and doesn't import any events, while the `max_idle_time` is not reached yet.
+    fn is_idle_block_suppressed(
+        &self,
+        block: &Block,
+        events: &[Event],
+        now: Instant,
+    ) -> bool {
+        let Some(max_idle_time) = self.max_idle_time else {
+            return false
+        };
+        let is_empty =
+            block.transactions().iter().all(|tx| tx.is_mint()) && events.is_empty();
+        let idle_time = now.saturating_duration_since(self.last_block_created);
+        is_empty && idle_time < max_idle_time
+    }
+
     pub(crate) async fn on_txpool_event(&mut self) -> anyhow::Result<()> {
         match self.trigger {
             Trigger::Instant => {
@@ -479,11 +519,47 @@ where
             }
         }
 
+        // pause the production while there are not enough connected peers
+        if self.connected_peers < self.min_peers_for_production {
+            tracing::info!(
+                "Block production is paused: connected to {} peers, but {} are required",
+                self.connected_peers,
+                self.min_peers_for_production
+            );
+        }
+        while self.connected_peers < self.min_peers_for_production {
+            tokio::select! {
+                biased;
+                result = watcher.while_started() => {
+                    should_continue = result?.started();
+                    return Ok(should_continue);
+                }
+                Some(connected_peers) = self.connected_peers_stream.next() => {
+                    self.connected_peers = connected_peers;
+                }
+                Some(request) = self.request_receiver.recv() => {
+                    match request {
+                        Request::ManualBlocks((_, response)) => {
+                            let _ = response.send(Err(anyhow!(
+                                "Block production is paused: connected to {} peers, but {} are required",
+                                self.connected_peers,
+                                self.min_peers_for_production
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+
         tokio::select! {
             biased;
             _ = watcher.while_started() => {
                 should_continue = false;
             }
+            Some(connected_peers) = self.connected_peers_stream.next() => {
+                self.connected_peers = connected_peers;
+                should_continue = true;
+            }
             request = self.request_receiver.recv() => {
                 if let Some(request) = request {
                     match request {
@@ -71,6 +71,7 @@ struct TestContextBuilder {
     txpool: Option<MockTransactionPool>,
     importer: Option<MockBlockImporter>,
     producer: Option<MockBlockProducer>,
+    p2p_port: Option<MockP2pPort>,
 }
 
 fn generate_p2p_port() -> MockP2pPort {
@@ -79,6 +80,9 @@ fn generate_p2p_port() -> MockP2pPort {
     p2p_port
         .expect_reserved_peers_count()
         .returning(move || Box::pin(tokio_stream::pending()));
+    p2p_port
+        .expect_connected_peers_count()
+        .returning(move || Box::pin(tokio_stream::pending()));
 
     p2p_port
 }
@@ -90,6 +94,7 @@ impl TestContextBuilder {
             txpool: None,
             importer: None,
             producer: None,
+            p2p_port: None,
         }
     }
 
@@ -113,6 +118,11 @@ impl TestContextBuilder {
         self
     }
 
+    fn with_p2p_port(&mut self, p2p_port: MockP2pPort) -> &mut Self {
+        self.p2p_port = Some(p2p_port);
+        self
+    }
+
     fn build(self) -> TestContext {
         let config = self.config.unwrap_or_default();
         let producer = self.producer.unwrap_or_else(|| {
@@ -146,7 +156,7 @@ impl TestContextBuilder {
             .txpool
             .unwrap_or_else(MockTransactionPool::no_tx_updates);
 
-        let p2p_port = generate_p2p_port();
+        let p2p_port = self.p2p_port.unwrap_or_else(generate_p2p_port);
 
         let service = new_service(
             &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
@@ -117,3 +117,80 @@ async fn can_manually_produce_block(
     // Stop
     assert_eq!(ctx.stop().await, State::Stopped);
 }
+
+#[tokio::test]
+async fn manual_production_is_rejected_below_min_peers_for_production() {
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(Config {
+        trigger: Trigger::Never,
+        signing_key: Some(test_signing_key()),
+        metrics: false,
+        min_peers_for_production: 2,
+        ..Default::default()
+    });
+
+    let (peers_sender, peers_receiver) = tokio::sync::mpsc::channel(8);
+    let mut p2p_port = MockP2pPort::default();
+    p2p_port
+        .expect_reserved_peers_count()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    p2p_port
+        .expect_connected_peers_count()
+        .return_once(move || {
+            Box::pin(tokio_stream::wrappers::ReceiverStream::new(peers_receiver))
+        });
+    ctx_builder.with_p2p_port(p2p_port);
+
+    let mut importer = MockBlockImporter::default();
+    let (tx, mut rx) = tokio::sync::mpsc::channel(8);
+    importer.expect_commit_result().returning(move |r| {
+        tx.try_send(*r.into_result().sealed_block.entity.header().height())
+            .unwrap();
+        Ok(())
+    });
+    importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    ctx_builder.with_importer(importer);
+    let ctx = ctx_builder.build();
+    let produce_block = || {
+        let shared = ctx.service.shared.clone();
+        tokio::spawn(async move {
+            shared
+                .manually_produce_block(
+                    None,
+                    Mode::Blocks {
+                        number_of_blocks: 1,
+                    },
+                )
+                .await
+        })
+    };
+
+    // Given
+    peers_sender.send(1).await.unwrap();
+    time::sleep(Duration::from_millis(100)).await;
+
+    // Then
+    assert!(produce_block().await.unwrap().is_err());
+    assert!(rx.try_recv().is_err());
+
+    // When
+    peers_sender.send(2).await.unwrap();
+    time::sleep(Duration::from_millis(100)).await;
+
+    // Then
+    produce_block().await.unwrap().unwrap();
+    assert!(rx.recv().await.is_some());
+
+    // When
+    peers_sender.send(1).await.unwrap();
+    time::sleep(Duration::from_millis(100)).await;
+
+    // Then
+    assert!(produce_block().await.unwrap().is_err());
+    assert!(rx.try_recv().is_err());
+
+    // Stop
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
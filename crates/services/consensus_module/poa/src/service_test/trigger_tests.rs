@@ -239,3 +239,53 @@ async fn interval_trigger_doesnt_react_to_full_txpool() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(start_paused = true)]
+async fn interval_trigger_with_max_idle_time_produces_only_heartbeat_blocks(
+) -> anyhow::Result<()> {
+    // Given
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::new(2, 0),
+        },
+        signing_key: Some(test_signing_key()),
+        metrics: false,
+        max_idle_time: Some(Duration::new(9, 0)),
+        ..Default::default()
+    });
+
+    // When
+    time::sleep(Duration::new(9, 0)).await;
+
+    // Then
+    // The producer returns only empty blocks, so nothing is produced while idle.
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // When
+    time::sleep(Duration::new(2, 0)).await;
+
+    // Then
+    // The heartbeat block is produced after the max idle time.
+    assert!(ctx.block_import.try_recv().is_ok());
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // The idle time is counted from the last produced block.
+    time::sleep(Duration::new(8, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    time::sleep(Duration::new(2, 0)).await;
+    assert!(ctx.block_import.try_recv().is_ok());
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
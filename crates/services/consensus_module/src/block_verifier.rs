@@ -13,12 +13,15 @@ use fuel_core_types::{
         primitives::DaBlockHeight,
         SealedBlockHeader,
     },
+    fuel_tx::Transaction,
     fuel_types::{
+        canonical::Serialize,
         BlockHeight,
         Bytes32,
     },
     tai64::Tai64,
 };
+use std::time::Duration;
 
 pub mod config;
 
@@ -65,6 +68,12 @@ where
                 )
             }
             Consensus::PoA(_) => {
+                if let Some(max_timestamp_drift) = self.config.max_timestamp_drift {
+                    verify_block_time(block.header(), Tai64::now(), max_timestamp_drift)?;
+                }
+                if let Some(max_block_size_bytes) = self.config.max_block_size_bytes {
+                    verify_block_size(block.transactions(), max_block_size_bytes)?;
+                }
                 let view = self.view_provider.latest_view();
                 fuel_core_poa::verifier::verify_block_fields(&view, block)
             }
@@ -90,6 +99,42 @@ where
     }
 }
 
+fn verify_block_time(
+    header: &BlockHeader,
+    now: Tai64,
+    max_timestamp_drift: Duration,
+) -> anyhow::Result<()> {
+    let max_time = Tai64(now.0.saturating_add(max_timestamp_drift.as_secs()));
+    ensure!(
+        header.time() <= max_time,
+        "The block time {:?} is too far in the future, the local time is {:?}",
+        header.time(),
+        now
+    );
+    Ok(())
+}
+
+/// Verifies that the total serialized size of the transactions of the block
+/// besides the `Mint` transaction doesn't exceed the `max_block_size_bytes`.
+fn verify_block_size(
+    transactions: &[Transaction],
+    max_block_size_bytes: u64,
+) -> anyhow::Result<()> {
+    let block_size_bytes = transactions
+        .iter()
+        .filter(|tx| !tx.is_mint())
+        .fold(0u64, |size, tx| {
+            size.saturating_add(u64::try_from(tx.size()).unwrap_or(u64::MAX))
+        });
+    ensure!(
+        block_size_bytes <= max_block_size_bytes,
+        "The size of the block transactions {} bytes exceeds the maximum of {} bytes",
+        block_size_bytes,
+        max_block_size_bytes
+    );
+    Ok(())
+}
+
 fn verify_genesis_block_fields(
     expected_genesis_height: BlockHeight,
     expected_genesis_da_height: DaBlockHeight,
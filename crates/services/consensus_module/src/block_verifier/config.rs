@@ -5,6 +5,7 @@ use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     fuel_types::BlockHeight,
 };
+use std::time::Duration;
 
 /// The config of the block verifier.
 pub struct Config {
@@ -14,6 +15,12 @@ pub struct Config {
     pub block_height: BlockHeight,
     /// The DA block height at genesis block.
     pub da_block_height: DaBlockHeight,
+    /// The maximum time the block's timestamp can be ahead of the local time.
+    /// If `None`, the timestamp is not checked against the local time.
+    pub max_timestamp_drift: Option<Duration>,
+    /// The maximum total serialized size of the block's transactions besides
+    /// the `Mint` transaction. If `None`, the size is not checked.
+    pub max_block_size_bytes: Option<u64>,
 }
 
 impl Config {
@@ -27,6 +34,8 @@ impl Config {
             chain_config,
             block_height,
             da_block_height,
+            max_timestamp_drift: None,
+            max_block_size_bytes: None,
         }
     }
 }
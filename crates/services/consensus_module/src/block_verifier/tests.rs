@@ -103,3 +103,32 @@ fn test_verify_genesis_block_fields(
         &header,
     )
 }
+
+#[test_case(0, 0 => matches Ok(_) ; "Block at the local time")]
+#[test_case(5, 10 => matches Ok(_) ; "Block in the future within the tolerance")]
+#[test_case(10, 10 => matches Ok(_) ; "Block in the future at the tolerance")]
+#[test_case(11, 10 => matches Err(_) ; "Block in the future beyond the tolerance")]
+#[test_case(5, 0 => matches Err(_) ; "Block in the future without tolerance")]
+fn test_verify_block_time(
+    seconds_ahead: u64,
+    max_timestamp_drift_secs: u64,
+) -> anyhow::Result<()> {
+    let now = Tai64(1_000_000);
+    let mut header = BlockHeader::default();
+    header.set_time(Tai64(now.0.saturating_add(seconds_ahead)));
+
+    verify_block_time(&header, now, Duration::from_secs(max_timestamp_drift_secs))
+}
+
+#[test_case(0 => matches Ok(_) ; "Block below the size limit")]
+#[test_case(1 => matches Ok(_) ; "Block at the size limit")]
+#[test_case(2 => matches Err(_) ; "Block above the size limit")]
+fn test_verify_block_size(extra_txs: usize) -> anyhow::Result<()> {
+    let tx = Transaction::default();
+    let max_block_size_bytes = u64::try_from(tx.size().saturating_mul(2)).unwrap();
+    // The `Mint` transaction is not counted.
+    let mut transactions = vec![tx; extra_txs.saturating_add(1)];
+    transactions.push(fuel_core_types::fuel_tx::Mint::default().into());
+
+    verify_block_size(&transactions, max_block_size_bytes)
+}
@@ -1,10 +1,12 @@
 use crate::{
+    gas_breakdown::GasBreakdownRecorder,
     ports::{
         MaybeCheckedTransaction,
         RelayerPort,
         TransactionsSource,
     },
     refs::ContractRef,
+    storage_access::StorageAccessRecorder,
 };
 use block_component::*;
 use fuel_core_storage::{
@@ -98,6 +100,7 @@ use fuel_core_types::{
         BlockHeight,
         ContractId,
         MessageId,
+        Nonce,
     },
     fuel_vm,
     fuel_vm::{
@@ -114,7 +117,10 @@ use fuel_core_types::{
             ExecutableTransaction,
             InterpreterParams,
         },
-        state::StateTransition,
+        state::{
+            DebugEval,
+            StateTransition,
+        },
         Backtrace as FuelBacktrace,
         Interpreter,
     },
@@ -134,10 +140,19 @@ use fuel_core_types::{
             UncommittedResult,
         },
         relayer::Event,
+        txpool::ValidationCache,
     },
 };
 use parking_lot::Mutex as ParkingMutex;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    num::NonZeroUsize,
+    time::{
+        Duration,
+        Instant,
+    },
+};
 use tracing::{
     debug,
     warn,
@@ -184,6 +199,42 @@ pub struct ExecutionData {
     event_inbox_root: Bytes32,
 }
 
+impl ExecutionData {
+    /// Appends the result of the transaction executed independently
+    /// from other transactions of the block.
+    fn merge(&mut self, tx_data: ExecutionData) -> ExecutorResult<()> {
+        debug_assert_eq!(self.tx_count, tx_data.tx_count);
+        self.coinbase = self
+            .coinbase
+            .checked_add(tx_data.coinbase)
+            .ok_or(ExecutorError::FeeOverflow)?;
+        self.used_gas = self.used_gas.saturating_add(tx_data.used_gas);
+        self.message_ids.extend(tx_data.message_ids);
+        self.tx_status.extend(tx_data.tx_status);
+        self.events.extend(tx_data.events);
+        self.tx_count = self
+            .tx_count
+            .checked_add(1)
+            .ok_or(ExecutorError::TooManyTransactions)?;
+        Ok(())
+    }
+}
+
+/// The resource of the storage that can be touched by several transactions.
+/// Transactions that share a conflict key are executed sequentially in the same group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ConflictKey {
+    /// The transaction itself or the outputs of the transaction.
+    Transaction(TxId),
+    /// The state, balances and the latest UTXO of the contract.
+    Contract(ContractId),
+    /// The message and its spent status.
+    Message(Nonce),
+}
+
+/// The executed group of transactions with their changes to the storage.
+type ExecutedGroup = (Changes, Vec<(usize, Transaction, ExecutionData)>);
+
 /// Per-block execution options
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug)]
 pub struct ExecutionOptions {
@@ -191,6 +242,71 @@ pub struct ExecutionOptions {
     pub utxo_validation: bool,
     /// Print execution backtraces if transaction execution reverts.
     pub backtrace: bool,
+    /// The number of workers used to execute independent transactions of the block
+    /// in parallel during validation. If `None`, transactions are executed sequentially.
+    ///
+    /// The option is not passed to the WASM executor, so it always executes sequentially.
+    #[serde(skip)]
+    pub parallel_execution_workers: Option<NonZeroUsize>,
+    /// Overrides the maximum depth of nested contract calls. The transaction exceeding
+    /// it fails with [`ExecutorError::CallDepthExceeded`]. It is a debug option that
+    /// diverges from the consensus rules.
+    ///
+    /// The option is not passed to the WASM executor.
+    #[serde(skip)]
+    pub max_call_depth: Option<u64>,
+    /// Overrides the maximum number of inputs of the transaction. The transaction
+    /// exceeding it fails with [`ExecutorError::TooManyInputs`]. It is a debug option
+    /// that diverges from the consensus rules.
+    ///
+    /// The option is not passed to the WASM executor.
+    #[serde(skip)]
+    pub max_inputs: Option<u16>,
+    /// Overrides the maximum number of outputs of the transaction. The transaction
+    /// exceeding it fails with [`ExecutorError::TooManyOutputs`]. It is a debug option
+    /// that diverges from the consensus rules.
+    ///
+    /// The option is not passed to the WASM executor.
+    #[serde(skip)]
+    pub max_outputs: Option<u16>,
+    /// The maximum wall-clock time of the execution of the transaction by the VM.
+    /// The time is checked before each instruction of the script, and the transaction
+    /// exceeding it runs out of gas, so it fails, and its changes are reverted.
+    /// It is a debug option that makes the execution non-deterministic and slows down
+    /// the VM. It is not checked during the validation of the block.
+    ///
+    /// The option is not passed to the WASM executor.
+    #[serde(skip)]
+    pub max_tx_execution_time: Option<Duration>,
+    /// The maximum bytes of the VM memory occupied by the stack and the heap of the
+    /// transaction. The memory is checked before each instruction of the script, and
+    /// the transaction exceeding it runs out of gas, so it fails, and its changes are
+    /// reverted. It is a debug option that diverges from the consensus rules and slows
+    /// down the VM. It is not checked during the validation of the block.
+    ///
+    /// The option is not passed to the WASM executor.
+    #[serde(skip)]
+    pub max_tx_memory: Option<u64>,
+    /// The transactions that already passed the predicates and signatures checks
+    /// at the admission into the `TxPool`. These checks are skipped for them.
+    ///
+    /// The option is not passed to the WASM executor.
+    #[serde(skip)]
+    pub validation_cache: Option<ValidationCache>,
+    /// Records the storage keys read and written by each transaction during the dry run
+    /// into the [`TransactionExecutionStatus::storage_access`]. It slows down the execution,
+    /// since each access to the storage is recorded.
+    ///
+    /// The option is not passed to the WASM executor.
+    #[serde(skip)]
+    pub record_storage_access: bool,
+    /// Records the gas consumed by each opcode of the script during the dry run into
+    /// the [`TransactionExecutionStatus::gas_breakdown`]. It slows down the execution,
+    /// since the VM stops before each instruction of the script.
+    ///
+    /// The option is not passed to the WASM executor.
+    #[serde(skip)]
+    pub record_gas_breakdown: bool,
 }
 
 /// The executor instance performs block production and validation. Given a block, it will execute all
@@ -206,8 +322,8 @@ pub struct ExecutionInstance<R, D> {
 
 impl<R, D> ExecutionInstance<R, D>
 where
-    R: RelayerPort,
-    D: KeyValueInspect<Column = Column>,
+    R: RelayerPort + Sync,
+    D: KeyValueInspect<Column = Column> + Sync,
 {
     pub fn execute_without_commit<TxSource>(
         self,
@@ -274,8 +390,8 @@ pub mod block_component {
 
 impl<R, D> ExecutionInstance<R, D>
 where
-    R: RelayerPort,
-    D: KeyValueInspect<Column = Column>,
+    R: RelayerPort + Sync,
+    D: KeyValueInspect<Column = Column> + Sync,
 {
     #[tracing::instrument(skip_all)]
     fn execute_inner<TxSource>(
@@ -437,8 +553,8 @@ where
 
 impl<R, D> BlockExecutor<R, D>
 where
-    R: RelayerPort,
-    D: KeyValueInspect<Column = Column>,
+    R: RelayerPort + Sync,
+    D: KeyValueInspect<Column = Column> + Sync,
 {
     #[tracing::instrument(skip_all)]
     /// Execute the fuel block with all transactions.
@@ -495,6 +611,48 @@ where
         debug_assert!(block.transactions.is_empty());
         let mut iter = source.next(remaining_gas_limit).into_iter().peekable();
 
+        // All transactions of the validated block are known in advance, so independent
+        // transactions can be executed in parallel. The `Mint` transaction depends on
+        // the result of all other transactions, so it is always executed at the end.
+        if execution_kind == ExecutionKind::Validation {
+            if let Some(workers) = self.options.parallel_execution_workers {
+                let mut transactions = iter.collect::<Vec<_>>();
+                let mint = match transactions.last() {
+                    Some(MaybeCheckedTransaction::Transaction(Transaction::Mint(_))) => {
+                        transactions.pop()
+                    }
+                    _ => None,
+                };
+
+                let result = self.execute_transactions_in_parallel(
+                    &transactions,
+                    workers,
+                    &block.header,
+                    coinbase_contract_id,
+                    gas_price,
+                    &thread_block_transaction,
+                );
+
+                match result {
+                    Some((changes, executed)) => {
+                        thread_block_transaction.commit_changes(changes)?;
+                        for (tx, tx_data) in executed {
+                            execution_data.merge(tx_data)?;
+                            block.transactions.push(tx);
+                        }
+                        transactions = mint.into_iter().collect();
+                    }
+                    None => {
+                        // Fallback to the sequential execution that returns
+                        // the canonical result or error.
+                        transactions.extend(mint);
+                    }
+                }
+
+                iter = transactions.into_iter().peekable();
+            }
+        }
+
         let mut execute_transaction = |execution_data: &mut ExecutionData,
                                        tx: MaybeCheckedTransaction|
          -> ExecutorResult<()> {
@@ -504,16 +662,31 @@ where
                     .write_transaction()
                     .with_policy(ConflictPolicy::Overwrite);
                 let tx_id = tx.id(&self.consensus_params.chain_id());
-                let result = self.execute_transaction(
-                    tx,
-                    &tx_id,
-                    &block.header,
-                    coinbase_contract_id,
-                    gas_price,
-                    execution_data,
-                    execution_kind,
-                    &mut tx_st_transaction,
-                );
+                let result = if self.options.record_storage_access
+                    && execution_kind == ExecutionKind::DryRun
+                {
+                    self.execute_transaction_recording_storage_access(
+                        tx,
+                        &tx_id,
+                        &block.header,
+                        coinbase_contract_id,
+                        gas_price,
+                        execution_data,
+                        execution_kind,
+                        &mut tx_st_transaction,
+                    )
+                } else {
+                    self.execute_transaction(
+                        tx,
+                        &tx_id,
+                        &block.header,
+                        coinbase_contract_id,
+                        gas_price,
+                        execution_data,
+                        execution_kind,
+                        &mut tx_st_transaction,
+                    )
+                };
 
                 let tx = match result {
                     Err(err) => {
@@ -660,6 +833,163 @@ where
         Ok(())
     }
 
+    /// Executes independent transactions of the block in parallel.
+    ///
+    /// Transactions are split into groups that don't share any [`ConflictKey`].
+    /// Transactions inside of the group are executed sequentially in the canonical order,
+    /// while groups are distributed between `workers` threads. Returns the combined
+    /// changes of all groups and executed transactions in the canonical order.
+    ///
+    /// Returns `None` if the block doesn't have independent transactions or
+    /// if the execution of any transaction fails. In this case, the caller
+    /// should execute transactions sequentially.
+    fn execute_transactions_in_parallel<T>(
+        &self,
+        transactions: &[MaybeCheckedTransaction],
+        workers: NonZeroUsize,
+        header: &PartialBlockHeader,
+        coinbase_contract_id: ContractId,
+        gas_price: Word,
+        block_storage: &StorageTransaction<T>,
+    ) -> Option<(Changes, Vec<(Transaction, ExecutionData)>)>
+    where
+        T: KeyValueInspect<Column = Column> + Sync,
+    {
+        let chain_id = self.consensus_params.chain_id();
+        let transactions = transactions
+            .iter()
+            .map(|tx| match tx {
+                MaybeCheckedTransaction::Transaction(tx) => Some((tx.id(&chain_id), tx)),
+                MaybeCheckedTransaction::CheckedTransaction(_) => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let groups = independent_groups(&transactions)?;
+        if groups.len() < 2 || workers.get() < 2 {
+            return None
+        }
+
+        // Distribute groups between workers, balancing the number of transactions.
+        let mut buckets = (0..workers.get().min(groups.len()))
+            .map(|_| (0usize, Vec::new()))
+            .collect::<Vec<_>>();
+        for group in groups {
+            let (load, bucket) = buckets.iter_mut().min_by_key(|(load, _)| *load)?;
+            *load = load.saturating_add(group.len());
+            bucket.push(group);
+        }
+
+        let transactions = &transactions;
+        let executed_groups = std::thread::scope(|scope| {
+            let handles = buckets
+                .into_iter()
+                .map(|(_, groups)| {
+                    scope.spawn(move || {
+                        groups
+                            .iter()
+                            .map(|group| {
+                                self.execute_group(
+                                    group,
+                                    transactions,
+                                    header,
+                                    coinbase_contract_id,
+                                    gas_price,
+                                    block_storage,
+                                )
+                            })
+                            .collect::<ExecutorResult<Vec<_>>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect::<ExecutorResult<Vec<_>>>()
+        });
+
+        let executed_groups = match executed_groups {
+            Ok(executed_groups) => executed_groups,
+            Err(err) => {
+                debug!("Parallel execution of the block failed: {err}");
+                return None
+            }
+        };
+
+        // Groups don't share conflict keys, so they can't modify the same data.
+        let mut merged_storage = block_storage
+            .read_transaction()
+            .with_policy(ConflictPolicy::Fail);
+        let mut executed = Vec::with_capacity(transactions.len());
+        for (changes, group) in executed_groups.into_iter().flatten() {
+            if let Err(err) = merged_storage.commit_changes(changes) {
+                warn!("Parallel execution produced conflicting changes: {err}");
+                return None
+            }
+            executed.extend(group);
+        }
+        executed.sort_by_key(|(index, _, _)| *index);
+
+        let executed = executed
+            .into_iter()
+            .map(|(_, tx, tx_data)| (tx, tx_data))
+            .collect();
+
+        Some((merged_storage.into_changes(), executed))
+    }
+
+    /// Executes the group of dependent transactions on top of the `block_storage`.
+    fn execute_group<T>(
+        &self,
+        group: &[usize],
+        transactions: &[(TxId, &Transaction)],
+        header: &PartialBlockHeader,
+        coinbase_contract_id: ContractId,
+        gas_price: Word,
+        block_storage: &StorageTransaction<T>,
+    ) -> ExecutorResult<ExecutedGroup>
+    where
+        T: KeyValueInspect<Column = Column>,
+    {
+        let mut group_transaction = block_storage
+            .read_transaction()
+            .with_policy(ConflictPolicy::Overwrite);
+        let mut executed = Vec::with_capacity(group.len());
+
+        for &index in group {
+            let (tx_id, tx) = transactions[index];
+            // Each transaction uses its position in the block for `TxPointer`s.
+            let mut tx_data = ExecutionData {
+                tx_count: u16::try_from(index)
+                    .map_err(|_| ExecutorError::TooManyTransactions)?,
+                ..Default::default()
+            };
+
+            let mut tx_st_transaction = group_transaction
+                .write_transaction()
+                .with_policy(ConflictPolicy::Overwrite);
+            let tx = self.execute_transaction(
+                MaybeCheckedTransaction::Transaction(tx.clone()),
+                &tx_id,
+                header,
+                coinbase_contract_id,
+                gas_price,
+                &mut tx_data,
+                ExecutionKind::Validation,
+                &mut tx_st_transaction,
+            )?;
+            tx_st_transaction.commit()?;
+
+            executed.push((index, tx, tx_data));
+        }
+
+        Ok((group_transaction.into_changes(), executed))
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn execute_transaction<T>(
         &self,
@@ -689,9 +1019,20 @@ where
 
         let block_height = *header.height();
         let checked_tx = match tx {
-            MaybeCheckedTransaction::Transaction(tx) => tx
-                .into_checked_basic(block_height, &self.consensus_params)?
-                .into(),
+            MaybeCheckedTransaction::Transaction(tx) => {
+                let cached = self
+                    .options
+                    .validation_cache
+                    .as_ref()
+                    .and_then(|cache| cache.get(tx_id, &tx));
+                let checked_tx: CheckedTransaction = tx
+                    .into_checked_basic(block_height, &self.consensus_params)?
+                    .into();
+                // The basic checks depend on the block height, so they are repeated
+                // even for the cached transaction. The cached one only allows skipping
+                // the checks of the predicates and signatures.
+                cached.unwrap_or(checked_tx)
+            }
             MaybeCheckedTransaction::CheckedTransaction(checked_tx) => checked_tx,
         };
 
@@ -726,6 +1067,80 @@ where
         }
     }
 
+    /// Executes the transaction the same way as [`Self::execute_transaction`], but also
+    /// records the storage keys accessed by the transaction into its status.
+    #[allow(clippy::too_many_arguments)]
+    fn execute_transaction_recording_storage_access<T>(
+        &self,
+        tx: MaybeCheckedTransaction,
+        tx_id: &TxId,
+        header: &PartialBlockHeader,
+        coinbase_contract_id: ContractId,
+        gas_price: Word,
+        execution_data: &mut ExecutionData,
+        execution_kind: ExecutionKind,
+        tx_st_transaction: &mut StorageTransaction<T>,
+    ) -> ExecutorResult<Transaction>
+    where
+        T: KeyValueInspect<Column = Column>,
+    {
+        let recorder = StorageAccessRecorder::new(&*tx_st_transaction);
+        let mut recorded_transaction = recorder.read_transaction();
+        let tx = self.execute_transaction(
+            tx,
+            tx_id,
+            header,
+            coinbase_contract_id,
+            gas_price,
+            execution_data,
+            execution_kind,
+            &mut recorded_transaction,
+        )?;
+        let changes = recorded_transaction.into_changes();
+        let storage_access = recorder.into_storage_access(&changes);
+        tx_st_transaction.commit_changes(changes)?;
+
+        if let Some(status) = execution_data.tx_status.last_mut() {
+            status.storage_access = Some(storage_access);
+        }
+
+        Ok(tx)
+    }
+
+    /// Verifies the number of inputs and outputs against the debug overrides.
+    fn verify_inputs_and_outputs_count<Tx>(
+        &self,
+        tx: &Tx,
+        transaction_id: TxId,
+    ) -> ExecutorResult<()>
+    where
+        Tx: ExecutableTransaction,
+    {
+        if let Some(max_inputs) = self.options.max_inputs {
+            let inputs = tx.inputs().len();
+            if inputs > usize::from(max_inputs) {
+                return Err(ExecutorError::TooManyInputs {
+                    transaction_id,
+                    inputs,
+                    max_inputs,
+                })
+            }
+        }
+
+        if let Some(max_outputs) = self.options.max_outputs {
+            let outputs = tx.outputs().len();
+            if outputs > usize::from(max_outputs) {
+                return Err(ExecutorError::TooManyOutputs {
+                    transaction_id,
+                    outputs,
+                    max_outputs,
+                })
+            }
+        }
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn execute_mint<T>(
         &self,
@@ -870,6 +1285,8 @@ where
                 result: None,
                 receipts: vec![],
             },
+            storage_access: None,
+            gas_breakdown: None,
         });
 
         if block_st_transaction
@@ -882,6 +1299,28 @@ where
         Ok(tx)
     }
 
+    /// Returns `true` if the transaction executed by the VM exceeds
+    /// the limits of the execution set by the options.
+    fn exceeds_limits(
+        &self,
+        registers: &[Word],
+        execution_start: Option<Instant>,
+    ) -> bool {
+        if let (Some(max_execution_time), Some(execution_start)) =
+            (self.options.max_tx_execution_time, execution_start)
+        {
+            if execution_start.elapsed() > max_execution_time {
+                return true
+            }
+        }
+        if let Some(max_memory) = self.options.max_tx_memory {
+            if occupied_memory(registers) > max_memory {
+                return true
+            }
+        }
+        false
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn execute_create_or_script<Tx, T>(
         &self,
@@ -901,6 +1340,8 @@ where
         let tx_id = checked_tx.id();
         let max_fee = checked_tx.transaction().max_fee_limit();
 
+        self.verify_inputs_and_outputs_count(checked_tx.transaction(), tx_id)?;
+
         if self.options.utxo_validation {
             checked_tx = checked_tx
                 .check_predicates(&CheckPredicateParams::from(&self.consensus_params))
@@ -957,16 +1398,69 @@ where
             .clone()
             .into_ready(gas_price, gas_costs, fee_params)?;
 
-        let vm_result: StateTransition<_> = vm
+        // The limits are not checked during validation, since the block producer
+        // has already executed the transactions of the block with its own limits.
+        let check_limits = execution_kind != ExecutionKind::Validation
+            && (self.options.max_tx_execution_time.is_some()
+                || self.options.max_tx_memory.is_some());
+        // The clock is only read if the option is set, since it is unavailable in WASM.
+        let execution_start = self
+            .options
+            .max_tx_execution_time
+            .filter(|_| check_limits)
+            .map(|_| Instant::now());
+        let mut gas_breakdown = (self.options.record_gas_breakdown
+            && execution_kind == ExecutionKind::DryRun)
+            .then(GasBreakdownRecorder::default);
+        // The VM stops before each instruction of the script to check the limits
+        // and to record the gas consumed by the instructions.
+        vm.set_single_stepping(check_limits || gas_breakdown.is_some());
+        let mut state = *vm
             .transact(ready_tx)
             .map_err(|error| ExecutorError::VmExecution {
                 error: error.to_string(),
                 transaction_id: tx_id,
             })?
-            .into();
+            .state();
+        while let Some(DebugEval::Breakpoint(_)) = state.debug_ref() {
+            if let Some(gas_breakdown) = &mut gas_breakdown {
+                let registers = vm.registers();
+                gas_breakdown.step(
+                    vm.memory(),
+                    registers[RegId::PC],
+                    registers[RegId::GGAS],
+                );
+            }
+            if check_limits && self.exceeds_limits(vm.registers(), execution_start) {
+                // The transaction runs out of gas on the next instruction,
+                // so it fails, and its changes are reverted.
+                vm.registers_mut()[RegId::GGAS] = 0;
+                vm.registers_mut()[RegId::CGAS] = 0;
+                vm.set_single_stepping(false);
+            }
+            state = vm.resume().map_err(|error| ExecutorError::VmExecution {
+                error: error.to_string(),
+                transaction_id: tx_id,
+            })?;
+        }
+        let gas_breakdown = gas_breakdown
+            .map(|gas_breakdown| gas_breakdown.finish(vm.registers()[RegId::GGAS]));
+        let vm_result =
+            StateTransition::new(state, vm.transaction().clone(), vm.receipts().to_vec());
         let reverted = vm_result.should_revert();
 
         let (state, mut tx, receipts): (_, Tx, _) = vm_result.into_inner();
+
+        if let Some(max_call_depth) = self.options.max_call_depth {
+            let depth = call_depth(&receipts);
+            if depth > max_call_depth {
+                return Err(ExecutorError::CallDepthExceeded {
+                    transaction_id: tx_id,
+                    depth,
+                    max_call_depth,
+                })
+            }
+        }
         #[cfg(debug_assertions)]
         {
             tx.precompute(&self.consensus_params.chain_id())?;
@@ -1053,7 +1547,9 @@ where
             })
         }
 
-        let final_tx = tx.into();
+        let final_tx: Transaction = tx.into();
+        // Only the scripts execute the instructions.
+        let gas_breakdown = gas_breakdown.filter(|_| final_tx.is_script());
 
         // Store tx into the block db transaction
         tx_st_transaction
@@ -1087,6 +1583,8 @@ where
         execution_data.tx_status.push(TransactionExecutionStatus {
             id: tx_id,
             result: status,
+            storage_access: None,
+            gas_breakdown,
         });
 
         Ok(final_tx)
@@ -1623,3 +2121,99 @@ where
         Ok(())
     }
 }
+
+/// Splits transactions into groups that don't share any [`ConflictKey`].
+/// Each group contains indexes of transactions in the canonical order.
+///
+/// Returns `None` if one of the transactions is `Mint`.
+fn independent_groups(transactions: &[(TxId, &Transaction)]) -> Option<Vec<Vec<usize>>> {
+    fn find(parents: &mut [usize], mut index: usize) -> usize {
+        while parents[index] != index {
+            parents[index] = parents[parents[index]];
+            index = parents[index];
+        }
+        index
+    }
+
+    let mut parents = (0..transactions.len()).collect::<Vec<_>>();
+    let mut owners = HashMap::<ConflictKey, usize>::new();
+
+    for (index, (tx_id, tx)) in transactions.iter().enumerate() {
+        let (inputs, outputs) = match tx {
+            Transaction::Script(script) => (script.inputs(), script.outputs()),
+            Transaction::Create(create) => (create.inputs(), create.outputs()),
+            Transaction::Mint(_) => return None,
+        };
+
+        let inputs_keys = inputs.iter().filter_map(|input| match input {
+            Input::CoinSigned(CoinSigned { utxo_id, .. })
+            | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                Some(ConflictKey::Transaction(*utxo_id.tx_id()))
+            }
+            Input::Contract(Contract { contract_id, .. }) => {
+                Some(ConflictKey::Contract(*contract_id))
+            }
+            _ => input.nonce().map(|nonce| ConflictKey::Message(*nonce)),
+        });
+        let outputs_keys = outputs.iter().filter_map(|output| match output {
+            Output::ContractCreated { contract_id, .. } => {
+                Some(ConflictKey::Contract(*contract_id))
+            }
+            _ => None,
+        });
+        let keys = core::iter::once(ConflictKey::Transaction(*tx_id))
+            .chain(inputs_keys)
+            .chain(outputs_keys);
+
+        for key in keys {
+            let owner = *owners.entry(key).or_insert(index);
+            let root = find(&mut parents, owner);
+            let current_root = find(&mut parents, index);
+            // The root is always the earliest transaction of the group.
+            parents[root.max(current_root)] = root.min(current_root);
+        }
+    }
+
+    let mut groups = Vec::<Vec<usize>>::new();
+    let mut group_of_root = HashMap::<usize, usize>::new();
+    for index in 0..transactions.len() {
+        let root = find(&mut parents, index);
+        let group = *group_of_root.entry(root).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len().saturating_sub(1)
+        });
+        groups[group].push(index);
+    }
+
+    Some(groups)
+}
+
+/// Returns the maximum depth of nested contract calls based on the receipts.
+fn call_depth(receipts: &[Receipt]) -> u64 {
+    let mut depth = 0u64;
+    let mut max_depth = 0u64;
+    for receipt in receipts {
+        match receipt {
+            Receipt::Call { .. } => {
+                depth = depth.saturating_add(1);
+                max_depth = max_depth.max(depth);
+            }
+            // The script itself returns with the zeroed contract id.
+            Receipt::Return { id, .. } | Receipt::ReturnData { id, .. }
+                if id != &ContractId::zeroed() =>
+            {
+                depth = depth.saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+    max_depth
+}
+
+/// Returns the bytes of the VM memory occupied by the stack and the heap
+/// based on the registers of the VM.
+fn occupied_memory(registers: &[u64]) -> u64 {
+    let stack = registers[RegId::SP];
+    let heap = fuel_vm::consts::VM_MAX_RAM.saturating_sub(registers[RegId::HP]);
+    stack.saturating_add(heap)
+}
@@ -0,0 +1,111 @@
+use fuel_core_types::{
+    fuel_asm::{
+        Instruction,
+        Opcode,
+        Word,
+    },
+    services::executor::OpcodeGas,
+};
+use std::collections::HashMap;
+
+/// Records the gas consumed by each opcode of the script executed with single-stepping.
+///
+/// The gas of the instruction is the drop of the global gas register
+/// between the instruction and the next one.
+#[derive(Default)]
+pub struct GasBreakdownRecorder {
+    per_opcode: HashMap<String, OpcodeGas>,
+    previous: Option<(Opcode, Word)>,
+}
+
+impl GasBreakdownRecorder {
+    /// Records the instruction at the `pc` of the `memory` that is about to be executed
+    /// with the `remaining_gas`. The invalid instructions are not recorded,
+    /// since the VM panics on them.
+    pub fn step(&mut self, memory: &[u8], pc: Word, remaining_gas: Word) {
+        self.charge_previous(remaining_gas);
+        self.previous = opcode_at(memory, pc).map(|opcode| (opcode, remaining_gas));
+    }
+
+    /// Charges the last instruction with the gas spent until the end of the execution,
+    /// and returns the gas of each opcode, ordered by the consumed gas.
+    pub fn finish(mut self, remaining_gas: Word) -> Vec<OpcodeGas> {
+        self.charge_previous(remaining_gas);
+        let mut per_opcode = self.per_opcode.into_values().collect::<Vec<_>>();
+        per_opcode
+            .sort_by(|a, b| b.gas.cmp(&a.gas).then_with(|| a.opcode.cmp(&b.opcode)));
+        per_opcode
+    }
+
+    fn charge_previous(&mut self, remaining_gas: Word) {
+        let Some((opcode, gas_before)) = self.previous.take() else {
+            return
+        };
+        let name = format!("{opcode:?}");
+        let entry = self.per_opcode.entry(name.clone()).or_insert(OpcodeGas {
+            opcode: name,
+            count: 0,
+            gas: 0,
+        });
+        entry.count = entry.count.saturating_add(1);
+        entry.gas = entry
+            .gas
+            .saturating_add(gas_before.saturating_sub(remaining_gas));
+    }
+}
+
+fn opcode_at(memory: &[u8], pc: Word) -> Option<Opcode> {
+    let start = usize::try_from(pc).ok()?;
+    let bytes = memory.get(start..start.saturating_add(Instruction::SIZE))?;
+    let bytes = <[u8; Instruction::SIZE]>::try_from(bytes).ok()?;
+    Instruction::try_from(bytes)
+        .ok()
+        .map(|instruction| instruction.opcode())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_asm::op;
+
+    #[test]
+    fn finish__returns_gas_drop_per_opcode_ordered_by_gas() {
+        // Given
+        let memory: Vec<u8> = [op::addi(0x10, 0x10, 1), op::log(0x10, 0, 0, 0)]
+            .into_iter()
+            .chain([op::addi(0x10, 0x10, 1), op::ret(0x10)])
+            .collect();
+        let mut recorder = GasBreakdownRecorder::default();
+
+        // When
+        recorder.step(&memory, 0, 100);
+        recorder.step(&memory, 4, 98);
+        recorder.step(&memory, 8, 90);
+        recorder.step(&memory, 12, 88);
+        let breakdown = recorder.finish(85);
+
+        // Then
+        assert_eq!(
+            breakdown,
+            vec![
+                OpcodeGas {
+                    opcode: "LOG".to_string(),
+                    count: 1,
+                    gas: 8,
+                },
+                OpcodeGas {
+                    opcode: "ADDI".to_string(),
+                    count: 2,
+                    gas: 4,
+                },
+                OpcodeGas {
+                    opcode: "RET".to_string(),
+                    count: 1,
+                    gas: 3,
+                },
+            ]
+        );
+        let total: u64 = breakdown.iter().map(|entry| entry.gas).sum();
+        assert_eq!(total, 15);
+    }
+}
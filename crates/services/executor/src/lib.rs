@@ -7,5 +7,8 @@ pub mod executor;
 pub mod ports;
 pub mod refs;
 
+mod gas_breakdown;
+mod storage_access;
+
 #[cfg(test)]
 fuel_core_trace::enable_tracing!();
@@ -0,0 +1,146 @@
+use fuel_core_storage::{
+    column::Column,
+    kv_store::{
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+    },
+    transactional::Changes,
+    Result as StorageResult,
+};
+use fuel_core_types::services::executor::StorageAccess;
+use parking_lot::Mutex;
+use std::collections::BTreeSet;
+
+/// The storage that records the keys read from the `inner` storage.
+///
+/// The writes are not forwarded to the `inner` storage, so they are collected
+/// by the storage transaction on top of the recorder.
+pub struct StorageAccessRecorder<S> {
+    inner: S,
+    reads: Mutex<BTreeSet<(u32, Vec<u8>)>>,
+}
+
+impl<S> StorageAccessRecorder<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            reads: Mutex::new(BTreeSet::new()),
+        }
+    }
+
+    /// Combines the recorded reads with the keys of the `changes`
+    /// made by the transaction on top of the recorder.
+    pub fn into_storage_access(self, changes: &Changes) -> StorageAccess {
+        let reads = self.reads.into_inner().into_iter().collect();
+        let writes = changes
+            .iter()
+            .flat_map(|(column, operations)| {
+                operations.keys().map(move |key| (*column, key.clone()))
+            })
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        StorageAccess { reads, writes }
+    }
+
+    fn record(&self, key: &[u8], column: Column) {
+        self.reads.lock().insert((column.id(), key.to_vec()));
+    }
+}
+
+impl<S> KeyValueInspect for StorageAccessRecorder<S>
+where
+    S: KeyValueInspect<Column = Column>,
+{
+    type Column = Column;
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        self.record(key, column);
+        self.inner.exists(key, column)
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        self.record(key, column);
+        self.inner.size_of_value(key, column)
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.record(key, column);
+        self.inner.get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        self.record(key, column);
+        self.inner.read(key, column, buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core_storage::{
+        structured_storage::test::InMemoryStorage,
+        tables::ContractsRawCode,
+        transactional::{
+            ReadTransaction,
+            WriteTransaction,
+        },
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::fuel_types::ContractId;
+
+    #[test]
+    fn into_storage_access__returns_keys_read_from_inner_storage_and_written_on_top() {
+        // Given
+        let read_id = ContractId::from([1; 32]);
+        let written_id = ContractId::from([2; 32]);
+        let mut storage = InMemoryStorage::<Column>::default();
+        let mut tx = storage.write_transaction();
+        tx.storage_as_mut::<ContractsRawCode>()
+            .insert(&read_id, [1u8, 2, 3].as_slice())
+            .unwrap();
+        tx.commit().unwrap();
+        let recorder = StorageAccessRecorder::new(&storage);
+
+        // When
+        let mut tx = recorder.read_transaction();
+        tx.storage::<ContractsRawCode>().get(&read_id).unwrap();
+        tx.storage_as_mut::<ContractsRawCode>()
+            .insert(&written_id, [4u8, 5, 6].as_slice())
+            .unwrap();
+        // The key written by the transaction is read from the transaction itself.
+        tx.storage::<ContractsRawCode>().get(&written_id).unwrap();
+        let not_found_id = ContractId::from([3; 32]);
+        tx.storage::<ContractsRawCode>().get(&not_found_id).unwrap();
+        let changes = tx.into_changes();
+        let storage_access = recorder.into_storage_access(&changes);
+
+        // Then
+        let column = Column::ContractsRawCode.id();
+        assert_eq!(
+            storage_access,
+            StorageAccess {
+                // The insertion loads the previous value of the written key.
+                reads: vec![
+                    (column, read_id.to_vec()),
+                    (column, written_id.to_vec()),
+                    (column, not_found_id.to_vec()),
+                ],
+                writes: vec![(column, written_id.to_vec())],
+            }
+        );
+    }
+}
@@ -1,11 +1,19 @@
 use fuel_core_chain_config::ChainConfig;
 use fuel_core_types::fuel_types::ChainId;
+use std::sync::Arc;
+use tokio_rayon::rayon::ThreadPool;
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub max_block_notify_buffer: usize,
     pub metrics: bool,
     pub chain_id: ChainId,
+    /// The dedicated pool for the execution of the imported blocks.
+    /// If `None`, the global rayon pool is used.
+    pub execution_pool: Option<Arc<ThreadPool>>,
+    /// The maximum size of the database in bytes. When the database exceeds it,
+    /// the importer enters the read-only mode and rejects new blocks. `None` means no limit.
+    pub max_db_size: Option<u64>,
 }
 
 impl Config {
@@ -14,6 +22,8 @@ impl Config {
             max_block_notify_buffer: 1 << 10,
             metrics: false,
             chain_id: chain_config.consensus_parameters.chain_id(),
+            execution_pool: None,
+            max_db_size: None,
         }
     }
 }
@@ -25,6 +35,8 @@ impl Default for Config {
             max_block_notify_buffer: 1,
             metrics: false,
             chain_id: ChainId::default(),
+            execution_pool: None,
+            max_db_size: None,
         }
     }
 }
@@ -44,6 +44,10 @@ use std::{
         DerefMut,
     },
     sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
         Arc,
         Mutex,
     },
@@ -58,6 +62,10 @@ use tokio::sync::{
     oneshot,
     TryAcquireError,
 };
+use tokio_rayon::{
+    rayon::ThreadPool,
+    AsyncThreadPool,
+};
 
 #[cfg(test)]
 pub mod test;
@@ -95,6 +103,14 @@ pub enum Error {
     ExecuteGenesis,
     #[display(fmt = "The database already contains the data at the height {_0}.")]
     NotUnique(BlockHeight),
+    #[display(
+        fmt = "The database size is {size} bytes, when the maximum is {max_db_size} bytes. \
+        The importer is in the read-only mode."
+    )]
+    DatabaseSizeExceeded {
+        size: u64,
+        max_db_size: u64,
+    },
     #[from]
     StorageError(StorageError),
     UnsupportedConsensusVariant(String),
@@ -118,6 +134,10 @@ pub struct Importer<D, E, V> {
     executor: Arc<E>,
     verifier: Arc<V>,
     chain_id: ChainId,
+    execution_pool: Option<Arc<ThreadPool>>,
+    max_db_size: Option<u64>,
+    /// Set when the database exceeds the `max_db_size`, while the importer rejects new blocks.
+    read_only: AtomicBool,
     broadcast: broadcast::Sender<SharedImportResult>,
     /// The channel to notify about the end of the processing of the previous block by all listeners.
     /// It is used to await until all receivers of the notification process the `SharedImportResult`
@@ -135,6 +155,9 @@ impl<D, E, V> Importer<D, E, V> {
             executor: Arc::new(executor),
             verifier: Arc::new(verifier),
             chain_id: config.chain_id,
+            execution_pool: config.execution_pool,
+            max_db_size: config.max_db_size,
+            read_only: AtomicBool::new(false),
             broadcast,
             prev_block_process_result: Default::default(),
             guard: tokio::sync::Semaphore::new(1),
@@ -145,6 +168,12 @@ impl<D, E, V> Importer<D, E, V> {
         self.broadcast.subscribe()
     }
 
+    /// Returns `true` if the importer rejects new blocks
+    /// because the database exceeded the maximum size.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::Relaxed)
+    }
+
     pub(crate) fn lock(&self) -> Result<tokio::sync::SemaphorePermit, Error> {
         let guard = self.guard.try_acquire();
         match guard {
@@ -202,6 +231,39 @@ where
         self._commit_result(result, database)
     }
 
+    /// Enters the read-only mode if the database exceeds the `max_db_size`,
+    /// and leaves it when the database is below the limit again, e.g., after pruning.
+    fn verify_database_size(&self, database: &D) -> Result<(), Error> {
+        let Some(max_db_size) = self.max_db_size else {
+            return Ok(())
+        };
+
+        let size = database.database_size()?;
+        let exceeded = size > max_db_size;
+        let was_read_only = self.read_only.swap(exceeded, Ordering::Relaxed);
+        match (was_read_only, exceeded) {
+            (false, true) => {
+                tracing::error!(
+                    "The database size is {size} bytes, when the maximum is {max_db_size} \
+                    bytes. The importer enters the read-only mode and rejects new blocks \
+                    until the database is pruned or the limit is increased."
+                );
+            }
+            (true, false) => {
+                tracing::info!(
+                    "The database size is {size} bytes, below the maximum of {max_db_size} \
+                    bytes. The importer leaves the read-only mode."
+                );
+            }
+            _ => {}
+        }
+
+        if exceeded {
+            return Err(Error::DatabaseSizeExceeded { size, max_db_size })
+        }
+        Ok(())
+    }
+
     /// The method commits the result of the block execution and notifies about a new imported block.
     #[tracing::instrument(
         skip_all,
@@ -217,6 +279,8 @@ where
         result: UncommittedResult<Changes>,
         database: &mut D,
     ) -> Result<(), Error> {
+        self.verify_database_size(database)?;
+
         let (result, changes) = result.into();
         let block = &result.sealed_block.entity;
         let consensus = &result.sealed_block.consensus;
@@ -436,14 +500,17 @@ where
 
         let executor = self.executor.clone();
         let verifier = self.verifier.clone();
-        let (result, execute_time) = tokio_rayon::spawn_fifo(|| {
+        let execute = || {
             let start = Instant::now();
             let result =
                 Self::verify_and_execute_block_inner(executor, verifier, sealed_block);
             let execute_time = start.elapsed().as_secs_f64();
             (result, execute_time)
-        })
-        .await;
+        };
+        let (result, execute_time) = match &self.execution_pool {
+            Some(execution_pool) => execution_pool.spawn_fifo_async(execute).await,
+            None => tokio_rayon::spawn_fifo(execute).await,
+        };
 
         let result = result?;
 
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use crate::{
     importer::Error,
     ports::{
@@ -7,6 +9,7 @@ use crate::{
         MockExecutor,
         Transactional,
     },
+    Config,
     Importer,
 };
 use anyhow::anyhow;
@@ -58,6 +61,8 @@ mockall::mock! {
         fn latest_block_height(&self) -> StorageResult<Option<BlockHeight>>;
 
         fn latest_block_root(&self) -> StorageResult<Option<MerkleRoot>>;
+
+        fn database_size(&self) -> StorageResult<u64>;
     }
 }
 
@@ -349,6 +354,74 @@ async fn commit_result_and_execute_and_commit_poa(
     commit_result
 }
 
+#[tokio::test]
+async fn commit_result__enters_read_only_mode_when_database_exceeds_max_size() {
+    // Given
+    let config = Config {
+        max_db_size: Some(100),
+        ..Default::default()
+    };
+    let mut db = underlying_db(ok(Some(0)))();
+    db.expect_database_size().times(1).returning(|| Ok(101));
+    db.expect_storage_transaction().never();
+    let importer = Importer::new(config, db, (), ());
+    let uncommitted_result = UncommittedResult::new(
+        ImportResult::new_from_local(poa_block(1), vec![], vec![]),
+        Default::default(),
+    );
+    let mut imported_blocks = importer.subscribe();
+
+    // When
+    let result = importer.commit_result(uncommitted_result).await;
+
+    // Then
+    assert_eq!(
+        result,
+        Err(Error::DatabaseSizeExceeded {
+            size: 101,
+            max_db_size: 100,
+        })
+    );
+    assert!(importer.is_read_only());
+    assert!(matches!(
+        imported_blocks.try_recv(),
+        Err(TryRecvError::Empty)
+    ));
+}
+
+#[tokio::test]
+async fn commit_result__leaves_read_only_mode_when_database_is_below_max_size() {
+    // Given
+    let config = Config {
+        max_db_size: Some(100),
+        ..Default::default()
+    };
+    let mut db = underlying_db(ok(Some(0)))();
+    let mut sizes = vec![101, 100].into_iter();
+    db.expect_database_size()
+        .times(2)
+        .returning(move || Ok(sizes.next().unwrap()));
+    let transaction = db_transaction(ok(Some(0)), ok(true), 1)();
+    db.expect_storage_transaction()
+        .return_once(move |_| transaction);
+    let importer = Importer::new(config, db, (), ());
+    let uncommitted_result = || {
+        UncommittedResult::new(
+            ImportResult::new_from_local(poa_block(1), vec![], vec![]),
+            Default::default(),
+        )
+    };
+    let _ = importer.commit_result(uncommitted_result()).await;
+    assert!(importer.is_read_only());
+
+    // When
+    let result = importer.commit_result(uncommitted_result()).await;
+
+    // Then
+    assert_eq!(result, Ok(()));
+    assert!(!importer.is_read_only());
+}
+
 async fn commit_result_assert(
     sealed_block: SealedBlock,
     mut underlying_db: MockDatabase,
@@ -68,6 +68,9 @@ pub trait ImporterDatabase: Transactional + Send + Sync {
 
     /// Returns the latest block root.
     fn latest_block_root(&self) -> StorageResult<Option<MerkleRoot>>;
+
+    /// Returns the approximate size of the database in bytes.
+    fn database_size(&self) -> StorageResult<u64>;
 }
 
 /// The port of the storage transaction required by the importer.
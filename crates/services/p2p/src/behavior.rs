@@ -1,7 +1,7 @@
 use crate::{
-    codecs::{
-        postcard::PostcardCodec,
-        NetworkCodec,
+    codecs::postcard::{
+        MessageExchangePostcardProtocol,
+        PostcardCodec,
     },
     config::Config,
     discovery,
@@ -112,8 +112,16 @@ impl FuelBehaviour {
             BlockHeight::default(),
         );
 
-        let req_res_protocol =
-            core::iter::once((codec.get_req_res_protocol(), ProtocolSupport::Full));
+        // The protocols are proposed to the peer in the order of preference.
+        // If the list is empty, only the original version of the protocol is supported.
+        let req_res_protocols = if p2p_config.req_res_protocols.is_empty() {
+            vec![MessageExchangePostcardProtocol::default()]
+        } else {
+            p2p_config.req_res_protocols.clone()
+        };
+        let req_res_protocol = req_res_protocols
+            .into_iter()
+            .map(|protocol| (protocol, ProtocolSupport::Full));
 
         let req_res_config = request_response::Config::default();
         req_res_config
@@ -39,7 +39,4 @@ pub trait NetworkCodec:
     + Send
     + 'static
 {
-    /// Returns RequestResponse's Protocol
-    /// Needed for initialization of RequestResponse Behaviour
-    fn get_req_res_protocol(&self) -> <Self as request_response::Codec>::Protocol;
 }
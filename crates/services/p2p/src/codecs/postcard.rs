@@ -12,16 +12,19 @@ use crate::{
         RequestMessage,
         ResponseMessage,
         REQUEST_RESPONSE_PROTOCOL_ID,
+        REQUEST_RESPONSE_PROTOCOL_ID_V2,
     },
 };
 use async_trait::async_trait;
 use futures::{
     AsyncRead,
     AsyncReadExt,
+    AsyncWrite,
     AsyncWriteExt,
 };
 use libp2p::request_response;
 use serde::{
+    de::DeserializeOwned,
     Deserialize,
     Serialize,
 };
@@ -60,8 +63,70 @@ impl PostcardCodec {
     }
 }
 
+impl PostcardCodec {
+    async fn read_message<T, M>(
+        &self,
+        protocol: &MessageExchangePostcardProtocol,
+        socket: &mut T,
+    ) -> io::Result<M>
+    where
+        T: AsyncRead + Unpin + Send,
+        M: DeserializeOwned,
+    {
+        let mut message = Vec::new();
+        match protocol {
+            MessageExchangePostcardProtocol::V1 => {
+                socket
+                    .take(self.max_response_size as u64)
+                    .read_to_end(&mut message)
+                    .await?;
+            }
+            MessageExchangePostcardProtocol::V2 => {
+                let mut length = [0u8; 4];
+                socket.read_exact(&mut length).await?;
+                let length = u32::from_be_bytes(length) as usize;
+                if length > self.max_response_size {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "The message of {length} bytes exceeds the limit of {} bytes",
+                            self.max_response_size
+                        ),
+                    ))
+                }
+                message.resize(length, 0);
+                socket.read_exact(&mut message).await?;
+            }
+        }
+        deserialize(&message)
+    }
+
+    async fn write_message<T, M>(
+        &self,
+        protocol: &MessageExchangePostcardProtocol,
+        socket: &mut T,
+        message: &M,
+    ) -> io::Result<()>
+    where
+        T: AsyncWrite + Unpin + Send,
+        M: Serialize,
+    {
+        let encoded_data = serialize(message)?;
+        if let MessageExchangePostcardProtocol::V2 = protocol {
+            let length = u32::try_from(encoded_data.len()).map_err(|_| {
+                io::Error::new(io::ErrorKind::InvalidInput, "The message is too large")
+            })?;
+            socket.write_all(&length.to_be_bytes()).await?;
+        }
+        socket.write_all(&encoded_data).await?;
+        Ok(())
+    }
+}
+
 /// Since Postcard does not support async reads or writes out of the box
 /// We prefix Request & Response Messages with the length of the data in bytes
+/// in the `V2` of the protocol, or read the message until the end of the substream
+/// in the `V1` of the protocol.
 /// We expect the substream to be properly closed when response channel is dropped.
 /// Since the request protocol used here expects a response, the sender considers this
 /// early close as a protocol violation which results in the connection being closed.
@@ -75,63 +140,48 @@ impl request_response::Codec for PostcardCodec {
 
     async fn read_request<T>(
         &mut self,
-        _: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
     ) -> io::Result<Self::Request>
     where
         T: AsyncRead + Unpin + Send,
     {
-        let mut response = Vec::new();
-        socket
-            .take(self.max_response_size as u64)
-            .read_to_end(&mut response)
-            .await?;
-        deserialize(&response)
+        self.read_message(protocol, socket).await
     }
 
     async fn read_response<T>(
         &mut self,
-        _: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
     ) -> io::Result<Self::Response>
     where
         T: AsyncRead + Unpin + Send,
     {
-        let mut response = Vec::new();
-        socket
-            .take(self.max_response_size as u64)
-            .read_to_end(&mut response)
-            .await?;
-
-        deserialize(&response)
+        self.read_message(protocol, socket).await
     }
 
     async fn write_request<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
         req: Self::Request,
     ) -> io::Result<()>
     where
-        T: futures::AsyncWrite + Unpin + Send,
+        T: AsyncWrite + Unpin + Send,
     {
-        let encoded_data = serialize(&req)?;
-        socket.write_all(&encoded_data).await?;
-        Ok(())
+        self.write_message(protocol, socket, &req).await
     }
 
     async fn write_response<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
         res: Self::Response,
     ) -> io::Result<()>
     where
-        T: futures::AsyncWrite + Unpin + Send,
+        T: AsyncWrite + Unpin + Send,
     {
-        let encoded_data = serialize(&res)?;
-        socket.write_all(&encoded_data).await?;
-        Ok(())
+        self.write_message(protocol, socket, &res).await
     }
 }
 
@@ -160,18 +210,27 @@ impl GossipsubCodec for PostcardCodec {
     }
 }
 
-impl NetworkCodec for PostcardCodec {
-    fn get_req_res_protocol(&self) -> <Self as request_response::Codec>::Protocol {
-        MessageExchangePostcardProtocol {}
-    }
-}
+impl NetworkCodec for PostcardCodec {}
 
-#[derive(Default, Debug, Clone)]
-pub struct MessageExchangePostcardProtocol;
+/// The versions of the request-response protocol. The versions differ only
+/// in the wire format of the messages and are negotiated with each peer
+/// when the substream is opened.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageExchangePostcardProtocol {
+    /// The postcard-encoded message, delimited by the end of the substream.
+    #[default]
+    V1,
+    /// The postcard-encoded message, prefixed with its length. The size of the message
+    /// is checked before reading it.
+    V2,
+}
 
 impl AsRef<str> for MessageExchangePostcardProtocol {
     fn as_ref(&self) -> &str {
-        REQUEST_RESPONSE_PROTOCOL_ID
+        match self {
+            MessageExchangePostcardProtocol::V1 => REQUEST_RESPONSE_PROTOCOL_ID,
+            MessageExchangePostcardProtocol::V2 => REQUEST_RESPONSE_PROTOCOL_ID_V2,
+        }
     }
 }
 
@@ -186,4 +245,37 @@ mod tests {
         let m = RequestMessage::Transactions(arbitrary_range);
         assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
     }
+
+    #[tokio::test]
+    async fn v2_message_is_prefixed_with_its_length() {
+        let mut codec = PostcardCodec::new(1024);
+        let protocol = MessageExchangePostcardProtocol::V2;
+        let request = RequestMessage::SealedHeaders(2..6);
+
+        let mut encoded = Vec::new();
+        codec
+            .write_request(&protocol, &mut encoded, request.clone())
+            .await
+            .unwrap();
+        let decoded = codec
+            .read_request(&protocol, &mut encoded.as_slice())
+            .await
+            .unwrap();
+
+        let payload = postcard::to_stdvec(&request).unwrap();
+        assert_eq!(encoded[..4], (payload.len() as u32).to_be_bytes());
+        assert_eq!(encoded[4..], payload[..]);
+        assert_eq!(decoded, request);
+    }
+
+    #[tokio::test]
+    async fn v2_message_above_the_limit_is_rejected() {
+        let mut codec = PostcardCodec::new(4);
+        let protocol = MessageExchangePostcardProtocol::V2;
+        let encoded = [&5u32.to_be_bytes()[..], &[0u8; 5]].concat();
+
+        let result = codec.read_request(&protocol, &mut encoded.as_slice()).await;
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
 }
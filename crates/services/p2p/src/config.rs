@@ -1,4 +1,5 @@
 use crate::{
+    codecs::postcard::MessageExchangePostcardProtocol,
     gossipsub::config::default_gossipsub_config,
     heartbeat,
     peer_manager::ConnectionState,
@@ -62,6 +63,9 @@ pub const MAX_RESPONSE_SIZE: usize = 18 * 1024 * 1024;
 /// Maximum number of headers per request.
 pub const MAX_HEADERS_PER_REQUEST: u32 = 100;
 
+/// Maximum number of transactions gossiped by a single peer per second.
+pub const MAX_GOSSIP_TRANSACTIONS_PER_PEER: u32 = 1000;
+
 /// Adds a timeout to the setup and protocol upgrade process for all
 /// inbound and outbound connections established through the transport.
 const TRANSPORT_TIMEOUT: Duration = Duration::from_secs(20);
@@ -92,6 +96,11 @@ pub struct Config<State = Initialized> {
 
     // `DiscoveryBehaviour` related fields
     pub bootstrap_nodes: Vec<Multiaddr>,
+    /// The DNS seeds in the `host:port` format. They are resolved into
+    /// the addresses of the peers to dial at startup and periodically after.
+    pub dns_seeds: Vec<String>,
+    /// The interval between resolutions of the `dns_seeds`.
+    pub dns_seeds_refresh_interval: Duration,
     pub enable_mdns: bool,
     pub allow_private_addresses: bool,
     pub random_walk: Option<Duration>,
@@ -102,6 +111,12 @@ pub struct Config<State = Initialized> {
     pub reserved_nodes: Vec<Multiaddr>,
     /// Should the node only accept connection requests from the Reserved Nodes
     pub reserved_nodes_only_mode: bool,
+    /// The delay between the first and the second attempts to reconnect to
+    /// the disconnected reserved node. The first attempt happens right after the disconnect.
+    pub reserved_nodes_reconnect_initial_backoff: Duration,
+    /// The delay between the attempts to reconnect to the disconnected reserved node
+    /// doubles after each attempt until it reaches this value.
+    pub reserved_nodes_reconnect_max_backoff: Duration,
 
     // `PeerManager` fields
     /// Max number of unique peers connected
@@ -111,6 +126,11 @@ pub struct Config<State = Initialized> {
     /// Max number of connections per single peer
     /// The total number of connections will be `(max_peers_connected + reserved_nodes.len()) * max_connections_per_peer`
     pub max_connections_per_peer: u32,
+    /// Max number of peers connected in total, including the reserved nodes.
+    /// New inbound connections are refused past the limit, and a reserved node
+    /// connecting past the limit replaces the non-reserved peer with the lowest score.
+    /// `None` means the total is only bound by `max_peers_connected` and `reserved_nodes`.
+    pub max_connections: Option<u32>,
     /// The interval at which identification requests are sent to
     /// the remote on established connections after the first request
     pub identify_interval: Option<Duration>,
@@ -128,6 +148,10 @@ pub struct Config<State = Initialized> {
     pub set_request_timeout: Duration,
     /// Sets the keep-alive timeout of idle connections.
     pub set_connection_keep_alive: Duration,
+    /// The versions of the request-response protocol supported by the node,
+    /// from the most preferred one. The version is negotiated with each peer,
+    /// so the peers that don't support the preferred version fall back to the next one.
+    pub req_res_protocols: Vec<MessageExchangePostcardProtocol>,
 
     /// Time between checking heartbeat status for all peers
     pub heartbeat_check_interval: Duration,
@@ -136,6 +160,10 @@ pub struct Config<State = Initialized> {
     /// Max time since a given peer has sent a heartbeat before getting reputation penalty
     pub heartbeat_max_time_since_last: Duration,
 
+    /// Max number of transactions gossiped by a single peer per second.
+    /// The transactions above the limit are ignored, and the peer gets reputation penalty.
+    pub max_gossip_transactions_per_peer: u32,
+
     /// Enables prometheus metrics for this fuel-service
     pub metrics: bool,
 
@@ -167,23 +195,32 @@ impl Config<NotInitialized> {
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
             bootstrap_nodes: self.bootstrap_nodes,
+            dns_seeds: self.dns_seeds,
+            dns_seeds_refresh_interval: self.dns_seeds_refresh_interval,
             enable_mdns: self.enable_mdns,
             max_peers_connected: self.max_peers_connected,
             max_connections_per_peer: self.max_connections_per_peer,
+            max_connections: self.max_connections,
             allow_private_addresses: self.allow_private_addresses,
             random_walk: self.random_walk,
             connection_idle_timeout: self.connection_idle_timeout,
             reserved_nodes: self.reserved_nodes,
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
+            reserved_nodes_reconnect_initial_backoff: self
+                .reserved_nodes_reconnect_initial_backoff,
+            reserved_nodes_reconnect_max_backoff: self
+                .reserved_nodes_reconnect_max_backoff,
             identify_interval: self.identify_interval,
             info_interval: self.info_interval,
             gossipsub_config: self.gossipsub_config,
             heartbeat_config: self.heartbeat_config,
             set_request_timeout: self.set_request_timeout,
             set_connection_keep_alive: self.set_connection_keep_alive,
+            req_res_protocols: self.req_res_protocols,
             heartbeat_check_interval: self.heartbeat_check_interval,
             heartbeat_max_avg_interval: self.heartbeat_max_time_since_last,
             heartbeat_max_time_since_last: self.heartbeat_max_time_since_last,
+            max_gossip_transactions_per_peer: self.max_gossip_transactions_per_peer,
             metrics: self.metrics,
             state: Initialized(()),
         })
@@ -215,21 +252,28 @@ impl Config<NotInitialized> {
             max_block_size: MAX_RESPONSE_SIZE,
             max_headers_per_request: MAX_HEADERS_PER_REQUEST,
             bootstrap_nodes: vec![],
+            dns_seeds: vec![],
+            dns_seeds_refresh_interval: Duration::from_secs(300),
             enable_mdns: false,
             max_peers_connected: 50,
             max_connections_per_peer: 3,
+            max_connections: None,
             allow_private_addresses: true,
             random_walk: Some(Duration::from_millis(500)),
             connection_idle_timeout: Some(Duration::from_secs(120)),
             reserved_nodes: vec![],
             reserved_nodes_only_mode: false,
+            reserved_nodes_reconnect_initial_backoff: Duration::from_secs(1),
+            reserved_nodes_reconnect_max_backoff: Duration::from_secs(60),
             gossipsub_config: default_gossipsub_config(),
             heartbeat_config: heartbeat::Config::default(),
             set_request_timeout: REQ_RES_TIMEOUT,
             set_connection_keep_alive: REQ_RES_TIMEOUT,
+            req_res_protocols: vec![MessageExchangePostcardProtocol::V1],
             heartbeat_check_interval: Duration::from_secs(10),
             heartbeat_max_avg_interval: Duration::from_secs(20),
             heartbeat_max_time_since_last: Duration::from_secs(40),
+            max_gossip_transactions_per_peer: MAX_GOSSIP_TRANSACTIONS_PER_PEER,
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
             metrics: false,
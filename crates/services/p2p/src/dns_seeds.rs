@@ -0,0 +1,131 @@
+use futures::stream::{
+    BoxStream,
+    StreamExt,
+};
+use libp2p::{
+    multiaddr::Protocol,
+    Multiaddr,
+};
+use std::{
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
+
+/// The delay before the next attempt if none of the seeds were resolved.
+pub const DNS_SEEDS_RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Resolves the DNS seed into the addresses of the peers.
+#[async_trait::async_trait]
+pub trait DnsResolver: Send + Sync {
+    /// Returns the addresses behind the `seed`. The `seed` has the `host:port` format.
+    async fn resolve(&self, seed: &str) -> anyhow::Result<Vec<Multiaddr>>;
+}
+
+/// The resolver that uses the DNS configuration of the operating system.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemDnsResolver;
+
+#[async_trait::async_trait]
+impl DnsResolver for SystemDnsResolver {
+    async fn resolve(&self, seed: &str) -> anyhow::Result<Vec<Multiaddr>> {
+        let addresses = tokio::net::lookup_host(seed)
+            .await?
+            .map(socket_addr_to_multiaddr)
+            .collect();
+        Ok(addresses)
+    }
+}
+
+fn socket_addr_to_multiaddr(address: SocketAddr) -> Multiaddr {
+    Multiaddr::from(address.ip()).with(Protocol::Tcp(address.port()))
+}
+
+/// Resolves all `seeds`. The seeds that failed to be resolved are skipped.
+pub async fn resolve_seeds(
+    resolver: &dyn DnsResolver,
+    seeds: &[String],
+) -> Vec<Multiaddr> {
+    let mut addresses = vec![];
+    for seed in seeds {
+        match resolver.resolve(seed).await {
+            Ok(resolved) => {
+                tracing::debug!("DNS seed {} resolved into {:?}", seed, resolved);
+                addresses.extend(resolved);
+            }
+            Err(e) => {
+                tracing::warn!("Failed to resolve the DNS seed {}: {:?}", seed, e);
+            }
+        }
+    }
+    addresses
+}
+
+/// Returns the stream of the addresses resolved from the `seeds`.
+/// The first resolution happens right away, and the next one after the `refresh_interval`.
+/// If nothing is resolved, the next attempt happens after the [`DNS_SEEDS_RETRY_INTERVAL`].
+pub fn dns_seeds_stream(
+    resolver: Arc<dyn DnsResolver>,
+    seeds: Vec<String>,
+    refresh_interval: Duration,
+) -> BoxStream<'static, Vec<Multiaddr>> {
+    if seeds.is_empty() {
+        return futures::stream::pending().boxed()
+    }
+
+    futures::stream::unfold(Duration::ZERO, move |delay| {
+        let resolver = resolver.clone();
+        let seeds = seeds.clone();
+        async move {
+            tokio::time::sleep(delay).await;
+            let addresses = resolve_seeds(resolver.as_ref(), &seeds).await;
+            let next_delay = if addresses.is_empty() {
+                DNS_SEEDS_RETRY_INTERVAL.min(refresh_interval)
+            } else {
+                refresh_interval
+            };
+            Some((addresses, next_delay))
+        }
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FailingResolver;
+
+    #[async_trait::async_trait]
+    impl DnsResolver for FailingResolver {
+        async fn resolve(&self, seed: &str) -> anyhow::Result<Vec<Multiaddr>> {
+            Err(anyhow::anyhow!("Unknown host {seed}"))
+        }
+    }
+
+    #[tokio::test]
+    async fn dns_seeds_stream_retries_after_resolution_failure() {
+        // Given
+        let seeds = vec!["seed.fuel.network:30333".to_string()];
+        let mut stream =
+            dns_seeds_stream(Arc::new(FailingResolver), seeds, Duration::from_millis(1));
+
+        // When
+        let first = stream.next().await;
+        let second = stream.next().await;
+
+        // Then
+        assert_eq!(first, Some(vec![]));
+        assert_eq!(second, Some(vec![]));
+    }
+
+    #[tokio::test]
+    async fn system_resolver_resolves_ip_seed() {
+        // When
+        let addresses = SystemDnsResolver.resolve("127.0.0.1:30333").await.unwrap();
+
+        // Then
+        let expected: Multiaddr = "/ip4/127.0.0.1/tcp/30333".parse().unwrap();
+        assert_eq!(addresses, vec![expected]);
+    }
+}
@@ -1,3 +1,4 @@
 pub mod config;
 pub mod messages;
+pub mod rate_limiter;
 pub mod topics;
@@ -0,0 +1,170 @@
+use fuel_core_types::services::p2p::PeerId;
+use std::{
+    collections::HashMap,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The verdict of the [`GossipRateLimiter`] about the gossiped message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GossipRateVerdict {
+    /// The peer is within the rate, the message can be processed.
+    Allowed,
+    /// The peer has just exceeded the rate. The message should be ignored
+    /// and the peer should be penalized.
+    Exceeded,
+    /// The peer has already been penalized during the current interval,
+    /// the message should be ignored.
+    Throttled,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    start: Instant,
+    messages: u32,
+}
+
+/// Limits the number of messages gossiped by each peer per interval.
+///
+/// The peer exceeding the limit is reported only once per interval
+/// to avoid flooding the reputation system with the reports.
+#[derive(Debug)]
+pub struct GossipRateLimiter {
+    max_messages_per_interval: u32,
+    interval: Duration,
+    windows: HashMap<PeerId, Window>,
+    next_cleanup: Instant,
+}
+
+impl GossipRateLimiter {
+    /// Creates the limiter allowing `max_messages_per_interval` messages from
+    /// each peer during the `interval`.
+    pub fn new(max_messages_per_interval: u32, interval: Duration) -> Self {
+        Self {
+            max_messages_per_interval,
+            interval,
+            windows: HashMap::new(),
+            next_cleanup: Instant::now(),
+        }
+    }
+
+    /// Records the message from the `peer_id` received at `now`
+    /// and returns what to do with it.
+    pub fn check(&mut self, peer_id: &PeerId, now: Instant) -> GossipRateVerdict {
+        self.remove_expired_windows(now);
+
+        let window = self.windows.entry(peer_id.clone()).or_insert(Window {
+            start: now,
+            messages: 0,
+        });
+        if now.saturating_duration_since(window.start) >= self.interval {
+            *window = Window {
+                start: now,
+                messages: 0,
+            };
+        }
+        window.messages = window.messages.saturating_add(1);
+
+        match window.messages.checked_sub(self.max_messages_per_interval) {
+            None | Some(0) => GossipRateVerdict::Allowed,
+            Some(1) => GossipRateVerdict::Exceeded,
+            Some(_) => GossipRateVerdict::Throttled,
+        }
+    }
+
+    /// Forgets the peers that haven't gossiped anything during the last interval.
+    fn remove_expired_windows(&mut self, now: Instant) {
+        if now < self.next_cleanup {
+            return
+        }
+        let interval = self.interval;
+        self.windows
+            .retain(|_, window| now.saturating_duration_since(window.start) < interval);
+        self.next_cleanup = now.checked_add(interval).unwrap_or(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use super::*;
+
+    const INTERVAL: Duration = Duration::from_secs(1);
+
+    fn peer(byte: u8) -> PeerId {
+        PeerId::from(vec![byte; 32])
+    }
+
+    #[test]
+    fn check__flooding_peer_is_penalized_once_and_throttled() {
+        // Given
+        let mut limiter = GossipRateLimiter::new(3, INTERVAL);
+        let flooding_peer = peer(1);
+        let now = Instant::now();
+
+        // When
+        let verdicts = (0..6)
+            .map(|_| limiter.check(&flooding_peer, now))
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(
+            verdicts,
+            vec![
+                GossipRateVerdict::Allowed,
+                GossipRateVerdict::Allowed,
+                GossipRateVerdict::Allowed,
+                GossipRateVerdict::Exceeded,
+                GossipRateVerdict::Throttled,
+                GossipRateVerdict::Throttled,
+            ]
+        );
+    }
+
+    #[test]
+    fn check__well_behaved_peer_is_unaffected_by_flooding_peer() {
+        // Given
+        let mut limiter = GossipRateLimiter::new(3, INTERVAL);
+        let flooding_peer = peer(1);
+        let well_behaved_peer = peer(2);
+        let now = Instant::now();
+        for _ in 0..10 {
+            limiter.check(&flooding_peer, now);
+        }
+
+        // When
+        let verdicts = (0..3)
+            .map(|_| limiter.check(&well_behaved_peer, now))
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(verdicts, vec![GossipRateVerdict::Allowed; 3]);
+        assert_eq!(
+            limiter.check(&flooding_peer, now),
+            GossipRateVerdict::Throttled
+        );
+    }
+
+    #[test]
+    fn check__throttled_peer_is_allowed_in_the_next_interval() {
+        // Given
+        let mut limiter = GossipRateLimiter::new(1, INTERVAL);
+        let flooding_peer = peer(1);
+        let now = Instant::now();
+        limiter.check(&flooding_peer, now);
+        assert_eq!(
+            limiter.check(&flooding_peer, now),
+            GossipRateVerdict::Exceeded
+        );
+
+        // When
+        let verdict = limiter.check(&flooding_peer, now + INTERVAL);
+
+        // Then
+        assert_eq!(verdict, GossipRateVerdict::Allowed);
+    }
+}
@@ -5,6 +5,7 @@ pub mod behavior;
 pub mod codecs;
 pub mod config;
 pub mod discovery;
+pub mod dns_seeds;
 pub mod gossipsub;
 pub mod heartbeat;
 pub mod p2p_service;
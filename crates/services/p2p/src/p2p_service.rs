@@ -20,6 +20,7 @@ use crate::{
     },
     heartbeat,
     peer_manager::{
+        reconnection::ReconnectionPolicy,
         PeerManager,
         Punisher,
     },
@@ -37,7 +38,10 @@ use crate::{
 use fuel_core_metrics::p2p_metrics::p2p_metrics;
 use fuel_core_types::{
     fuel_types::BlockHeight,
-    services::p2p::peer_reputation::AppScore,
+    services::p2p::{
+        peer_reputation::AppScore,
+        PeerDisconnectReason,
+    },
 };
 use futures::prelude::*;
 use libp2p::{
@@ -56,7 +60,10 @@ use libp2p::{
         OutboundRequestId,
         ResponseChannel,
     },
-    swarm::SwarmEvent,
+    swarm::{
+        DialError,
+        SwarmEvent,
+    },
     Multiaddr,
     PeerId,
     Swarm,
@@ -65,7 +72,10 @@ use libp2p::{
 use rand::seq::IteratorRandom;
 use std::{
     collections::HashMap,
-    time::Duration,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tokio::sync::broadcast;
 use tracing::{
@@ -155,7 +165,7 @@ pub enum FuelP2PEvent {
         request_message: RequestMessage,
     },
     PeerConnected(PeerId),
-    PeerDisconnected(PeerId),
+    PeerDisconnected(PeerId, PeerDisconnectReason),
     PeerInfoUpdated {
         peer_id: PeerId,
         block_height: BlockHeight,
@@ -220,6 +230,14 @@ impl FuelP2PService {
                 reserved_peers,
                 connection_state,
                 config.max_peers_connected as usize,
+                config
+                    .max_connections
+                    .map(|max_connections| max_connections as usize)
+                    .unwrap_or(usize::MAX),
+                ReconnectionPolicy {
+                    initial_backoff: config.reserved_nodes_reconnect_initial_backoff,
+                    max_backoff: config.reserved_nodes_reconnect_max_backoff,
+                },
             ),
         }
     }
@@ -271,6 +289,11 @@ impl FuelP2PService {
             .collect()
     }
 
+    /// Dials the peer behind the `address`.
+    pub fn dial(&mut self, address: Multiaddr) -> Result<(), DialError> {
+        self.swarm.dial(address)
+    }
+
     pub fn get_peers_ids_iter(&self) -> impl Iterator<Item = &PeerId> {
         self.peer_manager.get_peers_ids()
     }
@@ -513,11 +536,9 @@ impl FuelP2PService {
                 self.peer_manager.batch_update_score_with_decay()
             }
             PeerReportEvent::CheckReservedNodesHealth => {
-                let disconnected_peers: Vec<_> = self
+                let disconnected_peers = self
                     .peer_manager
-                    .get_disconnected_reserved_peers()
-                    .copied()
-                    .collect();
+                    .reserved_peers_to_reconnect(Instant::now());
 
                 for peer_id in disconnected_peers {
                     debug!(target: "fuel-p2p", "Trying to reconnect to reserved peer {:?}", peer_id);
@@ -535,14 +556,18 @@ impl FuelP2PService {
                 {
                     let _ = self.swarm.disconnect_peer_id(peer_id);
                 } else if initial_connection {
+                    for pruned_peer_id in self.peer_manager.take_pruned_peers() {
+                        debug!(target: "fuel-p2p", "Disconnecting the pruned peer {:?}", pruned_peer_id);
+                        let _ = self.swarm.disconnect_peer_id(pruned_peer_id);
+                    }
                     return Some(FuelP2PEvent::PeerConnected(peer_id));
                 }
             }
-            PeerReportEvent::PeerDisconnected { peer_id } => {
+            PeerReportEvent::PeerDisconnected { peer_id, reason } => {
                 if self.peer_manager.handle_peer_disconnect(peer_id) {
                     let _ = self.swarm.dial(peer_id);
                 }
-                return Some(FuelP2PEvent::PeerDisconnected(peer_id));
+                return Some(FuelP2PEvent::PeerDisconnected(peer_id, reason));
             }
         }
         None
@@ -706,7 +731,10 @@ mod tests {
         PublishError,
     };
     use crate::{
-        codecs::postcard::PostcardCodec,
+        codecs::postcard::{
+            MessageExchangePostcardProtocol,
+            PostcardCodec,
+        },
         config::Config,
         gossipsub::{
             messages::{
@@ -1508,13 +1536,28 @@ mod tests {
     }
 
     async fn request_response_works_with(request_msg: RequestMessage) {
+        request_response_works_between(
+            request_msg,
+            vec![MessageExchangePostcardProtocol::V1],
+            vec![MessageExchangePostcardProtocol::V1],
+        )
+        .await
+    }
+
+    async fn request_response_works_between(
+        request_msg: RequestMessage,
+        node_a_protocols: Vec<MessageExchangePostcardProtocol>,
+        node_b_protocols: Vec<MessageExchangePostcardProtocol>,
+    ) {
         let mut p2p_config = Config::default_initialized("request_response_works_with");
 
         // Node A
+        p2p_config.req_res_protocols = node_a_protocols;
         let mut node_a = build_service_from_config(p2p_config.clone()).await;
 
         // Node B
         p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        p2p_config.req_res_protocols = node_b_protocols;
         let mut node_b = build_service_from_config(p2p_config.clone()).await;
 
         let (tx_test_end, mut rx_test_end) = mpsc::channel::<bool>(1);
@@ -1616,6 +1659,38 @@ mod tests {
         request_response_works_with(RequestMessage::SealedHeaders(arbitrary_range)).await
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_negotiates_length_delimited_format() {
+        // Node B supports only the length-delimited format,
+        // so the exchange succeeds only if it is negotiated.
+        let arbitrary_range = 2..6;
+        request_response_works_between(
+            RequestMessage::SealedHeaders(arbitrary_range),
+            vec![
+                MessageExchangePostcardProtocol::V2,
+                MessageExchangePostcardProtocol::V1,
+            ],
+            vec![MessageExchangePostcardProtocol::V2],
+        )
+        .await
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_falls_back_to_original_format() {
+        let arbitrary_range = 2..6;
+        request_response_works_between(
+            RequestMessage::Transactions(arbitrary_range),
+            vec![
+                MessageExchangePostcardProtocol::V2,
+                MessageExchangePostcardProtocol::V1,
+            ],
+            vec![MessageExchangePostcardProtocol::V1],
+        )
+        .await
+    }
+
     /// We send a request for transactions, but it's responded by only headers
     #[tokio::test]
     #[instrument]
@@ -22,6 +22,7 @@ use std::{
         Arc,
         RwLock,
     },
+    time::Instant,
 };
 use tracing::{
     debug,
@@ -30,10 +31,17 @@ use tracing::{
 
 use crate::{
     gossipsub_config::GRAYLIST_THRESHOLD,
-    peer_manager::heartbeat_data::HeartbeatData,
+    peer_manager::{
+        heartbeat_data::HeartbeatData,
+        reconnection::{
+            ReconnectionPolicy,
+            Reconnections,
+        },
+    },
 };
 
 pub mod heartbeat_data;
+pub mod reconnection;
 
 /// At this point we better just ban the peer
 const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
@@ -67,7 +75,13 @@ pub struct PeerManager {
     reserved_peers: HashSet<PeerId>,
     connection_state: Arc<RwLock<ConnectionState>>,
     max_non_reserved_peers: usize,
+    /// The max number of peers connected in total, including the reserved ones.
+    max_connections: usize,
+    /// The non-reserved peers pruned to free the slots for the reserved peers,
+    /// that should be disconnected.
+    pruned_peers: Vec<PeerId>,
     reserved_peers_updates: tokio::sync::broadcast::Sender<usize>,
+    reserved_peers_reconnections: Reconnections,
 }
 
 impl PeerManager {
@@ -76,6 +90,8 @@ impl PeerManager {
         reserved_peers: HashSet<PeerId>,
         connection_state: Arc<RwLock<ConnectionState>>,
         max_non_reserved_peers: usize,
+        max_connections: usize,
+        reconnection_policy: ReconnectionPolicy,
     ) -> Self {
         Self {
             score_config: ScoreConfig::default(),
@@ -84,7 +100,10 @@ impl PeerManager {
             reserved_peers,
             connection_state,
             max_non_reserved_peers,
+            max_connections,
+            pruned_peers: vec![],
             reserved_peers_updates,
+            reserved_peers_reconnections: Reconnections::new(reconnection_policy),
         }
     }
 
@@ -183,6 +202,11 @@ impl PeerManager {
             .saturating_add(self.non_reserved_connected_peers.len())
     }
 
+    /// Returns the peers pruned since the last call, that should be disconnected.
+    pub fn take_pruned_peers(&mut self) -> Vec<PeerId> {
+        core::mem::take(&mut self.pruned_peers)
+    }
+
     pub fn get_peers_ids(&self) -> impl Iterator<Item = &PeerId> {
         self.non_reserved_connected_peers
             .keys()
@@ -208,31 +232,34 @@ impl PeerManager {
             .filter(|peer_id| !self.reserved_connected_peers.contains_key(peer_id))
     }
 
+    /// Returns the disconnected reserved peers that should be dialed at `now`
+    /// according to the reconnection backoff.
+    pub fn reserved_peers_to_reconnect(&mut self, now: Instant) -> Vec<PeerId> {
+        let disconnected_peers: Vec<_> =
+            self.get_disconnected_reserved_peers().copied().collect();
+        disconnected_peers
+            .into_iter()
+            .filter(|peer_id| self.reserved_peers_reconnections.try_attempt(peer_id, now))
+            .collect()
+    }
+
     /// Handles on peer's last connection getting disconnected
     /// Returns 'true' signaling we should try reconnecting
     pub fn handle_peer_disconnect(&mut self, peer_id: PeerId) -> bool {
-        // try immediate reconnect if it's a reserved peer
+        // try to reconnect if it's a reserved peer and the backoff allows it
         let is_reserved = self.reserved_peers.contains(&peer_id);
 
         if !is_reserved {
-            // check were all the slots taken prior to this disconnect
-            let all_slots_taken = self.max_non_reserved_peers
-                == self.non_reserved_connected_peers.len().saturating_add(1);
-
-            if self.non_reserved_connected_peers.remove(&peer_id).is_some()
-                && all_slots_taken
-            {
-                // since all the slots were full prior to this disconnect
-                // let's allow new peer non-reserved peers connections
-                if let Ok(mut connection_state) = self.connection_state.write() {
-                    connection_state.allow_new_peers();
-                }
+            if self.non_reserved_connected_peers.remove(&peer_id).is_some() {
+                self.update_connection_state();
             }
 
             false
         } else if self.reserved_connected_peers.remove(&peer_id).is_some() {
+            self.update_connection_state();
             self.send_reserved_peers_update();
-            true
+            self.reserved_peers_reconnections
+                .try_attempt(&peer_id, Instant::now())
         } else {
             false
         }
@@ -260,34 +287,69 @@ impl PeerManager {
 
         // if the connected Peer is not from the reserved peers
         if !self.reserved_peers.contains(peer_id) {
-            let non_reserved_peers_connected = self.non_reserved_connected_peers.len();
             // check if all the slots are already taken
-            if non_reserved_peers_connected >= self.max_non_reserved_peers {
+            if !self.has_non_reserved_slot() {
                 // Too many peers already connected, disconnect the Peer
                 return true
             }
 
-            if non_reserved_peers_connected.saturating_add(1)
-                == self.max_non_reserved_peers
-            {
-                // this is the last non-reserved peer allowed
-                if let Ok(mut connection_state) = self.connection_state.write() {
-                    connection_state.deny_new_peers();
-                }
-            }
-
             self.non_reserved_connected_peers
                 .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
         } else {
+            if self.total_peers_connected() >= self.max_connections
+                && !self.prune_lowest_score_peer()
+            {
+                // All the connections are taken by the reserved peers
+                return true
+            }
+
             self.reserved_connected_peers
                 .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
+            self.reserved_peers_reconnections.on_connected(peer_id);
 
             self.send_reserved_peers_update();
         }
+        self.update_connection_state();
 
         false
     }
 
+    fn has_non_reserved_slot(&self) -> bool {
+        self.non_reserved_connected_peers.len() < self.max_non_reserved_peers
+            && self.total_peers_connected() < self.max_connections
+    }
+
+    /// Allows new non-reserved peers connections only while there is a slot for them.
+    fn update_connection_state(&self) {
+        let has_slot = self.has_non_reserved_slot();
+        if let Ok(mut connection_state) = self.connection_state.write() {
+            if has_slot {
+                connection_state.allow_new_peers();
+            } else {
+                connection_state.deny_new_peers();
+            }
+        }
+    }
+
+    /// Removes the non-reserved peer with the lowest score to free the connection.
+    /// Returns `false` if there is no non-reserved peer to prune.
+    fn prune_lowest_score_peer(&mut self) -> bool {
+        let lowest_score_peer = self
+            .non_reserved_connected_peers
+            .iter()
+            .min_by(|(_, a), (_, b)| a.score.total_cmp(&b.score))
+            .map(|(peer_id, _)| *peer_id);
+
+        if let Some(peer_id) = lowest_score_peer {
+            debug!(target: "fuel-p2p", "Pruning the peer {:?} to free the connection for the reserved peer", peer_id);
+            self.non_reserved_connected_peers.remove(&peer_id);
+            self.pruned_peers.push(peer_id);
+            true
+        } else {
+            false
+        }
+    }
+
     fn send_reserved_peers_update(&self) {
         let _ = self
             .reserved_peers_updates
@@ -403,6 +465,12 @@ pub trait Punisher {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
+
+    const RECONNECTION_POLICY: ReconnectionPolicy = ReconnectionPolicy {
+        initial_backoff: Duration::from_secs(1),
+        max_backoff: Duration::from_secs(60),
+    };
 
     fn get_random_peers(size: usize) -> Vec<PeerId> {
         (0..size).map(|_| PeerId::random()).collect()
@@ -411,6 +479,18 @@ mod tests {
     fn initialize_peer_manager(
         reserved_peers: Vec<PeerId>,
         max_non_reserved_peers: usize,
+    ) -> PeerManager {
+        initialize_peer_manager_with_max_connections(
+            reserved_peers,
+            max_non_reserved_peers,
+            usize::MAX,
+        )
+    }
+
+    fn initialize_peer_manager_with_max_connections(
+        reserved_peers: Vec<PeerId>,
+        max_non_reserved_peers: usize,
+        max_connections: usize,
     ) -> PeerManager {
         let connection_state = ConnectionState::new();
         let (sender, _) =
@@ -421,6 +501,8 @@ mod tests {
             reserved_peers.into_iter().collect(),
             connection_state,
             max_non_reserved_peers,
+            max_connections,
+            RECONNECTION_POLICY,
         )
     }
 
@@ -499,4 +581,122 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[test]
+    fn dropped_reserved_peer_is_redialed_with_backoff() {
+        let reserved_peers = get_random_peers(1);
+        let reserved_peer = reserved_peers[0];
+        let mut peer_manager = initialize_peer_manager(reserved_peers, 0);
+        peer_manager.handle_initial_connection(&reserved_peer);
+
+        // the first reconnection attempt happens right after the disconnect
+        assert!(peer_manager.handle_peer_disconnect(reserved_peer));
+
+        // the next attempts wait for the doubling backoff
+        let now = Instant::now();
+        let initial_backoff = RECONNECTION_POLICY.initial_backoff;
+        assert!(peer_manager.reserved_peers_to_reconnect(now).is_empty());
+        let first_retry = now + initial_backoff;
+        assert_eq!(
+            peer_manager.reserved_peers_to_reconnect(first_retry),
+            vec![reserved_peer]
+        );
+        assert!(peer_manager
+            .reserved_peers_to_reconnect(first_retry + initial_backoff)
+            .is_empty());
+        assert_eq!(
+            peer_manager.reserved_peers_to_reconnect(first_retry + initial_backoff * 2),
+            vec![reserved_peer]
+        );
+
+        // the backoff is reset after the peer reconnects
+        peer_manager.handle_initial_connection(&reserved_peer);
+        assert!(peer_manager.handle_peer_disconnect(reserved_peer));
+    }
+
+    #[test]
+    fn new_peers_are_refused_past_max_connections() {
+        let max_connections = 5;
+        let reserved_peers = get_random_peers(2);
+        let mut peer_manager = initialize_peer_manager_with_max_connections(
+            reserved_peers.clone(),
+            10,
+            max_connections,
+        );
+
+        for peer_id in &reserved_peers {
+            assert!(!peer_manager.handle_initial_connection(peer_id));
+        }
+
+        // try connecting more random peers than the connections left
+        let random_peers = get_random_peers(max_connections);
+        let refused = random_peers
+            .iter()
+            .filter(|peer_id| peer_manager.handle_initial_connection(peer_id))
+            .count();
+
+        assert_eq!(
+            refused,
+            random_peers.len() - (max_connections - reserved_peers.len())
+        );
+        assert_eq!(peer_manager.total_peers_connected(), max_connections);
+        assert!(!peer_manager
+            .connection_state
+            .read()
+            .unwrap()
+            .available_slot());
+
+        // the slot is available again after a disconnect
+        peer_manager.handle_peer_disconnect(random_peers[0]);
+        assert!(peer_manager
+            .connection_state
+            .read()
+            .unwrap()
+            .available_slot());
+    }
+
+    #[test]
+    fn reserved_peer_prunes_the_lowest_score_peer_at_max_connections() {
+        let max_connections = 3;
+        let reserved_peers = get_random_peers(1);
+        let reserved_peer = reserved_peers[0];
+        let mut peer_manager = initialize_peer_manager_with_max_connections(
+            reserved_peers,
+            10,
+            max_connections,
+        );
+
+        let random_peers = get_random_peers(max_connections);
+        for (i, peer_id) in random_peers.iter().enumerate() {
+            peer_manager.handle_initial_connection(peer_id);
+            peer_manager
+                .non_reserved_connected_peers
+                .get_mut(peer_id)
+                .unwrap()
+                .score = DEFAULT_APP_SCORE - i as AppScore;
+        }
+        let lowest_score_peer = *random_peers.last().unwrap();
+
+        // the reserved peer takes the connection of the lowest score peer
+        assert!(!peer_manager.handle_initial_connection(&reserved_peer));
+
+        assert_eq!(peer_manager.total_peers_connected(), max_connections);
+        assert!(peer_manager.get_peer_info(&reserved_peer).is_some());
+        assert!(peer_manager.get_peer_info(&lowest_score_peer).is_none());
+        assert_eq!(peer_manager.take_pruned_peers(), vec![lowest_score_peer]);
+        assert!(peer_manager.take_pruned_peers().is_empty());
+    }
+
+    #[test]
+    fn reserved_peer_is_refused_when_max_connections_are_taken_by_reserved_peers() {
+        let reserved_peers = get_random_peers(2);
+        let mut peer_manager =
+            initialize_peer_manager_with_max_connections(reserved_peers.clone(), 10, 1);
+
+        assert!(!peer_manager.handle_initial_connection(&reserved_peers[0]));
+        assert!(peer_manager.handle_initial_connection(&reserved_peers[1]));
+
+        assert_eq!(peer_manager.total_peers_connected(), 1);
+        assert!(peer_manager.take_pruned_peers().is_empty());
+    }
 }
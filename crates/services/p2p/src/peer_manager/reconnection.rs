@@ -0,0 +1,124 @@
+use libp2p::PeerId;
+use std::{
+    collections::HashMap,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The policy of the reconnection to the disconnected reserved peers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectionPolicy {
+    /// The delay between the first and the second attempts to reconnect to the peer.
+    /// The first attempt happens right after the disconnect.
+    pub initial_backoff: Duration,
+    /// The delay doubles after each attempt until it reaches the `max_backoff`.
+    pub max_backoff: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Backoff {
+    next_attempt: Instant,
+    delay: Duration,
+}
+
+/// Tracks the attempts to reconnect to the disconnected peers with the exponential backoff.
+///
+/// It is used only for the reserved peers. They are never banned by the
+/// [`super::PeerManager`], so there are no banned peers to skip.
+#[derive(Debug)]
+pub struct Reconnections {
+    policy: ReconnectionPolicy,
+    backoffs: HashMap<PeerId, Backoff>,
+}
+
+impl Reconnections {
+    pub fn new(policy: ReconnectionPolicy) -> Self {
+        Self {
+            policy,
+            backoffs: HashMap::new(),
+        }
+    }
+
+    /// Returns `true` if the peer should be dialed at `now`.
+    /// The next attempt is allowed after the current delay, and the delay doubles.
+    pub fn try_attempt(&mut self, peer_id: &PeerId, now: Instant) -> bool {
+        let policy = self.policy;
+        let backoff = self.backoffs.entry(*peer_id).or_insert(Backoff {
+            next_attempt: now,
+            delay: policy.initial_backoff.min(policy.max_backoff),
+        });
+        if now < backoff.next_attempt {
+            return false
+        }
+
+        backoff.next_attempt = now.checked_add(backoff.delay).unwrap_or(now);
+        backoff.delay = backoff.delay.saturating_mul(2).min(policy.max_backoff);
+        true
+    }
+
+    /// Resets the backoff of the peer after the successful connection.
+    pub fn on_connected(&mut self, peer_id: &PeerId) {
+        self.backoffs.remove(peer_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    #![allow(clippy::arithmetic_side_effects)]
+
+    use super::*;
+
+    const POLICY: ReconnectionPolicy = ReconnectionPolicy {
+        initial_backoff: Duration::from_secs(1),
+        max_backoff: Duration::from_secs(4),
+    };
+
+    /// Returns the milliseconds since `start` at which the attempts are allowed,
+    /// checking every 100 milliseconds during the `seconds`.
+    fn attempts(
+        reconnections: &mut Reconnections,
+        peer_id: &PeerId,
+        start: Instant,
+        seconds: u64,
+    ) -> Vec<u128> {
+        (0..seconds * 10)
+            .map(|tick| start + Duration::from_millis(tick * 100))
+            .filter(|now| reconnections.try_attempt(peer_id, *now))
+            .map(|now| (now - start).as_millis())
+            .collect()
+    }
+
+    #[test]
+    fn try_attempt__dropped_peer_is_dialed_with_exponential_backoff() {
+        // Given
+        let mut reconnections = Reconnections::new(POLICY);
+        let peer_id = PeerId::random();
+        let start = Instant::now();
+
+        // When
+        let attempts = attempts(&mut reconnections, &peer_id, start, 20);
+
+        // Then
+        assert_eq!(attempts, vec![0, 1000, 3000, 7000, 11000, 15000, 19000]);
+    }
+
+    #[test]
+    fn try_attempt__backoff_is_reset_after_connection() {
+        // Given
+        let mut reconnections = Reconnections::new(POLICY);
+        let peer_id = PeerId::random();
+        let start = Instant::now();
+        attempts(&mut reconnections, &peer_id, start, 10);
+
+        // When
+        reconnections.on_connected(&peer_id);
+        let restart = start + Duration::from_secs(10);
+        let attempts = attempts(&mut reconnections, &peer_id, restart, 4);
+
+        // Then
+        assert_eq!(attempts, vec![0, 1000, 3000]);
+    }
+}
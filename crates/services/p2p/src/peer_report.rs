@@ -1,4 +1,5 @@
 use crate::config::Config;
+use fuel_core_types::services::p2p::PeerDisconnectReason;
 use libp2p::{
     self,
     core::Endpoint,
@@ -35,6 +36,7 @@ use tokio::time::{
 };
 
 const HEALTH_CHECK_INTERVAL_IN_SECONDS: u64 = 10;
+const MIN_HEALTH_CHECK_INTERVAL: Duration = Duration::from_millis(100);
 const REPUTATION_DECAY_INTERVAL_IN_SECONDS: u64 = 1;
 
 /// Events emitted by PeerReportBehavior
@@ -46,6 +48,7 @@ pub enum PeerReportEvent {
     },
     PeerDisconnected {
         peer_id: PeerId,
+        reason: PeerDisconnectReason,
     },
     /// Informs p2p service / PeerManager to check health of reserved nodes' connections
     CheckReservedNodesHealth,
@@ -62,12 +65,15 @@ pub struct Behaviour {
 }
 
 impl Behaviour {
-    pub(crate) fn new(_config: &Config) -> Self {
+    pub(crate) fn new(config: &Config) -> Self {
+        // The reserved nodes are checked at least as often as the first
+        // reconnection backoff, so the reconnection attempts are not delayed.
+        let health_check_interval = Duration::from_secs(HEALTH_CHECK_INTERVAL_IN_SECONDS)
+            .min(config.reserved_nodes_reconnect_initial_backoff)
+            .max(MIN_HEALTH_CHECK_INTERVAL);
         Self {
             pending_events: VecDeque::default(),
-            health_check: time::interval(Duration::from_secs(
-                HEALTH_CHECK_INTERVAL_IN_SECONDS,
-            )),
+            health_check: time::interval(health_check_interval),
             decay_interval: time::interval(Duration::from_secs(
                 REPUTATION_DECAY_INTERVAL_IN_SECONDS,
             )),
@@ -117,13 +123,18 @@ impl NetworkBehaviour for Behaviour {
                 let ConnectionClosed {
                     remaining_established,
                     peer_id,
+                    cause,
                     ..
                 } = connection_closed;
 
                 if remaining_established == 0 {
                     // this was the last connection to a given Peer
+                    let reason = match cause {
+                        Some(error) => PeerDisconnectReason::Error(error.to_string()),
+                        None => PeerDisconnectReason::Closed,
+                    };
                     self.pending_events
-                        .push_back(PeerReportEvent::PeerDisconnected { peer_id })
+                        .push_back(PeerReportEvent::PeerDisconnected { peer_id, reason })
                 }
             }
             _ => {}
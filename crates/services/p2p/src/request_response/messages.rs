@@ -15,6 +15,8 @@ use thiserror::Error;
 use tokio::sync::oneshot;
 
 pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
+/// The version of the protocol with the length-delimited messages.
+pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID_V2: &str = "/fuel/req_res/0.0.2";
 
 /// Max Size in Bytes of the Request Message
 #[cfg(test)]
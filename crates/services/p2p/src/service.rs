@@ -4,9 +4,20 @@ use crate::{
         Config,
         NotInitialized,
     },
-    gossipsub::messages::{
-        GossipsubBroadcastRequest,
-        GossipsubMessage,
+    dns_seeds::{
+        dns_seeds_stream,
+        DnsResolver,
+        SystemDnsResolver,
+    },
+    gossipsub::{
+        messages::{
+            GossipsubBroadcastRequest,
+            GossipsubMessage,
+        },
+        rate_limiter::{
+            GossipRateLimiter,
+            GossipRateVerdict,
+        },
     },
     p2p_service::{
         FuelP2PEvent,
@@ -52,6 +63,7 @@ use fuel_core_types::{
         GossipData,
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
+        PeerConnectionEvent,
         PeerId as FuelPeerId,
         TransactionGossipData,
         Transactions,
@@ -59,11 +71,13 @@ use fuel_core_types::{
 };
 use futures::{
     future::BoxFuture,
+    stream::BoxStream as SendBoxStream,
     StreamExt,
 };
 use libp2p::{
     gossipsub::MessageAcceptance,
     request_response::InboundRequestId,
+    Multiaddr,
     PeerId,
 };
 use std::{
@@ -186,6 +200,8 @@ pub trait TaskP2PService: Send {
     ) -> anyhow::Result<()>;
 
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()>;
+
+    fn dial(&mut self, address: Multiaddr) -> anyhow::Result<()>;
 }
 
 impl TaskP2PService for FuelP2PService {
@@ -255,6 +271,11 @@ impl TaskP2PService for FuelP2PService {
         self.update_block_height(height);
         Ok(())
     }
+
+    fn dial(&mut self, address: Multiaddr) -> anyhow::Result<()> {
+        self.dial(address)?;
+        Ok(())
+    }
 }
 
 pub trait Broadcast: Send {
@@ -271,6 +292,9 @@ pub trait Broadcast: Send {
     ) -> anyhow::Result<()>;
 
     fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()>;
+
+    fn peer_connection_broadcast(&self, event: PeerConnectionEvent)
+        -> anyhow::Result<()>;
 }
 
 impl Broadcast for SharedState {
@@ -295,6 +319,14 @@ impl Broadcast for SharedState {
         self.tx_broadcast.send(transaction)?;
         Ok(())
     }
+
+    fn peer_connection_broadcast(
+        &self,
+        event: PeerConnectionEvent,
+    ) -> anyhow::Result<()> {
+        self.peer_connection_broadcast.send(event)?;
+        Ok(())
+    }
 }
 
 /// Uninitialized task for the p2p that can be upgraded later into [`Task`].
@@ -306,6 +338,7 @@ pub struct UninitializedTask<V, B> {
     request_receiver: mpsc::Receiver<TaskRequest>,
     broadcast: B,
     config: Config<NotInitialized>,
+    dns_resolver: Arc<dyn DnsResolver>,
 }
 
 /// Orchestrates various p2p-related events between the inner `P2pService`
@@ -315,6 +348,8 @@ pub struct Task<P, V, B> {
     p2p_service: P,
     view_provider: V,
     next_block_height: BoxStream<BlockHeight>,
+    /// The addresses of the peers resolved from the DNS seeds.
+    dns_seed_addresses: SendBoxStream<'static, Vec<Multiaddr>>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     broadcast: B,
@@ -325,6 +360,9 @@ pub struct Task<P, V, B> {
     heartbeat_max_time_since_last: Duration,
     next_check_time: Instant,
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
+    /// Limits the number of transactions gossiped by each peer per second.
+    gossip_rate_limiter: GossipRateLimiter,
+    gossip_rate_exceeded_penalty: AppScore,
 }
 
 #[derive(Clone)]
@@ -343,6 +381,7 @@ impl<V> UninitializedTask<V, SharedState> {
         let (request_sender, request_receiver) = mpsc::channel(1024 * 10);
         let (tx_broadcast, _) = broadcast::channel(1024 * 10);
         let (block_height_broadcast, _) = broadcast::channel(1024 * 10);
+        let (peer_connection_broadcast, _) = broadcast::channel(1024);
 
         let (reserved_peers_broadcast, _) = broadcast::channel::<usize>(
             config
@@ -363,12 +402,22 @@ impl<V> UninitializedTask<V, SharedState> {
                 tx_broadcast,
                 reserved_peers_broadcast,
                 block_height_broadcast,
+                peer_connection_broadcast,
             },
             config,
+            dns_resolver: Arc::new(SystemDnsResolver),
         }
     }
 }
 
+impl<V, B> UninitializedTask<V, B> {
+    /// Overrides the resolver of the DNS seeds.
+    pub fn with_dns_resolver(mut self, dns_resolver: Arc<dyn DnsResolver>) -> Self {
+        self.dns_resolver = dns_resolver;
+        self
+    }
+}
+
 impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
     fn peer_heartbeat_reputation_checks(&self) -> anyhow::Result<()> {
         for (peer_id, peer_info) in self.p2p_service.get_all_peer_info() {
@@ -410,6 +459,40 @@ impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
             .report_peer(peer_id, app_score, reporting_service)?;
         Ok(())
     }
+
+    /// Records the gossiped message from the `peer_id`. The messages above the rate
+    /// are ignored before reaching the subscribers, and the peer exceeding the rate
+    /// is penalized.
+    fn check_gossip_rate(
+        &mut self,
+        peer_id: &PeerId,
+        message_id: Vec<u8>,
+    ) -> GossipRateVerdict {
+        let fuel_peer_id = FuelPeerId::from(peer_id.to_bytes());
+        let verdict = self
+            .gossip_rate_limiter
+            .check(&fuel_peer_id, Instant::now().into_std());
+        if verdict == GossipRateVerdict::Allowed {
+            return verdict
+        }
+
+        let message_info = GossipsubMessageInfo {
+            message_id,
+            peer_id: fuel_peer_id.clone(),
+        };
+        let _ = self
+            .p2p_service
+            .report_message(message_info, GossipsubMessageAcceptance::Ignore);
+        if verdict == GossipRateVerdict::Exceeded {
+            tracing::debug!("Peer {:?} exceeded the gossip rate limit", peer_id);
+            let _ = self.broadcast.report_peer(
+                fuel_peer_id,
+                self.gossip_rate_exceeded_penalty,
+                "p2p",
+            );
+        }
+        verdict
+    }
 }
 
 fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
@@ -445,6 +528,7 @@ where
             request_receiver,
             broadcast,
             config,
+            dns_resolver,
         } = self;
 
         let view = view_provider.latest_view();
@@ -456,8 +540,14 @@ where
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
+            max_gossip_transactions_per_peer,
             ..
         } = config;
+        let dns_seed_addresses = dns_seeds_stream(
+            dns_resolver,
+            config.dns_seeds.clone(),
+            config.dns_seeds_refresh_interval,
+        );
 
         // Hardcoded for now, but left here to be configurable in the future.
         // TODO: https://github.com/FuelLabs/fuel-core/issues/1340
@@ -465,6 +555,11 @@ where
             old_heartbeat_penalty: -5.,
             low_heartbeat_frequency_penalty: -5.,
         };
+        let gossip_rate_limiter = GossipRateLimiter::new(
+            max_gossip_transactions_per_peer,
+            Duration::from_secs(1),
+        );
+        let gossip_rate_exceeded_penalty = -10.;
 
         let mut p2p_service = FuelP2PService::new(
             broadcast.reserved_peers_broadcast.clone(),
@@ -484,6 +579,7 @@ where
             view_provider,
             request_receiver,
             next_block_height,
+            dns_seed_addresses,
             broadcast,
             max_headers_per_request,
             heartbeat_check_interval,
@@ -491,6 +587,8 @@ where
             heartbeat_max_time_since_last,
             next_check_time,
             heartbeat_peer_reputation_config,
+            gossip_rate_limiter,
+            gossip_rate_exceeded_penalty,
         };
         Ok(task)
     }
@@ -579,13 +677,33 @@ where
 
                         let _ = self.broadcast.block_height_broadcast(block_height_data);
                     }
+                    Some(FuelP2PEvent::PeerConnected(peer_id)) => {
+                        let peer_id: Vec<u8> = peer_id.into();
+                        let event = PeerConnectionEvent::PeerConnected {
+                            peer_id: peer_id.into(),
+                        };
+
+                        let _ = self.broadcast.peer_connection_broadcast(event);
+                    }
+                    Some(FuelP2PEvent::PeerDisconnected(peer_id, reason)) => {
+                        let peer_id: Vec<u8> = peer_id.into();
+                        let event = PeerConnectionEvent::PeerDisconnected {
+                            peer_id: peer_id.into(),
+                            reason,
+                        };
+
+                        let _ = self.broadcast.peer_connection_broadcast(event);
+                    }
                     Some(FuelP2PEvent::GossipsubMessage { message, message_id, peer_id,.. }) => {
                         let message_id = message_id.0;
 
                         match message {
                             GossipsubMessage::NewTx(transaction) => {
-                                let next_transaction = GossipData::new(transaction, peer_id, message_id);
-                                let _ = self.broadcast.tx_broadcast(next_transaction);
+                                let verdict = self.check_gossip_rate(&peer_id, message_id.clone());
+                                if verdict == GossipRateVerdict::Allowed {
+                                    let next_transaction = GossipData::new(transaction, peer_id, message_id);
+                                    let _ = self.broadcast.tx_broadcast(next_transaction);
+                                }
                             },
                         }
                     },
@@ -644,6 +762,14 @@ where
                 }
                 self.next_check_time += self.heartbeat_check_interval;
             },
+            dns_seed_addresses = self.dns_seed_addresses.next() => {
+                should_continue = true;
+                for address in dns_seed_addresses.into_iter().flatten() {
+                    if let Err(e) = self.p2p_service.dial(address.clone()) {
+                        tracing::warn!("Failed to dial the DNS seed address {}: {:?}", address, e);
+                    }
+                }
+            }
             latest_block_height = self.next_block_height.next() => {
                 if let Some(latest_block_height) = latest_block_height {
                     let _ = self.p2p_service.update_block_height(latest_block_height);
@@ -680,6 +806,8 @@ pub struct SharedState {
     request_sender: mpsc::Sender<TaskRequest>,
     /// Sender of p2p blopck height data
     block_height_broadcast: broadcast::Sender<BlockHeightHeartbeatData>,
+    /// Sender of the events about connected and disconnected peers.
+    peer_connection_broadcast: broadcast::Sender<PeerConnectionEvent>,
 }
 
 impl SharedState {
@@ -790,6 +918,11 @@ impl SharedState {
         self.reserved_peers_broadcast.subscribe()
     }
 
+    /// Subscribes to the events about connections with other peers.
+    pub fn subscribe_peer_connections(&self) -> broadcast::Receiver<PeerConnectionEvent> {
+        self.peer_connection_broadcast.subscribe()
+    }
+
     pub fn report_peer<T: PeerReport>(
         &self,
         peer_id: FuelPeerId,
@@ -949,8 +1082,12 @@ pub mod tests {
         assert!(service.stop_and_await().await.unwrap().stopped());
     }
 
+    #[derive(Default)]
     struct FakeP2PService {
         peer_info: Vec<(PeerId, PeerInfo)>,
+        dialed: Vec<Multiaddr>,
+        events: VecDeque<FuelP2PEvent>,
+        reported_messages: Vec<(GossipsubMessageInfo, GossipsubMessageAcceptance)>,
     }
 
     impl TaskP2PService for FakeP2PService {
@@ -967,7 +1104,10 @@ pub mod tests {
         }
 
         fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
-            std::future::pending().boxed()
+            match self.events.pop_front() {
+                Some(event) => futures::future::ready(Some(event)).boxed(),
+                None => std::future::pending().boxed(),
+            }
         }
 
         fn publish_message(
@@ -996,10 +1136,11 @@ pub mod tests {
 
         fn report_message(
             &mut self,
-            _message: GossipsubMessageInfo,
-            _acceptance: GossipsubMessageAcceptance,
+            message: GossipsubMessageInfo,
+            acceptance: GossipsubMessageAcceptance,
         ) -> anyhow::Result<()> {
-            todo!()
+            self.reported_messages.push((message, acceptance));
+            Ok(())
         }
 
         fn report_peer(
@@ -1014,6 +1155,11 @@ pub mod tests {
         fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
             todo!()
         }
+
+        fn dial(&mut self, address: Multiaddr) -> anyhow::Result<()> {
+            self.dialed.push(address);
+            Ok(())
+        }
     }
 
     #[derive(Clone)]
@@ -1059,6 +1205,7 @@ pub mod tests {
 
     struct FakeBroadcast {
         pub peer_reports: mpsc::Sender<(FuelPeerId, AppScore, String)>,
+        pub transactions: mpsc::Sender<TransactionGossipData>,
     }
 
     impl Broadcast for FakeBroadcast {
@@ -1083,9 +1230,14 @@ pub mod tests {
             todo!()
         }
 
-        fn tx_broadcast(
+        fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()> {
+            self.transactions.try_send(transaction)?;
+            Ok(())
+        }
+
+        fn peer_connection_broadcast(
             &self,
-            _transaction: TransactionGossipData,
+            _event: PeerConnectionEvent,
         ) -> anyhow::Result<()> {
             todo!()
         }
@@ -1114,12 +1266,17 @@ pub mod tests {
             score: 100.0,
         };
         let peer_info = vec![(peer_id, peer_info)];
-        let p2p_service = FakeP2PService { peer_info };
+        let p2p_service = FakeP2PService {
+            peer_info,
+            ..Default::default()
+        };
         let (_request_sender, request_receiver) = mpsc::channel(100);
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
+        let (transaction_sender, _transaction_receiver) = mpsc::channel(100);
         let broadcast = FakeBroadcast {
             peer_reports: report_sender,
+            transactions: transaction_sender,
         };
 
         // Less than actual
@@ -1138,6 +1295,7 @@ pub mod tests {
             p2p_service,
             view_provider: FakeDB,
             next_block_height: FakeBlockImporter.next_block_height(),
+            dns_seed_addresses: futures::stream::pending().boxed(),
             request_receiver,
             broadcast,
             max_headers_per_request: 0,
@@ -1146,6 +1304,8 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            gossip_rate_limiter: GossipRateLimiter::new(100, Duration::from_secs(1)),
+            gossip_rate_exceeded_penalty: -10.,
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1195,12 +1355,17 @@ pub mod tests {
             score: 100.0,
         };
         let peer_info = vec![(peer_id, peer_info)];
-        let p2p_service = FakeP2PService { peer_info };
+        let p2p_service = FakeP2PService {
+            peer_info,
+            ..Default::default()
+        };
         let (_request_sender, request_receiver) = mpsc::channel(100);
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
+        let (transaction_sender, _transaction_receiver) = mpsc::channel(100);
         let broadcast = FakeBroadcast {
             peer_reports: report_sender,
+            transactions: transaction_sender,
         };
 
         // Greater than actual
@@ -1219,6 +1384,7 @@ pub mod tests {
             p2p_service,
             view_provider: FakeDB,
             next_block_height: FakeBlockImporter.next_block_height(),
+            dns_seed_addresses: futures::stream::pending().boxed(),
             request_receiver,
             broadcast,
             max_headers_per_request: 0,
@@ -1227,6 +1393,8 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            gossip_rate_limiter: GossipRateLimiter::new(100, Duration::from_secs(1)),
+            gossip_rate_exceeded_penalty: -10.,
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1250,4 +1418,166 @@ pub mod tests {
         );
         assert_eq!(reporting_service, "p2p");
     }
+
+    struct FakeDnsResolver {
+        addresses: Vec<Multiaddr>,
+    }
+
+    #[async_trait::async_trait]
+    impl DnsResolver for FakeDnsResolver {
+        async fn resolve(&self, _seed: &str) -> anyhow::Result<Vec<Multiaddr>> {
+            Ok(self.addresses.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn run__dials_addresses_resolved_from_dns_seeds() {
+        // given
+        let addresses: Vec<Multiaddr> = vec![
+            "/ip4/10.0.0.1/tcp/30333".parse().unwrap(),
+            "/ip4/10.0.0.2/tcp/30333".parse().unwrap(),
+        ];
+        let resolver = FakeDnsResolver {
+            addresses: addresses.clone(),
+        };
+        let dns_seed_addresses = dns_seeds_stream(
+            Arc::new(resolver),
+            vec!["seed.fuel.network:30333".to_string()],
+            Duration::from_secs(300),
+        );
+        let p2p_service = FakeP2PService::default();
+        let (_request_sender, request_receiver) = mpsc::channel(100);
+        let (report_sender, _report_receiver) = mpsc::channel(100);
+        let (transaction_sender, _transaction_receiver) = mpsc::channel(100);
+        let broadcast = FakeBroadcast {
+            peer_reports: report_sender,
+            transactions: transaction_sender,
+        };
+        let mut task = Task {
+            chain_id: Default::default(),
+            p2p_service,
+            view_provider: FakeDB,
+            next_block_height: FakeBlockImporter.next_block_height(),
+            dns_seed_addresses,
+            request_receiver,
+            broadcast,
+            max_headers_per_request: 0,
+            heartbeat_check_interval: Duration::from_secs(100),
+            heartbeat_max_avg_interval: Duration::from_secs(20),
+            heartbeat_max_time_since_last: Duration::from_secs(40),
+            next_check_time: Instant::now()
+                .checked_add(Duration::from_secs(100))
+                .unwrap(),
+            heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig {
+                old_heartbeat_penalty: 5.6,
+                low_heartbeat_frequency_penalty: 20.45,
+            },
+            gossip_rate_limiter: GossipRateLimiter::new(100, Duration::from_secs(1)),
+            gossip_rate_exceeded_penalty: -10.,
+        };
+        let (_watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
+        let mut watcher = StateWatcher::from(watch_receiver);
+
+        // when
+        task.run(&mut watcher).await.unwrap();
+
+        // then
+        assert_eq!(task.p2p_service.dialed, addresses);
+    }
+
+    fn gossiped_transaction(peer_id: PeerId, seed: u8) -> FuelP2PEvent {
+        FuelP2PEvent::GossipsubMessage {
+            peer_id,
+            message_id: libp2p::gossipsub::MessageId::new(&[seed]),
+            topic_hash: libp2p::gossipsub::TopicHash::from_raw("new_tx"),
+            message: GossipsubMessage::NewTx(Transaction::default_test_tx()),
+        }
+    }
+
+    #[tokio::test]
+    async fn run__ignores_and_penalizes_peer_exceeding_gossip_rate() {
+        // given
+        let max_transactions_per_peer = 2;
+        let flooding_peer = PeerId::random();
+        let honest_peer = PeerId::random();
+        let mut events: VecDeque<_> = (0..5)
+            .map(|seed| gossiped_transaction(flooding_peer, seed))
+            .collect();
+        events.push_back(gossiped_transaction(honest_peer, 5));
+        let number_of_events = events.len();
+        let p2p_service = FakeP2PService {
+            events,
+            ..Default::default()
+        };
+        let (_request_sender, request_receiver) = mpsc::channel(100);
+        let (report_sender, mut report_receiver) = mpsc::channel(100);
+        let (transaction_sender, mut transaction_receiver) = mpsc::channel(100);
+        let broadcast = FakeBroadcast {
+            peer_reports: report_sender,
+            transactions: transaction_sender,
+        };
+        let gossip_rate_exceeded_penalty = -10.;
+        let mut task = Task {
+            chain_id: Default::default(),
+            p2p_service,
+            view_provider: FakeDB,
+            next_block_height: FakeBlockImporter.next_block_height(),
+            dns_seed_addresses: futures::stream::pending().boxed(),
+            request_receiver,
+            broadcast,
+            max_headers_per_request: 0,
+            heartbeat_check_interval: Duration::from_secs(100),
+            heartbeat_max_avg_interval: Duration::from_secs(20),
+            heartbeat_max_time_since_last: Duration::from_secs(40),
+            next_check_time: Instant::now()
+                .checked_add(Duration::from_secs(100))
+                .unwrap(),
+            heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig {
+                old_heartbeat_penalty: 5.6,
+                low_heartbeat_frequency_penalty: 20.45,
+            },
+            gossip_rate_limiter: GossipRateLimiter::new(
+                max_transactions_per_peer,
+                Duration::from_secs(100),
+            ),
+            gossip_rate_exceeded_penalty,
+        };
+        let (_watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
+        let mut watcher = StateWatcher::from(watch_receiver);
+
+        // when
+        for _ in 0..number_of_events {
+            task.run(&mut watcher).await.unwrap();
+        }
+
+        // then
+        let mut broadcasted_peers = vec![];
+        while let Ok(transaction) = transaction_receiver.try_recv() {
+            broadcasted_peers.push(transaction.peer_id);
+        }
+        let flooding_peer_id = FuelPeerId::from(flooding_peer.to_bytes());
+        let honest_peer_id = FuelPeerId::from(honest_peer.to_bytes());
+        assert_eq!(
+            broadcasted_peers,
+            vec![
+                flooding_peer_id.clone(),
+                flooding_peer_id.clone(),
+                honest_peer_id
+            ]
+        );
+
+        let ignored = &task.p2p_service.reported_messages;
+        assert_eq!(ignored.len(), 3);
+        assert!(ignored.iter().all(|(info, acceptance)| {
+            info.peer_id == flooding_peer_id
+                && *acceptance == GossipsubMessageAcceptance::Ignore
+        }));
+
+        let (report_peer_id, report, reporting_service) =
+            report_receiver.try_recv().unwrap();
+        assert_eq!(report_peer_id, flooding_peer_id);
+        assert_eq!(report, gossip_rate_exceeded_penalty);
+        assert_eq!(reporting_service, "p2p");
+        assert!(report_receiver.try_recv().is_err());
+    }
 }
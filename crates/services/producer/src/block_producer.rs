@@ -29,6 +29,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            ExecutionResult,
             TransactionExecutionStatus,
             UncommittedResult,
         },
@@ -37,6 +38,7 @@ use fuel_core_types::{
 };
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tokio_rayon::AsyncThreadPool;
 use tracing::debug;
 
 #[cfg(test)]
@@ -100,6 +102,7 @@ where
     ) -> anyhow::Result<UncommittedResult<Changes>>
     where
         Executor: ports::Executor<TxSource> + 'static,
+        TxSource: Send + 'static,
     {
         //  - get previous block info (hash, root, etc)
         //  - select best da_height from relayer
@@ -131,11 +134,17 @@ where
         // Store the context string in case we error.
         let context_string =
             format!("Failed to produce block {height:?} due to execution failure");
-        let result = self
-            .executor
-            .execute_without_commit(component)
-            .map_err(Into::<anyhow::Error>::into)
-            .context(context_string)?;
+        let result = match &self.config.execution_pool {
+            Some(execution_pool) => {
+                let executor = self.executor.clone();
+                execution_pool
+                    .spawn_fifo_async(move || executor.execute_without_commit(component))
+                    .await
+            }
+            None => self.executor.execute_without_commit(component),
+        }
+        .map_err(Into::<anyhow::Error>::into)
+        .context(context_string)?;
 
         debug!("Produced block with result: {:?}", result.result());
         Ok(result)
@@ -149,6 +158,7 @@ where
     ViewProvider::View: BlockProducerDatabase,
     TxPool: ports::TxPool<TxSource = TxSource> + 'static,
     Executor: ports::Executor<TxSource> + 'static,
+    TxSource: Send + 'static,
     GasPriceProvider: GasPriceProviderConstraint,
 {
     /// Produces and execute block for the specified height with transactions from the `TxPool`.
@@ -158,7 +168,13 @@ where
         block_time: Tai64,
     ) -> anyhow::Result<UncommittedResult<Changes>> {
         self.produce_and_execute(height, block_time, |height| {
-            self.txpool.get_source(height)
+            self.txpool.get_source(
+                height,
+                self.config.packing_strategy,
+                self.config.max_tx_per_block,
+                self.config.max_outputs_per_block,
+                self.config.max_block_size_bytes,
+            )
         })
         .await
     }
@@ -182,6 +198,50 @@ where
         self.produce_and_execute(height, block_time, |_| transactions)
             .await
     }
+
+    /// Simulates the block with `transactions` on top of the latest block.
+    /// The block is executed the same way as the produced one, but the changes
+    /// are discarded, so neither the state nor the chain are affected.
+    ///
+    /// Like the `dry_run`, it doesn't acquire the production lock and uses
+    /// the DA height of the latest block instead of waiting for the relayer.
+    pub async fn simulate_block(
+        &self,
+        transactions: Vec<Transaction>,
+        block_time: Option<Tai64>,
+    ) -> anyhow::Result<ExecutionResult> {
+        let height = self
+            .view_provider
+            .latest_height()
+            .unwrap_or_default()
+            .succ()
+            .expect("It is impossible to overflow the current block height");
+
+        let gas_price = self
+            .gas_price_provider
+            .gas_price(height.into())
+            .ok_or(anyhow!("No gas price found for height {height:?}"))?;
+
+        let header = self._new_header(height, block_time.unwrap_or_else(Tai64::now))?;
+        let component = Components {
+            header_to_produce: header,
+            transactions_source: transactions,
+            coinbase_recipient: self.config.coinbase_recipient.unwrap_or_default(),
+            gas_price,
+        };
+
+        let executor = self.executor.clone();
+
+        // use the blocking threadpool to avoid clogging up the main async runtime
+        let result = tokio_rayon::spawn_fifo(
+            move || -> anyhow::Result<UncommittedResult<Changes>> {
+                Ok(executor.execute_without_commit(component)?)
+            },
+        )
+        .await?;
+
+        Ok(result.into_result())
+    }
 }
 
 impl<ViewProvider, TxPool, Executor, GasPriceProvider>
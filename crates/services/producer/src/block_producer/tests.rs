@@ -49,6 +49,7 @@ use std::{
         Mutex,
     },
 };
+use tokio_rayon::rayon::ThreadPoolBuilder;
 
 pub struct MockProducerGasPrice {
     pub gas_price: Option<u64>,
@@ -534,6 +535,31 @@ mod produce_and_execute_block_txpool {
         // then
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn produce_and_execute_block_txpool__executes_on_execution_pool_if_configured()
+    {
+        // given
+        let execution_pool = ThreadPoolBuilder::new()
+            .num_threads(1)
+            .thread_name(|index| format!("execution-{index}"))
+            .build()
+            .unwrap();
+        let executor = MockExecutorWithCapture::default();
+        let mut ctx = TestContext::default_from_executor(executor.clone());
+        ctx.config.execution_pool = Some(Arc::new(execution_pool));
+        let producer = ctx.producer();
+
+        // when
+        let _ = producer
+            .produce_and_execute_block_txpool(1u32.into(), Tai64::now())
+            .await
+            .unwrap();
+
+        // then
+        let execution_thread = executor.execution_thread.lock().unwrap();
+        assert_eq!(execution_thread.as_deref(), Some("execution-0"));
+    }
 }
 
 struct TestContext<Executor> {
@@ -1,4 +1,9 @@
-use fuel_core_types::fuel_types::ContractId;
+use fuel_core_types::{
+    fuel_types::ContractId,
+    services::txpool::PackingStrategy,
+};
+use std::sync::Arc;
+use tokio_rayon::rayon::ThreadPool;
 
 #[derive(Clone, Debug, Default)]
 pub struct Config {
@@ -6,4 +11,21 @@ pub struct Config {
     pub coinbase_recipient: Option<ContractId>,
     pub metrics: bool,
     pub block_gas_limit: u64,
+    pub packing_strategy: PackingStrategy,
+    /// The max number of transactions in the produced block besides the `Mint` transaction.
+    /// The packing stops at this number even if the block has gas left.
+    /// If `None`, the number is limited only by the gas limit.
+    pub max_tx_per_block: Option<u16>,
+    /// The max number of outputs created by the transactions of the produced block
+    /// besides the `Mint` transaction. The packing stops at the first transaction
+    /// exceeding it, bounding the growth of the UTXO set per block.
+    /// If `None`, the number is not limited.
+    pub max_outputs_per_block: Option<u32>,
+    /// The max total serialized size of the transactions of the produced block
+    /// besides the `Mint` transaction. The packing stops at the first transaction
+    /// exceeding it. If `None`, the size is limited only by the consensus parameters.
+    pub max_block_size_bytes: Option<u64>,
+    /// The dedicated pool for the execution of the produced blocks.
+    /// If `None`, the block is executed on the thread of the caller.
+    pub execution_pool: Option<Arc<ThreadPool>>,
 }
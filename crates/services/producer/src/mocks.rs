@@ -38,7 +38,10 @@ use fuel_core_types::{
             Result as ExecutorResult,
             UncommittedResult,
         },
-        txpool::ArcPoolTx,
+        txpool::{
+            ArcPoolTx,
+            PackingStrategy,
+        },
     },
 };
 use std::{
@@ -87,7 +90,14 @@ pub struct MockTxPool(pub Vec<ArcPoolTx>);
 impl TxPool for MockTxPool {
     type TxSource = Vec<ArcPoolTx>;
 
-    fn get_source(&self, _: BlockHeight) -> Self::TxSource {
+    fn get_source(
+        &self,
+        _: BlockHeight,
+        _: PackingStrategy,
+        _: Option<u16>,
+        _: Option<u32>,
+        _: Option<u64>,
+    ) -> Self::TxSource {
         self.0.clone()
     }
 }
@@ -192,6 +202,8 @@ impl Executor<Vec<ArcPoolTx>> for FailingMockExecutor {
 #[derive(Clone)]
 pub struct MockExecutorWithCapture {
     pub captured: Arc<Mutex<Option<Components<Vec<ArcPoolTx>>>>>,
+    /// The name of the thread that executed the block.
+    pub execution_thread: Arc<Mutex<Option<String>>>,
 }
 
 impl Executor<Vec<ArcPoolTx>> for MockExecutorWithCapture {
@@ -201,6 +213,8 @@ impl Executor<Vec<ArcPoolTx>> for MockExecutorWithCapture {
     ) -> ExecutorResult<UncommittedResult<Changes>> {
         let block = to_block(&component);
         *self.captured.lock().unwrap() = Some(component);
+        *self.execution_thread.lock().unwrap() =
+            std::thread::current().name().map(ToString::to_string);
         Ok(UncommittedResult::new(
             ExecutionResult {
                 block,
@@ -217,6 +231,7 @@ impl Default for MockExecutorWithCapture {
     fn default() -> Self {
         Self {
             captured: Arc::new(Mutex::new(None)),
+            execution_thread: Arc::new(Mutex::new(None)),
         }
     }
 }
@@ -24,6 +24,7 @@ use fuel_core_types::{
             TransactionExecutionStatus,
             UncommittedResult,
         },
+        txpool::PackingStrategy,
     },
 };
 use std::borrow::Cow;
@@ -56,6 +57,10 @@ pub trait TxPool: Send + Sync {
         &self,
         // could be used by the txpool to filter txs based on maturity
         block_height: BlockHeight,
+        packing_strategy: PackingStrategy,
+        max_txs: Option<u16>,
+        max_outputs: Option<u32>,
+        max_bytes: Option<u64>,
     ) -> Self::TxSource;
 }
 
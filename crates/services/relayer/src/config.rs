@@ -30,6 +30,9 @@ pub struct Config {
     /// Number of pages or blocks containing logs that
     /// should be downloaded in a single call to the da layer
     pub log_page_size: u64,
+    /// The maximum number of blocks covered by a single log query to the da layer.
+    /// Pages bigger than this are downloaded by several queries.
+    pub max_log_query_range: u64,
     /// This throttles the background relayer loop to
     /// at least this duration to prevent spamming the DA node.
     pub sync_minimum_duration: Duration,
@@ -47,6 +50,7 @@ pub struct Config {
 #[allow(missing_docs)]
 impl Config {
     pub const DEFAULT_LOG_PAGE_SIZE: u64 = 10_000;
+    pub const DEFAULT_MAX_LOG_QUERY_RANGE: u64 = 10_000;
     pub const DEFAULT_DA_DEPLOY_HEIGHT: u64 = 0;
     pub const DEFAULT_SYNC_MINIMUM_DURATION: Duration = Duration::from_secs(5);
     pub const DEFAULT_SYNCING_CALL_FREQ: Duration = Duration::from_secs(5);
@@ -63,6 +67,7 @@ impl Default for Config {
             )
             .unwrap()],
             log_page_size: Self::DEFAULT_LOG_PAGE_SIZE,
+            max_log_query_range: Self::DEFAULT_MAX_LOG_QUERY_RANGE,
             sync_minimum_duration: Self::DEFAULT_SYNC_MINIMUM_DURATION,
             syncing_call_frequency: Self::DEFAULT_SYNCING_CALL_FREQ,
             syncing_log_frequency: Self::DEFAULT_SYNCING_LOG_FREQ,
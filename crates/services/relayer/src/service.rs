@@ -53,6 +53,10 @@ mod test;
 
 type Synced = watch::Receiver<Option<DaBlockHeight>>;
 type NotifySynced = watch::Sender<Option<DaBlockHeight>>;
+type Healthy = watch::Receiver<bool>;
+type NotifyHealthy = watch::Sender<bool>;
+type DaHead = watch::Receiver<Option<DaBlockHeight>>;
+type NotifyDaHead = watch::Sender<Option<DaBlockHeight>>;
 
 /// The alias of runnable relayer service.
 pub type Service<D> = CustomizableService<Provider<Http>, D>;
@@ -63,6 +67,10 @@ type CustomizableService<P, D> = ServiceRunner<NotInitializedTask<P, D>>;
 pub struct SharedState<D> {
     /// Receives signals when the relayer reaches consistency with the DA layer.
     synced: Synced,
+    /// Receives signals when the relayer loses or restores the connection with the DA layer.
+    healthy: Healthy,
+    /// Receives the latest finalized height of the DA layer seen by the relayer.
+    da_head: DaHead,
     database: D,
 }
 
@@ -70,6 +78,10 @@ pub struct SharedState<D> {
 pub struct NotInitializedTask<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends signals when the relayer loses or restores the connection with the DA layer.
+    healthy: NotifyHealthy,
+    /// Sends the latest finalized height of the DA layer seen by the relayer.
+    da_head: NotifyDaHead,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -84,6 +96,10 @@ pub struct NotInitializedTask<P, D> {
 pub struct Task<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends signals when the relayer loses or restores the connection with the DA layer.
+    healthy: NotifyHealthy,
+    /// Sends the latest finalized height of the DA layer seen by the relayer.
+    da_head: NotifyDaHead,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -101,8 +117,13 @@ impl<P, D> NotInitializedTask<P, D> {
     /// Create a new relayer task.
     fn new(eth_node: P, database: D, config: Config, retry_on_error: bool) -> Self {
         let (synced, _) = watch::channel(None);
+        // The relayer is unhealthy until it reaches the DA layer for the first time.
+        let (healthy, _) = watch::channel(false);
+        let (da_head, _) = watch::channel(None);
         Self {
             synced,
+            healthy,
+            da_head,
             eth_node,
             database,
             config,
@@ -111,6 +132,36 @@ impl<P, D> NotInitializedTask<P, D> {
     }
 }
 
+impl<P, D> Task<P, D> {
+    /// Marks the relayer as unhealthy if the last iteration failed. The node keeps
+    /// running in the degraded mode while the relayer retries in the background.
+    fn update_health(&self, result: &anyhow::Result<()>) {
+        self.healthy.send_if_modified(|healthy| {
+            let is_healthy = result.is_ok();
+            if *healthy == is_healthy {
+                return false
+            }
+
+            match result {
+                Ok(()) => {
+                    tracing::info!(
+                        "The relayer restored the connection with the DA layer"
+                    );
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "The relayer can't sync with the DA layer and runs in the \
+                        degraded mode until the connection is restored: {:?}",
+                        err
+                    );
+                }
+            }
+            *healthy = is_healthy;
+            true
+        });
+    }
+}
+
 impl<P, D> Task<P, D>
 where
     D: RelayerDb + 'static,
@@ -154,6 +205,7 @@ where
             self.config.eth_v2_listening_contracts.clone(),
             &self.eth_node,
             self.config.log_page_size,
+            self.config.max_log_query_range,
         );
         let logs = logs.take_until(self.shutdown.while_started());
 
@@ -161,6 +213,14 @@ where
     }
 
     fn update_synced(&self, state: &state::EthState) {
+        self.da_head.send_if_modified(|da_head| {
+            let remote = Some(DaBlockHeight::from(state.remote()));
+            if *da_head == remote {
+                return false
+            }
+            *da_head = remote;
+            true
+        });
         self.synced.send_if_modified(|last_state| {
             if let Some(val) = state.is_synced_at() {
                 *last_state = Some(DaBlockHeight::from(val));
@@ -186,9 +246,13 @@ where
 
     fn shared_data(&self) -> Self::SharedData {
         let synced = self.synced.subscribe();
+        let healthy = self.healthy.subscribe();
+        let da_head = self.da_head.subscribe();
 
         SharedState {
             synced,
+            healthy,
+            da_head,
             database: self.database.clone(),
         }
     }
@@ -201,6 +265,8 @@ where
         let shutdown = watcher.clone();
         let NotInitializedTask {
             synced,
+            healthy,
+            da_head,
             eth_node,
             database,
             config,
@@ -208,6 +274,8 @@ where
         } = self;
         let mut task = Task {
             synced,
+            healthy,
+            da_head,
             eth_node,
             database,
             config,
@@ -231,9 +299,12 @@ where
 
         let result = run::run(self).await;
 
-        if self.shutdown.borrow_and_update().started()
-            && (result.is_err() | self.synced.borrow().is_some())
-        {
+        let started = self.shutdown.borrow_and_update().started();
+        if started {
+            self.update_health(&result);
+        }
+
+        if started && (result.is_err() | self.synced.borrow().is_some()) {
             // Sleep the loop so the da node is not spammed.
             tokio::time::sleep(
                 self.config
@@ -296,6 +367,23 @@ impl<D> SharedState<D> {
         Ok(())
     }
 
+    /// Returns `true` if the relayer reached consistency with the DA layer at least once.
+    pub fn is_synced(&self) -> bool {
+        self.synced.borrow().is_some()
+    }
+
+    /// Returns `false` if the last attempt to sync with the DA layer failed,
+    /// or if the relayer didn't reach the DA layer yet.
+    pub fn is_healthy(&self) -> bool {
+        *self.healthy.borrow()
+    }
+
+    /// Returns the latest finalized height of the DA layer seen by the relayer,
+    /// or `None` if the relayer didn't reach the DA layer yet.
+    pub fn latest_known_da_height(&self) -> Option<DaBlockHeight> {
+        *self.da_head.borrow()
+    }
+
     /// Get finalized da height that represents last block from da layer that got finalized.
     /// Panics if height is not set as of initialization of the relayer.
     pub fn get_finalized_da_height(&self) -> anyhow::Result<DaBlockHeight>
@@ -4,17 +4,26 @@ use fuel_core_types::{
     services::relayer::Event,
 };
 use futures::TryStreamExt;
-use std::collections::BTreeMap;
+use std::{
+    collections::{
+        BTreeMap,
+        VecDeque,
+    },
+    ops::RangeInclusive,
+};
 
 #[cfg(test)]
 mod test;
 
 /// Download the logs from the DA layer.
+///
+/// Each page is requested by queries that cover at most `max_log_query_range` blocks.
 pub(crate) fn download_logs<'a, P>(
     eth_sync_gap: &state::EthSyncGap,
     contracts: Vec<H160>,
     eth_node: &'a P,
     page_size: u64,
+    max_log_query_range: u64,
 ) -> impl futures::Stream<Item = Result<(u64, Vec<Log>), ProviderError>> + 'a
 where
     P: Middleware<Error = ProviderError> + 'static,
@@ -28,29 +37,27 @@ where
                 match page {
                     None => Ok(None),
                     Some(page) => {
-                        // Create the log filter from the page.
-                        let filter = Filter::new()
-                            .from_block(page.oldest())
-                            .to_block(page.latest())
-                            .address(ValueOrArray::Array(contracts))
-                            .topic0(*crate::config::ETH_LOG_MESSAGE);
-
                         tracing::info!(
                             "Downloading logs for block range: {}..={}",
                             page.oldest(),
                             page.latest()
                         );
 
+                        let oldest_block = page.oldest();
                         let latest_block = page.latest();
 
                         // Reduce the page.
                         let page = page.reduce();
 
                         // Get the logs and return the reduced page.
-                        eth_node
-                            .get_logs(&filter)
-                            .await
-                            .map(|logs| Some(((latest_block, logs), page)))
+                        get_logs_in_range(
+                            eth_node,
+                            &contracts,
+                            oldest_block..=latest_block,
+                            max_log_query_range,
+                        )
+                        .await
+                        .map(|logs| Some(((latest_block, logs), page)))
                     }
                 }
             }
@@ -58,6 +65,75 @@ where
     )
 }
 
+/// Requests the logs of the `range` in chunks of at most `max_log_query_range` blocks.
+///
+/// If the DA node rejects the query, the range of the query is split in half
+/// until it covers a single block. The error is returned if
+/// the DA node rejects the query for a single block.
+async fn get_logs_in_range<P>(
+    eth_node: &P,
+    contracts: &[H160],
+    range: RangeInclusive<u64>,
+    max_log_query_range: u64,
+) -> Result<Vec<Log>, ProviderError>
+where
+    P: Middleware<Error = ProviderError> + 'static,
+{
+    let mut queries = split_range(range, max_log_query_range);
+    let mut logs = vec![];
+
+    while let Some(query) = queries.pop_front() {
+        let filter = Filter::new()
+            .from_block(*query.start())
+            .to_block(*query.end())
+            .address(ValueOrArray::Array(contracts.to_vec()))
+            .topic0(*crate::config::ETH_LOG_MESSAGE);
+
+        match eth_node.get_logs(&filter).await {
+            Ok(query_logs) => logs.extend(query_logs),
+            Err(err) if query.start() < query.end() => {
+                let middle = query.start().saturating_add(
+                    query.end().saturating_sub(*query.start()).saturating_div(2),
+                );
+                tracing::warn!(
+                    "Failed to download logs for block range: {}..={}, \
+                    retrying with smaller ranges: {:?}",
+                    query.start(),
+                    query.end(),
+                    err
+                );
+                queries.push_front(middle.saturating_add(1)..=*query.end());
+                queries.push_front(*query.start()..=middle);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(logs)
+}
+
+/// Splits the `range` into consecutive ranges of at most `max_len` blocks.
+fn split_range(
+    range: RangeInclusive<u64>,
+    max_len: u64,
+) -> VecDeque<RangeInclusive<u64>> {
+    let max_len = max_len.max(1);
+    let mut ranges = VecDeque::new();
+    let mut start = *range.start();
+    let end = *range.end();
+
+    while start <= end {
+        let chunk_end = start.saturating_add(max_len.saturating_sub(1)).min(end);
+        ranges.push_back(start..=chunk_end);
+        if chunk_end == end {
+            break
+        }
+        start = chunk_end.saturating_add(1);
+    }
+
+    ranges
+}
+
 /// Write the logs to the database.
 pub(crate) async fn write_logs<D, S>(database: &mut D, logs: S) -> anyhow::Result<()>
 where
@@ -136,6 +136,7 @@ async fn can_paginate_logs(input: Input) -> Expected {
         contracts,
         &eth_node,
         DEFAULT_LOG_PAGE_SIZE,
+        DEFAULT_LOG_PAGE_SIZE,
     )
     .map_ok(|(_, l)| l)
     .try_concat()
@@ -147,6 +148,84 @@ async fn can_paginate_logs(input: Input) -> Expected {
     }
 }
 
+#[derive(Clone, Debug)]
+struct RangeLimitInput {
+    eth_gap: RangeInclusive<u64>,
+    max_log_query_range: u64,
+    provider_max_logs_range: u64,
+}
+
+#[test_case(
+    RangeLimitInput {
+        eth_gap: 0..=10,
+        max_log_query_range: 2,
+        provider_max_logs_range: 2,
+    }
+    => Expected{ num_get_logs_calls: 7, m: messages(0..=10, 0..=10, 0..=0) }
+    ; "Chunks queries to stay within the provider limit"
+)]
+#[test_case(
+    RangeLimitInput {
+        eth_gap: 0..=4,
+        max_log_query_range: 5,
+        provider_max_logs_range: 2,
+    }
+    => Expected{ num_get_logs_calls: 5, m: messages(0..=4, 0..=4, 0..=0) }
+    ; "Splits rejected queries into smaller ranges"
+)]
+#[test_case(
+    RangeLimitInput {
+        eth_gap: 0..=4,
+        max_log_query_range: 5,
+        provider_max_logs_range: 1,
+    }
+    => Expected{ num_get_logs_calls: 9, m: messages(0..=4, 0..=4, 0..=0) }
+    ; "Falls back to single block queries"
+)]
+#[tokio::test]
+async fn download_logs_succeeds_with_provider_limiting_the_range(
+    input: RangeLimitInput,
+) -> Expected {
+    let RangeLimitInput {
+        eth_gap,
+        max_log_query_range,
+        provider_max_logs_range,
+    } = input;
+    let eth_node = MockMiddleware::default();
+
+    // Given
+    eth_node.update_data(|data| {
+        data.logs_batch = vec![messages(0..=20, 0..=20, 0..=0)];
+        data.best_block.number = Some((*eth_gap.end()).into());
+        data.max_logs_range = Some(provider_max_logs_range);
+    });
+    let count = std::sync::Arc::new(AtomicUsize::new(0));
+    let num_calls = count.clone();
+    eth_node.set_after_event(move |_, evt| {
+        if let TriggerType::GetLogs(_) = evt {
+            count.fetch_add(1, atomic::Ordering::SeqCst);
+        }
+    });
+
+    // When
+    let result = download_logs(
+        &EthSyncGap::new(*eth_gap.start(), *eth_gap.end()),
+        contracts(&[0]),
+        &eth_node,
+        DEFAULT_LOG_PAGE_SIZE,
+        max_log_query_range,
+    )
+    .map_ok(|(_, l)| l)
+    .try_concat()
+    .await;
+
+    // Then
+    Expected {
+        num_get_logs_calls: num_calls.load(atomic::Ordering::SeqCst),
+        m: result.expect("Should download logs in smaller ranges"),
+    }
+}
+
 #[test_case(vec![
     Ok((1, messages_n(1, 0)))
     ] => 1 ; "Can add single"
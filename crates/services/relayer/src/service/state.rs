@@ -42,6 +42,11 @@ pub struct EthSyncPage {
 }
 
 impl EthState {
+    /// The finalized block height of the remote Ethereum node.
+    pub fn remote(&self) -> u64 {
+        self.remote
+    }
+
     /// Is the relayer in sync with the Ethereum node?
     pub fn is_synced(&self) -> bool {
         self.is_synced_at().is_some()
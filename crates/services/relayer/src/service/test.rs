@@ -36,6 +36,7 @@ async fn can_download_logs() {
         contracts,
         &eth_node,
         DEFAULT_LOG_PAGE_SIZE,
+        DEFAULT_LOG_PAGE_SIZE,
     )
     .map_ok(|(_, l)| l)
     .try_concat()
@@ -112,3 +113,28 @@ async fn update_sync__changes_latest_eth_state(
     let actual = *shared.synced.borrow().deref();
     assert_eq!(expected, actual);
 }
+
+#[tokio::test]
+async fn relayer_is_unhealthy_until_the_da_layer_is_reachable() {
+    let mock_db = crate::mock_db::MockDb::default();
+    let eth_node = MockMiddleware::default();
+    let relayer = NotInitializedTask::new(eth_node, mock_db, Config::default(), true);
+    let shared = relayer.shared_data();
+    let task = relayer.into_task(&Default::default(), ()).await.unwrap();
+
+    // Given
+    assert!(!shared.is_healthy());
+
+    // When
+    task.update_health(&Err(anyhow::anyhow!("The DA layer is unreachable")));
+
+    // Then
+    assert!(!shared.is_healthy());
+    assert!(!shared.is_synced());
+
+    // When
+    task.update_health(&Ok(()));
+
+    // Then
+    assert!(shared.is_healthy());
+}
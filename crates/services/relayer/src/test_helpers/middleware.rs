@@ -53,6 +53,9 @@ pub struct MockData {
     pub best_block: Block<TxHash>,
     pub logs_batch: Vec<Vec<Log>>,
     pub logs_batch_index: usize,
+    /// The maximum number of blocks in the range of the `get_logs` query.
+    /// Queries with bigger ranges are rejected.
+    pub max_logs_range: Option<u64>,
 }
 
 impl MockMiddleware {
@@ -143,6 +146,7 @@ impl Default for MockData {
             is_syncing: SyncingStatus::IsFalse,
             logs_batch: Vec::new(),
             logs_batch_index: 0,
+            max_logs_range: None,
         }
     }
 }
@@ -262,10 +266,16 @@ impl Middleware for MockMiddleware {
     async fn get_logs(&self, filter: &Filter) -> Result<Vec<Log>, Self::Error> {
         tokio::task::yield_now().await;
         self.before_event(TriggerType::GetLogs(filter));
-        let r =
-            self.update_data(|data| take_logs_based_on_filter(&data.logs_batch, filter));
+        let r = self.update_data(|data| {
+            if exceeds_max_logs_range(data.max_logs_range, filter) {
+                return Err(ProviderError::CustomError(
+                    "The block range of the query is too big".to_string(),
+                ))
+            }
+            Ok(take_logs_based_on_filter(&data.logs_batch, filter))
+        });
         self.after_event(TriggerType::GetLogs(filter));
-        Ok(r)
+        r
     }
 
     /// used for initial sync to get block hash. Other fields can be ignored.
@@ -282,6 +292,23 @@ impl Middleware for MockMiddleware {
     }
 }
 
+fn exceeds_max_logs_range(max_logs_range: Option<u64>, filter: &Filter) -> bool {
+    let (Some(max_logs_range), Some(from), Some(to)) = (
+        max_logs_range,
+        filter
+            .block_option
+            .get_from_block()
+            .and_then(|b| b.as_number()),
+        filter
+            .block_option
+            .get_to_block()
+            .and_then(|b| b.as_number()),
+    ) else {
+        return false
+    };
+    to.as_u64().saturating_sub(from.as_u64()).saturating_add(1) > max_logs_range
+}
+
 fn take_logs_based_on_filter(logs_batch: &[Vec<Log>], filter: &Filter) -> Vec<Log> {
     logs_batch
         .iter()
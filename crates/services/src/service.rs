@@ -45,6 +45,9 @@ pub struct EmptyShared;
 /// the lifecycle of services such as start/stop and health status.
 #[async_trait::async_trait]
 pub trait Service {
+    /// The name of the service.
+    fn name(&self) -> &'static str;
+
     /// Send a start signal to the service without waiting for it to start.
     /// Returns an error if the service was already started.
     fn start(&self) -> anyhow::Result<()>;
@@ -197,6 +200,10 @@ impl<S> Service for ServiceRunner<S>
 where
     S: RunnableService + 'static,
 {
+    fn name(&self) -> &'static str {
+        S::NAME
+    }
+
     fn start(&self) -> anyhow::Result<()> {
         let started = self.state.send_if_modified(|state| {
             if state.not_started() {
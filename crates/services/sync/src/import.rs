@@ -10,6 +10,7 @@ use fuel_core_types::{
     self,
     blockchain::{
         block::Block,
+        primitives::BlockId,
         SealedBlock,
         SealedBlockHeader,
     },
@@ -19,6 +20,7 @@ use fuel_core_types::{
         SourcePeer,
         Transactions,
     },
+    tai64::Tai64,
 };
 use futures::{
     stream::StreamExt,
@@ -26,12 +28,17 @@ use futures::{
     Stream,
 };
 use std::{
+    collections::{
+        BTreeMap,
+        VecDeque,
+    },
     future::Future,
     ops::{
         Range,
         RangeInclusive,
     },
     sync::Arc,
+    time::Duration,
 };
 use tokio::sync::Notify;
 use tracing::Instrument;
@@ -65,6 +72,18 @@ pub struct Config {
     pub block_stream_buffer_size: usize,
     /// The maximum number of headers to request in a single batch.
     pub header_batch_size: usize,
+    /// Keeps the most recent rejected blocks in the [`RejectedBlocks`] record.
+    /// Rejected blocks are logged regardless of this flag.
+    pub record_rejected_blocks: bool,
+    /// The maximum number of blocks received ahead of their parent, kept until
+    /// the parent is imported. When the buffer is full, the highest blocks are evicted.
+    /// The buffer is disabled if the size is `0`.
+    pub orphan_buffer_size: usize,
+    /// The maximum age of the blocks accepted from the peers once the node is synced.
+    /// Older blocks are rejected. The age is not checked during the deep sync,
+    /// i.e. until the node imports a block younger than this age.
+    /// The age is not checked if not set.
+    pub max_block_age: Option<Duration>,
 }
 
 impl Default for Config {
@@ -72,7 +91,183 @@ impl Default for Config {
         Self {
             block_stream_buffer_size: 10,
             header_batch_size: 100,
+            record_rejected_blocks: false,
+            orphan_buffer_size: 0,
+            max_block_age: None,
+        }
+    }
+}
+
+/// The max number of blocks kept by the [`RejectedBlocks`] record.
+pub const MAX_RECORDED_REJECTED_BLOCKS: usize = 1000;
+
+/// The block received from the peer and rejected by the import.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RejectedBlock {
+    /// The peer that sent the block.
+    pub peer_id: PeerId,
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The id of the block.
+    pub block_id: BlockId,
+    /// The reason of the rejection.
+    pub reason: String,
+}
+
+/// The record of the most recent rejected blocks, from the oldest to the newest.
+/// It keeps at most [`MAX_RECORDED_REJECTED_BLOCKS`] blocks.
+#[derive(Clone)]
+pub struct RejectedBlocks {
+    enabled: bool,
+    blocks: SharedMutex<VecDeque<RejectedBlock>>,
+}
+
+impl RejectedBlocks {
+    /// Creates the record. Blocks are only logged if the record is not `enabled`.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            blocks: SharedMutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Returns the recorded blocks.
+    pub fn blocks(&self) -> Vec<RejectedBlock> {
+        self.blocks.apply(|blocks| blocks.iter().cloned().collect())
+    }
+
+    fn reject(&self, block: RejectedBlock) {
+        tracing::warn!(
+            peer_id = %block.peer_id,
+            height = %block.height,
+            block_id = %block.block_id,
+            reason = %block.reason,
+            "Rejected the block from the peer"
+        );
+        if self.enabled {
+            self.blocks.apply(|blocks| {
+                if blocks.len() >= MAX_RECORDED_REJECTED_BLOCKS {
+                    blocks.pop_front();
+                }
+                blocks.push_back(block);
+            });
+        }
+    }
+}
+
+/// The block received before its parent, with the peer that sent it.
+struct OrphanBlock {
+    peer_id: PeerId,
+    block: SealedBlock,
+}
+
+/// The bounded buffer of the blocks received ahead of their parents.
+/// The blocks are connected to the chain once their parents are imported,
+/// so they don't need to be requested again.
+#[derive(Clone)]
+struct OrphanBlocks {
+    max_size: usize,
+    blocks: SharedMutex<BTreeMap<u32, OrphanBlock>>,
+}
+
+impl OrphanBlocks {
+    fn new(max_size: usize) -> Self {
+        Self {
+            max_size,
+            blocks: SharedMutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.max_size > 0
+    }
+
+    /// Keeps the blocks of the `batch`. The highest blocks are evicted
+    /// if the buffer is full.
+    fn insert_batch(&self, batch: SealedBlockBatch) {
+        if !self.is_enabled() {
+            return
         }
+        let Batch { peer, results, .. } = batch;
+        self.blocks.apply(|blocks| {
+            for block in results {
+                let height = **block.entity.header().height();
+                blocks.insert(
+                    height,
+                    OrphanBlock {
+                        peer_id: peer.clone(),
+                        block,
+                    },
+                );
+                if blocks.len() > self.max_size {
+                    if let Some((evicted, _)) = blocks.pop_last() {
+                        tracing::debug!("Evicted the orphan block at height {}", evicted);
+                    }
+                }
+            }
+        })
+    }
+
+    /// Returns the lowest height of the kept blocks within the `range`.
+    fn first_height_in(&self, range: &RangeInclusive<u32>) -> Option<u32> {
+        self.blocks.apply(|blocks| {
+            blocks
+                .range(range.clone())
+                .next()
+                .map(|(height, _)| *height)
+        })
+    }
+
+    fn take(&self, height: u32) -> Option<OrphanBlock> {
+        self.blocks.apply(|blocks| blocks.remove(&height))
+    }
+
+    /// Drops the blocks at or below the `committed` height.
+    fn prune(&self, committed: u32) {
+        self.blocks.apply(|blocks| {
+            *blocks = blocks.split_off(&committed.saturating_add(1));
+        })
+    }
+
+    fn clear(&self) {
+        self.blocks.apply(|blocks| blocks.clear())
+    }
+}
+
+/// Rejects the stale blocks once the node is synced.
+#[derive(Clone)]
+struct BlockAge {
+    max_age: Option<Duration>,
+    /// The time of the most recent block imported from the peers.
+    latest_block_time: SharedMutex<Option<Tai64>>,
+}
+
+impl BlockAge {
+    fn new(max_age: Option<Duration>) -> Self {
+        Self {
+            max_age,
+            latest_block_time: SharedMutex::new(None),
+        }
+    }
+
+    fn record(&self, time: Tai64) {
+        self.latest_block_time.apply(|latest| {
+            *latest = (*latest).max(Some(time));
+        })
+    }
+
+    /// Returns `true` if the node is synced and the block at `time` is older than
+    /// the maximum age. The node is synced once the most recent imported block
+    /// isn't older than the maximum age.
+    fn is_stale(&self, time: Tai64, now: Tai64) -> bool {
+        let Some(max_age) = self.max_age else {
+            return false
+        };
+        let oldest_allowed = Tai64(now.0.saturating_sub(max_age.as_secs()));
+        let synced = self
+            .latest_block_time
+            .apply(|latest| matches!(latest, Some(latest) if *latest >= oldest_allowed));
+        synced && time < oldest_allowed
     }
 }
 
@@ -91,6 +286,12 @@ pub struct Import<P, E, C> {
     executor: Arc<E>,
     /// Consensus port.
     consensus: Arc<C>,
+    /// The record of rejected blocks.
+    rejected_blocks: RejectedBlocks,
+    /// The blocks received ahead of their parents.
+    orphan_blocks: OrphanBlocks,
+    /// The age of the imported blocks.
+    block_age: BlockAge,
 }
 
 impl<P, E, C> Import<P, E, C> {
@@ -104,6 +305,9 @@ impl<P, E, C> Import<P, E, C> {
         executor: Arc<E>,
         consensus: Arc<C>,
     ) -> Self {
+        let rejected_blocks = RejectedBlocks::new(params.record_rejected_blocks);
+        let orphan_blocks = OrphanBlocks::new(params.orphan_buffer_size);
+        let block_age = BlockAge::new(params.max_block_age);
         Self {
             state,
             notify,
@@ -111,9 +315,17 @@ impl<P, E, C> Import<P, E, C> {
             p2p,
             executor,
             consensus,
+            rejected_blocks,
+            orphan_blocks,
+            block_age,
         }
     }
 
+    /// Returns the record of rejected blocks.
+    pub fn rejected_blocks(&self) -> RejectedBlocks {
+        self.rejected_blocks.clone()
+    }
+
     /// Signal other asynchronous tasks that an import event has occurred.
     pub fn notify_one(&self) {
         self.notify.notify_one()
@@ -161,8 +373,8 @@ where
     async fn import_inner(&self, shutdown: &StateWatcher) -> anyhow::Result<()> {
         // If there is a range to process, launch the stream.
         if let Some(range) = self.state.apply(|s| s.process_range()) {
-            // Launch the stream to import the range.
-            let count = self.launch_stream(range.clone(), shutdown).await;
+            // Import the range using the stream and the orphan blocks.
+            let count = self.import_range(range.clone(), shutdown).await;
 
             // Get the size of the range.
             let range_len = range.size_hint().0;
@@ -183,6 +395,66 @@ where
         Ok(())
     }
 
+    /// Imports the range of blocks. Only the blocks below the first orphan block are
+    /// requested from the network. The orphan blocks are imported after their parents.
+    /// Returns the number of imported blocks.
+    async fn import_range(
+        &self,
+        range: RangeInclusive<u32>,
+        shutdown: &StateWatcher,
+    ) -> usize {
+        if let Some(committed) = range.start().checked_sub(1) {
+            self.orphan_blocks.prune(committed);
+        }
+
+        let fetch_end = match self.orphan_blocks.first_height_in(&range) {
+            Some(first_orphan) => first_orphan.checked_sub(1),
+            None => Some(*range.end()),
+        };
+        let fetched = match fetch_end {
+            Some(fetch_end) if fetch_end >= *range.start() => {
+                self.launch_stream(*range.start()..=fetch_end, shutdown)
+                    .await
+            }
+            _ => 0,
+        };
+
+        fetched.saturating_add(self.connect_orphan_blocks().await)
+    }
+
+    /// Imports the orphan blocks following the last committed block.
+    /// Returns the number of imported blocks.
+    async fn connect_orphan_blocks(&self) -> usize {
+        let mut count = 0usize;
+        while let Some(next) = self.state.apply(|s| s.process_range()) {
+            let height = *next.start();
+            let Some(OrphanBlock { peer_id, block }) = self.orphan_blocks.take(height)
+            else {
+                break
+            };
+            let block_id = block.entity.id();
+            let time = block.entity.header().time();
+            match execute_and_commit(self.executor.as_ref(), &self.state, block).await {
+                Ok(()) => {
+                    self.block_age.record(time);
+                    count = count.saturating_add(1);
+                }
+                Err(e) => {
+                    self.rejected_blocks.reject(RejectedBlock {
+                        peer_id,
+                        height: height.into(),
+                        block_id,
+                        reason: format!("{e:?}"),
+                    });
+                    // The descendants of the rejected block can't be imported either.
+                    self.orphan_blocks.clear();
+                    break
+                }
+            }
+        }
+        count
+    }
+
     #[tracing::instrument(skip(self, shutdown))]
     /// Launches a stream to import and execute a range of blocks.
     ///
@@ -201,6 +473,9 @@ where
             p2p,
             executor,
             consensus,
+            rejected_blocks,
+            orphan_blocks,
+            block_age,
             ..
         } = &self;
 
@@ -208,8 +483,14 @@ where
         let (shutdown_guard, mut shutdown_guard_recv) =
             tokio::sync::mpsc::channel::<()>(1);
 
-        let block_stream =
-            get_block_stream(range.clone(), params, p2p.clone(), consensus.clone());
+        let block_stream = get_block_stream(
+            range.clone(),
+            params,
+            p2p.clone(),
+            consensus.clone(),
+            rejected_blocks.clone(),
+            block_age.clone(),
+        );
         let result = block_stream
             .map(move |stream_block_batch| {
                 let shutdown_guard = shutdown_guard.clone();
@@ -242,7 +523,8 @@ where
             .into_scan_none()
             .scan_none()
             .into_scan_err()
-            .scan_err()
+            // Blocks of the batches requested after the failed one are kept as orphans.
+            .scan_err_keeping_orphans(orphan_blocks.clone(), params.block_stream_buffer_size)
             .then(|batch| {
                 async move {
                     let Batch {
@@ -253,16 +535,26 @@ where
 
                     let mut done = vec![];
                     for sealed_block in results {
+                        let height = *sealed_block.entity.header().height();
+                        let block_id = sealed_block.entity.id();
+                        let time = sealed_block.entity.header().time();
                         let res = execute_and_commit(executor.as_ref(), state, sealed_block).await;
 
                         match &res {
                             Ok(_) => {
+                                block_age.record(time);
                                 done.push(());
                             },
                             Err(e) => {
                                 // If this fails, then it means that consensus has approved a block that is invalid.
                                 // This would suggest a more serious issue than a bad peer, e.g. a fork or an out-of-date client.
                                 tracing::error!("Failed to execute and commit block from peer {:?}: {:?}", peer, e);
+                                rejected_blocks.reject(RejectedBlock {
+                                    peer_id: peer.clone(),
+                                    height,
+                                    block_id,
+                                    reason: format!("{e:?}"),
+                                });
                                 break;
                             },
                         };
@@ -303,12 +595,15 @@ fn get_block_stream<
     params: &Config,
     p2p: Arc<P>,
     consensus: Arc<C>,
+    rejected_blocks: RejectedBlocks,
+    block_age: BlockAge,
 ) -> impl Stream<Item = impl Future<Output = SealedBlockBatch>> + '_ {
     let header_stream = get_header_batch_stream(range.clone(), params, p2p.clone());
     header_stream
         .map({
             let consensus = consensus.clone();
             let p2p = p2p.clone();
+            let rejected_blocks = rejected_blocks.clone();
             move |header_batch: SealedHeaderBatch| {
                 let Batch {
                     peer,
@@ -318,7 +613,14 @@ fn get_block_stream<
                 let checked_headers = results
                     .into_iter()
                     .take_while(|header| {
-                        check_sealed_header(header, peer.clone(), &p2p, &consensus)
+                        check_sealed_header(
+                            header,
+                            peer.clone(),
+                            &p2p,
+                            &consensus,
+                            &rejected_blocks,
+                            &block_age,
+                        )
                     })
                     .collect::<Vec<_>>();
                 Batch::new(peer, range, checked_headers)
@@ -327,6 +629,7 @@ fn get_block_stream<
         .map(move |headers| {
             let consensus = consensus.clone();
             let p2p = p2p.clone();
+            let rejected_blocks = rejected_blocks.clone();
             async move {
                 let Batch {
                     peer,
@@ -344,7 +647,7 @@ fn get_block_stream<
                     )
                     .await;
                     let headers = SealedHeaderBatch::new(peer, range, results);
-                    get_blocks(&p2p, headers).await
+                    get_blocks(&p2p, headers, &rejected_blocks).await
                 }
             }
             .instrument(tracing::debug_span!("consensus_and_transactions"))
@@ -388,12 +691,31 @@ fn check_sealed_header<
     peer_id: PeerId,
     p2p: &Arc<P>,
     consensus: &Arc<C>,
+    rejected_blocks: &RejectedBlocks,
+    block_age: &BlockAge,
 ) -> bool {
+    if block_age.is_stale(header.entity.time(), Tai64::now()) {
+        rejected_blocks.reject(RejectedBlock {
+            peer_id: peer_id.clone(),
+            height: *header.entity.height(),
+            block_id: header.entity.id(),
+            reason: "The block is older than the maximum block age".to_string(),
+        });
+        report_peer(p2p, peer_id.clone(), PeerReportReason::BadBlockHeader);
+        return false
+    }
+
     let validity = consensus
         .check_sealed_header(header)
         .trace_err("Failed to check consensus on header")
         .unwrap_or(false);
     if !validity {
+        rejected_blocks.reject(RejectedBlock {
+            peer_id: peer_id.clone(),
+            height: *header.entity.height(),
+            block_id: header.entity.id(),
+            reason: "The consensus of the header is invalid".to_string(),
+        });
         report_peer(p2p, peer_id.clone(), PeerReportReason::BadBlockHeader);
     }
     validity
@@ -511,8 +833,12 @@ where
 }
 
 /// Get blocks correlating to the headers from a specific peer
-#[tracing::instrument(skip(p2p, headers))]
-async fn get_blocks<P>(p2p: &Arc<P>, headers: SealedHeaderBatch) -> SealedBlockBatch
+#[tracing::instrument(skip(p2p, headers, rejected_blocks))]
+async fn get_blocks<P>(
+    p2p: &Arc<P>,
+    headers: SealedHeaderBatch,
+    rejected_blocks: &RejectedBlocks,
+) -> SealedBlockBatch
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
@@ -533,6 +859,8 @@ where
             consensus,
             entity: header,
         } = block_header;
+        let height = *header.height();
+        let block_id = header.id();
         let block =
             Block::try_from_executed(header, transactions.0).map(|block| SealedBlock {
                 entity: block,
@@ -541,6 +869,12 @@ where
         if let Some(block) = block {
             blocks.push(block);
         } else {
+            rejected_blocks.reject(RejectedBlock {
+                peer_id: peer.clone(),
+                height,
+                block_id,
+                reason: "The transactions don't match the header".to_string(),
+            });
             report_peer(p2p, peer.clone(), PeerReportReason::InvalidTransactions);
             break
         }
@@ -624,4 +958,35 @@ impl<S> ScanErr<S> {
             }
         })
     }
+
+    /// Scans the stream for errors like [`ScanErr::scan_err`], but keeps the blocks
+    /// of up to `in_flight` batches following the erroneous one in the `orphan_blocks`.
+    fn scan_err_keeping_orphans<'a>(
+        self,
+        orphan_blocks: OrphanBlocks,
+        in_flight: usize,
+    ) -> impl Stream<Item = SealedBlockBatch> + 'a
+    where
+        S: Stream<Item = SealedBlockBatch> + Send + 'a,
+    {
+        let stream = self.0.boxed::<'a>();
+        futures::stream::unfold((false, stream), move |(err, mut stream)| {
+            let orphan_blocks = orphan_blocks.clone();
+            async move {
+                if err {
+                    if orphan_blocks.is_enabled() {
+                        let mut in_flight_batches = stream.take(in_flight);
+                        while let Some(batch) = in_flight_batches.next().await {
+                            orphan_blocks.insert_batch(batch);
+                        }
+                    }
+                    None
+                } else {
+                    let batch = stream.next().await?;
+                    let err = batch.is_err();
+                    Some((batch, (err, stream)))
+                }
+            }
+        })
+    }
 }
@@ -24,6 +24,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        record_rejected_blocks: false,
+        orphan_buffer_size: 0,
+        max_block_age: None,
     }
     => Count::default() ; "Empty sanity test"
 )]
@@ -36,6 +39,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        record_rejected_blocks: false,
+        orphan_buffer_size: 0,
+        max_block_age: None,
     }
     => is less_or_equal_than Count{ headers: 1, consensus: 1, transactions: 1, executes: 1, blocks: 1 }
     ; "Single with slow headers"
@@ -49,6 +55,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        record_rejected_blocks: false,
+        orphan_buffer_size: 0,
+        max_block_age: None,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow headers"
@@ -62,6 +71,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        record_rejected_blocks: false,
+        orphan_buffer_size: 0,
+        max_block_age: None,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow transactions"
@@ -75,6 +87,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        record_rejected_blocks: false,
+        orphan_buffer_size: 0,
+        max_block_age: None,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 with slow executes"
@@ -88,6 +103,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        record_rejected_blocks: false,
+        orphan_buffer_size: 0,
+        max_block_age: None,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 size and max 10 requests"
@@ -105,14 +123,7 @@ async fn test_back_pressure(input: Input, state: State, params: Config) -> Count
     let consensus = Arc::new(PressureConsensus::new(counts.clone(), input.consensus));
     let notify = Arc::new(Notify::new());
 
-    let import = Import {
-        state,
-        notify,
-        params,
-        p2p,
-        executor,
-        consensus,
-    };
+    let import = Import::new(state, notify, params, p2p, executor, consensus);
 
     import.notify.notify_one();
     let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
@@ -53,6 +53,7 @@ async fn test_import_0_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
     let mocks = Mocks {
         consensus_port,
@@ -99,6 +100,7 @@ async fn test_import_3_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
     let mocks = Mocks {
         consensus_port,
@@ -165,6 +167,7 @@ async fn test_import_0_to_499() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size,
+        ..Default::default()
     };
     let mocks = Mocks {
         consensus_port,
@@ -217,6 +220,7 @@ async fn import__signature_fails_on_header_5_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -265,6 +269,7 @@ async fn import__signature_fails_on_header_4_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -296,6 +301,7 @@ async fn import__header_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -327,6 +333,7 @@ async fn import__header_response_incomplete() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -366,6 +373,7 @@ async fn import__header_5_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -398,6 +406,7 @@ async fn import__header_4_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -442,6 +451,7 @@ async fn import__transactions_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -496,6 +506,7 @@ async fn import__transactions_not_found_for_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -541,6 +552,7 @@ async fn import__transactions_not_found_for_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -568,6 +580,7 @@ async fn import__p2p_error() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -612,6 +625,7 @@ async fn import__p2p_error_on_4_transactions() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -660,6 +674,7 @@ async fn import__consensus_error_on_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -714,6 +729,7 @@ async fn import__consensus_error_on_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -774,6 +790,7 @@ async fn import__execution_error_on_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -834,6 +851,7 @@ async fn import__execution_error_on_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -843,6 +861,78 @@ async fn import__execution_error_on_header_5() {
     assert_eq!((State::new(4, None), false), res);
 }
 
+#[tokio::test]
+async fn import__execution_error_records_rejected_block() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(2)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .returning(|range| {
+            let peer = random_peer();
+            let headers = Some(range.map(empty_header).collect());
+            let headers = peer.bind(headers);
+            Ok(headers)
+        });
+    p2p.expect_get_transactions()
+        .times(1)
+        .returning(|block_ids| {
+            let data = block_ids.data;
+            let v = data.into_iter().map(|_| Transactions::default()).collect();
+            Ok(Some(v))
+        });
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_execute_and_commit()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("Some execution error")));
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        record_rejected_blocks: true,
+        ..Default::default()
+    };
+    let import = Import::new(
+        State::new(3, 5).into(),
+        Arc::new(Notify::new()),
+        params,
+        Arc::new(p2p),
+        Arc::new(executor),
+        Arc::new(consensus_port),
+    );
+    let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
+    let mut watcher = shutdown.into();
+
+    // when
+    import.notify.notify_one();
+    let _ = import.import(&mut watcher).await;
+
+    // then
+    let rejected_blocks = import.rejected_blocks().blocks();
+    assert_eq!(rejected_blocks.len(), 1);
+    let rejected = &rejected_blocks[0];
+    assert_eq!(rejected.peer_id, random_peer());
+    assert_eq!(rejected.height, 4u32.into());
+    assert_eq!(rejected.block_id, empty_header(4u32).entity.id());
+    assert!(
+        rejected.reason.contains("Some execution error"),
+        "{}",
+        rejected.reason
+    );
+}
+
 #[tokio::test]
 async fn signature_always_fails() {
     // given
@@ -862,6 +952,7 @@ async fn signature_always_fails() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -914,6 +1005,7 @@ async fn import__can_work_in_two_loops() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        ..Default::default()
     };
 
     // when
@@ -923,6 +1015,188 @@ async fn import__can_work_in_two_loops() {
     assert_eq!((State::new(6, None), true), res);
 }
 
+#[tokio::test]
+async fn import__orphan_blocks_are_connected_once_the_parent_arrives() {
+    // given
+    let requested_ranges = SharedMutex::new(vec![]);
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers().times(3).returning({
+        let requested_ranges = requested_ranges.clone();
+        move |range| {
+            let first_request = requested_ranges.apply(|ranges| {
+                ranges.push(range.clone());
+                ranges.len() == 1
+            });
+            // The header 5 is missing in the first response, so the blocks
+            // 6 and 7 arrive before their parent.
+            let headers = if first_request {
+                vec![empty_header(range.start)]
+            } else {
+                range.map(empty_header).collect()
+            };
+            Ok(random_peer().bind(Some(headers)))
+        }
+    });
+    p2p.expect_get_transactions()
+        .times(3)
+        .returning(|block_ids| {
+            let data = block_ids.data;
+            let v = data.into_iter().map(|_| Transactions::default()).collect();
+            Ok(Some(v))
+        });
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+
+    let executed_heights = SharedMutex::new(vec![]);
+    let mut executor = MockBlockImporterPort::default();
+    executor.expect_execute_and_commit().times(4).returning({
+        let executed_heights = executed_heights.clone();
+        move |block| {
+            let height = **block.entity.header().height();
+            executed_heights.apply(|heights| heights.push(height));
+            Ok(())
+        }
+    });
+
+    let consensus_port: MockConsensusPort = DefaultMocks::times([4, 3]);
+    let state: SharedMutex<State> = State::new(3, 7).into();
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 2,
+        orphan_buffer_size: 10,
+        ..Default::default()
+    };
+    let import = Import::new(
+        state.clone(),
+        Arc::new(Notify::new()),
+        params,
+        Arc::new(p2p),
+        Arc::new(executor),
+        Arc::new(consensus_port),
+    );
+    let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
+    let watcher: StateWatcher = shutdown.into();
+    let first_attempt = import.import_inner(&watcher).await;
+    assert!(first_attempt.is_err());
+    assert_eq!(state.apply(|s| s.clone()), State::new(4, None));
+
+    // when
+    state.apply(|s| s.observe(7));
+    let second_attempt = import.import_inner(&watcher).await;
+
+    // then
+    assert!(second_attempt.is_ok());
+    assert_eq!(state.apply(|s| s.clone()), State::new(7, None));
+    assert_eq!(
+        executed_heights.apply(|heights| heights.clone()),
+        vec![4, 5, 6, 7]
+    );
+    // Only the missing parent is requested again.
+    assert_eq!(
+        requested_ranges.apply(|ranges| ranges.clone()),
+        vec![4..6, 6..8, 5..6]
+    );
+}
+
+/// The header `4` is produced now, while the header `5` is one hour old.
+fn header_with_age(height: u32) -> SealedBlockHeader {
+    let mut header = empty_header(height);
+    let now = Tai64::now();
+    let time = if height < 5 {
+        now
+    } else {
+        Tai64(now.0.saturating_sub(3600))
+    };
+    header.entity.set_time(time);
+    header
+}
+
+fn import_with_max_block_age(
+    state: SharedMutex<State>,
+) -> Import<MockPeerToPeerPort, MockBlockImporterPort, MockConsensusPort> {
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers().returning(|range| {
+        let headers = range.map(header_with_age).collect();
+        Ok(random_peer().bind(Some(headers)))
+    });
+    p2p.expect_get_transactions().returning(|block_ids| {
+        let data = block_ids.data;
+        let v = data.into_iter().map(|_| Transactions::default()).collect();
+        Ok(Some(v))
+    });
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+
+    let mut executor = MockBlockImporterPort::default();
+    executor.expect_execute_and_commit().returning(|_| Ok(()));
+
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .returning(|_| Ok(()));
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        record_rejected_blocks: true,
+        max_block_age: Some(Duration::from_secs(60)),
+        ..Default::default()
+    };
+    Import::new(
+        state,
+        Arc::new(Notify::new()),
+        params,
+        Arc::new(p2p),
+        Arc::new(executor),
+        Arc::new(consensus_port),
+    )
+}
+
+#[tokio::test]
+async fn import__stale_block_is_rejected_when_synced() {
+    // given
+    let state: SharedMutex<State> = State::new(3, 4).into();
+    let import = import_with_max_block_age(state.clone());
+    let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
+    let watcher: StateWatcher = shutdown.into();
+    // The recent block `4` makes the node synced.
+    import.import_inner(&watcher).await.unwrap();
+    assert_eq!(state.apply(|s| s.clone()), State::new(4, None));
+
+    // when
+    state.apply(|s| s.observe(5));
+    let result = import.import_inner(&watcher).await;
+
+    // then
+    assert!(result.is_err());
+    assert_eq!(state.apply(|s| s.clone()), State::new(4, None));
+    let rejected_blocks = import.rejected_blocks().blocks();
+    assert_eq!(rejected_blocks.len(), 1);
+    assert_eq!(rejected_blocks[0].height, 5u32.into());
+    assert_eq!(
+        rejected_blocks[0].reason,
+        "The block is older than the maximum block age"
+    );
+}
+
+#[tokio::test]
+async fn import__stale_block_is_accepted_during_deep_sync() {
+    // given
+    let state: SharedMutex<State> = State::new(4, 5).into();
+    let import = import_with_max_block_age(state.clone());
+    let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
+    let watcher: StateWatcher = shutdown.into();
+
+    // when
+    let result = import.import_inner(&watcher).await;
+
+    // then
+    assert!(result.is_ok());
+    assert_eq!(state.apply(|s| s.clone()), State::new(5, None));
+    assert!(import.rejected_blocks().blocks().is_empty());
+}
+
 async fn test_import_inner(
     state: SharedMutex<State>,
     mocks: Mocks,
@@ -941,14 +1215,7 @@ async fn test_import_inner(
     let executor = Arc::new(executor);
     let consensus = Arc::new(consensus_port);
 
-    let import = Import {
-        state,
-        notify,
-        params,
-        p2p,
-        executor,
-        consensus,
-    };
+    let import = Import::new(state, notify, params, p2p, executor, consensus);
     let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
     let mut watcher = shutdown.into();
     let received_notify_signal = match count {
@@ -1101,16 +1368,10 @@ impl PeerReportTestBuilder {
         let params = Config {
             block_stream_buffer_size: 10,
             header_batch_size: 10,
+            ..Default::default()
         };
 
-        let import = Import {
-            state,
-            notify,
-            params,
-            p2p,
-            executor,
-            consensus,
-        };
+        let import = Import::new(state, notify, params, p2p, executor, consensus);
         let (_tx, shutdown) =
             tokio::sync::watch::channel(fuel_core_services::State::Started);
         let mut watcher = shutdown.into();
@@ -8,6 +8,7 @@
 
 pub mod import;
 pub mod ports;
+pub mod progress;
 pub mod service;
 pub mod state;
 pub mod sync;
@@ -0,0 +1,110 @@
+//! # Sync progress
+//! Estimates the remaining time of the sync from the recent import rate.
+
+use std::{
+    collections::VecDeque,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use fuel_core_services::SharedMutex;
+
+#[cfg(test)]
+mod tests;
+
+/// The number of the most recent commits used to estimate the import rate.
+pub const IMPORT_RATE_WINDOW: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The estimated time to sync with the best known peer.
+pub enum SyncEstimate {
+    /// The node has all blocks known to its peers.
+    Synced,
+    /// The node is behind the best known peer.
+    Syncing {
+        /// The number of blocks between the committed and the best known peer height.
+        remaining_blocks: u32,
+        /// The time to import the remaining blocks at the recent import rate.
+        /// `None` until the rate is known.
+        time_left: Option<Duration>,
+    },
+}
+
+#[derive(Debug, Clone)]
+/// The progress of the sync shared with other services.
+pub struct SyncProgress {
+    inner: SharedMutex<Progress>,
+}
+
+#[derive(Debug)]
+struct Progress {
+    committed: Option<u32>,
+    best_peer_height: Option<u32>,
+    /// The time and the height of the most recent commits, from the oldest to the newest.
+    recent_commits: VecDeque<(Instant, u32)>,
+}
+
+impl SyncProgress {
+    /// Creates the progress starting at the `committed` height.
+    pub fn new(committed: impl Into<Option<u32>>) -> Self {
+        Self {
+            inner: SharedMutex::new(Progress {
+                committed: committed.into(),
+                best_peer_height: None,
+                recent_commits: VecDeque::with_capacity(IMPORT_RATE_WINDOW),
+            }),
+        }
+    }
+
+    /// Records the height reported by a peer.
+    pub fn observe(&self, height: u32) {
+        self.inner.apply(|progress| {
+            progress.best_peer_height = progress.best_peer_height.max(Some(height));
+        });
+    }
+
+    /// Records the `height` committed at the `time`.
+    pub fn commit(&self, height: u32, time: Instant) {
+        self.inner.apply(|progress| {
+            progress.committed = progress.committed.max(Some(height));
+            if progress.recent_commits.len() >= IMPORT_RATE_WINDOW {
+                progress.recent_commits.pop_front();
+            }
+            progress.recent_commits.push_back((time, height));
+        });
+    }
+
+    /// Estimates the time to sync with the best known peer.
+    pub fn estimate_time_to_sync(&self) -> SyncEstimate {
+        self.inner.apply(|progress| {
+            let committed = progress.committed.unwrap_or_default();
+            let remaining_blocks = match progress.best_peer_height {
+                Some(best_peer_height) if best_peer_height > committed => {
+                    best_peer_height.saturating_sub(committed)
+                }
+                _ => return SyncEstimate::Synced,
+            };
+            SyncEstimate::Syncing {
+                remaining_blocks,
+                time_left: progress.time_to_import(remaining_blocks),
+            }
+        })
+    }
+}
+
+impl Progress {
+    /// Returns the time to import the `blocks` at the rate of the recent commits.
+    fn time_to_import(&self, blocks: u32) -> Option<Duration> {
+        let (oldest_time, oldest_height) = self.recent_commits.front()?;
+        let (newest_time, newest_height) = self.recent_commits.back()?;
+        let imported = newest_height.saturating_sub(*oldest_height);
+        let elapsed = newest_time.saturating_duration_since(*oldest_time);
+        if imported == 0 || elapsed.is_zero() {
+            return None
+        }
+        let time_per_block = elapsed.checked_div(imported)?;
+        time_per_block.checked_mul(blocks)
+    }
+}
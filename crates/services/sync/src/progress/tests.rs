@@ -0,0 +1,82 @@
+#![allow(clippy::arithmetic_side_effects)]
+#![allow(non_snake_case)]
+
+use super::*;
+
+#[test]
+fn estimate__synced_without_known_peers() {
+    let progress = SyncProgress::new(10);
+
+    assert_eq!(progress.estimate_time_to_sync(), SyncEstimate::Synced);
+}
+
+#[test]
+fn estimate__synced_when_caught_up_with_the_best_peer() {
+    // Given
+    let progress = SyncProgress::new(10);
+    progress.observe(5);
+    progress.observe(12);
+
+    // When
+    progress.commit(12, Instant::now());
+
+    // Then
+    assert_eq!(progress.estimate_time_to_sync(), SyncEstimate::Synced);
+}
+
+#[test]
+fn estimate__unknown_time_left_until_the_import_rate_is_known() {
+    // Given
+    let progress = SyncProgress::new(0);
+    progress.observe(100);
+
+    // When
+    progress.commit(1, Instant::now());
+
+    // Then
+    assert_eq!(
+        progress.estimate_time_to_sync(),
+        SyncEstimate::Syncing {
+            remaining_blocks: 99,
+            time_left: None,
+        }
+    );
+}
+
+#[test]
+fn estimate__decreases_as_blocks_are_imported() {
+    // Given
+    let start = Instant::now();
+    let progress = SyncProgress::new(0);
+    progress.observe(100);
+    progress.commit(0, start);
+
+    let mut previous = None;
+    for second in 1..=9u32 {
+        // When
+        let time = start + Duration::from_secs(second.into());
+        progress.commit(second * 10, time);
+
+        // Then
+        let SyncEstimate::Syncing {
+            remaining_blocks,
+            time_left: Some(time_left),
+        } = progress.estimate_time_to_sync()
+        else {
+            panic!("The node should be syncing with the known import rate");
+        };
+        // The import rate is 10 blocks per second.
+        assert_eq!(remaining_blocks, 100 - second * 10);
+        assert_eq!(time_left, Duration::from_secs((10 - second).into()));
+        if let Some(previous) = previous {
+            assert!(time_left < previous);
+        }
+        previous = Some(time_left);
+    }
+
+    // When
+    progress.commit(100, start + Duration::from_secs(10));
+
+    // Then
+    assert_eq!(progress.estimate_time_to_sync(), SyncEstimate::Synced);
+}
@@ -5,6 +5,7 @@ use crate::{
     import::{
         Config,
         Import,
+        RejectedBlocks,
     },
     ports::{
         self,
@@ -12,6 +13,10 @@ use crate::{
         ConsensusPort,
         PeerToPeerPort,
     },
+    progress::{
+        SyncEstimate,
+        SyncProgress,
+    },
     state::State,
     sync::SyncHeights,
 };
@@ -51,10 +56,12 @@ where
     let height_stream = p2p.height_stream();
     let committed_height_stream = executor.committed_height_stream();
     let state = State::new(Some(current_fuel_block_height.into()), None);
+    let progress = SyncProgress::new(Some(current_fuel_block_height.into()));
     Ok(ServiceRunner::new(SyncTask::new(
         height_stream,
         committed_height_stream,
         state,
+        progress,
         params,
         p2p,
         executor,
@@ -62,6 +69,22 @@ where
     )?))
 }
 
+/// The shared state of the sync service.
+#[derive(Clone)]
+pub struct SharedState {
+    /// The record of the blocks rejected during the sync.
+    pub rejected_blocks: RejectedBlocks,
+    /// The progress of the sync.
+    pub progress: SyncProgress,
+}
+
+impl SharedState {
+    /// Estimates the time to sync with the best known peer.
+    pub fn estimate_time_to_sync(&self) -> SyncEstimate {
+        self.progress.estimate_time_to_sync()
+    }
+}
+
 /// Task for syncing heights.
 /// Contains import task as a child task.
 pub struct SyncTask<P, E, C>
@@ -72,6 +95,7 @@ where
 {
     sync_heights: SyncHeights,
     import_task_handle: ServiceRunner<ImportTask<P, E, C>>,
+    shared: SharedState,
 }
 
 struct ImportTask<P, E, C>(Import<P, E, C>);
@@ -86,6 +110,7 @@ where
         height_stream: BoxStream<BlockHeight>,
         committed_height_stream: BoxStream<BlockHeight>,
         state: State,
+        progress: SyncProgress,
         params: Config,
         p2p: P,
         executor: E,
@@ -101,12 +126,18 @@ where
             committed_height_stream,
             state.clone(),
             notify.clone(),
+            progress.clone(),
         );
         let import = Import::new(state, notify, params, p2p, executor, consensus);
+        let shared = SharedState {
+            rejected_blocks: import.rejected_blocks(),
+            progress,
+        };
         let import_task_handle = ServiceRunner::new(ImportTask(import));
         Ok(Self {
             sync_heights,
             import_task_handle,
+            shared,
         })
     }
 }
@@ -138,12 +169,14 @@ where
 {
     const NAME: &'static str = "SyncTask";
 
-    type SharedData = ();
+    type SharedData = SharedState;
 
     type Task = SyncTask<P, E, C>;
     type TaskParams = ();
 
-    fn shared_data(&self) -> Self::SharedData {}
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
 
     async fn into_task(
         mut self,
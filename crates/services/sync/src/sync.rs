@@ -1,7 +1,10 @@
 //! # Sync task
 //! Updates the state from the height stream.
 
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Instant,
+};
 
 use fuel_core_services::{
     stream::{
@@ -14,7 +17,10 @@ use fuel_core_types::fuel_types::BlockHeight;
 use futures::stream::StreamExt;
 use tokio::sync::Notify;
 
-use crate::state::State;
+use crate::{
+    progress::SyncProgress,
+    state::State,
+};
 
 #[cfg(test)]
 mod tests;
@@ -28,6 +34,7 @@ pub(crate) struct SyncHeights {
     height_stream: BoxStream<IncomingHeight>,
     state: SharedMutex<State>,
     notify: Arc<Notify>,
+    progress: SyncProgress,
 }
 
 impl SyncHeights {
@@ -36,6 +43,7 @@ impl SyncHeights {
         committed_height_stream: BoxStream<BlockHeight>,
         state: SharedMutex<State>,
         notify: Arc<Notify>,
+        progress: SyncProgress,
     ) -> Self {
         let height_stream = futures::stream::select(
             height_stream.map(IncomingHeight::Observed),
@@ -46,6 +54,7 @@ impl SyncHeights {
             height_stream,
             state,
             notify,
+            progress,
         }
     }
 
@@ -57,10 +66,14 @@ impl SyncHeights {
         let state_change = match height {
             IncomingHeight::Committed(height) => {
                 self.state.apply(|s| s.commit(*height));
+                self.progress.commit(*height, Instant::now());
                 // A new committed height doesn't represent new work for the import stream.
                 false
             }
-            IncomingHeight::Observed(height) => self.state.apply(|s| s.observe(*height)),
+            IncomingHeight::Observed(height) => {
+                self.progress.observe(*height);
+                self.state.apply(|s| s.observe(*height))
+            }
         };
         if state_change {
             self.notify.notify_one();
@@ -18,6 +18,7 @@ async fn test_sync() {
         height_stream,
         state,
         notify,
+        progress: SyncProgress::new(None),
     };
 
     while s.sync().await.is_some() {}
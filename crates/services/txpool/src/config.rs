@@ -5,7 +5,11 @@ use fuel_core_types::{
         Address,
         UtxoId,
     },
-    fuel_types::Nonce,
+    fuel_types::{
+        Nonce,
+        Word,
+    },
+    services::txpool::ValidationCache,
 };
 use std::{
     collections::HashSet,
@@ -74,6 +78,36 @@ pub struct Config {
     pub number_of_active_subscription: usize,
     /// The blacklist used to validate transaction.
     pub blacklist: BlackList,
+    /// Rejects transactions that use contracts not yet deployed on chain.
+    /// When disabled, contracts created by transactions inside the pool are allowed.
+    pub reject_unknown_contracts: bool,
+    /// The max gas that a single predicate may use. Transactions declaring more
+    /// are rejected before their predicates are executed.
+    /// If not set, the limit from the consensus parameters is used.
+    pub max_gas_per_predicate: Option<Word>,
+    /// The max gas that all predicates of one transaction may use together.
+    /// If not set, the limit from the consensus parameters is used.
+    pub max_predicate_gas_per_tx: Option<Word>,
+    /// Gossips the valid transactions that were not inserted only because the pool is full.
+    /// It is useful for relay nodes that forward transactions without storing all of them.
+    pub relay_rejected_transactions: bool,
+    /// Stores the pending transactions on shutdown and re-admits the still valid ones
+    /// on startup.
+    pub persist_transactions: bool,
+    /// The minimum increase of the tip required from the transaction to replace
+    /// the pending transaction spending the same inputs.
+    pub min_replacement_tip_bump: Word,
+    /// The max time the transaction spending a message unknown to the node is held,
+    /// waiting for the relayer to import the message. The held transactions are
+    /// re-evaluated after each imported block. Only the local transactions are held,
+    /// the gossiped ones are rejected. If `None`, such transactions are rejected.
+    pub unrelayed_message_hold_time: Option<Duration>,
+    /// The max number of predicate inputs of one transaction.
+    /// If not set, the limit of inputs from the consensus parameters is used.
+    pub max_predicate_inputs: Option<u16>,
+    /// Shares the fully checked transactions of the pool with the executor,
+    /// so it doesn't check them again during the block validation.
+    pub validation_cache: Option<ValidationCache>,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -95,6 +129,14 @@ impl Default for Config {
             transaction_ttl,
             number_of_active_subscription,
             Default::default(),
+            false,
+            None,
+            None,
+            false,
+            false,
+            0,
+            None,
+            None,
         )
     }
 }
@@ -110,6 +152,14 @@ impl Config {
         transaction_ttl: Duration,
         number_of_active_subscription: usize,
         blacklist: BlackList,
+        reject_unknown_contracts: bool,
+        max_gas_per_predicate: Option<Word>,
+        max_predicate_gas_per_tx: Option<Word>,
+        relay_rejected_transactions: bool,
+        persist_transactions: bool,
+        min_replacement_tip_bump: Word,
+        unrelayed_message_hold_time: Option<Duration>,
+        max_predicate_inputs: Option<u16>,
     ) -> Self {
         // # Dev-note: If you add a new field, be sure that this field is propagated correctly
         //  in all places where `new` is used.
@@ -122,6 +172,50 @@ impl Config {
             transaction_ttl,
             number_of_active_subscription,
             blacklist,
+            reject_unknown_contracts,
+            max_gas_per_predicate,
+            max_predicate_gas_per_tx,
+            relay_rejected_transactions,
+            persist_transactions,
+            min_replacement_tip_bump,
+            unrelayed_message_hold_time,
+            max_predicate_inputs,
+            validation_cache: None,
         }
     }
+
+    /// The max gas that a single predicate may use.
+    pub fn max_gas_per_predicate(&self) -> Word {
+        let consensus_limit = self
+            .chain_config
+            .consensus_parameters
+            .predicate_params()
+            .max_gas_per_predicate();
+        self.max_gas_per_predicate
+            .map_or(consensus_limit, |limit| limit.min(consensus_limit))
+    }
+
+    /// The max gas that all predicates of one transaction may use together.
+    pub fn max_predicate_gas_per_tx(&self) -> Word {
+        let consensus_limit = self
+            .chain_config
+            .consensus_parameters
+            .tx_params()
+            .max_gas_per_tx();
+        self.max_predicate_gas_per_tx
+            .map_or(consensus_limit, |limit| limit.min(consensus_limit))
+    }
+
+    /// The max number of predicate inputs of one transaction.
+    pub fn max_predicate_inputs(&self) -> usize {
+        let consensus_limit = self
+            .chain_config
+            .consensus_parameters
+            .tx_params()
+            .max_inputs();
+        let limit = self
+            .max_predicate_inputs
+            .map_or(consensus_limit, |limit| limit.min(consensus_limit));
+        usize::from(limit)
+    }
 }
@@ -46,6 +46,9 @@ pub struct Dependency {
     max_depth: usize,
     /// utxo-validation feature flag
     utxo_validation: bool,
+    /// The minimum increase of the tip required to replace the transaction
+    /// spending the same inputs.
+    min_replacement_tip_bump: Word,
 }
 
 #[derive(Debug, Clone)]
@@ -91,16 +94,40 @@ pub struct MessageState {
 }
 
 impl Dependency {
-    pub fn new(max_depth: usize, utxo_validation: bool) -> Self {
+    pub fn new(
+        max_depth: usize,
+        utxo_validation: bool,
+        min_replacement_tip_bump: Word,
+    ) -> Self {
         Self {
             coins: HashMap::new(),
             contracts: HashMap::new(),
             messages: HashMap::new(),
             max_depth,
             utxo_validation,
+            min_replacement_tip_bump,
         }
     }
 
+    /// Checks that the `tx` bumps the tip of the `replaced` transaction
+    /// enough to replace it.
+    fn check_replacement_tip(
+        &self,
+        replaced: &TxId,
+        replaced_tip: Word,
+        tx: &ArcPoolTx,
+    ) -> Result<(), Error> {
+        let required_tip = replaced_tip.saturating_add(self.min_replacement_tip_bump);
+        if tx.tip() < required_tip {
+            return Err(Error::NotInsertedReplacementUnderpriced {
+                replaced: *replaced,
+                tip: tx.tip(),
+                required_tip,
+            })
+        }
+        Ok(())
+    }
+
     /// find all dependent Transactions that are inside txpool.
     /// Does not check db. They can be sorted by gasPrice to get order of dependency
     pub(crate) fn find_dependent(
@@ -280,7 +307,10 @@ impl Dependency {
                         max_depth =
                             core::cmp::max(state.depth.saturating_add(1), max_depth);
                         if max_depth > self.max_depth {
-                            return Err(Error::NotInsertedMaxDepth)
+                            return Err(Error::NotInsertedMaxDepth {
+                                depth: max_depth,
+                                max_depth: self.max_depth,
+                            })
                         }
                         // output is present but is it spend by other tx?
                         if let Some(ref spend_by) = state.is_spend_by {
@@ -293,7 +323,9 @@ impl Dependency {
                                 return Err(Error::NotInsertedCollision(
                                     *spend_by, *utxo_id,
                                 ))
-                            } else if state.is_in_database() {
+                            }
+                            self.check_replacement_tip(spend_by, txpool_tx.tip(), tx)?;
+                            if state.is_in_database() {
                                 // this means it is loaded from db. Get tx to compare output.
                                 if self.utxo_validation {
                                     let coin = db
@@ -391,6 +423,7 @@ impl Dependency {
                                 *nonce,
                             ))
                         } else {
+                            self.check_replacement_tip(&state.spent_by, state.tip, tx)?;
                             collided.push(state.spent_by);
                         }
                     }
@@ -411,10 +444,15 @@ impl Dependency {
                                 *contract_id,
                             ))
                         }
-                        // check depth.
-                        max_depth = core::cmp::max(state.depth, max_depth);
+                        // check depth. The transaction is one level deeper
+                        // than the transaction that created the contract.
+                        max_depth =
+                            core::cmp::max(state.depth.saturating_add(1), max_depth);
                         if max_depth > self.max_depth {
-                            return Err(Error::NotInsertedMaxDepth)
+                            return Err(Error::NotInsertedMaxDepth {
+                                depth: max_depth,
+                                max_depth: self.max_depth,
+                            })
                         }
                     } else {
                         if !db
@@ -471,6 +509,53 @@ impl Dependency {
         Ok((max_depth, db_coins, db_contracts, db_messages, collided))
     }
 
+    /// Returns the depth of the transaction in the chain of dependent transactions
+    /// inside of the pool. The transaction that doesn't depend on other transactions
+    /// from the pool has the depth `1`.
+    pub(crate) fn depth(&self, txs: &HashMap<TxId, TxInfo>, tx: &ArcPoolTx) -> usize {
+        let mut depths = HashMap::new();
+        self.depth_inner(txs, tx, &mut depths)
+    }
+
+    fn depth_inner(
+        &self,
+        txs: &HashMap<TxId, TxInfo>,
+        tx: &ArcPoolTx,
+        depths: &mut HashMap<TxId, usize>,
+    ) -> usize {
+        if let Some(depth) = depths.get(&tx.id()) {
+            return *depth
+        }
+
+        let mut parents_depth = 0;
+        for input in tx.inputs() {
+            let parent = match input {
+                Input::CoinSigned(CoinSigned { utxo_id, .. })
+                | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                    Some(*utxo_id.tx_id())
+                }
+                Input::Contract(Contract { contract_id, .. }) => self
+                    .contracts
+                    .get(contract_id)
+                    .and_then(|state| state.origin)
+                    .map(|origin| *origin.tx_id()),
+                Input::MessageCoinSigned(_)
+                | Input::MessageCoinPredicate(_)
+                | Input::MessageDataSigned(_)
+                | Input::MessageDataPredicate(_) => None,
+            };
+
+            if let Some(parent) = parent.and_then(|parent| txs.get(&parent)) {
+                let parent_depth = self.depth_inner(txs, parent.tx(), depths);
+                parents_depth = core::cmp::max(parent_depth, parents_depth);
+            }
+        }
+
+        let depth = parents_depth.saturating_add(1);
+        depths.insert(tx.id(), depth);
+        depth
+    }
+
     /// insert tx inside dependency
     /// return list of transactions that are removed from txpool
     pub(crate) fn insert<'a, DB>(
@@ -1,4 +1,7 @@
-use crate::ports::TxPoolDb;
+use crate::ports::{
+    TxPoolDb,
+    TxPoolPersistence,
+};
 use fuel_core_storage::{
     transactional::AtomicView,
     Result as StorageResult,
@@ -14,6 +17,8 @@ use fuel_core_types::{
     fuel_tx::{
         Contract,
         ContractId,
+        Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -38,6 +43,7 @@ pub struct Data {
     pub contracts: HashMap<ContractId, Contract>,
     pub messages: HashMap<Nonce, Message>,
     pub spent_messages: HashSet<Nonce>,
+    pub persisted_transactions: HashMap<TxId, Transaction>,
 }
 
 #[derive(Clone, Default)]
@@ -65,6 +71,10 @@ impl MockDb {
     pub fn spend_message(&self, id: Nonce) {
         self.data.lock().unwrap().spent_messages.insert(id);
     }
+
+    pub fn remove_coin(&self, utxo_id: &UtxoId) {
+        self.data.lock().unwrap().coins.remove(utxo_id);
+    }
 }
 
 impl TxPoolDb for MockDb {
@@ -90,6 +100,37 @@ impl TxPoolDb for MockDb {
     }
 }
 
+impl TxPoolPersistence for MockDb {
+    fn load_transactions(&self) -> StorageResult<Vec<(TxId, Transaction)>> {
+        let data = self.data.lock().unwrap();
+        Ok(data
+            .persisted_transactions
+            .iter()
+            .map(|(id, tx)| (*id, tx.clone()))
+            .collect())
+    }
+
+    fn store_transactions(
+        &mut self,
+        transactions: Vec<(TxId, Transaction)>,
+    ) -> StorageResult<()> {
+        self.data
+            .lock()
+            .unwrap()
+            .persisted_transactions
+            .extend(transactions);
+        Ok(())
+    }
+
+    fn remove_transactions(&mut self, tx_ids: &[TxId]) -> StorageResult<()> {
+        let mut data = self.data.lock().unwrap();
+        for tx_id in tx_ids {
+            data.persisted_transactions.remove(tx_id);
+        }
+        Ok(())
+    }
+}
+
 pub struct MockDBProvider(pub MockDb);
 
 impl AtomicView for MockDBProvider {
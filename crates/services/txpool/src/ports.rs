@@ -7,6 +7,7 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -55,3 +56,19 @@ pub trait TxPoolDb: Send + Sync {
 
     fn is_message_spent(&self, message_id: &Nonce) -> StorageResult<bool>;
 }
+
+/// Keeps the pending transactions of the `TxPool` across restarts of the node.
+/// Each transaction is stored under its id.
+pub trait TxPoolPersistence: Send + Sync {
+    /// Returns the stored transactions along with their ids.
+    fn load_transactions(&self) -> StorageResult<Vec<(TxId, Transaction)>>;
+
+    /// Stores the transactions under their ids.
+    fn store_transactions(
+        &mut self,
+        transactions: Vec<(TxId, Transaction)>,
+    ) -> StorageResult<()>;
+
+    /// Removes the transactions with the `tx_ids` from the storage.
+    fn remove_transactions(&mut self, tx_ids: &[TxId]) -> StorageResult<()>;
+}
@@ -3,6 +3,7 @@ use crate::{
         BlockImporter,
         PeerToPeer,
         TxPoolDb,
+        TxPoolPersistence,
     },
     transaction_selector::select_transactions,
     txpool::{
@@ -44,7 +45,8 @@ use fuel_core_types::{
         txpool::{
             ArcPoolTx,
             Error,
-            InsertionResult,
+            InsertionOutcome,
+            PackingStrategy,
             TransactionStatus,
         },
     },
@@ -56,6 +58,7 @@ use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::services::block_importer::SharedImportResult;
 use parking_lot::Mutex as ParkingMutex;
 use std::{
+    collections::HashSet,
     sync::Arc,
     time::Duration,
 };
@@ -152,6 +155,9 @@ pub struct Task<P2P, ViewProvider, GasPriceProvider> {
     committed_block_stream: BoxStream<SharedImportResult>,
     tx_pool_shared_state: SharedState<P2P, ViewProvider, GasPriceProvider>,
     ttl_timer: tokio::time::Interval,
+    persistence: Box<dyn TxPoolPersistence>,
+    /// The ids of the persisted transactions restored into the pool on startup.
+    persisted_tx_ids: HashSet<TxId>,
 }
 
 #[async_trait::async_trait]
@@ -178,6 +184,34 @@ where
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        if self.tx_pool_shared_state.config.persist_transactions {
+            let (ids, txs): (Vec<_>, Vec<_>) = self
+                .persistence
+                .load_transactions()?
+                .into_iter()
+                .map(|(id, tx)| (id, Arc::new(tx)))
+                .unzip();
+
+            // The transactions are validated against the current state,
+            // so the transactions with already spent inputs are dropped.
+            let results = self.tx_pool_shared_state.insert(txs).await;
+            let mut dropped = vec![];
+            for (id, result) in ids.into_iter().zip(results.iter()) {
+                if let Err(err) = result {
+                    tracing::info!("Dropped the persisted transaction {}: {}", id, err);
+                    dropped.push(id);
+                } else {
+                    self.persisted_tx_ids.insert(id);
+                }
+            }
+            self.persistence.remove_transactions(&dropped)?;
+            tracing::info!(
+                "Restored {} of {} persisted transactions",
+                self.persisted_tx_ids.len(),
+                results.len()
+            );
+        }
+
         self.ttl_timer.reset();
         Ok(self)
     }
@@ -203,10 +237,16 @@ where
             }
 
             _ = self.ttl_timer.tick() => {
-                let removed = self.tx_pool_shared_state.txpool.lock().prune_old_txs();
-                for tx in removed {
-                    self.tx_pool_shared_state.tx_status_sender.send_squeezed_out(tx.id(), Error::TTLReason);
-                }
+                let inserted = {
+                    let mut txpool = self.tx_pool_shared_state.txpool.lock();
+                    let removed = txpool.prune_old_txs();
+                    for tx in removed {
+                        self.tx_pool_shared_state.tx_status_sender.send_squeezed_out(tx.id(), Error::TTLReason);
+                    }
+                    // Drops the transactions waiting too long for the relayer.
+                    txpool.insert_pending_on_relayer(&self.tx_pool_shared_state.tx_status_sender)
+                };
+                self.tx_pool_shared_state.broadcast_inserted(inserted);
 
                 should_continue = true
             }
@@ -217,13 +257,22 @@ where
                         .sealed_block
                         .entity.header().height();
 
-                    {
+                    let inserted = {
                         let mut lock = self.tx_pool_shared_state.txpool.lock();
                         lock.block_update(
                             &result.tx_status,
                         );
+                        // The block may import the messages relayed from the DA layer.
+                        let inserted = lock.insert_pending_on_relayer(&self.tx_pool_shared_state.tx_status_sender);
+                        if let Some(validation_cache) = &self.tx_pool_shared_state.config.validation_cache {
+                            // The transactions that spend the same inputs as the transactions
+                            // of the block can't be included anymore.
+                            validation_cache.remove_spending(result.sealed_block.entity.transactions());
+                        }
                         *self.tx_pool_shared_state.current_height.lock() = new_height;
-                    }
+                        inserted
+                    };
+                    self.tx_pool_shared_state.broadcast_inserted(inserted);
                     should_continue = true;
                 } else {
                     should_continue = false;
@@ -255,6 +304,11 @@ where
                                 Some(Ok(_)) => {
                                     GossipsubMessageAcceptance::Accept
                                 },
+                                // The transaction is valid, so it can be propagated further
+                                // even if it doesn't fit into our pool.
+                                Some(Err(Error::NotInsertedLimitHit)) if self.tx_pool_shared_state.config.relay_rejected_transactions => {
+                                    GossipsubMessageAcceptance::Accept
+                                },
                                 // Use similar p2p punishment rules as bitcoin
                                 // https://github.com/bitcoin/bitcoin/blob/6ff0aa089c01ff3e610ecb47814ed739d685a14c/src/net_processing.cpp#L1856
                                 Some(Err(Error::ConsensusValidity(_))) | Some(Err(Error::MintIsDisallowed)) => {
@@ -285,11 +339,31 @@ where
         Ok(should_continue)
     }
 
-    async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
-        // and we don't spawn any sub-tasks that we need to finish or await.
-        // Maybe we will save and load the previous list of transactions in the future to
-        // avoid losing them.
+    async fn shutdown(mut self) -> anyhow::Result<()> {
+        // We don't spawn any sub-tasks that we need to finish or await.
+        // The only state worth keeping is the list of pending transactions.
+        if self.tx_pool_shared_state.config.persist_transactions {
+            let chain_id = self.tx_pool_shared_state.consensus_params.chain_id();
+            let txs = self
+                .tx_pool_shared_state
+                .txpool
+                .lock()
+                .pending_transactions()
+                .into_iter()
+                .map(|tx| (tx.id(&chain_id), tx))
+                .collect::<Vec<_>>();
+
+            // The restored transactions that are not pending anymore
+            // were included into blocks or evicted from the pool.
+            for (id, _) in &txs {
+                self.persisted_tx_ids.remove(id);
+            }
+            let outdated = self.persisted_tx_ids.drain().collect::<Vec<_>>();
+            self.persistence.remove_transactions(&outdated)?;
+
+            tracing::info!("Persisting {} pending transactions", txs.len());
+            self.persistence.store_transactions(txs)?;
+        }
         Ok(())
     }
 }
@@ -305,6 +379,13 @@ impl<P2P, ViewProvider, GasPriceProvider>
         self.txpool.lock().pending_number()
     }
 
+    /// Returns the number of the pending transactions and their total size in bytes,
+    /// taken at the same moment.
+    pub fn pending_stats(&self) -> (usize, usize) {
+        let txpool = self.txpool.lock();
+        (txpool.pending_number(), txpool.pending_bytes())
+    }
+
     pub fn total_consumable_gas(&self) -> u64 {
         self.txpool.lock().consumable_gas()
     }
@@ -321,14 +402,43 @@ impl<P2P, ViewProvider, GasPriceProvider>
         self.txpool.lock().find_one(&id)
     }
 
+    pub fn dependency_depth(&self, id: TxId) -> Option<usize> {
+        self.txpool.lock().dependency_depth(&id)
+    }
+
+    pub fn min_admission_tip(&self) -> Option<u64> {
+        self.txpool.lock().min_admission_tip()
+    }
+
+    pub fn tip_histogram(&self, bounds: &[u64]) -> Vec<usize> {
+        self.txpool.lock().tip_histogram(bounds)
+    }
+
     pub fn find_dependent(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
         self.txpool.lock().find_dependent(&ids)
     }
 
-    pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
+    pub fn select_transactions(
+        &self,
+        block_height: BlockHeight,
+        max_gas: u64,
+        max_txs: u16,
+        max_outputs: u32,
+        max_bytes: u64,
+        strategy: PackingStrategy,
+    ) -> Vec<ArcPoolTx> {
         let mut guard = self.txpool.lock();
-        let txs = guard.includable();
-        let sorted_txs = select_transactions(txs, max_gas);
+        let txs: Vec<_> = guard.includable().collect();
+        let sorted_txs = select_transactions(
+            txs.into_iter(),
+            block_height,
+            max_gas,
+            max_txs,
+            max_outputs,
+            max_bytes,
+            strategy,
+            &mut guard.skipped_txs,
+        );
 
         for tx in sorted_txs.iter() {
             guard.remove_committed_tx(&tx.id());
@@ -377,7 +487,7 @@ where
     pub async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
-    ) -> Vec<Result<InsertionResult, Error>> {
+    ) -> Vec<Result<InsertionOutcome, Error>> {
         // verify txs
         let current_height = *self.current_height.lock();
 
@@ -403,21 +513,24 @@ where
             .collect();
 
         // insert txs
-        let insertion = { self.txpool.lock().insert(&self.tx_status_sender, valid_txs) };
+        let insertion = {
+            self.txpool
+                .lock()
+                .insert_local(&self.tx_status_sender, valid_txs)
+        };
 
         for (ret, tx) in insertion.iter().zip(txs.into_iter()) {
-            match ret {
-                Ok(_) => {
-                    let result = self.p2p.broadcast_transaction(tx.clone());
-                    if let Err(e) = result {
-                        // It can be only in the case of p2p being down or requests overloading it.
-                        tracing::error!(
-                            "Unable to broadcast transaction, got an {} error",
-                            e
-                        );
-                    }
+            let should_broadcast = match ret {
+                Ok(InsertionOutcome::Inserted(_)) => true,
+                // The deferred transaction is broadcasted once it is inserted.
+                Ok(InsertionOutcome::Deferred(_)) => false,
+                Err(Error::NotInsertedLimitHit) => {
+                    self.config.relay_rejected_transactions
                 }
-                Err(_) => {}
+                Err(_) => false,
+            };
+            if should_broadcast {
+                self.broadcast_transaction(tx.clone());
             }
         }
 
@@ -435,6 +548,21 @@ where
             })
             .collect()
     }
+
+    /// Broadcasts the deferred transactions inserted into the pool.
+    fn broadcast_inserted(&self, inserted: Vec<ArcPoolTx>) {
+        for tx in inserted {
+            self.broadcast_transaction(Arc::new(Transaction::from(tx.as_ref())));
+        }
+    }
+
+    fn broadcast_transaction(&self, tx: Arc<Transaction>) {
+        let result = self.p2p.broadcast_transaction(tx);
+        if let Err(e) = result {
+            // It can be only in the case of p2p being down or requests overloading it.
+            tracing::error!("Unable to broadcast transaction, got an {} error", e);
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -463,16 +591,18 @@ pub enum TxStatusMessage {
     FailedStatus,
 }
 
-pub fn new_service<P2P, Importer, ViewProvider, GasPriceProvider>(
+pub fn new_service<P2P, Importer, ViewProvider, GasPriceProvider, Persistence>(
     config: Config,
     provider: ViewProvider,
     importer: Importer,
     p2p: P2P,
     current_height: BlockHeight,
     gas_price_provider: GasPriceProvider,
+    persistence: Persistence,
 ) -> Service<P2P, ViewProvider, GasPriceProvider>
 where
     Importer: BlockImporter,
+    Persistence: TxPoolPersistence + 'static,
     P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
     ViewProvider: AtomicView,
     ViewProvider::View: TxPoolDb,
@@ -506,6 +636,8 @@ where
             gas_price_provider: Arc::new(gas_price_provider),
         },
         ttl_timer,
+        persistence: Box::new(persistence),
+        persisted_tx_ids: HashSet::new(),
     };
 
     Service::new(task)
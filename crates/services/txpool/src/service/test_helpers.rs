@@ -87,6 +87,10 @@ impl TestContext {
     pub fn setup_coin(&self) -> (Coin, Input) {
         crate::test_helpers::setup_coin(&mut self.rng.borrow_mut(), Some(&self.mock_db))
     }
+
+    pub fn mock_db(&self) -> &MockDb {
+        &self.mock_db
+    }
 }
 
 mockall::mock! {
@@ -156,6 +160,33 @@ impl MockImporter {
         });
         importer
     }
+
+    /// The importer emits the blocks received from the `block_provider`.
+    pub fn with_block_provider(
+        block_provider: tokio::sync::mpsc::Receiver<SealedBlock>,
+    ) -> Self {
+        let mut importer = MockImporter::default();
+        let block_provider = Arc::new(ParkingMutex::new(Some(block_provider)));
+        importer.expect_block_events().returning(move || {
+            let block_provider = block_provider
+                .lock()
+                .take()
+                .expect("The block events are subscribed only once");
+            let stream = fuel_core_services::stream::unfold(
+                block_provider,
+                |mut block_provider| async {
+                    let sealed_block = block_provider.recv().await?;
+                    let result: SharedImportResult = Arc::new(
+                        ImportResult::new_from_local(sealed_block, vec![], vec![]),
+                    );
+
+                    Some((result, block_provider))
+                },
+            );
+            Box::pin(stream)
+        });
+        importer
+    }
 }
 
 pub struct TestContextBuilder {
@@ -188,6 +219,11 @@ impl TestContextBuilder {
         self
     }
 
+    pub fn with_mock_db(mut self, mock_db: MockDb) -> Self {
+        self.mock_db = mock_db;
+        self
+    }
+
     pub fn with_importer(&mut self, importer: MockImporter) {
         self.importer = Some(importer)
     }
@@ -236,6 +272,7 @@ impl TestContextBuilder {
             p2p,
             Default::default(),
             gas_price_provider,
+            mock_db.clone(),
         );
 
         TestContext {
@@ -1,11 +1,21 @@
 use super::*;
-use crate::service::test_helpers::{
-    TestContext,
-    TestContextBuilder,
+use crate::{
+    service::test_helpers::{
+        MockImporter,
+        MockP2P,
+        TestContext,
+        TestContextBuilder,
+    },
+    txpool::test_helpers::create_message_predicate_from_message,
 };
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
-    fuel_tx::UniqueIdentifier,
+    blockchain::SealedBlock,
+    fuel_tx::{
+        Cacheable,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
     fuel_types::ChainId,
 };
 use std::time::Duration;
@@ -279,3 +289,149 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn persisted_transaction_survives_restart_when_still_valid() {
+    // Given
+    let config = Config {
+        persist_transactions: true,
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config.clone())
+        .build_and_start()
+        .await;
+    let tx = Arc::new(ctx.setup_script_tx(10));
+    let out = ctx.service().shared.insert(vec![tx.clone()]).await;
+    assert!(out[0].is_ok(), "Tx should be OK, got err:{out:?}");
+
+    // When
+    ctx.service().stop_and_await().await.unwrap();
+    let restarted = TestContextBuilder::new()
+        .with_config(config)
+        .with_mock_db(ctx.mock_db().clone())
+        .build_and_start()
+        .await;
+
+    // Then
+    let found = restarted
+        .service()
+        .shared
+        .find_one(tx.id(&ChainId::default()));
+    assert!(found.is_some(), "Tx should be restored after restart");
+    restarted.service().stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn persisted_transaction_is_dropped_on_restart_when_input_is_spent() {
+    // Given
+    let config = Config {
+        persist_transactions: true,
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config.clone())
+        .build_and_start()
+        .await;
+    let (coin, input) = ctx.setup_coin();
+    let mut tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1000)
+        .add_input(input)
+        .finalize_as_transaction();
+    tx.precompute(&Default::default()).unwrap();
+    let tx = Arc::new(tx);
+    let out = ctx.service().shared.insert(vec![tx.clone()]).await;
+    assert!(out[0].is_ok(), "Tx should be OK, got err:{out:?}");
+    ctx.service().stop_and_await().await.unwrap();
+
+    // When
+    ctx.mock_db().remove_coin(&coin.utxo_id);
+    let restarted = TestContextBuilder::new()
+        .with_config(config)
+        .with_mock_db(ctx.mock_db().clone())
+        .build_and_start()
+        .await;
+
+    // Then
+    let found = restarted
+        .service()
+        .shared
+        .find_one(tx.id(&ChainId::default()));
+    assert!(found.is_none(), "Tx with the spent input should be dropped");
+    let persisted = ctx
+        .mock_db()
+        .data
+        .lock()
+        .unwrap()
+        .persisted_transactions
+        .len();
+    assert_eq!(
+        persisted, 0,
+        "Dropped tx should be removed from the storage"
+    );
+    restarted.service().stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn tx_spending_unrelayed_message_is_inserted_after_message_is_relayed() {
+    // Given
+    let config = Config {
+        unrelayed_message_hold_time: Some(Duration::from_secs(60)),
+        ..Default::default()
+    };
+    let (block_sender, block_receiver) = tokio::sync::mpsc::channel(1);
+    let mut builder = TestContextBuilder::new().with_config(config);
+    builder.with_importer(MockImporter::with_block_provider(block_receiver));
+    let (broadcast_sender, mut broadcasts) = tokio::sync::mpsc::unbounded_channel();
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    p2p.expect_broadcast_transaction().returning(move |tx| {
+        broadcast_sender.send(tx).unwrap();
+        Ok(())
+    });
+    builder.with_p2p(p2p);
+    let ctx = builder.build_and_start().await;
+    let service = ctx.service();
+
+    let (message, input) = create_message_predicate_from_message(5000, 0);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1000)
+        .add_input(input)
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+
+    // When
+    let out = service.shared.insert(vec![Arc::new(tx)]).await;
+
+    // Then
+    assert!(
+        matches!(out[0], Ok(InsertionOutcome::Deferred(_))),
+        "The tx should be deferred, got: {out:?}"
+    );
+    assert!(
+        service.shared.find_one(tx_id).is_none(),
+        "The tx is not includable until the message is relayed"
+    );
+    assert!(
+        broadcasts.try_recv().is_err(),
+        "The tx is not broadcasted until it is inserted"
+    );
+
+    // When
+    ctx.mock_db().insert_message(message);
+    block_sender.send(SealedBlock::default()).await.unwrap();
+
+    // Then
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while service.shared.find_one(tx_id).is_none() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("The tx should be inserted after the message is relayed");
+    let broadcasted = broadcasts
+        .try_recv()
+        .expect("The inserted tx is broadcasted");
+    assert_eq!(broadcasted.id(&ChainId::default()), tx_id);
+
+    service.stop_and_await().await.unwrap();
+}
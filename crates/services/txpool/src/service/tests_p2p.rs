@@ -267,3 +267,104 @@ async fn test_gossipped_transaction_with_transient_error_ignored() {
         "expected to receive gossip validity notification"
     )
 }
+
+#[tokio::test]
+async fn insert_from_local_broadcasts_rejected_by_full_pool_tx_when_relay_enabled() {
+    let config = Config {
+        max_tx: 1,
+        relay_rejected_transactions: true,
+        ..Default::default()
+    };
+    let mut ctx_builder = TestContextBuilder::new().with_config(config);
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let tx2 = ctx_builder.setup_script_tx(5);
+
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    let mock_tx2 = tx2.clone();
+    p2p.expect_broadcast_transaction()
+        .withf(move |receive: &Arc<Transaction>| **receive == mock_tx2)
+        .times(1)
+        .returning(|_| Ok(()));
+    ctx_builder.with_p2p(p2p);
+    let ctx = ctx_builder.build_and_start().await;
+    let service = ctx.service();
+
+    // Given
+    let out = service.shared.insert(vec![Arc::new(tx1)]).await;
+    assert!(out[0].is_ok());
+
+    // When
+    let out = service.shared.insert(vec![Arc::new(tx2)]).await;
+
+    // Then
+    assert!(matches!(out[0], Err(Error::NotInsertedLimitHit)));
+}
+
+#[tokio::test]
+async fn insert_from_local_does_not_broadcast_rejected_by_full_pool_tx_by_default() {
+    let config = Config {
+        max_tx: 1,
+        ..Default::default()
+    };
+    let mut ctx_builder = TestContextBuilder::new().with_config(config);
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let tx2 = ctx_builder.setup_script_tx(5);
+
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    let mock_tx2 = tx2.clone();
+    p2p.expect_broadcast_transaction()
+        .withf(move |receive: &Arc<Transaction>| **receive == mock_tx2)
+        .times(0);
+    ctx_builder.with_p2p(p2p);
+    let ctx = ctx_builder.build_and_start().await;
+    let service = ctx.service();
+
+    // Given
+    let out = service.shared.insert(vec![Arc::new(tx1)]).await;
+    assert!(out[0].is_ok());
+
+    // When
+    let out = service.shared.insert(vec![Arc::new(tx2)]).await;
+
+    // Then
+    assert!(matches!(out[0], Err(Error::NotInsertedLimitHit)));
+}
+
+#[tokio::test]
+async fn test_gossipped_transaction_rejected_by_full_pool_accepted_when_relay_enabled() {
+    let config = Config {
+        max_tx: 0,
+        relay_rejected_transactions: true,
+        ..Default::default()
+    };
+    let mut ctx_builder = TestContextBuilder::new().with_config(config);
+    let tx1 = ctx_builder.setup_script_tx(0);
+    // setup p2p mock - with tx incoming from p2p
+    let txs = vec![tx1.clone()];
+    let mut p2p = MockP2P::new_with_txs(txs);
+    let (send, mut receive) = broadcast::channel::<()>(1);
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, validity| {
+            // Expect the transaction to be propagated further
+            assert_eq!(validity, GossipsubMessageAcceptance::Accept);
+            // Notify test that the gossipsub acceptance was set
+            send.send(()).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    // build and start the txpool service
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    service.start_and_await().await.unwrap();
+    // verify p2p was notified about the transaction validity
+    let gossip_validity_notified =
+        tokio::time::timeout(Duration::from_millis(100), receive.recv()).await;
+    assert!(
+        gossip_validity_notified.is_ok(),
+        "expected to receive gossip validity notification"
+    );
+    // the transaction is not stored locally
+    let out = service.shared.find(vec![tx1.id(&Default::default())]);
+    assert!(out[0].is_none());
+}
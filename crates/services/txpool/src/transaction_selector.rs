@@ -1,42 +1,125 @@
 use fuel_core_types::{
-    fuel_types::Word,
-    services::txpool::ArcPoolTx,
+    fuel_tx::TxId,
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+    services::txpool::{
+        ArcPoolTx,
+        PackingStrategy,
+    },
+};
+use std::collections::{
+    HashMap,
+    HashSet,
 };
 
 // transaction selection could use a plugin based approach in the
 // future for block producers to customize block building (e.g. alternative priorities besides gas fees)
 
+/// The blocks that skipped the transaction because it didn't fit into them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Skips {
+    /// The number of blocks that skipped the transaction.
+    pub count: u32,
+    /// The height of the last block that skipped the transaction.
+    pub last_height: Option<BlockHeight>,
+}
+
 // Expects sorted by gas price transactions, highest first
+#[allow(clippy::too_many_arguments)]
 pub fn select_transactions(
     includable_txs: impl Iterator<Item = ArcPoolTx>,
+    block_height: BlockHeight,
     max_gas: u64,
+    max_txs: u16,
+    max_outputs: u32,
+    max_bytes: u64,
+    strategy: PackingStrategy,
+    skipped_txs: &mut HashMap<TxId, Skips>,
 ) -> Vec<ArcPoolTx> {
     // Select all txs that fit into the block, preferring ones with higher gas price.
     //
     // Future improvements to this algorithm may take into account the parallel nature of
     // transactions to maximize throughput.
     let mut used_block_space: Word = 0;
+    let mut used_outputs: u32 = 0;
+    let mut used_bytes: u64 = 0;
     // The type of the index for the transaction is `u16`, so we need to
     // limit it to `MAX` value minus 1(because of the `Mint` transaction).
-    let takes_txs = u16::MAX - 1;
+    let takes_txs = core::cmp::min(max_txs, u16::MAX - 1);
+
+    let mut includable_txs: Vec<_> = includable_txs.collect();
+    if !skipped_txs.is_empty() {
+        // Forget about transactions that are not in the pool anymore.
+        let includable_ids: HashSet<_> =
+            includable_txs.iter().map(|tx| tx.id()).collect();
+        skipped_txs.retain(|tx_id, _| includable_ids.contains(tx_id));
+    }
+
+    if let PackingStrategy::Skip { max_skips } = strategy {
+        // Transactions skipped too many times go first, so they are not starved.
+        // The sort is stable and preserves the priority order inside both groups.
+        includable_txs.sort_by_key(|tx| {
+            let skips = skipped_txs.get(&tx.id()).copied().unwrap_or_default();
+            skips.count < max_skips
+        });
+    }
 
     // Pick as many transactions as we can fit into the block (greedy)
-    includable_txs
-        .filter(|tx| {
-            let tx_block_space = tx.max_gas();
-            if let Some(new_used_space) = used_block_space.checked_add(tx_block_space) {
-                if new_used_space <= max_gas {
-                    used_block_space = new_used_space;
-                    true
-                } else {
-                    false
+    let mut selected_txs = vec![];
+    for tx in includable_txs {
+        if selected_txs.len() >= takes_txs as usize {
+            break
+        }
+
+        // The packing stops at the first transaction exceeding the output limit
+        // regardless of the strategy to bound the growth of the UTXO set.
+        let tx_outputs = u32::try_from(tx.outputs().len()).unwrap_or(u32::MAX);
+        let Some(new_used_outputs) = used_outputs
+            .checked_add(tx_outputs)
+            .filter(|new_used_outputs| *new_used_outputs <= max_outputs)
+        else {
+            break
+        };
+
+        // The same for the byte limit, the block is closed once it is full.
+        // The size is the same as checked by the block verifier.
+        let tx_bytes = u64::try_from(tx.size()).unwrap_or(u64::MAX);
+        let Some(new_used_bytes) = used_bytes
+            .checked_add(tx_bytes)
+            .filter(|new_used_bytes| *new_used_bytes <= max_bytes)
+        else {
+            break
+        };
+
+        let tx_block_space = tx.max_gas();
+        let new_used_space = used_block_space
+            .checked_add(tx_block_space)
+            .filter(|new_used_space| *new_used_space <= max_gas);
+
+        if let Some(new_used_space) = new_used_space {
+            used_block_space = new_used_space;
+            used_outputs = new_used_outputs;
+            used_bytes = new_used_bytes;
+            skipped_txs.remove(&tx.id());
+            selected_txs.push(tx);
+        } else {
+            match strategy {
+                PackingStrategy::Skip { .. } => {
+                    // The executor requests transactions several times per block,
+                    // so the skip is counted once per block.
+                    let skips = skipped_txs.entry(tx.id()).or_default();
+                    if skips.last_height != Some(block_height) {
+                        skips.count = skips.count.saturating_add(1);
+                        skips.last_height = Some(block_height);
+                    }
                 }
-            } else {
-                false
+                PackingStrategy::Stop => break,
             }
-        })
-        .take(takes_txs as usize)
-        .collect()
+        }
+    }
+    selected_txs
 }
 
 #[cfg(test)]
@@ -73,9 +156,8 @@ mod tests {
         pub limit: u64,
     }
 
-    /// A test helper that generates set of txs with given gas prices and limits and runs
-    /// `select_transactions` against that, returning the list of selected gas price, limit pairs
-    fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
+    /// A test helper that generates set of txs with given gas prices and limits
+    fn make_txs(txs: &[TxGas]) -> Vec<ArcPoolTx> {
         let mut rng = thread_rng();
 
         let fee_params = FeeParameters::default()
@@ -112,14 +194,44 @@ mod tests {
             .map(Arc::new)
             .collect::<Vec<ArcPoolTx>>();
         txs.sort_by_key(|a| core::cmp::Reverse(a.tip()));
+        txs
+    }
 
-        select_transactions(txs.into_iter(), block_gas_limit)
-            .into_iter()
-            .map(|tx| TxGas {
-                limit: tx.script_gas_limit().unwrap_or_default(),
-                tip: tx.tip(),
-            })
-            .collect()
+    /// A test helper that runs `select_transactions` against the sorted txs,
+    /// returning the list of selected gas price, limit pairs
+    fn select(
+        txs: &[ArcPoolTx],
+        block_height: BlockHeight,
+        block_gas_limit: Word,
+        strategy: PackingStrategy,
+        skipped_txs: &mut HashMap<TxId, Skips>,
+    ) -> Vec<TxGas> {
+        select_transactions(
+            txs.iter().cloned(),
+            block_height,
+            block_gas_limit,
+            u16::MAX,
+            u32::MAX,
+            u64::MAX,
+            strategy,
+            skipped_txs,
+        )
+        .into_iter()
+        .map(|tx| TxGas {
+            limit: tx.script_gas_limit().unwrap_or_default(),
+            tip: tx.tip(),
+        })
+        .collect()
+    }
+
+    fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
+        select(
+            &make_txs(txs),
+            Default::default(),
+            block_gas_limit,
+            PackingStrategy::default(),
+            &mut HashMap::new(),
+        )
     }
 
     #[test]
@@ -201,4 +313,186 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn selector_stops_at_max_txs_with_gas_to_spare() {
+        #[rustfmt::skip]
+        let txs = make_txs(&[
+            TxGas { tip: 5, limit: 1000 },
+            TxGas { tip: 4, limit: 1000 },
+            TxGas { tip: 3, limit: 1000 },
+        ]);
+
+        let selected = select_transactions(
+            txs.into_iter(),
+            Default::default(),
+            1_000_000,
+            2,
+            u32::MAX,
+            u64::MAX,
+            PackingStrategy::default(),
+            &mut HashMap::new(),
+        );
+
+        let tips: Vec<_> = selected.iter().map(|tx| tx.tip()).collect();
+        assert_eq!(tips, vec![5, 4]);
+    }
+
+    #[test]
+    fn selector_stops_at_max_outputs_with_gas_to_spare() {
+        // Each transaction has one output.
+        #[rustfmt::skip]
+        let txs = make_txs(&[
+            TxGas { tip: 5, limit: 1000 },
+            TxGas { tip: 4, limit: 1000 },
+            TxGas { tip: 3, limit: 1000 },
+        ]);
+
+        let selected = select_transactions(
+            txs.into_iter(),
+            Default::default(),
+            1_000_000,
+            u16::MAX,
+            2,
+            u64::MAX,
+            PackingStrategy::default(),
+            &mut HashMap::new(),
+        );
+
+        let tips: Vec<_> = selected.iter().map(|tx| tx.tip()).collect();
+        assert_eq!(tips, vec![5, 4]);
+    }
+
+    #[test]
+    fn selector_stops_at_max_bytes_with_gas_to_spare() {
+        // All transactions have the same size.
+        #[rustfmt::skip]
+        let txs = make_txs(&[
+            TxGas { tip: 5, limit: 1000 },
+            TxGas { tip: 4, limit: 1000 },
+            TxGas { tip: 3, limit: 1000 },
+        ]);
+        let tx_bytes = txs[0].size() as u64;
+
+        let selected = select_transactions(
+            txs.into_iter(),
+            Default::default(),
+            1_000_000,
+            u16::MAX,
+            u32::MAX,
+            tx_bytes.saturating_mul(3).saturating_sub(1),
+            PackingStrategy::default(),
+            &mut HashMap::new(),
+        );
+
+        let tips: Vec<_> = selected.iter().map(|tx| tx.tip()).collect();
+        assert_eq!(tips, vec![5, 4]);
+    }
+
+    #[test]
+    fn stop_strategy_does_not_pack_txs_after_the_first_not_fitting_one() {
+        #[rustfmt::skip]
+        let txs = make_txs(&[
+            TxGas { tip: 5, limit: 1000 },
+            TxGas { tip: 4, limit: 3000 },
+            TxGas { tip: 3, limit: 1000 },
+        ]);
+
+        let height = BlockHeight::default();
+        let skip = select(
+            &txs,
+            height,
+            2500,
+            PackingStrategy::default(),
+            &mut HashMap::new(),
+        );
+        let stop = select(
+            &txs,
+            height,
+            2500,
+            PackingStrategy::Stop,
+            &mut HashMap::new(),
+        );
+
+        assert_eq!(
+            skip,
+            vec![
+                TxGas {
+                    tip: 5,
+                    limit: 1000
+                },
+                TxGas {
+                    tip: 3,
+                    limit: 1000
+                }
+            ]
+        );
+        assert_eq!(
+            stop,
+            vec![TxGas {
+                tip: 5,
+                limit: 1000
+            }]
+        );
+    }
+
+    #[test]
+    fn skip_strategy_forces_tx_skipped_max_times_into_the_next_block() {
+        let strategy = PackingStrategy::Skip { max_skips: 2 };
+        let large_tx = TxGas {
+            tip: 1,
+            limit: 3000,
+        };
+        let small_tx = TxGas {
+            tip: 5,
+            limit: 1000,
+        };
+        let large = make_txs(&[large_tx]);
+        let mut skipped_txs = HashMap::new();
+
+        // The large tx is skipped in favor of the small ones `max_skips` times.
+        for height in 1..=2u32 {
+            let mut txs = make_txs(&[small_tx, small_tx, small_tx]);
+            txs.extend(large.iter().cloned());
+            let selected = select(&txs, height.into(), 3000, strategy, &mut skipped_txs);
+            assert_eq!(selected, vec![small_tx, small_tx, small_tx]);
+        }
+
+        // The next block contains the large tx despite the small ones.
+        let mut txs = make_txs(&[small_tx, small_tx, small_tx]);
+        txs.extend(large.iter().cloned());
+        let selected = select(&txs, 3u32.into(), 3000, strategy, &mut skipped_txs);
+        assert_eq!(selected, vec![large_tx]);
+        assert!(!skipped_txs.contains_key(&large[0].id()));
+    }
+
+    #[test]
+    fn skip_strategy_counts_the_skip_once_per_block() {
+        let strategy = PackingStrategy::Skip { max_skips: 2 };
+        let large_tx = TxGas {
+            tip: 1,
+            limit: 3000,
+        };
+        let small_tx = TxGas {
+            tip: 5,
+            limit: 1000,
+        };
+        let mut txs = make_txs(&[small_tx]);
+        txs.extend(make_txs(&[large_tx]));
+        let large_tx_id = txs[1].id();
+        let mut skipped_txs = HashMap::new();
+        let height = 1u32.into();
+
+        // The executor requests transactions several times for the same block.
+        select(&txs, height, 3000, strategy, &mut skipped_txs);
+        select(&txs[1..], height, 2000, strategy, &mut skipped_txs);
+
+        assert_eq!(
+            skipped_txs.get(&large_tx_id),
+            Some(&Skips {
+                count: 1,
+                last_height: Some(height),
+            })
+        );
+    }
 }
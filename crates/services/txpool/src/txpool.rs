@@ -6,6 +6,7 @@ use crate::{
     },
     ports::TxPoolDb,
     service::TxStatusChange,
+    transaction_selector::Skips,
     types::*,
     Config,
     Error,
@@ -13,7 +14,10 @@ use crate::{
 };
 use fuel_core_types::{
     fuel_tx::Transaction,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
     fuel_vm::{
         checked_transaction::{
             CheckPredicates,
@@ -27,6 +31,7 @@ use fuel_core_types::{
     },
     services::txpool::{
         ArcPoolTx,
+        InsertionOutcome,
         InsertionResult,
     },
     tai64::Tai64,
@@ -36,6 +41,7 @@ use fuel_core_metrics::txpool_metrics::txpool_metrics;
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
     fuel_tx::{
+        field::Inputs,
         input::{
             coin::{
                 CoinPredicate,
@@ -62,16 +68,29 @@ use std::{
 use tokio_rayon::AsyncRayonHandle;
 
 #[cfg(test)]
-mod test_helpers;
+pub(crate) mod test_helpers;
 #[cfg(test)]
 mod tests;
 
+/// The transaction spending a message that is not relayed yet.
+#[derive(Debug, Clone)]
+struct PendingOnRelayer {
+    tx: ArcPoolTx,
+    fully_checked: bool,
+    /// The transaction is dropped if the message is not relayed until this moment.
+    deadline: tokio::time::Instant,
+}
+
 #[derive(Debug, Clone)]
 pub struct TxPool<ViewProvider> {
     by_hash: HashMap<TxId, TxInfo>,
     by_tip: TipSort,
     by_time: TimeSort,
     by_dependency: Dependency,
+    /// The number of times each transaction was skipped during the block production.
+    pub(crate) skipped_txs: HashMap<TxId, Skips>,
+    /// The transactions waiting for the relayer to import the messages they spend.
+    pending_on_relayer: HashMap<TxId, PendingOnRelayer>,
     config: Config,
     database: ViewProvider,
 }
@@ -96,7 +115,13 @@ impl<ViewProvider> TxPool<ViewProvider> {
             by_hash: HashMap::new(),
             by_tip: TipSort::default(),
             by_time: TimeSort::default(),
-            by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            by_dependency: Dependency::new(
+                max_depth,
+                config.utxo_validation,
+                config.min_replacement_tip_bump,
+            ),
+            skipped_txs: HashMap::new(),
+            pending_on_relayer: HashMap::new(),
             config,
             database,
         }
@@ -149,6 +174,9 @@ impl<ViewProvider> TxPool<ViewProvider> {
         if let Some(info) = &info {
             self.by_time.remove(info);
             self.by_tip.remove(info);
+            if let Some(validation_cache) = &self.config.validation_cache {
+                validation_cache.remove(tx_id);
+            }
         }
 
         info
@@ -176,6 +204,38 @@ impl<ViewProvider> TxPool<ViewProvider> {
         self.txs().get(hash).cloned()
     }
 
+    /// Returns the depth of the transaction in the chain of dependent transactions
+    /// inside of the pool, or `None` if the pool doesn't contain the transaction.
+    pub fn dependency_depth(&self, hash: &TxId) -> Option<usize> {
+        let tx = self.txs().get(hash)?;
+        Some(self.by_dependency.depth(self.txs(), tx.tx()))
+    }
+
+    /// Returns the minimal tip required for the admission of a new transaction
+    /// when the pool is full, or `None` if the pool has free space.
+    pub fn min_admission_tip(&self) -> Option<Word> {
+        if self.by_hash.len() >= self.config.max_tx {
+            let lowest_tip = self.by_tip.lowest_value().unwrap_or_default();
+            Some(lowest_tip.saturating_add(1))
+        } else {
+            None
+        }
+    }
+
+    /// Counts the transactions of the pool per tip bucket. The ascending `bounds` split
+    /// the tips into `bounds.len() + 1` buckets, the bucket `i` contains the
+    /// transactions with the tip in `[bounds[i - 1], bounds[i])`.
+    pub fn tip_histogram(&self, bounds: &[Word]) -> Vec<usize> {
+        let mut counts = vec![0usize; bounds.len().saturating_add(1)];
+        for key in self.by_tip.sort.keys() {
+            let bucket = bounds.partition_point(|bound| bound <= key.value());
+            if let Some(count) = counts.get_mut(bucket) {
+                *count = count.saturating_add(1);
+            }
+        }
+        counts
+    }
+
     /// find all dependent tx and return them with requested dependencies in one list sorted by Price.
     pub fn find_dependent(&self, hashes: &[TxId]) -> Vec<ArcPoolTx> {
         let mut seen = HashMap::new();
@@ -202,6 +262,25 @@ impl<ViewProvider> TxPool<ViewProvider> {
         self.by_hash.len()
     }
 
+    /// The serialized size in bytes of all pending transactions combined,
+    /// including the witnesses.
+    pub fn pending_bytes(&self) -> usize {
+        self.by_hash
+            .values()
+            .map(|tx| tx.size())
+            .fold(0usize, usize::saturating_add)
+    }
+
+    /// Returns all pending transactions in the order of insertion, so
+    /// the transactions always follow the transactions they depend on.
+    pub fn pending_transactions(&self) -> Vec<Transaction> {
+        let mut txs = self.by_hash.values().collect::<Vec<_>>();
+        txs.sort_by_key(|info| info.created());
+        txs.into_iter()
+            .map(|info| info.tx().as_ref().into())
+            .collect()
+    }
+
     /// The amount of gas in all includable transactions combined
     pub fn consumable_gas(&self) -> u64 {
         self.by_hash.values().map(|tx| tx.max_gas()).sum()
@@ -222,6 +301,7 @@ impl<ViewProvider> TxPool<ViewProvider> {
         for status in tx_status {
             let tx_id = status.id;
             self.remove_committed_tx(&tx_id);
+            self.pending_on_relayer.remove(&tx_id);
         }
     }
 
@@ -341,6 +421,23 @@ where
     ViewProvider: AtomicView<View = View>,
     View: TxPoolDb,
 {
+    fn check_contracts_deployed(tx: &PoolTransaction, view: &View) -> Result<(), Error> {
+        for input in tx.inputs() {
+            if let Input::Contract(contract) = input {
+                if !view
+                    .contract_exist(&contract.contract_id)
+                    .map_err(|e| Error::Database(format!("{:?}", e)))?
+                {
+                    return Err(Error::NotInsertedInputContractNotDeployed(
+                        contract.contract_id,
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     #[cfg(test)]
     fn insert_single(
         &mut self,
@@ -357,16 +454,111 @@ where
         tx: Checked<Transaction>,
         view: &View,
     ) -> Result<InsertionResult, Error> {
-        let tx: CheckedTransaction = tx.into();
+        let (tx, fully_checked) = into_pool_tx(tx)?;
+        self.insert_pool_tx(tx, fully_checked, view)
+    }
+
+    #[tracing::instrument(level = "debug", skip_all, fields(tx_id = %tx.id()), ret, err)]
+    // The same as `insert_inner`, but defers the transactions spending unknown messages.
+    fn insert_or_defer_inner(
+        &mut self,
+        tx: Checked<Transaction>,
+        view: &View,
+    ) -> Result<InsertionOutcome, Error> {
+        let (tx, fully_checked) = into_pool_tx(tx)?;
+
+        match self.insert_pool_tx(tx.clone(), fully_checked, view) {
+            Ok(result) => Ok(InsertionOutcome::Inserted(result)),
+            Err(Error::NotInsertedInputMessageUnknown(nonce)) => {
+                self.defer_until_relayed(tx, fully_checked, nonce)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Holds the transaction spending the unknown message until the relayer imports it,
+    /// if it is allowed by the config.
+    fn defer_until_relayed(
+        &mut self,
+        tx: ArcPoolTx,
+        fully_checked: bool,
+        nonce: Nonce,
+    ) -> Result<InsertionOutcome, Error> {
+        let deadline = self
+            .config
+            .unrelayed_message_hold_time
+            .and_then(|hold_time| tokio::time::Instant::now().checked_add(hold_time));
+        let Some(deadline) = deadline else {
+            return Err(Error::NotInsertedInputMessageUnknown(nonce))
+        };
+        if self.pending_on_relayer.len() >= self.config.max_tx {
+            return Err(Error::NotInsertedInputMessageUnknown(nonce))
+        }
+        if self.pending_on_relayer.contains_key(&tx.id()) {
+            return Err(Error::NotInsertedTxKnown)
+        }
+
+        tracing::debug!(
+            "Transaction {} is deferred until the message {nonce:#x} is relayed",
+            tx.id()
+        );
+        self.pending_on_relayer.insert(
+            tx.id(),
+            PendingOnRelayer {
+                tx: tx.clone(),
+                fully_checked,
+                deadline,
+            },
+        );
+        Ok(InsertionOutcome::Deferred(tx))
+    }
 
-        let tx = Arc::new(match tx {
-            CheckedTransaction::Script(script) => PoolTransaction::Script(script),
-            CheckedTransaction::Create(create) => PoolTransaction::Create(create),
-            CheckedTransaction::Mint(_) => return Err(Error::MintIsDisallowed),
-        });
+    /// Inserts the transactions waiting for the relayer if their messages are imported,
+    /// and returns the inserted transactions. The transactions still waiting after
+    /// the deadline, or not valid anymore, are squeezed out.
+    pub fn insert_pending_on_relayer(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+    ) -> Vec<ArcPoolTx> {
+        if self.pending_on_relayer.is_empty() {
+            return vec![]
+        }
+        let now = tokio::time::Instant::now();
+        let view = self.database.latest_view();
+        let pending_on_relayer = core::mem::take(&mut self.pending_on_relayer);
+
+        let mut inserted = vec![];
+        for (id, pending) in pending_on_relayer {
+            match self.insert_pool_tx(pending.tx.clone(), pending.fully_checked, &view) {
+                Ok(result) => {
+                    announce_insertion(tx_status_sender, &result);
+                    inserted.push(result.inserted);
+                }
+                Err(Error::NotInsertedInputMessageUnknown(_))
+                    if now < pending.deadline =>
+                {
+                    self.pending_on_relayer.insert(id, pending);
+                }
+                Err(err) => {
+                    tx_status_sender.send_squeezed_out(id, err);
+                }
+            }
+        }
+        inserted
+    }
 
+    fn insert_pool_tx(
+        &mut self,
+        tx: ArcPoolTx,
+        fully_checked: bool,
+        view: &View,
+    ) -> Result<InsertionResult, Error> {
         self.check_blacklisting(tx.as_ref())?;
 
+        if self.config.reject_unknown_contracts {
+            Self::check_contracts_deployed(tx.as_ref(), view)?;
+        }
+
         if !tx.is_computed() {
             return Err(Error::NoMetadata)
         }
@@ -410,7 +602,14 @@ where
         self.by_tip.insert(&info);
         self.by_time.insert(&info);
         self.by_hash.insert(tx.id(), info);
+        if fully_checked {
+            if let Some(validation_cache) = &self.config.validation_cache {
+                validation_cache.insert(tx.clone());
+            }
+        }
 
+        // the transactions colliding with the inserted one are replaced by it
+        let replaced = rem.iter().map(|tx| tx.id()).collect();
         // if some transaction were removed so we don't need to check limit
         let removed = if rem.is_empty() {
             if max_limit_hit {
@@ -434,11 +633,12 @@ where
             inserted: tx,
             submitted_time,
             removed,
+            replaced,
         })
     }
 
     #[tracing::instrument(level = "info", skip_all)]
-    /// Import a set of transactions from network gossip or GraphQL endpoints.
+    /// Import a set of transactions from network gossip.
     pub fn insert(
         &mut self,
         tx_status_sender: &TxStatusChange,
@@ -454,32 +654,63 @@ where
         }
 
         // announce to subscribers
+        for result in res.iter().flatten() {
+            announce_insertion(tx_status_sender, result);
+        }
+        res
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    /// Import a set of local transactions submitted via GraphQL endpoints.
+    /// Unlike [`Self::insert`], the transactions spending the messages that are
+    /// not relayed yet are deferred if it is allowed by the config.
+    pub fn insert_local(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        txs: Vec<Checked<Transaction>>,
+    ) -> Vec<Result<InsertionOutcome, Error>> {
+        let mut res = Vec::new();
+        let view = self.database.latest_view();
+
+        for tx in txs.into_iter() {
+            res.push(self.insert_or_defer_inner(tx, &view));
+        }
+
+        // The deferred transactions are announced when they are inserted.
         for ret in res.iter() {
-            match ret {
-                Ok(InsertionResult {
-                    removed,
-                    inserted,
-                    submitted_time,
-                }) => {
-                    for removed in removed {
-                        // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
-                        // but for now it is okay to just use Error::Removed.
-                        tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
-                    }
-                    tx_status_sender.send_submitted(
-                        inserted.id(),
-                        Tai64::from_unix(submitted_time.as_secs() as i64),
-                    );
-                }
-                Err(_) => {
-                    // @dev should not broadcast tx if error occurred
-                }
+            if let Ok(InsertionOutcome::Inserted(result)) = ret {
+                announce_insertion(tx_status_sender, result);
             }
         }
         res
     }
 }
 
+fn into_pool_tx(tx: Checked<Transaction>) -> Result<(ArcPoolTx, bool), Error> {
+    let fully_checked = tx.checks().contains(Checks::all());
+    let tx: CheckedTransaction = tx.into();
+
+    let tx = Arc::new(match tx {
+        CheckedTransaction::Script(script) => PoolTransaction::Script(script),
+        CheckedTransaction::Create(create) => PoolTransaction::Create(create),
+        CheckedTransaction::Mint(_) => return Err(Error::MintIsDisallowed),
+    });
+    Ok((tx, fully_checked))
+}
+
+/// Announces the inserted transaction and the transactions removed by it to subscribers.
+fn announce_insertion(tx_status_sender: &TxStatusChange, result: &InsertionResult) {
+    for removed in &result.removed {
+        // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
+        // but for now it is okay to just use Error::Removed.
+        tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+    }
+    tx_status_sender.send_submitted(
+        result.inserted.id(),
+        Tai64::from_unix(result.submitted_time.as_secs() as i64),
+    );
+}
+
 pub async fn check_transactions<Provider>(
     txs: &[Arc<Transaction>],
     current_height: BlockHeight,
@@ -519,6 +750,9 @@ pub async fn check_single_tx<GasPrice: GasPriceProvider>(
     let tx: Checked<Transaction> = if config.utxo_validation {
         let consensus_params = &config.chain_config.consensus_parameters;
 
+        // Reject expensive predicates before spending resources on their execution.
+        verify_predicates(&tx, config)?;
+
         let tx = tx
             .into_checked_basic(current_height, consensus_params)?
             .check_signatures(&consensus_params.chain_id())?;
@@ -545,6 +779,50 @@ pub async fn check_single_tx<GasPrice: GasPriceProvider>(
     Ok(tx)
 }
 
+fn verify_predicates(tx: &Transaction, config: &Config) -> Result<(), Error> {
+    let inputs = match tx {
+        Transaction::Script(script) => script.inputs(),
+        Transaction::Create(create) => create.inputs(),
+        Transaction::Mint(_) => return Err(Error::MintIsDisallowed),
+    };
+
+    let max_predicate_inputs = config.max_predicate_inputs();
+    let predicate_inputs = inputs.iter().filter_map(Input::predicate_gas_used).count();
+    if predicate_inputs > max_predicate_inputs {
+        return Err(Error::NotInsertedMaxPredicateInputs {
+            predicate_inputs,
+            limit: max_predicate_inputs,
+        })
+    }
+
+    let max_gas_per_predicate = config.max_gas_per_predicate();
+    let mut total_predicate_gas: Word = 0;
+    for predicate_gas in inputs.iter().filter_map(Input::predicate_gas_used) {
+        if predicate_gas > max_gas_per_predicate {
+            return Err(Error::NotInsertedMaxPredicateGas {
+                predicate_gas,
+                limit: max_gas_per_predicate,
+            })
+        }
+        total_predicate_gas = total_predicate_gas.saturating_add(predicate_gas);
+    }
+
+    let max_predicate_gas_per_tx = config.max_predicate_gas_per_tx();
+    if total_predicate_gas > max_predicate_gas_per_tx {
+        return Err(Error::NotInsertedMaxTxPredicateGas {
+            predicate_gas: total_predicate_gas,
+            limit: max_predicate_gas_per_tx,
+        })
+    }
+
+    Ok(())
+}
+
+/// Verifies that the transaction covers the fee at the current gas price.
+///
+/// The fee is always paid in the base asset: the balance checks of the transaction
+/// are part of the consensus rules of the VM, so the `TxPool` can't accept fees
+/// in other assets without a change of the state transition function.
 fn verify_tx_min_gas_price(
     tx: Checked<Transaction>,
     config: &Config,
@@ -37,6 +37,10 @@ use fuel_core_types::{
         CheckError,
         Checked,
     },
+    services::txpool::{
+        InsertionResult,
+        ValidationCache,
+    },
 };
 
 use crate::types::GasPrice;
@@ -50,6 +54,7 @@ use fuel_core_types::fuel_tx::{
 use std::{
     cmp::Reverse,
     collections::HashMap,
+    time::Duration,
     vec,
 };
 
@@ -108,6 +113,38 @@ async fn insert_simple_tx_succeeds() {
         .expect("Transaction should be OK, got Err");
 }
 
+#[tokio::test]
+async fn validation_cache_contains_only_transactions_of_the_pool() {
+    let mut context = TextContext::default();
+
+    let (_, gas_coin) = context.setup_coin();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let validation_cache = ValidationCache::default();
+    txpool.config_mut().validation_cache = Some(validation_cache.clone());
+    let tx = check_unwrap_tx(tx, &txpool.config).await;
+    let tx_id = tx.id();
+    let transaction = tx.transaction().clone();
+
+    // When
+    txpool
+        .insert_single(tx)
+        .expect("Transaction should be OK, got Err");
+
+    // Then
+    assert!(validation_cache.get(&tx_id, &transaction).is_some());
+
+    // When
+    txpool.remove_committed_tx(&tx_id);
+
+    // Then
+    assert!(validation_cache.is_empty());
+}
+
 #[tokio::test]
 async fn insert_simple_tx_with_blacklisted_utxo_id_fails() {
     let mut context = TextContext::default();
@@ -448,6 +485,64 @@ async fn higher_priced_tx_removes_lower_priced_tx() {
     assert_eq!(vec.removed[0].id(), tx1_id, "Tx1 id should be removed");
 }
 
+async fn replacement_of_tx_with_tip_10(
+    replacement_tip: u64,
+) -> Result<InsertionResult, Error> {
+    let mut context = TextContext::default().config(Config {
+        min_replacement_tip_bump: 5,
+        ..Default::default()
+    });
+    let (_, coin_input) = context.setup_coin();
+    let original = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin_input.clone())
+        .finalize_as_transaction();
+    let replacement = TransactionBuilder::script(vec![], vec![])
+        .tip(replacement_tip)
+        .max_fee_limit(replacement_tip)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin_input)
+        .finalize_as_transaction();
+    let mut txpool = context.build();
+    let original = check_unwrap_tx(original, &txpool.config).await;
+    txpool
+        .insert_single(original)
+        .expect("The original tx should be inserted");
+
+    let replacement = check_unwrap_tx(replacement, &txpool.config).await;
+    txpool.insert_single(replacement)
+}
+
+#[tokio::test]
+async fn replacement_with_enough_tip_bump_evicts_original_tx() {
+    // When
+    let result = replacement_of_tx_with_tip_10(15).await;
+
+    // Then
+    let result = result.expect("The replacement should be inserted");
+    assert_eq!(result.removed.len(), 1);
+    assert_eq!(result.replaced, vec![result.removed[0].id()]);
+}
+
+#[tokio::test]
+async fn replacement_with_insufficient_tip_bump_is_rejected() {
+    // When
+    let result = replacement_of_tx_with_tip_10(14).await;
+
+    // Then
+    let err = result.expect_err("The replacement should be rejected");
+    assert!(matches!(
+        err,
+        Error::NotInsertedReplacementUnderpriced {
+            tip: 14,
+            required_tip: 15,
+            ..
+        }
+    ));
+}
+
 #[tokio::test]
 async fn underpriced_tx1_not_included_coin_collision() {
     let mut context = TextContext::default();
@@ -591,6 +686,92 @@ async fn dependent_contract_input_inserted() {
         .expect("Tx2 should be Ok, got Err");
 }
 
+#[tokio::test]
+async fn tx_with_unknown_contract_rejected_when_check_is_enabled() {
+    let mut context = TextContext::default().config(Config {
+        reject_unknown_contracts: true,
+        ..Default::default()
+    });
+    let contract_id = Contract::EMPTY_CONTRACT_ID;
+
+    // Given
+    let (_, gas_coin) = context.setup_coin();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_input(create_contract_input(
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction();
+    let mut txpool = context.build();
+    let tx = check_unwrap_tx(tx, &txpool.config).await;
+
+    // When
+    let result = txpool.insert_single(tx);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::NotInsertedInputContractNotDeployed(id)) if id == contract_id
+    ));
+}
+
+#[tokio::test]
+async fn dependent_contract_input_rejected_when_check_is_enabled() {
+    let mut context = TextContext::default().config(Config {
+        reject_unknown_contracts: true,
+        ..Default::default()
+    });
+
+    let contract_id = Contract::EMPTY_CONTRACT_ID;
+    let (_, gas_funds) = context.setup_coin();
+    let tx1 = TransactionBuilder::create(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .tip(10)
+    .max_fee_limit(10)
+    .add_input(gas_funds)
+    .add_output(create_contract_output(contract_id))
+    .finalize_as_transaction();
+
+    let (_, gas_funds) = context.setup_coin();
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_funds)
+        .add_input(create_contract_input(
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+
+    // Given
+    txpool
+        .insert_single(tx1)
+        .expect("Tx1 should be Ok, got Err");
+
+    // When
+    let result = txpool.insert_single(tx2);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::NotInsertedInputContractNotDeployed(id)) if id == contract_id
+    ));
+}
+
 #[tokio::test]
 async fn more_priced_tx3_removes_tx1_and_dependent_tx2() {
     let mut context = TextContext::default();
@@ -729,6 +910,30 @@ async fn tx_limit_hit() {
     assert!(matches!(err, Error::NotInsertedLimitHit));
 }
 
+#[tokio::test]
+async fn min_admission_tip_is_above_lowest_tip_when_limit_hit() {
+    let mut context = TextContext::default().config(Config {
+        max_tx: 1,
+        ..Default::default()
+    });
+
+    let (_, gas_coin) = context.setup_coin();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let tx = check_unwrap_tx(tx, &txpool.config).await;
+    assert_eq!(txpool.min_admission_tip(), None);
+
+    txpool.insert_single(tx).expect("Tx should be Ok, got Err");
+
+    assert_eq!(txpool.min_admission_tip(), Some(11));
+}
+
 #[tokio::test]
 async fn tx_depth_hit() {
     let mut context = TextContext::default().config(Config {
@@ -773,7 +978,104 @@ async fn tx_depth_hit() {
     let err = txpool
         .insert_single(tx3)
         .expect_err("Tx3 should be Err, got Ok");
-    assert!(matches!(err, Error::NotInsertedMaxDepth));
+    assert!(matches!(
+        err,
+        Error::NotInsertedMaxDepth {
+            depth: 3,
+            max_depth: 2
+        }
+    ));
+}
+
+#[tokio::test]
+async fn tx_depth_at_limit_is_accepted() {
+    let mut context = TextContext::default().config(Config {
+        max_depth: 2,
+        ..Default::default()
+    });
+
+    let (_, gas_coin) = context.setup_coin();
+    let (output, unset_input) = context.create_output_and_input(10_000);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&Default::default());
+    let tx2_id = tx2.id(&Default::default());
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+
+    txpool
+        .insert_single(tx1)
+        .expect("Tx1 should be OK, got Err");
+    txpool
+        .insert_single(tx2)
+        .expect("Tx2 should be OK, got Err");
+
+    assert_eq!(txpool.dependency_depth(&tx1_id), Some(1));
+    assert_eq!(txpool.dependency_depth(&tx2_id), Some(2));
+}
+
+#[tokio::test]
+async fn tx_depth_hit_with_contract_created_in_pool() {
+    let mut context = TextContext::default().config(Config {
+        max_depth: 1,
+        ..Default::default()
+    });
+
+    let contract_id = Contract::EMPTY_CONTRACT_ID;
+    let (_, gas_funds) = context.setup_coin();
+    let tx1 = TransactionBuilder::create(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .tip(10)
+    .max_fee_limit(10)
+    .add_input(gas_funds)
+    .add_output(create_contract_output(contract_id))
+    .finalize_as_transaction();
+
+    let (_, gas_funds) = context.setup_coin();
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_funds)
+        .add_input(create_contract_input(
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    txpool
+        .insert_single(tx1)
+        .expect("Tx1 should be Ok, got Err");
+
+    let err = txpool
+        .insert_single(tx2)
+        .expect_err("Tx2 should be Err, got Ok");
+    assert!(matches!(
+        err,
+        Error::NotInsertedMaxDepth {
+            depth: 2,
+            max_depth: 1
+        }
+    ));
 }
 
 #[tokio::test]
@@ -1013,6 +1315,30 @@ async fn tx_rejected_from_pool_when_input_message_id_does_not_exist_in_db() {
     ));
 }
 
+#[tokio::test]
+async fn tx_from_gossip_spending_unknown_message_is_not_deferred() {
+    let context = TextContext::default().config(Config {
+        unrelayed_message_hold_time: Some(Duration::from_secs(60)),
+        ..Default::default()
+    });
+    let (message, input) = create_message_predicate_from_message(5000, 0);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    // The deferral is allowed only for the local transactions.
+    let mut txpool = context.build();
+    let tx = check_unwrap_tx(tx, &txpool.config).await;
+    let err = txpool.insert_single(tx).expect_err("should fail");
+
+    // check error
+    assert!(matches!(
+        err,
+        Error::NotInsertedInputMessageUnknown(msg_id) if msg_id == *message.id()
+    ));
+}
+
 #[tokio::test]
 async fn tx_rejected_from_pool_when_gas_price_is_lower_than_another_tx_with_same_message_id(
 ) {
@@ -1256,3 +1582,95 @@ async fn predicate_that_returns_false_is_invalid() {
         "unexpected error: {err}",
     )
 }
+
+#[tokio::test]
+async fn predicate_exceeding_configured_gas_limit_is_rejected() {
+    let mut context = TextContext::default();
+
+    // Given
+    let coin = context.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
+    let predicate_gas = coin.predicate_gas_used().unwrap();
+    let limit = predicate_gas.saturating_sub(1);
+    let config = Config {
+        max_gas_per_predicate: Some(limit),
+        ..Default::default()
+    };
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin)
+        .finalize_as_transaction();
+
+    // When
+    let result = check_tx(tx, &config).await;
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::NotInsertedMaxPredicateGas { predicate_gas: gas, limit: max })
+            if gas == predicate_gas && max == limit
+    ));
+}
+
+#[tokio::test]
+async fn predicates_exceeding_configured_gas_limit_per_tx_are_rejected() {
+    let mut context = TextContext::default();
+
+    // Given
+    let coin_1 = context.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
+    let coin_2 = context.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
+    let predicate_gas = coin_1
+        .predicate_gas_used()
+        .unwrap()
+        .saturating_add(coin_2.predicate_gas_used().unwrap());
+    let limit = predicate_gas.saturating_sub(1);
+    let config = Config {
+        max_predicate_gas_per_tx: Some(limit),
+        ..Default::default()
+    };
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin_1)
+        .add_input(coin_2)
+        .finalize_as_transaction();
+
+    // When
+    let result = check_tx(tx, &config).await;
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::NotInsertedMaxTxPredicateGas { predicate_gas: gas, limit: max })
+            if gas == predicate_gas && max == limit
+    ));
+}
+
+#[tokio::test]
+async fn transaction_exceeding_configured_predicate_inputs_limit_is_rejected() {
+    let mut context = TextContext::default();
+
+    // Given
+    let limit = 2;
+    let config = Config {
+        max_predicate_inputs: Some(limit),
+        ..Default::default()
+    };
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.script_gas_limit(GAS_LIMIT);
+    for _ in 0..=limit {
+        let coin = context.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
+        builder.add_input(coin);
+    }
+    let tx = builder.finalize_as_transaction();
+
+    // When
+    let result = check_tx(tx, &config).await;
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::NotInsertedMaxPredicateInputs {
+            predicate_inputs: 3,
+            limit: 2
+        })
+    ));
+}
@@ -1,4 +1,9 @@
 use fuel_core_executor::executor::ExecutionOptions;
+use fuel_core_types::services::txpool::ValidationCache;
+use std::{
+    num::NonZeroUsize,
+    time::Duration,
+};
 
 #[derive(Clone, Debug, Default)]
 pub struct Config {
@@ -6,6 +11,34 @@ pub struct Config {
     pub backtrace: bool,
     /// Default mode for utxo_validation
     pub utxo_validation_default: bool,
+    /// The number of workers used to validate independent transactions of the block
+    /// in parallel. If `None`, transactions are executed sequentially.
+    /// It is only used by the native executor.
+    pub parallel_execution_workers: Option<NonZeroUsize>,
+    /// Overrides the maximum depth of nested contract calls.
+    /// It is only used by the native executor.
+    pub max_call_depth: Option<u64>,
+    /// Overrides the maximum number of inputs of the transaction.
+    /// It is only used by the native executor.
+    pub max_inputs: Option<u16>,
+    /// Overrides the maximum number of outputs of the transaction.
+    /// It is only used by the native executor.
+    pub max_outputs: Option<u16>,
+    /// The maximum wall-clock time of the execution of the transaction.
+    /// It is only used by the native executor.
+    pub max_tx_execution_time: Option<Duration>,
+    /// The maximum bytes of the VM memory occupied by the transaction.
+    /// It is only used by the native executor.
+    pub max_tx_memory: Option<u64>,
+    /// The transactions checked at the admission into the `TxPool`, which the executor
+    /// doesn't check again. It is only used by the native executor.
+    pub validation_cache: Option<ValidationCache>,
+    /// Records the storage keys accessed by each transaction during the dry run.
+    /// It is only used by the native executor.
+    pub record_storage_access: bool,
+    /// Records the gas consumed by each opcode of the script during the dry run.
+    /// It is only used by the native executor.
+    pub record_gas_breakdown: bool,
 }
 
 impl From<&Config> for ExecutionOptions {
@@ -13,6 +46,15 @@ impl From<&Config> for ExecutionOptions {
         Self {
             utxo_validation: value.utxo_validation_default,
             backtrace: value.backtrace,
+            parallel_execution_workers: value.parallel_execution_workers,
+            max_call_depth: value.max_call_depth,
+            max_inputs: value.max_inputs,
+            max_outputs: value.max_outputs,
+            max_tx_execution_time: value.max_tx_execution_time,
+            max_tx_memory: value.max_tx_memory,
+            validation_cache: value.validation_cache.clone(),
+            record_storage_access: value.record_storage_access,
+            record_gas_breakdown: value.record_gas_breakdown,
         }
     }
 }
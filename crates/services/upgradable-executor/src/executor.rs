@@ -190,6 +190,15 @@ where
         let options = ExecutionOptions {
             utxo_validation,
             backtrace: self.config.backtrace,
+            parallel_execution_workers: self.config.parallel_execution_workers,
+            max_call_depth: self.config.max_call_depth,
+            max_inputs: self.config.max_inputs,
+            max_outputs: self.config.max_outputs,
+            max_tx_execution_time: self.config.max_tx_execution_time,
+            max_tx_memory: self.config.max_tx_memory,
+            validation_cache: self.config.validation_cache.clone(),
+            record_storage_access: self.config.record_storage_access,
+            record_gas_breakdown: self.config.record_gas_breakdown,
         };
 
         let component = Components {
@@ -65,6 +65,9 @@ pub enum Column {
     // TODO: Remove this column and use `Metadata` column instead.
     /// Table for genesis state import progress tracking.
     GenesisMetadata = 20,
+    /// The contract bytecode by its hash. It is used only if the deduplication
+    /// of the bytecode is enabled, and the `ContractsRawCode` keeps the hashes.
+    ContractsBytecode = 21,
 }
 
 impl Column {
@@ -2,6 +2,7 @@
 
 use crate::{
     blockchain::primitives::BlockId,
+    fuel_crypto::PublicKey,
     fuel_tx::Input,
     fuel_types::{
         Address,
@@ -29,15 +30,24 @@ pub enum Consensus {
 impl Consensus {
     /// Retrieve the block producer address from the consensus data
     pub fn block_producer(&self, block_id: &BlockId) -> anyhow::Result<Address> {
+        let address = self
+            .block_signer(block_id)?
+            .map(|public_key| Input::owner(&public_key))
+            .unwrap_or_else(Address::zeroed);
+        Ok(address)
+    }
+
+    /// Recover the public key that signed the block from the consensus data.
+    /// The genesis block is not signed, so `None` is returned for it.
+    pub fn block_signer(&self, block_id: &BlockId) -> anyhow::Result<Option<PublicKey>> {
         match &self {
-            Consensus::Genesis(_) => Ok(Address::zeroed()),
+            Consensus::Genesis(_) => Ok(None),
             Consensus::PoA(poa_data) => {
                 let public_key = poa_data
                     .signature
                     .recover(block_id.as_message())
                     .map_err(|e| anyhow::anyhow!("Can't recover public key: {:?}", e))?;
-                let address = Input::owner(&public_key);
-                Ok(address)
+                Ok(Some(public_key))
             }
         }
     }
@@ -74,6 +74,45 @@ pub struct TransactionExecutionStatus {
     pub id: Bytes32,
     /// The result of the executed transaction.
     pub result: TransactionExecutionResult,
+    /// The storage keys accessed by the transaction. It is only recorded
+    /// during the dry run if the executor is configured to do so.
+    ///
+    /// It is recorded only by the native executor and is not a part of
+    /// the serialized status, so the results of the WASM executor can be decoded.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub storage_access: Option<StorageAccess>,
+    /// The gas consumed by each opcode of the script, ordered by the consumed gas.
+    /// It is only recorded during the dry run if the executor is configured to do so.
+    ///
+    /// It is recorded only by the native executor and is not a part of
+    /// the serialized status, so the results of the WASM executor can be decoded.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub gas_breakdown: Option<Vec<OpcodeGas>>,
+}
+
+/// The gas consumed by the executions of an opcode of the script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpcodeGas {
+    /// The name of the opcode, like `CALL`.
+    pub opcode: String,
+    /// The number of the executions of the opcode.
+    pub count: u64,
+    /// The gas consumed by all executions of the opcode.
+    pub gas: u64,
+}
+
+/// The storage keys accessed by the transaction during the execution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StorageAccess {
+    /// The `(column, key)` pairs read from the storage, ordered by the column and the key.
+    /// It includes the keys whose previous values are loaded by the writes, but not
+    /// the keys read after the transaction has written them.
+    pub reads: Vec<(u32, Vec<u8>)>,
+    /// The `(column, key)` pairs inserted or removed by the transaction,
+    /// ordered by the column and the key.
+    pub writes: Vec<(u32, Vec<u8>)>,
 }
 
 /// The result of transaction execution.
@@ -361,6 +400,32 @@ pub enum Error {
     /// It is possible to occur untyped errors in the case of the upgrade.
     #[display(fmt = "Occurred untyped error: {_0}")]
     Other(String),
+    // The variants below are appended after `Other` to not change the encoding
+    // of the errors returned by the already deployed WASM executor.
+    #[display(
+        fmt = "Transaction({transaction_id:#x}) reached the call depth {depth} exceeding the max call depth {max_call_depth}"
+    )]
+    CallDepthExceeded {
+        transaction_id: Bytes32,
+        depth: u64,
+        max_call_depth: u64,
+    },
+    #[display(
+        fmt = "Transaction({transaction_id:#x}) has {inputs} inputs exceeding the max inputs {max_inputs}"
+    )]
+    TooManyInputs {
+        transaction_id: Bytes32,
+        inputs: usize,
+        max_inputs: u16,
+    },
+    #[display(
+        fmt = "Transaction({transaction_id:#x}) has {outputs} outputs exceeding the max outputs {max_outputs}"
+    )]
+    TooManyOutputs {
+        transaction_id: Bytes32,
+        outputs: usize,
+        max_outputs: u16,
+    },
 }
 
 impl From<Error> for anyhow::Error {
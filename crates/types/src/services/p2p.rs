@@ -120,6 +120,32 @@ pub struct BlockHeightHeartbeatData {
     pub block_height: BlockHeight,
 }
 
+/// The reason why the connection with the peer was closed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeerDisconnectReason {
+    /// The connection was closed gracefully by one of the sides.
+    Closed,
+    /// The connection was closed because of the error.
+    Error(String),
+}
+
+/// Events about the connections with other peers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeerConnectionEvent {
+    /// The first connection with the peer was established.
+    PeerConnected {
+        /// The connected peer.
+        peer_id: PeerId,
+    },
+    /// The last connection with the peer was closed.
+    PeerDisconnected {
+        /// The disconnected peer.
+        peer_id: PeerId,
+        /// The reason of the disconnection.
+        reason: PeerDisconnectReason,
+    },
+}
+
 /// Opaque peer identifier.
 #[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -57,3 +57,17 @@ impl From<RelayedTransaction> for Event {
         Event::Transaction(transaction)
     }
 }
+
+/// The progress of the relayer syncing with the DA layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelayerStatus {
+    /// The DA height up to which the relayer downloaded the events.
+    pub synced_da_height: DaBlockHeight,
+    /// The DA height of the bridge contracts deployment, the relayer starts from it.
+    pub da_deploy_height: DaBlockHeight,
+    /// The latest finalized DA height known to the relayer.
+    /// It is `None` until the relayer reaches the DA layer.
+    pub latest_known_da_height: Option<DaBlockHeight>,
+    /// Whether the `synced_da_height` reached the `latest_known_da_height`.
+    pub synced: bool,
+}
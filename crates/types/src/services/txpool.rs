@@ -22,6 +22,7 @@ use crate::{
         UtxoId,
     },
     fuel_types::{
+        canonical::Serialize,
         Address,
         ContractId,
         Nonce,
@@ -40,7 +41,15 @@ use fuel_vm_private::{
     fuel_types::BlockHeight,
 };
 use std::{
-    sync::Arc,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        PoisonError,
+    },
     time::Duration,
 };
 use tai64::Tai64;
@@ -69,6 +78,14 @@ impl PoolTransaction {
         }
     }
 
+    /// The size of the serialized transaction, including the witnesses.
+    pub fn size(&self) -> usize {
+        match self {
+            PoolTransaction::Script(script) => script.transaction().size(),
+            PoolTransaction::Create(create) => create.transaction().size(),
+        }
+    }
+
     /// Returns the transaction ID
     pub fn id(&self) -> TxId {
         match self {
@@ -170,6 +187,138 @@ pub struct InsertionResult {
     pub submitted_time: Duration,
     /// These were removed during the insertion
     pub removed: Vec<ArcPoolTx>,
+    /// The ids of the pending transactions replaced by the `inserted` transaction,
+    /// because it spends the same inputs with a higher tip. It includes the
+    /// transactions depending on the replaced ones. All of them are also in the `removed`.
+    pub replaced: Vec<TxId>,
+}
+
+/// The outcome of the accepted local transaction.
+#[derive(Debug)]
+pub enum InsertionOutcome {
+    /// The transaction is inserted into the pool.
+    Inserted(InsertionResult),
+    /// The transaction spends a message that is not relayed from the DA layer yet.
+    /// It is held outside of the pool and is inserted once the message is relayed.
+    Deferred(ArcPoolTx),
+}
+
+/// The cache of the transactions that passed the predicates and signatures checks
+/// at the admission into the `TxPool`. The executor uses it to skip these checks
+/// when it validates a block with the same transactions.
+///
+/// The checks don't depend on the state, so the cached result stays valid until
+/// the inputs of the transaction are spent by another transaction.
+#[derive(Clone, Default)]
+pub struct ValidationCache {
+    transactions: Arc<Mutex<HashMap<TxId, ArcPoolTx>>>,
+}
+
+impl ValidationCache {
+    /// Caches the fully checked transaction.
+    pub fn insert(&self, tx: ArcPoolTx) {
+        self.lock().insert(tx.id(), tx);
+    }
+
+    /// Returns the checked transaction if the cache contains exactly the same `tx`.
+    pub fn get(&self, tx_id: &TxId, tx: &Transaction) -> Option<CheckedTransaction> {
+        let transactions = self.lock();
+        let cached = transactions.get(tx_id)?;
+        let is_same = match (cached.as_ref(), tx) {
+            (PoolTransaction::Script(cached), Transaction::Script(tx)) => {
+                cached.transaction() == tx
+            }
+            (PoolTransaction::Create(cached), Transaction::Create(tx)) => {
+                cached.transaction() == tx
+            }
+            _ => false,
+        };
+        is_same.then(|| cached.as_ref().into())
+    }
+
+    /// Removes the transaction from the cache.
+    pub fn remove(&self, tx_id: &TxId) {
+        self.lock().remove(tx_id);
+    }
+
+    /// Removes the cached transactions that spend any coin or message
+    /// spent by the `spending` transactions.
+    pub fn remove_spending(&self, spending: &[Transaction]) {
+        let inputs = spending.iter().flat_map(|tx| match tx {
+            Transaction::Script(script) => script.inputs().as_slice(),
+            Transaction::Create(create) => create.inputs().as_slice(),
+            Transaction::Mint(_) => &[],
+        });
+        let mut spent_coins = HashSet::<UtxoId>::new();
+        let mut spent_messages = HashSet::<Nonce>::new();
+        for input in inputs {
+            if let Some(utxo_id) = input.utxo_id() {
+                spent_coins.insert(*utxo_id);
+            }
+            if let Some(nonce) = input.nonce() {
+                spent_messages.insert(*nonce);
+            }
+        }
+
+        self.lock().retain(|_, tx| {
+            !tx.inputs().iter().any(|input| {
+                matches!(input.utxo_id(), Some(utxo_id) if spent_coins.contains(utxo_id))
+                    || matches!(input.nonce(), Some(nonce) if spent_messages.contains(nonce))
+            })
+        });
+    }
+
+    /// Returns the number of cached transactions.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<TxId, ArcPoolTx>> {
+        self.transactions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl core::fmt::Debug for ValidationCache {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ValidationCache")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// The policy of the block producer for transactions that don't fit
+/// into the remaining gas of the block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PackingStrategy {
+    /// Skips the transaction and continues packing smaller transactions.
+    /// The transaction skipped `max_skips` times is forced into the next block
+    /// before other transactions, so it is not starved by them.
+    Skip {
+        /// The number of times the transaction can be skipped.
+        max_skips: u32,
+    },
+    /// Stops packing the block to preserve the strict priority order of transactions.
+    Stop,
+}
+
+impl PackingStrategy {
+    /// The default number of times the transaction can be skipped.
+    pub const DEFAULT_MAX_SKIPS: u32 = 10;
+}
+
+impl Default for PackingStrategy {
+    fn default() -> Self {
+        Self::Skip {
+            max_skips: Self::DEFAULT_MAX_SKIPS,
+        }
+    }
 }
 
 /// The status of the transaction during its life from the tx pool until the block.
@@ -265,10 +414,20 @@ pub enum Error {
         "Transaction is not inserted. A higher priced tx {0:#x} is already spending this message: {1:#x}"
     )]
     NotInsertedCollisionMessageId(TxId, Nonce),
+    #[error("Transaction is not inserted. The tip {tip} is not enough to replace the tx {replaced:#x} spending the same inputs, the required tip is {required_tip}")]
+    NotInsertedReplacementUnderpriced {
+        replaced: TxId,
+        tip: Word,
+        required_tip: Word,
+    },
     #[error("Transaction is not inserted. UTXO input does not exist: {0:#x}")]
     NotInsertedOutputDoesNotExist(UtxoId),
     #[error("Transaction is not inserted. UTXO input contract does not exist or was already spent: {0:#x}")]
     NotInsertedInputContractDoesNotExist(ContractId),
+    #[error(
+        "Transaction is not inserted. Input contract is not deployed on chain: {0:#x}"
+    )]
+    NotInsertedInputContractNotDeployed(ContractId),
     #[error("Transaction is not inserted. ContractId is already taken {0:#x}")]
     NotInsertedContractIdAlreadyTaken(ContractId),
     #[error("Transaction is not inserted. UTXO does not exist: {0:#x}")]
@@ -299,10 +458,19 @@ pub enum Error {
         "Transaction is not inserted. Input output mismatch. Expected coin but output is contract"
     )]
     NotInsertedIoContractOutput,
-    #[error("Transaction is not inserted. Maximum depth of dependent transaction chain reached")]
-    NotInsertedMaxDepth,
+    #[error("Transaction is not inserted. The depth {depth} of the dependent transaction chain exceeds the limit {max_depth}")]
+    NotInsertedMaxDepth { depth: usize, max_depth: usize },
     #[error("Transaction exceeds the max gas per block limit. Tx gas: {tx_gas}, block limit {block_limit}")]
     NotInsertedMaxGasLimit { tx_gas: Word, block_limit: Word },
+    #[error("Transaction is not inserted. The predicate gas {predicate_gas} exceeds the limit per predicate {limit}")]
+    NotInsertedMaxPredicateGas { predicate_gas: Word, limit: Word },
+    #[error("Transaction is not inserted. The gas of all predicates {predicate_gas} exceeds the limit per transaction {limit}")]
+    NotInsertedMaxTxPredicateGas { predicate_gas: Word, limit: Word },
+    #[error("Transaction is not inserted. The number of predicate inputs {predicate_inputs} exceeds the limit per transaction {limit}")]
+    NotInsertedMaxPredicateInputs {
+        predicate_inputs: usize,
+        limit: usize,
+    },
     // small todo for now it can pass but in future we should include better messages
     #[error("Transaction removed.")]
     Removed,
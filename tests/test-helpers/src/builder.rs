@@ -36,6 +36,7 @@ use rand::{
 use std::{
     collections::HashMap,
     io,
+    num::NonZeroUsize,
 };
 
 /// Helper for wrapping a currently running node environment
@@ -93,6 +94,9 @@ pub struct TestSetupBuilder {
     pub starting_block: BlockHeight,
     pub utxo_validation: bool,
     pub trigger: Trigger,
+    pub parallel_execution_workers: Option<NonZeroUsize>,
+    pub tx_validation_cache: bool,
+    pub record_storage_access: bool,
 }
 
 impl TestSetupBuilder {
@@ -196,7 +200,7 @@ impl TestSetupBuilder {
             ..StateConfig::default()
         };
 
-        let config = Config {
+        let mut config = Config {
             utxo_validation: self.utxo_validation,
             txpool: fuel_core_txpool::Config {
                 chain_config: chain_conf.clone(),
@@ -205,8 +209,11 @@ impl TestSetupBuilder {
             snapshot_reader: SnapshotReader::new_in_memory(chain_conf, state),
             block_production: self.trigger,
             static_gas_price: self.min_gas_price,
+            parallel_execution_workers: self.parallel_execution_workers,
+            tx_validation_cache: self.tx_validation_cache,
             ..Config::local_node()
         };
+        config.vm.record_storage_access = self.record_storage_access;
 
         let srv = FuelService::new_node(config).await.unwrap();
         let client = FuelClient::from(srv.bound_address);
@@ -230,6 +237,9 @@ impl Default for TestSetupBuilder {
             starting_block: Default::default(),
             utxo_validation: true,
             trigger: Trigger::Instant,
+            parallel_execution_workers: None,
+            tx_validation_cache: false,
+            record_storage_access: false,
         }
     }
 }
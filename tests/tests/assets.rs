@@ -0,0 +1,111 @@
+use fuel_core::{
+    fuel_core_graphql_api::AssetMetadata,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_types::fuel_types::AssetId;
+use reqwest::header::CONTENT_TYPE;
+use serde_json::{
+    json,
+    Value,
+};
+
+async fn query_assets(srv: &FuelService, ids: &[AssetId]) -> Value {
+    let ids = ids.iter().map(|id| format!("{id:#x}")).collect::<Vec<_>>();
+    let query = "query($ids: [AssetId!]!) { assets(ids: $ids) { id metadata { symbol name decimals } } }";
+    let body = reqwest::Client::new()
+        .post(format!("http://{}/v1/graphql", srv.bound_address))
+        .header(CONTENT_TYPE, "application/json")
+        .body(json!({ "query": query, "variables": { "ids": ids } }).to_string())
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    serde_json::from_str(&body).unwrap()
+}
+
+#[tokio::test]
+async fn assets_return_configured_metadata_and_null_for_unknown_assets() {
+    // Given
+    let usdc = AssetId::from([1; 32]);
+    let unknown = AssetId::from([2; 32]);
+    let mut config = Config::local_node();
+    let base_asset_id = *config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .base_asset_id();
+    config.api_asset_metadata.insert(
+        usdc,
+        AssetMetadata {
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+        },
+    );
+    config.api_base_asset_metadata = Some(AssetMetadata {
+        symbol: "TST".to_string(),
+        name: "Test".to_string(),
+        decimals: 9,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    // When
+    let response = query_assets(&srv, &[usdc, base_asset_id, unknown]).await;
+
+    // Then
+    let assets = response["data"]["assets"]
+        .as_array()
+        .expect("Expected assets");
+    assert_eq!(assets.len(), 3);
+    assert_eq!(assets[0]["id"], format!("{usdc:#x}"));
+    assert_eq!(
+        assets[0]["metadata"],
+        json!({ "symbol": "USDC", "name": "USD Coin", "decimals": "6" })
+    );
+    assert_eq!(assets[1]["id"], format!("{base_asset_id:#x}"));
+    assert_eq!(
+        assets[1]["metadata"],
+        json!({ "symbol": "TST", "name": "Test", "decimals": "9" })
+    );
+    assert_eq!(assets[2]["id"], format!("{unknown:#x}"));
+    assert_eq!(assets[2]["metadata"], Value::Null);
+}
+
+#[tokio::test]
+async fn assets_return_null_metadata_for_base_asset_if_not_configured() {
+    // Given
+    let config = Config::local_node();
+    let base_asset_id = *config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .base_asset_id();
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    // When
+    let response = query_assets(&srv, &[base_asset_id]).await;
+
+    // Then
+    assert_eq!(response["data"]["assets"][0]["metadata"], Value::Null);
+}
+
+#[tokio::test]
+async fn assets_rejects_more_ids_than_allowed_per_query() {
+    // Given
+    let mut config = Config::local_node();
+    config.api_max_assets_per_query = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let ids = (0..3u8).map(|i| AssetId::from([i; 32])).collect::<Vec<_>>();
+
+    // When
+    let response = query_assets(&srv, &ids).await;
+
+    // Then
+    let message = response["errors"][0]["message"].as_str().unwrap();
+    assert!(message.contains("too many assets requested"), "{message}");
+}
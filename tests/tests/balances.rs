@@ -6,6 +6,7 @@ use fuel_core::{
         SnapshotReader,
         StateConfig,
     },
+    coins_query::CoinsQueryError,
     service::{
         Config,
         FuelService,
@@ -236,3 +237,47 @@ async fn first_5_balances() {
         assert_eq!(balances[i].amount, 300);
     }
 }
+
+#[tokio::test]
+async fn balances_error_too_many_assets() {
+    let owner = Address::from([10u8; 32]);
+
+    // Given
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coins = (0..10u8)
+        .map(|i| CoinConfig {
+            owner,
+            amount: 100,
+            asset_id: AssetId::new([i; 32]),
+            ..coin_generator.generate()
+        })
+        .collect();
+    let state_config = StateConfig {
+        coins,
+        ..Default::default()
+    };
+    let mut config = Config {
+        snapshot_reader: SnapshotReader::local_testnet().with_state_config(state_config),
+        ..Config::local_node()
+    };
+    config.api_max_assets_per_query = 5;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client
+        .balances(
+            &owner,
+            PaginationRequest {
+                cursor: None,
+                results: 5,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await;
+
+    // Then
+    let error = result.expect_err("The query should exceed the assets limit");
+    let expected = CoinsQueryError::TooManyAssets { max: 5 }.to_string();
+    assert!(error.to_string().contains(&expected), "{error}");
+}
@@ -32,7 +32,9 @@ use fuel_core_types::{
         block::CompressedBlock,
         consensus::Consensus,
     },
+    fuel_merkle,
     fuel_tx::*,
+    fuel_types::canonical::Serialize,
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
@@ -314,6 +316,281 @@ async fn block_connection_5(
     };
 }
 
+#[tokio::test]
+async fn transaction_inclusion_proof_verifies_against_transactions_root() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    for i in 0..3u8 {
+        let tx = TransactionBuilder::script(vec![], vec![i])
+            .script_gas_limit(10000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        client.submit(&tx).await.unwrap();
+    }
+    client.produce_blocks(1, None).await.unwrap();
+    let block = client.block_by_height(1.into()).await.unwrap().unwrap();
+    // The submitted transactions and the mint transaction.
+    assert_eq!(block.transactions.len(), 4);
+
+    for tx_id in block.transactions.iter() {
+        // When
+        let proof = client
+            .transaction_inclusion_proof(1.into(), tx_id)
+            .await
+            .unwrap()
+            .expect("The block contains the transaction");
+
+        // Then
+        let tx = client
+            .transaction(tx_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .transaction;
+        let proof_set: Vec<_> = proof.proof_set.iter().map(|b| *b.deref()).collect();
+        assert!(fuel_merkle::binary::verify(
+            block.header.transactions_root.deref(),
+            &tx.to_bytes(),
+            &proof_set,
+            proof.proof_index,
+            block.header.transactions_count,
+        ));
+    }
+}
+
+#[tokio::test]
+async fn produced_block_stops_at_max_tx_per_block_with_gas_to_spare() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.block_producer.max_tx_per_block = Some(2);
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    for i in 0..3u8 {
+        let tx = TransactionBuilder::script(vec![], vec![i])
+            .script_gas_limit(10000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        client.submit(&tx).await.unwrap();
+    }
+
+    // When
+    client.produce_blocks(2, None).await.unwrap();
+
+    // Then
+    let first_block = client.block_by_height(1.into()).await.unwrap().unwrap();
+    let second_block = client.block_by_height(2.into()).await.unwrap().unwrap();
+    // The transactions from the `TxPool` and the mint transaction.
+    assert_eq!(first_block.transactions.len(), 3);
+    assert_eq!(second_block.transactions.len(), 2);
+}
+
+#[tokio::test]
+async fn produced_block_stops_at_max_outputs_per_block_with_txs_available() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.block_producer.max_outputs_per_block = Some(5);
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    // Each transaction creates two outputs.
+    for i in 0..3u8 {
+        let tx = TransactionBuilder::script(vec![], vec![i])
+            .script_gas_limit(10000)
+            .add_random_fee_input()
+            .add_output(Output::coin(Default::default(), 0, Default::default()))
+            .add_output(Output::coin(Default::default(), 0, Default::default()))
+            .finalize_as_transaction();
+        client.submit(&tx).await.unwrap();
+    }
+
+    // When
+    client.produce_blocks(2, None).await.unwrap();
+
+    // Then
+    let first_block = client.block_by_height(1.into()).await.unwrap().unwrap();
+    let second_block = client.block_by_height(2.into()).await.unwrap().unwrap();
+    // The transactions from the `TxPool` and the mint transaction.
+    assert_eq!(first_block.transactions.len(), 3);
+    assert_eq!(second_block.transactions.len(), 2);
+}
+
+#[tokio::test]
+async fn produced_block_stops_at_max_block_size_bytes_with_txs_available() {
+    use fuel_core_types::fuel_types::canonical::Serialize;
+
+    // Given
+    // All transactions have the same size.
+    let txs = (0..3u8)
+        .map(|i| {
+            TransactionBuilder::script(vec![], vec![i])
+                .script_gas_limit(10000)
+                .add_random_fee_input()
+                .finalize_as_transaction()
+        })
+        .collect::<Vec<_>>();
+    let tx_size = txs[0].size() as u64;
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.max_block_size_bytes = Some(tx_size * 2 + tx_size / 2);
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    for tx in &txs {
+        client.submit(tx).await.unwrap();
+    }
+
+    // When
+    client.produce_blocks(2, None).await.unwrap();
+
+    // Then
+    let first_block = client.block_by_height(1.into()).await.unwrap().unwrap();
+    let second_block = client.block_by_height(2.into()).await.unwrap().unwrap();
+    // The transactions from the `TxPool` and the mint transaction.
+    assert_eq!(first_block.transactions.len(), 3);
+    assert_eq!(second_block.transactions.len(), 2);
+}
+
+#[tokio::test]
+async fn transaction_inclusion_proof_is_none_for_transaction_outside_of_block() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let tx = Transaction::default_test_tx();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    // When
+    let proof = client
+        .transaction_inclusion_proof(2.into(), &tx.id(&ChainId::default()))
+        .await
+        .unwrap();
+
+    // Then
+    assert!(proof.is_none());
+}
+
+#[tokio::test]
+async fn block_headers_match_headers_of_full_blocks() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    client.produce_blocks(5, None).await.unwrap();
+
+    // When
+    let headers = client.block_headers(1.into(), 4).await.unwrap();
+
+    // Then
+    let mut expected_headers = vec![];
+    for height in 1..=4u32 {
+        let block = client
+            .block_by_height(height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        expected_headers.push(block.header);
+    }
+    assert_eq!(headers, expected_headers);
+}
+
+#[tokio::test]
+async fn block_headers_stop_at_the_latest_block() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    client.produce_blocks(2, None).await.unwrap();
+
+    // When
+    let headers = client.block_headers(1.into(), 10).await.unwrap();
+
+    // Then
+    let heights = headers.iter().map(|header| header.height).collect_vec();
+    assert_eq!(heights, vec![1, 2]);
+}
+
+#[tokio::test]
+async fn block_headers_fails_when_too_many_headers_requested() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.block_headers(0.into(), 1001).await;
+
+    // Then
+    let error = result.expect_err("Should fail because of the too big range");
+    assert!(error.to_string().contains("more than 1000 headers"));
+}
+
+#[tokio::test]
+async fn forks_deeper_than_max_reorg_depth_are_rejected() {
+    // Given
+    let mut config = Config::local_node();
+    config.max_reorg_depth = Some(3);
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(10, None).await.unwrap();
+    let block_at = |height: u32| {
+        srv.shared
+            .database
+            .on_chain()
+            .get_sealed_block_by_height(&height.into())
+            .unwrap()
+            .unwrap()
+    };
+
+    // When
+    let deep_fork = srv
+        .shared
+        .block_importer
+        .execute_and_commit(block_at(5))
+        .await;
+    let shallow_fork = srv
+        .shared
+        .block_importer
+        .execute_and_commit(block_at(8))
+        .await;
+
+    // Then
+    let deep_error = deep_fork.expect_err("The fork requires reverting 6 blocks");
+    assert!(
+        deep_error.to_string().contains("maximum reorg depth is 3"),
+        "{deep_error}"
+    );
+    // The shallow fork passes the depth check and is handled by the importer.
+    let shallow_error =
+        shallow_fork.expect_err("The importer accepts only the next block");
+    assert!(
+        !shallow_error.to_string().contains("maximum reorg depth"),
+        "{shallow_error}"
+    );
+}
+
 mod full_block {
     use super::*;
     use cynic::QueryBuilder;
@@ -355,6 +632,27 @@ mod full_block {
         pub transactions: Vec<OpaqueTransaction>,
     }
 
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "BlockByHeightArgs"
+    )]
+    pub struct BlockWithoutMintByHeightQuery {
+        #[arguments(height: $height)]
+        pub block: Option<BlockWithoutMint>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Block"
+    )]
+    pub struct BlockWithoutMint {
+        #[arguments(includeMint: false)]
+        pub transactions: Vec<OpaqueTransaction>,
+    }
+
     #[async_trait::async_trait]
     pub trait ClientExt {
         async fn full_block_by_height(
@@ -393,4 +691,442 @@ mod full_block {
         assert_eq!(block.header.height.0, 1);
         assert_eq!(block.transactions.len(), 2 /* mint + our tx */);
     }
+
+    #[tokio::test]
+    async fn get_block_with_and_without_mint() {
+        let srv = FuelService::from_database(Database::default(), Config::local_node())
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // Given
+        let tx = Transaction::default_test_tx();
+        client.submit_and_await_commit(&tx).await.unwrap();
+
+        // When
+        let with_mint = client.full_block_by_height(1).await.unwrap().unwrap();
+        let query = BlockWithoutMintByHeightQuery::build(BlockByHeightArgs {
+            height: Some(U32(1)),
+        });
+        let without_mint = client.query(query).await.unwrap().block.unwrap();
+
+        // Then
+        let is_mint = |tx: &OpaqueTransaction| {
+            let tx: Transaction = tx.clone().try_into().unwrap();
+            tx.is_mint()
+        };
+        assert_eq!(with_mint.transactions.len(), 2);
+        assert!(is_mint(with_mint.transactions.last().unwrap()));
+        assert_eq!(without_mint.transactions.len(), 1);
+        assert!(!is_mint(&without_mint.transactions[0]));
+    }
+}
+
+mod block_signer {
+    use super::*;
+    use cynic::QueryBuilder;
+    use fuel_core_client::client::{
+        schema::{
+            block::BlockByHeightArgs,
+            schema,
+            PublicKey,
+            U32,
+        },
+        FuelClient,
+    };
+    use fuel_core_types::fuel_types::Bytes64;
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "BlockByHeightArgs"
+    )]
+    pub struct BlockSignerByHeightQuery {
+        #[arguments(height: $height)]
+        pub block: Option<BlockSigner>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Block"
+    )]
+    pub struct BlockSigner {
+        pub signer: Option<PublicKey>,
+    }
+
+    async fn block_signer(client: &FuelClient, height: u32) -> Option<PublicKey> {
+        let query = BlockSignerByHeightQuery::build(BlockByHeightArgs {
+            height: Some(U32(height)),
+        });
+        client.query(query).await.unwrap().block.unwrap().signer
+    }
+
+    #[tokio::test]
+    async fn block_signer_matches_consensus_key() {
+        let config = Config::local_node();
+        let srv = FuelService::from_database(Database::default(), config.clone())
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // Given
+        client.produce_blocks(1, None).await.unwrap();
+
+        // When
+        let signer = block_signer(&client, 1)
+            .await
+            .expect("Block should be signed");
+
+        // Then
+        let expected_pub_key = config
+            .consensus_key
+            .unwrap()
+            .expose_secret()
+            .deref()
+            .public_key();
+        let actual_pub_key: Bytes64 = signer.into();
+        assert_eq!(*actual_pub_key, *expected_pub_key);
+    }
+
+    #[tokio::test]
+    async fn genesis_block_has_no_signer() {
+        let srv = FuelService::from_database(Database::default(), Config::local_node())
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        let signer = block_signer(&client, 0).await;
+
+        assert!(signer.is_none());
+    }
+}
+
+mod block_summary {
+    use super::*;
+    use cynic::QueryBuilder;
+    use fuel_core::chain_config::CoinConfig;
+    use fuel_core_client::client::{
+        schema::{
+            block::BlockByHeightArgs,
+            schema,
+            U32,
+            U64,
+        },
+        FuelClient,
+    };
+    use fuel_core_types::{
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_crypto::SecretKey,
+        fuel_tx::field::Outputs,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+    use test_helpers::builder::{
+        TestContext,
+        TestSetupBuilder,
+    };
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "BlockByHeightArgs"
+    )]
+    pub struct BlockSummaryByHeightQuery {
+        #[arguments(height: $height)]
+        pub block: Option<BlockSummary>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Block"
+    )]
+    pub struct BlockSummary {
+        pub transaction_count: Option<U64>,
+        pub total_gas_used: Option<U64>,
+        pub total_fee: Option<U64>,
+    }
+
+    async fn block_summary(client: &FuelClient, height: u32) -> BlockSummary {
+        let query = BlockSummaryByHeightQuery::build(BlockByHeightArgs {
+            height: Some(U32(height)),
+        });
+        client.query(query).await.unwrap().block.unwrap()
+    }
+
+    #[tokio::test]
+    async fn block_summary_matches_transactions_of_the_block() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2322);
+        let coin_amount = 100_000;
+        let scripts = (0..3)
+            .map(|_| {
+                TransactionBuilder::script(
+                    vec![op::ret(RegId::ONE)].into_iter().collect(),
+                    vec![],
+                )
+                .script_gas_limit(10_000)
+                .max_fee_limit(10_000)
+                .add_unsigned_coin_input(
+                    SecretKey::random(&mut rng),
+                    rng.gen(),
+                    coin_amount,
+                    AssetId::BASE,
+                    Default::default(),
+                )
+                .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+                .finalize()
+            })
+            .collect::<Vec<_>>();
+
+        let create_secret = SecretKey::random(&mut rng);
+        let create_utxo_id: UtxoId = rng.gen();
+        let bytecode: Witness = vec![op::ret(RegId::ONE)]
+            .into_iter()
+            .collect::<Vec<u8>>()
+            .into();
+        let salt: Salt = rng.gen();
+        let contract = Contract::from(bytecode.as_ref());
+        let state_root = Contract::default_state_root();
+        let contract_id = contract.id(&salt, &contract.root(), &state_root);
+        let create = TransactionBuilder::create(bytecode, salt, vec![])
+            .max_fee_limit(10_000)
+            .add_unsigned_coin_input(
+                create_secret,
+                create_utxo_id,
+                coin_amount,
+                AssetId::BASE,
+                Default::default(),
+            )
+            .add_output(Output::contract_created(contract_id, state_root))
+            .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+            .finalize();
+
+        let mut test_builder = TestSetupBuilder::new(2322);
+        test_builder.min_gas_price = 1;
+        test_builder.trigger = Trigger::Never;
+        test_builder
+            .config_coin_inputs_from_transactions(&scripts.iter().collect::<Vec<_>>());
+        test_builder.initial_coins.push(CoinConfig {
+            tx_id: *create_utxo_id.tx_id(),
+            output_index: create_utxo_id.output_index(),
+            owner: Input::owner(&create_secret.public_key()),
+            amount: coin_amount,
+            asset_id: AssetId::BASE,
+            ..Default::default()
+        });
+        let TestContext { client, .. } = test_builder.finalize().await;
+        for script in scripts {
+            client.submit(&script.into()).await.unwrap();
+        }
+        client.submit(&create.into()).await.unwrap();
+
+        // When
+        let height = client.produce_blocks(1, None).await.unwrap();
+        let summary = block_summary(&client, *height).await;
+
+        // Then
+        let consensus_parameters =
+            client.chain_info().await.unwrap().consensus_parameters;
+        let gas_costs = consensus_parameters.gas_costs();
+        let fee_params = consensus_parameters.fee_params();
+        let block = client.block_by_height(height).await.unwrap().unwrap();
+        let mut expected_gas_used = 0;
+        let mut expected_fee = 0;
+        let mut creates = 0;
+        for tx_id in block.transactions.iter() {
+            let receipts = client.receipts(tx_id).await.unwrap().unwrap_or_default();
+            expected_gas_used += receipts
+                .iter()
+                .filter_map(|receipt| match receipt {
+                    Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                    _ => None,
+                })
+                .sum::<u64>();
+
+            let tx = client
+                .transaction(tx_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .transaction;
+            let (min_gas, outputs) = match &tx {
+                Transaction::Script(script) => {
+                    (script.min_gas(gas_costs, fee_params), script.outputs())
+                }
+                Transaction::Create(create) => {
+                    creates += 1;
+                    (create.min_gas(gas_costs, fee_params), create.outputs())
+                }
+                _ => continue,
+            };
+            let change = outputs
+                .iter()
+                .filter_map(|output| match output {
+                    Output::Change { amount, .. } => Some(*amount),
+                    _ => None,
+                })
+                .sum::<u64>();
+            expected_gas_used += min_gas;
+            expected_fee += coin_amount - change;
+        }
+        assert_eq!(block.transactions.len(), 5);
+        assert_eq!(creates, 1);
+        assert_eq!(
+            summary.transaction_count.map(u64::from),
+            Some(block.transactions.len() as u64)
+        );
+        assert_eq!(
+            summary.total_gas_used.map(u64::from),
+            Some(expected_gas_used)
+        );
+        assert_eq!(summary.total_fee.map(u64::from), Some(expected_fee));
+        assert_ne!(expected_fee, 0);
+    }
+
+    #[tokio::test]
+    async fn block_summary_is_null_for_genesis_block() {
+        let srv = FuelService::from_database(Database::default(), Config::local_node())
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        let summary = block_summary(&client, 0).await;
+
+        assert!(summary.transaction_count.is_none());
+        assert!(summary.total_gas_used.is_none());
+        assert!(summary.total_fee.is_none());
+    }
+}
+
+mod canonical_chain {
+    use super::*;
+    use cynic::QueryBuilder;
+    use fuel_core_client::client::{
+        schema::{
+            block::BlockByHeightArgs,
+            schema,
+            BlockId,
+            U32,
+        },
+        FuelClient,
+    };
+    use fuel_core_types::fuel_types::Bytes32;
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IsCanonicalBlockArgs {
+        pub id: BlockId,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "IsCanonicalBlockArgs"
+    )]
+    pub struct IsCanonicalBlockQuery {
+        #[arguments(id: $id)]
+        pub is_canonical_block: bool,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "BlockByHeightArgs"
+    )]
+    pub struct BlockParentByHeightQuery {
+        #[arguments(height: $height)]
+        pub block: Option<BlockParent>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Block"
+    )]
+    pub struct BlockParent {
+        pub id: BlockId,
+        pub parent_id: Option<BlockId>,
+    }
+
+    async fn block_parent(
+        client: &FuelClient,
+        height: u32,
+    ) -> (Bytes32, Option<Bytes32>) {
+        let query = BlockParentByHeightQuery::build(BlockByHeightArgs {
+            height: Some(U32(height)),
+        });
+        let block = client.query(query).await.unwrap().block.unwrap();
+        (block.id.into(), block.parent_id.map(Into::into))
+    }
+
+    async fn is_canonical_block(client: &FuelClient, id: Bytes32) -> bool {
+        let query = IsCanonicalBlockQuery::build(IsCanonicalBlockArgs { id: id.into() });
+        client.query(query).await.unwrap().is_canonical_block
+    }
+
+    #[tokio::test]
+    async fn parent_id_links_to_the_previous_block() {
+        let srv = FuelService::from_database(Database::default(), Config::local_node())
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // Given
+        client.produce_blocks(2, None).await.unwrap();
+
+        // When
+        let (genesis_id, genesis_parent_id) = block_parent(&client, 0).await;
+        let (first_id, first_parent_id) = block_parent(&client, 1).await;
+        let (_, second_parent_id) = block_parent(&client, 2).await;
+
+        // Then
+        assert_eq!(genesis_parent_id, None);
+        assert_eq!(first_parent_id, Some(genesis_id));
+        assert_eq!(second_parent_id, Some(first_id));
+    }
+
+    #[tokio::test]
+    async fn orphaned_block_is_not_canonical() {
+        let node = FuelService::from_database(Database::default(), Config::local_node())
+            .await
+            .unwrap();
+        let fork = FuelService::from_database(Database::default(), Config::local_node())
+            .await
+            .unwrap();
+        let node_client = FuelClient::from(node.bound_address);
+        let fork_client = FuelClient::from(fork.bound_address);
+
+        // Given
+        // Both nodes build the block at the same height on top of the same genesis,
+        // so the block of the fork is orphaned from the point of view of the node.
+        let time = Tai64::now().0;
+        node_client.produce_blocks(1, Some(time)).await.unwrap();
+        fork_client
+            .produce_blocks(1, Some(time + 10))
+            .await
+            .unwrap();
+        let (canonical_id, canonical_parent_id) = block_parent(&node_client, 1).await;
+        let (orphaned_id, orphaned_parent_id) = block_parent(&fork_client, 1).await;
+        assert_ne!(canonical_id, orphaned_id);
+        assert_eq!(canonical_parent_id, orphaned_parent_id);
+
+        // When
+        let canonical = is_canonical_block(&node_client, canonical_id).await;
+        let orphaned = is_canonical_block(&node_client, orphaned_id).await;
+
+        // Then
+        assert!(canonical);
+        assert!(!orphaned);
+    }
 }
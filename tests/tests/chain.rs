@@ -6,6 +6,7 @@ use fuel_core::{
         StateConfig,
     },
     service::{
+        genesis::create_genesis_block,
         Config,
         FuelService,
     },
@@ -16,11 +17,13 @@ use fuel_core_client::client::{
             AssetId,
             UtxoId,
         },
+        EffectiveValue,
         TransactionStatus,
     },
     FuelClient,
 };
 use fuel_core_types::{
+    blockchain::primitives::BlockId,
     fuel_crypto::SecretKey,
     fuel_tx::{
         Input,
@@ -53,6 +56,109 @@ async fn chain_info() {
     );
 }
 
+#[tokio::test]
+async fn node_starts_when_genesis_id_matches_expected() {
+    // Given
+    let mut node_config = Config::local_node();
+    let genesis_id = create_genesis_block(&node_config).header().id();
+    node_config.expected_genesis_id = Some(genesis_id);
+
+    // When
+    let srv = FuelService::new_node(node_config).await.unwrap();
+
+    // Then
+    let client = FuelClient::from(srv.bound_address);
+    let genesis = client.block_by_height(0u32.into()).await.unwrap().unwrap();
+    assert_eq!(BlockId::from(genesis.id), genesis_id);
+}
+
+#[tokio::test]
+async fn node_fails_to_start_when_genesis_id_mismatches_expected() {
+    // Given
+    let mut node_config = Config::local_node();
+    let expected_genesis_id = BlockId::from([1; 32]);
+    node_config.expected_genesis_id = Some(expected_genesis_id);
+
+    // When
+    let result = FuelService::new_node(node_config).await;
+
+    // Then
+    let err = result.expect_err("The node should fail to start");
+    assert!(
+        err.to_string()
+            .contains("doesn't match the expected genesis block id"),
+        "unexpected error: {err}",
+    );
+}
+
+#[tokio::test]
+async fn effective_consensus_parameters_report_overrides() {
+    // Given
+    let mut node_config = Config::local_node();
+    let consensus_parameters = node_config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .clone();
+    let max_gas_per_predicate = consensus_parameters
+        .predicate_params()
+        .max_gas_per_predicate()
+        .saturating_sub(1);
+    node_config.txpool.max_gas_per_predicate = Some(max_gas_per_predicate);
+    node_config.debug = true;
+    node_config.vm.max_call_depth = Some(10);
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let parameters = client.effective_consensus_parameters().await.unwrap();
+
+    // Then
+    assert_eq!(parameters.consensus_parameters, consensus_parameters);
+    assert_eq!(
+        parameters.max_gas_per_predicate,
+        EffectiveValue {
+            value: Some(max_gas_per_predicate),
+            overridden: true,
+        }
+    );
+    assert_eq!(
+        parameters.max_call_depth,
+        EffectiveValue {
+            value: Some(10),
+            overridden: true,
+        }
+    );
+    assert_eq!(
+        parameters.max_predicate_gas_per_tx,
+        EffectiveValue {
+            value: Some(consensus_parameters.tx_params().max_gas_per_tx()),
+            overridden: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn chain_config_returns_config_the_node_started_with() {
+    // Given
+    let mut node_config = Config::local_node();
+    let mut chain_config = node_config.snapshot_reader.chain_config().clone();
+    chain_config.chain_name = "chain_config_test".to_string();
+    node_config.snapshot_reader = node_config
+        .snapshot_reader
+        .clone()
+        .with_chain_config(chain_config.clone());
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let json = client.chain_config().await.unwrap();
+
+    // Then
+    let returned_config: ChainConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(returned_config, chain_config);
+}
+
 #[tokio::test]
 async fn network_operates_with_non_zero_chain_id() {
     let mut rng = rand::rngs::StdRng::seed_from_u64(0xBAADF00D);
@@ -180,3 +180,395 @@ async fn get_coins_forwards_backwards(
     assert!(!coins.results.is_empty());
     assert_eq!(coins.results.len(), 10);
 }
+
+mod block_coin_changes {
+    use cynic::QueryBuilder;
+    use fuel_core::{
+        chain_config::{
+            CoinConfig,
+            SnapshotReader,
+            StateConfig,
+        },
+        service::{
+            Config,
+            FuelService,
+        },
+    };
+    use fuel_core_client::client::{
+        schema::{
+            schema,
+            Address,
+            AssetId,
+            UtxoId,
+            U32,
+            U64,
+        },
+        types::TransactionStatus,
+        FuelClient,
+    };
+    use fuel_core_types::{
+        fuel_crypto::SecretKey,
+        fuel_tx::{
+            self,
+            Input,
+            Output,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct BlockCoinChangesArgs {
+        pub height: U32,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "BlockCoinChangesArgs"
+    )]
+    pub struct BlockCoinChangesQuery {
+        #[arguments(height: $height)]
+        pub block_coin_changes: Option<BlockCoinChanges>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "../crates/client/assets/schema.sdl")]
+    pub struct BlockCoinChanges {
+        pub height: U32,
+        pub created: Vec<Coin>,
+        pub spent: Vec<UtxoId>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "../crates/client/assets/schema.sdl")]
+    pub struct Coin {
+        pub utxo_id: UtxoId,
+        pub owner: Address,
+        pub amount: U64,
+        pub asset_id: AssetId,
+    }
+
+    #[tokio::test]
+    async fn block_coin_changes_returns_coins_created_and_spent_by_the_block() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let secret = SecretKey::random(&mut rng);
+        let owner = Input::owner(&secret.public_key());
+        let recipient = fuel_tx::Address::new([7; 32]);
+        let genesis_utxo_id = fuel_tx::UtxoId::new([1; 32].into(), 0);
+        let amount = 1000;
+        let transferred = 100;
+
+        // Given
+        let state_config = StateConfig {
+            coins: vec![CoinConfig {
+                tx_id: *genesis_utxo_id.tx_id(),
+                output_index: genesis_utxo_id.output_index(),
+                owner,
+                amount,
+                asset_id: fuel_tx::AssetId::BASE,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let node_config = Config {
+            utxo_validation: true,
+            snapshot_reader: SnapshotReader::local_testnet()
+                .with_state_config(state_config),
+            ..Config::local_node()
+        };
+        let srv = FuelService::new_node(node_config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        let chain_id = client
+            .chain_info()
+            .await
+            .unwrap()
+            .consensus_parameters
+            .chain_id();
+
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .with_chain_id(chain_id)
+            .max_fee_limit(amount - transferred)
+            .add_unsigned_coin_input(
+                secret,
+                genesis_utxo_id,
+                amount,
+                fuel_tx::AssetId::BASE,
+                Default::default(),
+            )
+            .add_output(Output::coin(recipient, transferred, fuel_tx::AssetId::BASE))
+            .add_output(Output::change(owner, 0, fuel_tx::AssetId::BASE))
+            .finalize_as_transaction();
+        let tx_id = tx.id(&chain_id);
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+        let TransactionStatus::Success { block_height, .. } = status else {
+            panic!("The transfer should succeed: {status:?}");
+        };
+
+        // When
+        let query = BlockCoinChangesQuery::build(BlockCoinChangesArgs {
+            height: block_height.into(),
+        });
+        let changes = client
+            .query(query)
+            .await
+            .unwrap()
+            .block_coin_changes
+            .expect("The coin changes of the produced block should be indexed");
+
+        // Then
+        assert_eq!(changes.height.0, *block_height);
+        let spent: Vec<fuel_tx::UtxoId> =
+            changes.spent.into_iter().map(Into::into).collect();
+        assert_eq!(spent, vec![genesis_utxo_id]);
+        // The `Mint` transaction credits the coinbase contract,
+        // so only the outputs of the transfer create coins.
+        let created: Vec<(fuel_tx::UtxoId, fuel_tx::Address)> = changes
+            .created
+            .iter()
+            .map(|coin| (coin.utxo_id.clone().into(), coin.owner.clone().into()))
+            .collect();
+        assert_eq!(
+            created,
+            vec![
+                (fuel_tx::UtxoId::new(tx_id, 0), recipient),
+                (fuel_tx::UtxoId::new(tx_id, 1), owner),
+            ]
+        );
+        assert_eq!(changes.created[0].amount.0, transferred);
+    }
+
+    #[tokio::test]
+    async fn block_coin_changes_of_unknown_block_is_none() {
+        // Given
+        let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // When
+        let query =
+            BlockCoinChangesQuery::build(BlockCoinChangesArgs { height: 100.into() });
+        let result = client.query(query).await.unwrap();
+
+        // Then
+        assert!(result.block_coin_changes.is_none());
+    }
+}
+
+mod coins_diff {
+    use super::block_coin_changes::Coin;
+    use cynic::QueryBuilder;
+    use fuel_core::{
+        chain_config::{
+            CoinConfig,
+            SnapshotReader,
+            StateConfig,
+        },
+        schema::coins::MAX_COINS_DIFF_BLOCKS,
+        service::{
+            Config,
+            FuelService,
+        },
+    };
+    use fuel_core_client::client::{
+        schema::{
+            schema,
+            UtxoId,
+            U32,
+        },
+        types::TransactionStatus,
+        FuelClient,
+    };
+    use fuel_core_types::{
+        fuel_crypto::SecretKey,
+        fuel_tx::{
+            self,
+            Input,
+            Output,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::{
+            BlockHeight,
+            ChainId,
+        },
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+    use std::collections::HashSet;
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct CoinsDiffArgs {
+        pub from_height: U32,
+        pub to_height: U32,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "CoinsDiffArgs"
+    )]
+    pub struct CoinsDiffQuery {
+        #[arguments(fromHeight: $from_height, toHeight: $to_height)]
+        pub coins_diff: CoinsDiff,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "../crates/client/assets/schema.sdl")]
+    pub struct CoinsDiff {
+        pub from_height: U32,
+        pub to_height: U32,
+        pub added: Vec<Coin>,
+        pub removed: Vec<UtxoId>,
+    }
+
+    async fn coins_diff(
+        client: &FuelClient,
+        from: u32,
+        to: u32,
+    ) -> std::io::Result<(HashSet<fuel_tx::UtxoId>, HashSet<fuel_tx::UtxoId>)> {
+        let query = CoinsDiffQuery::build(CoinsDiffArgs {
+            from_height: from.into(),
+            to_height: to.into(),
+        });
+        let diff = client.query(query).await?.coins_diff;
+        assert_eq!((diff.from_height.0, diff.to_height.0), (from, to));
+        let added = diff
+            .added
+            .into_iter()
+            .map(|coin| coin.utxo_id.into())
+            .collect();
+        let removed = diff.removed.into_iter().map(Into::into).collect();
+        Ok((added, removed))
+    }
+
+    /// Sends the `amount` of the coin to the `recipient` and the rest back to the owner.
+    async fn transfer(
+        client: &FuelClient,
+        chain_id: &ChainId,
+        secret: SecretKey,
+        utxo_id: fuel_tx::UtxoId,
+        recipient: fuel_tx::Address,
+        amount: u64,
+    ) -> (fuel_tx::TxId, BlockHeight) {
+        let owner = Input::owner(&secret.public_key());
+        let coin = client.coin(&utxo_id).await.unwrap().unwrap();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .with_chain_id(*chain_id)
+            .max_fee_limit(coin.amount - amount)
+            .add_unsigned_coin_input(
+                secret,
+                utxo_id,
+                coin.amount,
+                fuel_tx::AssetId::BASE,
+                Default::default(),
+            )
+            .add_output(Output::coin(recipient, amount, fuel_tx::AssetId::BASE))
+            .add_output(Output::change(owner, 0, fuel_tx::AssetId::BASE))
+            .finalize_as_transaction();
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+        let TransactionStatus::Success { block_height, .. } = status else {
+            panic!("The transfer should succeed: {status:?}");
+        };
+        (tx.id(chain_id), block_height)
+    }
+
+    #[tokio::test]
+    async fn coins_diff_matches_the_transfers_between_heights() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let secret = SecretKey::random(&mut rng);
+        let owner = Input::owner(&secret.public_key());
+        let recipient = fuel_tx::Address::new([7; 32]);
+        let genesis_utxo_id = fuel_tx::UtxoId::new([1; 32].into(), 0);
+
+        // Given
+        let state_config = StateConfig {
+            coins: vec![CoinConfig {
+                tx_id: *genesis_utxo_id.tx_id(),
+                output_index: genesis_utxo_id.output_index(),
+                owner,
+                amount: 1000,
+                asset_id: fuel_tx::AssetId::BASE,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let node_config = Config {
+            utxo_validation: true,
+            snapshot_reader: SnapshotReader::local_testnet()
+                .with_state_config(state_config),
+            ..Config::local_node()
+        };
+        let srv = FuelService::new_node(node_config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        let chain_id = client
+            .chain_info()
+            .await
+            .unwrap()
+            .consensus_parameters
+            .chain_id();
+
+        // Each transfer spends the change of the previous one.
+        let mut transfers = vec![];
+        let mut utxo_id = genesis_utxo_id;
+        for _ in 0..3 {
+            let (tx_id, height) =
+                transfer(&client, &chain_id, secret, utxo_id, recipient, 100).await;
+            transfers.push((utxo_id, tx_id, height));
+            utxo_id = fuel_tx::UtxoId::new(tx_id, 1);
+        }
+        let first_height = *transfers[0].2;
+        let last_height = *transfers[2].2;
+
+        // When
+        let full_diff = coins_diff(&client, first_height - 1, last_height)
+            .await
+            .unwrap();
+        let partial_diff = coins_diff(&client, first_height, last_height)
+            .await
+            .unwrap();
+
+        // Then
+        let manual_diff =
+            |transfers: &[(fuel_tx::UtxoId, fuel_tx::TxId, BlockHeight)]| {
+                let created: HashSet<_> = transfers
+                    .iter()
+                    .flat_map(|(_, tx_id, _)| {
+                        [
+                            fuel_tx::UtxoId::new(*tx_id, 0),
+                            fuel_tx::UtxoId::new(*tx_id, 1),
+                        ]
+                    })
+                    .collect();
+                let spent: HashSet<_> =
+                    transfers.iter().map(|(utxo_id, _, _)| *utxo_id).collect();
+                let added = created.difference(&spent).copied().collect::<HashSet<_>>();
+                let removed = spent.difference(&created).copied().collect::<HashSet<_>>();
+                (added, removed)
+            };
+        assert_eq!(full_diff, manual_diff(&transfers));
+        assert_eq!(full_diff.1, HashSet::from([genesis_utxo_id]));
+        assert_eq!(partial_diff, manual_diff(&transfers[1..]));
+    }
+
+    #[tokio::test]
+    async fn coins_diff_above_the_max_range_is_rejected() {
+        // Given
+        let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // When
+        let result = coins_diff(&client, 0, MAX_COINS_DIFF_BLOCKS + 1).await;
+
+        // Then
+        let err = result.expect_err("The range above the maximum should be rejected");
+        assert!(err.to_string().contains("exceeds the maximum"), "{err}");
+    }
+}
@@ -2,6 +2,7 @@ use crate::helpers::TestContext;
 use fuel_core::{
     chain_config::{
         CoinConfig,
+        CoinConfigGenerator,
         MessageConfig,
         SnapshotReader,
         StateConfig,
@@ -13,14 +14,26 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
-    types::CoinType,
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        Coin,
+        CoinType,
+    },
     FuelClient,
 };
-use fuel_core_types::fuel_tx::*;
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::*,
+};
 use rand::{
     prelude::StdRng,
+    Rng,
     SeedableRng,
 };
+use std::collections::HashSet;
 
 mod coin {
     use super::*;
@@ -707,6 +720,130 @@ mod all_coins {
     }
 }
 
+async fn all_coins_of_asset(client: &FuelClient, asset_id: &AssetId) -> Vec<Coin> {
+    let mut coins = vec![];
+    let mut cursor = None;
+    loop {
+        let page = client.all_coins(asset_id, cursor, 2).await.unwrap();
+        coins.extend(page.results);
+        if !page.has_next_page {
+            break
+        }
+        cursor = page.cursor;
+    }
+    coins
+}
+
+async fn owned_coins_of_asset(
+    client: &FuelClient,
+    owners: &[Address],
+    asset_id: &AssetId,
+) -> Vec<Coin> {
+    let mut coins = vec![];
+    for owner in owners {
+        let request = PaginationRequest {
+            cursor: None,
+            results: 100,
+            direction: PageDirection::Forward,
+        };
+        let page = client.coins(owner, Some(asset_id), request).await.unwrap();
+        coins.extend(page.results);
+    }
+    coins
+}
+
+#[tokio::test]
+async fn all_coins_stay_consistent_after_transfer() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let secret_key = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret_key.public_key());
+    let other_owner: Address = rng.gen();
+    let recipient: Address = rng.gen();
+    let asset_id_a: AssetId = rng.gen();
+    let asset_id_b: AssetId = rng.gen();
+
+    // Given
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coins = vec![
+        (owner, 50, asset_id_a),
+        (owner, 100, asset_id_a),
+        (owner, 150, asset_id_a),
+        (other_owner, 200, asset_id_a),
+        (owner, 100, asset_id_b),
+    ]
+    .into_iter()
+    .map(|(owner, amount, asset_id)| CoinConfig {
+        owner,
+        amount,
+        asset_id,
+        ..coin_generator.generate()
+    })
+    .collect();
+    let state_config = StateConfig {
+        coins,
+        ..Default::default()
+    };
+    let config = Config {
+        snapshot_reader: SnapshotReader::local_testnet().with_state_config(state_config),
+        ..Config::local_node()
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let owners = [owner, other_owner, recipient];
+    let spent_coins = owned_coins_of_asset(&client, &[owner], &asset_id_a).await;
+    assert_eq!(all_coins_of_asset(&client, &asset_id_a).await.len(), 4);
+
+    // When
+    let mut script = TransactionBuilder::script(vec![], vec![]);
+    for coin in &spent_coins {
+        script.add_unsigned_coin_input(
+            secret_key,
+            coin.utxo_id,
+            coin.amount,
+            coin.asset_id,
+            Default::default(),
+        );
+    }
+    script.add_output(Output::coin(recipient, 120, asset_id_a));
+    script.add_output(Output::change(owner, 0, asset_id_a));
+    let tx = script.finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // Then
+    let all_coins = all_coins_of_asset(&client, &asset_id_a).await;
+    let owned_coins = owned_coins_of_asset(&client, &owners, &asset_id_a).await;
+    assert_eq!(all_coins.len(), owned_coins.len());
+    let as_set = |coins: &[Coin]| {
+        coins
+            .iter()
+            .map(|coin| (coin.utxo_id, coin.owner, coin.amount))
+            .collect::<HashSet<_>>()
+    };
+    assert_eq!(as_set(&all_coins), as_set(&owned_coins));
+    let mut amounts = all_coins.iter().map(|coin| coin.amount).collect::<Vec<_>>();
+    amounts.sort();
+    assert_eq!(amounts, vec![120, 180, 200]);
+    assert!(spent_coins
+        .iter()
+        .all(|spent| all_coins.iter().all(|coin| coin.utxo_id != spent.utxo_id)));
+    assert_eq!(all_coins_of_asset(&client, &asset_id_b).await.len(), 1);
+}
+
+#[tokio::test]
+async fn all_coins_page_above_the_limit_is_rejected() {
+    let context = empty_setup().await;
+
+    // When
+    let result = context
+        .client
+        .all_coins(&AssetId::default(), None, 1001)
+        .await;
+
+    // Then
+    let err = result.expect_err("The page above the limit should be rejected");
+    assert!(err.to_string().contains("exceeds the maximum"), "{err}");
+}
+
 async fn empty_setup() -> TestContext {
     // setup config
     let config = Config {
@@ -772,6 +909,45 @@ async fn coins_to_spend_error_duplicate_asset_query(
     );
 }
 
+#[tokio::test]
+async fn coins_to_spend_error_too_many_assets() {
+    let owner = Address::from([5; 32]);
+
+    // Given
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coins = (0..10u8)
+        .map(|i| CoinConfig {
+            owner,
+            amount: 100,
+            asset_id: AssetId::new([i; 32]),
+            ..coin_generator.generate()
+        })
+        .collect();
+    let state_config = StateConfig {
+        coins,
+        ..Default::default()
+    };
+    let mut config = Config {
+        snapshot_reader: SnapshotReader::local_testnet().with_state_config(state_config),
+        ..Config::local_node()
+    };
+    config.api_max_assets_per_query = 5;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let query_per_asset = (0..10u8)
+        .map(|i| (AssetId::new([i; 32]), 1, None))
+        .collect();
+    let result = client.coins_to_spend(&owner, query_per_asset, None).await;
+
+    // Then
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        CoinsQueryError::TooManyAssets { max: 5 }.to_str_error_string()
+    );
+}
+
 trait ToStdErrorString {
     fn to_str_error_string(self) -> String;
 }
@@ -223,6 +223,131 @@ async fn test_5_contract_balances(
     }
 }
 
+#[rstest]
+#[tokio::test]
+async fn contract_storage_slots_returns_genesis_state(
+    #[values(PageDirection::Forward, PageDirection::Backward)] direction: PageDirection,
+) {
+    use fuel_core::chain_config::ContractStateConfig;
+
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    let states: Vec<_> = (1..=5)
+        .map(|i| ContractStateConfig {
+            key: key(i),
+            value: vec![i; 32],
+        })
+        .collect();
+    let expected_states = states.clone();
+    test_builder.contracts.get_mut(&contract_id).unwrap().states = states;
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // When
+    let first_page = client
+        .contract_storage_slots(
+            &contract_id,
+            PaginationRequest {
+                cursor: None,
+                results: 3,
+                direction,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = client
+        .contract_storage_slots(
+            &contract_id,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 3,
+                direction,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let mut slots: Vec<_> = first_page
+        .results
+        .into_iter()
+        .chain(second_page.results)
+        .map(|slot| ContractStateConfig {
+            key: slot.key,
+            value: slot.value,
+        })
+        .collect();
+    if direction == PageDirection::Backward {
+        slots.reverse();
+    }
+    assert!(first_page.has_next_page);
+    assert!(!second_page.has_next_page);
+    assert_eq!(slots, expected_states);
+}
+
+#[tokio::test]
+async fn contract_storage_slot_returns_genesis_value() {
+    use fuel_core::chain_config::ContractStateConfig;
+
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    test_builder.contracts.get_mut(&contract_id).unwrap().states =
+        vec![ContractStateConfig {
+            key: key(1),
+            value: vec![1; 32],
+        }];
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // When
+    let existing = client
+        .contract_storage_slot(&contract_id, &key(1))
+        .await
+        .unwrap();
+    let missing = client
+        .contract_storage_slot(&contract_id, &key(2))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(existing.unwrap().value, vec![1; 32]);
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+async fn contract_storage_slots_rejects_too_large_pages() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let result = client
+        .contract_storage_slots(
+            &contract_id,
+            PaginationRequest {
+                cursor: None,
+                results: 1001,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+}
+
 fn key(i: u8) -> Bytes32 {
     Bytes32::new(
         [0u8; 31]
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use fuel_core::service::{
     Config,
     FuelService,
@@ -85,3 +87,179 @@ async fn reset() {
     let result = client.end_session(id).await.unwrap();
     assert!(result);
 }
+
+mod transaction_trace {
+    use super::*;
+    use cynic::QueryBuilder;
+    use fuel_core_client::client::schema::{
+        schema,
+        Bytes,
+        HexString,
+        U64,
+    };
+    use fuel_core_types::{
+        fuel_tx::{
+            Finalizable,
+            Receipt,
+            Transaction,
+            TransactionBuilder,
+        },
+        fuel_types::canonical::Serialize,
+    };
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct TransactionTraceArgs {
+        pub tx: HexString,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "TransactionTraceArgs"
+    )]
+    pub struct TransactionTraceQuery {
+        #[arguments(tx: $tx)]
+        pub transaction_trace: TransactionTrace,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "../crates/client/assets/schema.sdl")]
+    pub struct TransactionTrace {
+        pub steps: Vec<TraceStep>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "../crates/client/assets/schema.sdl")]
+    pub struct TraceStep {
+        pub pc: U64,
+        pub opcode: String,
+        pub registers: Option<Vec<U64>>,
+    }
+
+    fn script_tx() -> Transaction {
+        let script = vec![
+            op::addi(0x10, RegId::ZERO, 0xca),
+            op::addi(0x11, RegId::ZERO, 0xba),
+            op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ];
+        TransactionBuilder::script(script.into_iter().collect(), vec![])
+            .script_gas_limit(1_000_000)
+            .add_random_fee_input()
+            .finalize_as_transaction()
+    }
+
+    async fn trace(
+        client: &FuelClient,
+        tx: &Transaction,
+    ) -> std::io::Result<TransactionTrace> {
+        let query = TransactionTraceQuery::build(TransactionTraceArgs {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        client.query(query).await.map(|r| r.transaction_trace)
+    }
+
+    #[tokio::test]
+    async fn transaction_trace__contains_executed_opcodes() {
+        let mut config = Config::local_node();
+        config.vm.backtrace = true;
+        let srv = FuelService::new_node(config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // Given
+        let tx = script_tx();
+
+        // When
+        let trace = trace(&client, &tx).await.unwrap();
+
+        // Then
+        let opcodes: Vec<_> = trace
+            .steps
+            .iter()
+            .map(|step| step.opcode.as_str())
+            .collect();
+        assert_eq!(opcodes, vec!["ADDI", "ADDI", "LOG", "RET"]);
+        let ret = trace.steps.last().unwrap();
+        let registers = ret
+            .registers
+            .as_ref()
+            .expect("Registers of `RET` are captured");
+        assert_eq!(registers[0x10].0, 0xca);
+        assert_eq!(registers[0x11].0, 0xba);
+        assert!(trace.steps[0].registers.is_none());
+    }
+
+    #[tokio::test]
+    async fn transaction_trace__fails_without_backtrace() {
+        let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // Given
+        let tx = script_tx();
+
+        // When
+        let result = trace(&client, &tx).await;
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn dry_run__gas_breakdown_sums_to_script_gas_used() {
+        let mut config = Config::local_node();
+        config.vm.backtrace = true;
+        let srv = FuelService::new_node(config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // Given
+        let tx = script_tx();
+
+        // When
+        let breakdowns = client.dry_run_gas_breakdown(&[tx.clone()]).await.unwrap();
+
+        // Then
+        let breakdown = breakdowns[0]
+            .as_ref()
+            .expect("The breakdown is available with backtrace");
+        let mut opcodes: Vec<_> = breakdown
+            .iter()
+            .map(|entry| entry.opcode.as_str())
+            .collect();
+        opcodes.sort();
+        assert_eq!(opcodes, vec!["ADDI", "LOG", "RET"]);
+        let addi = breakdown
+            .iter()
+            .find(|entry| entry.opcode == "ADDI")
+            .unwrap();
+        assert_eq!(addi.count, 2);
+
+        let statuses = client.dry_run(&[tx]).await.unwrap();
+        let gas_used = statuses[0]
+            .result
+            .receipts()
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .expect("The script result is present");
+        let breakdown_gas: u64 = breakdown.iter().map(|entry| entry.gas).sum();
+        assert_eq!(breakdown_gas, gas_used);
+    }
+
+    #[tokio::test]
+    async fn dry_run__no_gas_breakdown_without_backtrace() {
+        let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // Given
+        let tx = script_tx();
+
+        // When
+        let breakdowns = client.dry_run_gas_breakdown(&[tx]).await.unwrap();
+
+        // Then
+        assert!(breakdowns[0].is_none());
+    }
+}
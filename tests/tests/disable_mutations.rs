@@ -0,0 +1,63 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::Transaction;
+
+async fn read_only_node() -> (FuelService, FuelClient) {
+    let mut config = Config::local_node();
+    config.api_disable_mutations = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    (srv, client)
+}
+
+#[tokio::test]
+async fn submit_is_rejected_when_mutations_are_disabled() {
+    // Given
+    let (_srv, client) = read_only_node().await;
+    let tx = Transaction::default_test_tx();
+
+    // When
+    let result = client.submit(&tx).await;
+
+    // Then
+    let error = result.expect_err("The submission should be rejected");
+    assert!(
+        error.to_string().contains("Mutations are disabled"),
+        "{error}"
+    );
+}
+
+#[tokio::test]
+async fn submit_and_await_is_rejected_when_mutations_are_disabled() {
+    // Given
+    let (_srv, client) = read_only_node().await;
+    let tx = Transaction::default_test_tx();
+
+    // When
+    let result = client.submit_and_await_commit(&tx).await;
+
+    // Then
+    let error = result.expect_err("The submission should be rejected");
+    assert!(
+        error
+            .to_string()
+            .contains("Transaction submissions are disabled"),
+        "{error}"
+    );
+}
+
+#[tokio::test]
+async fn queries_work_when_mutations_are_disabled() {
+    // Given
+    let (_srv, client) = read_only_node().await;
+
+    // When
+    let result = client.chain_info().await;
+
+    // Then
+    let chain_info = result.expect("Queries should be allowed");
+    assert_eq!(chain_info.latest_block.header.height, 0);
+}
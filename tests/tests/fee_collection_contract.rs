@@ -28,6 +28,7 @@ use fuel_core_types::{
         Finalizable,
         Input,
         Output,
+        Transaction,
         TransactionBuilder,
         Witness,
     },
@@ -264,6 +265,45 @@ async fn no_fees_collected_yet() {
     assert_eq!(ctx.client.balance(&ctx.address, None).await.unwrap(), 0);
 }
 
+#[tokio::test]
+async fn produced_block_mints_fees_to_the_coinbase_recipient() {
+    let rng = &mut StdRng::seed_from_u64(0);
+
+    let ctx = setup(rng).await;
+
+    // When
+    make_block_with_fee(rng, &ctx).await;
+
+    // Then
+    let block = ctx.client.chain_info().await.unwrap().latest_block;
+    let mint_id = block
+        .transactions
+        .last()
+        .expect("The block should contain the mint transaction");
+    let mint = ctx
+        .client
+        .transaction(mint_id)
+        .await
+        .unwrap()
+        .expect("The mint transaction should exist")
+        .transaction;
+    let Transaction::Mint(mint) = mint else {
+        panic!("The last transaction of the block should be the mint")
+    };
+    assert_eq!(mint.input_contract().contract_id, ctx.contract_id);
+    assert!(*mint.mint_amount() > 0);
+}
+
+#[tokio::test]
+async fn zero_coinbase_recipient_is_rejected_at_startup() {
+    let mut config = Config::local_node();
+    config.block_producer.coinbase_recipient = Some(ContractId::zeroed());
+
+    let result = FuelService::new_node(config).await;
+
+    assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn missing_variable_output() {
     let rng = &mut StdRng::seed_from_u64(0);
@@ -7,6 +7,7 @@ use fuel_core::{
         StateConfig,
     },
     service::{
+        config::Trigger,
         Config,
         FuelService,
     },
@@ -34,7 +35,10 @@ use fuel_core_types::{
         UtxoId,
     },
 };
-use rand::prelude::StdRng;
+use rand::{
+    prelude::StdRng,
+    Rng,
+};
 
 async fn setup_service_with_coin(
     owner: Address,
@@ -146,3 +150,279 @@ async fn estimate_gas_price__should_be_static() {
     let actual = u64::from(gas_price);
     assert_eq!(expected, actual);
 }
+
+#[tokio::test]
+async fn required_gas_price__is_static_floor_when_txpool_has_free_space() {
+    // given
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let required = client.required_gas_price().await.unwrap();
+
+    // then
+    assert_eq!(u64::from(required.gas_price), node_config.static_gas_price);
+    assert_eq!(u64::from(required.min_tip), 0);
+}
+
+#[tokio::test]
+async fn required_gas_price__rises_above_static_floor_when_txpool_is_full() {
+    // given
+    let static_gas_price = 2;
+    let mut node_config = Config::local_node();
+    node_config.static_gas_price = static_gas_price;
+    node_config.block_production = Trigger::Never;
+    node_config.txpool.max_tx = 1;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut rng = StdRng::seed_from_u64(2322);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .tip(5)
+        .max_fee_limit(1000)
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            rng.gen(),
+            1000,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .finalize_as_transaction();
+    client.submit(&tx).await.unwrap();
+
+    // when
+    let required = client.required_gas_price().await.unwrap();
+
+    // then
+    assert_eq!(u64::from(required.gas_price), static_gas_price);
+    assert_eq!(u64::from(required.min_tip), 6);
+}
+
+mod block_gas_price {
+    use super::*;
+    use cynic::QueryBuilder;
+    use fuel_core_client::client::schema::{
+        schema,
+        ConnectionArgs,
+        U32,
+        U64,
+    };
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct BlockGasPriceArgs {
+        pub height: U32,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "BlockGasPriceArgs"
+    )]
+    pub struct BlockGasPriceQuery {
+        #[arguments(height: $height)]
+        pub block_gas_price: Option<BlockGasPrice>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "ConnectionArgs"
+    )]
+    pub struct BlockGasPricesQuery {
+        #[arguments(after: $after, before: $before, first: $first, last: $last)]
+        pub block_gas_prices: BlockGasPriceConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "../crates/client/assets/schema.sdl")]
+    pub struct BlockGasPriceConnection {
+        pub nodes: Vec<BlockGasPrice>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "../crates/client/assets/schema.sdl")]
+    pub struct BlockGasPrice {
+        pub gas_price: U64,
+        pub block_height: U32,
+    }
+
+    #[tokio::test]
+    async fn block_gas_price__returns_gas_price_used_by_produced_blocks() {
+        // given
+        let static_gas_price = 3;
+        let mut node_config = Config::local_node();
+        node_config.static_gas_price = static_gas_price;
+        let srv = FuelService::new_node(node_config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        client.produce_blocks(3, None).await.unwrap();
+
+        // when
+        let mut gas_prices = vec![];
+        for height in 1..=3u32 {
+            let query = BlockGasPriceQuery::build(BlockGasPriceArgs {
+                height: height.into(),
+            });
+            let gas_price = client
+                .query(query)
+                .await
+                .unwrap()
+                .block_gas_price
+                .expect("The gas price of the produced block should be indexed");
+            gas_prices.push((gas_price.block_height.0, gas_price.gas_price.0));
+        }
+
+        // then
+        assert_eq!(
+            gas_prices,
+            vec![
+                (1, static_gas_price),
+                (2, static_gas_price),
+                (3, static_gas_price)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn block_gas_price__genesis_block_has_no_gas_price() {
+        // given
+        let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // when
+        let query = BlockGasPriceQuery::build(BlockGasPriceArgs { height: 0.into() });
+        let result = client.query(query).await.unwrap();
+
+        // then
+        assert!(result.block_gas_price.is_none());
+    }
+
+    #[tokio::test]
+    async fn block_gas_prices__returns_gas_prices_in_the_range() {
+        // given
+        let static_gas_price = 5;
+        let mut node_config = Config::local_node();
+        node_config.static_gas_price = static_gas_price;
+        let srv = FuelService::new_node(node_config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        client.produce_blocks(4, None).await.unwrap();
+
+        // when
+        let query = BlockGasPricesQuery::build(ConnectionArgs {
+            last: Some(2),
+            ..Default::default()
+        });
+        let gas_prices: Vec<_> = client
+            .query(query)
+            .await
+            .unwrap()
+            .block_gas_prices
+            .nodes
+            .into_iter()
+            .map(|gas_price| (gas_price.block_height.0, gas_price.gas_price.0))
+            .collect();
+
+        // then
+        assert_eq!(
+            gas_prices,
+            vec![(4, static_gas_price), (3, static_gas_price)]
+        );
+    }
+}
+
+mod gas_price_histogram {
+    use super::*;
+    use cynic::QueryBuilder;
+    use fuel_core_client::client::schema::{
+        schema,
+        U32,
+        U64,
+    };
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct GasPriceHistogramArgs {
+        pub bounds: Vec<U64>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "GasPriceHistogramArgs"
+    )]
+    pub struct GasPriceHistogramQuery {
+        #[arguments(bounds: $bounds)]
+        pub gas_price_histogram: Vec<GasPriceBucket>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "../crates/client/assets/schema.sdl")]
+    pub struct GasPriceBucket {
+        pub from: U64,
+        pub to: Option<U64>,
+        pub count: U32,
+    }
+
+    #[tokio::test]
+    async fn gas_price_histogram__counts_pending_transactions_per_bucket() {
+        // given
+        let mut node_config = Config::local_node();
+        node_config.block_production = Trigger::Never;
+        let srv = FuelService::new_node(node_config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        let mut rng = StdRng::seed_from_u64(2322);
+        for tip in [0, 5, 10, 20] {
+            let tx = TransactionBuilder::script(vec![], vec![])
+                .tip(tip)
+                .max_fee_limit(1000)
+                .add_unsigned_coin_input(
+                    SecretKey::random(&mut rng),
+                    rng.gen(),
+                    1000,
+                    AssetId::BASE,
+                    Default::default(),
+                )
+                .finalize_as_transaction();
+            client.submit(&tx).await.unwrap();
+        }
+
+        // when
+        let query = GasPriceHistogramQuery::build(GasPriceHistogramArgs {
+            bounds: vec![5u64.into(), 15u64.into()],
+        });
+        let buckets: Vec<_> = client
+            .query(query)
+            .await
+            .unwrap()
+            .gas_price_histogram
+            .into_iter()
+            .map(|bucket| (bucket.from.0, bucket.to.map(|to| to.0), bucket.count.0))
+            .collect();
+
+        // then
+        assert_eq!(
+            buckets,
+            vec![(0, Some(5), 1), (5, Some(15), 2), (15, None, 1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn gas_price_histogram__rejects_unordered_bounds() {
+        // given
+        let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // when
+        let query = GasPriceHistogramQuery::build(GasPriceHistogramArgs {
+            bounds: vec![15u64.into(), 5u64.into()],
+        });
+        let result = client.query(query).await;
+
+        // then
+        let err = result.expect_err("Unordered bounds should be rejected");
+        assert!(err.to_string().contains("strictly ascending"));
+    }
+}
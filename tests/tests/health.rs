@@ -1,6 +1,11 @@
 use fuel_core::{
     combined_database::CombinedDatabase,
     database::Database,
+    fuel_core_graphql_api::{
+        CorsConfig,
+        RateLimitConfig,
+        WebSocketKeepAlive,
+    },
     service::{
         Config,
         FuelService,
@@ -9,6 +14,34 @@ use fuel_core::{
     types::fuel_tx::Transaction,
 };
 use fuel_core_client::client::FuelClient;
+use reqwest::{
+    header::{
+        ACCESS_CONTROL_ALLOW_METHODS,
+        ACCESS_CONTROL_ALLOW_ORIGIN,
+        ACCESS_CONTROL_REQUEST_HEADERS,
+        ACCESS_CONTROL_REQUEST_METHOD,
+        ORIGIN,
+        RETRY_AFTER,
+    },
+    Method,
+    Response,
+    StatusCode,
+};
+use std::{
+    net::{
+        IpAddr,
+        Ipv4Addr,
+    },
+    num::NonZeroU32,
+    time::Duration,
+};
+use tokio::{
+    io::{
+        AsyncReadExt,
+        AsyncWriteExt,
+    },
+    net::TcpStream,
+};
 
 #[tokio::test]
 async fn health() {
@@ -21,6 +54,239 @@ async fn health() {
     assert!(health);
 }
 
+#[tokio::test]
+async fn idle_connection_is_closed_after_idle_timeout() {
+    // Given
+    let mut config = Config::local_node();
+    config.api_idle_timeout = Some(Duration::from_millis(500));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let mut stream = TcpStream::connect(srv.bound_address).await.unwrap();
+    stream
+        .write_all(b"GET /v1/health HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .await
+        .unwrap();
+
+    // When
+    let mut response = vec![];
+    let result =
+        tokio::time::timeout(Duration::from_secs(10), stream.read_to_end(&mut response))
+            .await;
+
+    // Then
+    result
+        .expect("The idle connection should be closed by the node")
+        .unwrap();
+    let response = String::from_utf8_lossy(&response);
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{response}");
+}
+
+#[tokio::test]
+async fn websocket_not_answering_pings_is_closed_after_pong_timeout() {
+    // Given
+    let mut config = Config::local_node();
+    config.api_websocket_keep_alive = Some(WebSocketKeepAlive {
+        ping_interval: Duration::from_millis(200),
+        pong_timeout: Duration::from_millis(200),
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let mut stream = TcpStream::connect(srv.bound_address).await.unwrap();
+    stream
+        .write_all(
+            b"GET /v1/graphql-ws HTTP/1.1\r\nHost: localhost\r\n\
+            Connection: Upgrade\r\nUpgrade: websocket\r\n\
+            Sec-WebSocket-Version: 13\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+            Sec-WebSocket-Protocol: graphql-transport-ws\r\n\r\n",
+        )
+        .await
+        .unwrap();
+
+    // When
+    // The client never reads the pings, so it never answers them.
+    let mut response = vec![];
+    let result =
+        tokio::time::timeout(Duration::from_secs(10), stream.read_to_end(&mut response))
+            .await;
+
+    // Then
+    result
+        .expect("The idle WebSocket should be closed by the node")
+        .unwrap();
+    let headers_end = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .expect("The response should contain the headers");
+    let (headers, frames) = response.split_at(headers_end + 4);
+    let headers = String::from_utf8_lossy(headers);
+    assert!(
+        headers.starts_with("HTTP/1.1 101 Switching Protocols"),
+        "{headers}"
+    );
+    // The first byte of the frame is the FIN bit with the opcode,
+    // `0x89` for the ping and `0x88` for the close.
+    assert_eq!(frames.first(), Some(&0x89));
+    assert!(frames.contains(&0x88), "{frames:?}");
+}
+
+#[tokio::test]
+async fn in_flight_request_completes_within_shutdown_grace_period() {
+    // Given
+    let mut config = Config::local_node();
+    config.api_shutdown_grace_period = Duration::from_secs(10);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let body = br#"{"query":"{ chain { name } }"}"#;
+    let (first_half, second_half) = body.split_at(body.len() / 2);
+    let mut stream = TcpStream::connect(srv.bound_address).await.unwrap();
+    let headers = format!(
+        "POST /v1/graphql HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+        Content-Type: application/json\r\nContent-Length: {}\r\n\r\n",
+        body.len()
+    );
+    stream.write_all(headers.as_bytes()).await.unwrap();
+    stream.write_all(first_half).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // When
+    let finish_request = async {
+        // The request is finished after the node started the shutdown.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        stream.write_all(second_half).await.unwrap();
+        let mut response = vec![];
+        stream.read_to_end(&mut response).await.unwrap();
+        response
+    };
+    let (stopped, response) = tokio::join!(srv.stop_and_await(), finish_request);
+
+    // Then
+    stopped.unwrap();
+    let response = String::from_utf8_lossy(&response);
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{response}");
+    assert!(response.contains(r#""chain""#), "{response}");
+}
+
+async fn node_with_rate_limit(allowlist: Vec<IpAddr>) -> FuelService {
+    let mut config = Config::local_node();
+    config.api_rate_limit = Some(RateLimitConfig {
+        requests_per_second: NonZeroU32::new(1).unwrap(),
+        burst: NonZeroU32::new(2).unwrap(),
+        allowlist,
+    });
+    FuelService::new_node(config).await.unwrap()
+}
+
+async fn health_statuses(srv: &FuelService, requests: usize) -> Vec<StatusCode> {
+    let url = format!("http://{}/v1/health", srv.bound_address);
+    let mut statuses = vec![];
+    for _ in 0..requests {
+        let response = reqwest::get(&url).await.unwrap();
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            assert!(response.headers().contains_key(RETRY_AFTER));
+        }
+        statuses.push(response.status());
+    }
+    statuses
+}
+
+#[tokio::test]
+async fn rate_limit_throttles_client_exceeding_the_rate() {
+    // Given
+    let srv = node_with_rate_limit(vec![]).await;
+
+    // When
+    let statuses = health_statuses(&srv, 5).await;
+
+    // Then
+    assert_eq!(&statuses[..2], &[StatusCode::OK, StatusCode::OK]);
+    assert!(statuses[2..].contains(&StatusCode::TOO_MANY_REQUESTS));
+}
+
+#[tokio::test]
+async fn rate_limit_does_not_throttle_allowlisted_client() {
+    // Given
+    let srv = node_with_rate_limit(vec![Ipv4Addr::LOCALHOST.into()]).await;
+
+    // When
+    let statuses = health_statuses(&srv, 5).await;
+
+    // Then
+    assert!(statuses.iter().all(|status| *status == StatusCode::OK));
+}
+
+const ALLOWED_ORIGIN: &str = "https://app.fuel.network";
+
+async fn node_with_cors() -> FuelService {
+    let mut config = Config::local_node();
+    config.api_cors = CorsConfig {
+        allowed_origins: vec![ALLOWED_ORIGIN.to_string()],
+        ..Default::default()
+    };
+    FuelService::new_node(config).await.unwrap()
+}
+
+async fn graphql_preflight(srv: &FuelService, origin: &str) -> Response {
+    reqwest::Client::new()
+        .request(
+            Method::OPTIONS,
+            format!("http://{}/v1/graphql", srv.bound_address),
+        )
+        .header(ORIGIN, origin)
+        .header(ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .header(ACCESS_CONTROL_REQUEST_HEADERS, "content-type")
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn cors_preflight_from_allowed_origin_is_accepted() {
+    // Given
+    let srv = node_with_cors().await;
+
+    // When
+    let response = graphql_preflight(&srv, ALLOWED_ORIGIN).await;
+
+    // Then
+    let headers = response.headers();
+    assert_eq!(
+        headers.get(ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+        ALLOWED_ORIGIN
+    );
+    let methods = headers
+        .get(ACCESS_CONTROL_ALLOW_METHODS)
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(methods.contains("POST"), "{methods}");
+}
+
+#[tokio::test]
+async fn cors_preflight_from_disallowed_origin_is_rejected() {
+    // Given
+    let srv = node_with_cors().await;
+
+    // When
+    let response = graphql_preflight(&srv, "https://evil.example").await;
+
+    // Then
+    assert!(!response.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN));
+}
+
+#[tokio::test]
+async fn cors_credentials_with_wildcard_origin_fail_to_start() {
+    // Given
+    let mut config = Config::local_node();
+    config.api_cors = CorsConfig {
+        allowed_origins: vec!["*".to_string()],
+        allow_credentials: true,
+        ..Default::default()
+    };
+
+    // When
+    let result = FuelService::new_node(config).await;
+
+    // Then
+    assert!(result.is_err());
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
@@ -29,7 +295,8 @@ async fn can_restart_node() {
 
     // start node once
     {
-        let database = Database::open_rocksdb(tmp_dir.path(), None).unwrap();
+        let database =
+            Database::open_rocksdb(tmp_dir.path(), None, Default::default()).unwrap();
         let first_startup = FuelService::from_database(database, Config::local_node())
             .await
             .unwrap();
@@ -37,7 +304,8 @@ async fn can_restart_node() {
     }
 
     {
-        let database = Database::open_rocksdb(tmp_dir.path(), None).unwrap();
+        let database =
+            Database::open_rocksdb(tmp_dir.path(), None, Default::default()).unwrap();
         let _second_startup = FuelService::from_database(database, Config::local_node())
             .await
             .unwrap();
@@ -51,7 +319,8 @@ async fn can_restart_node_with_transactions() {
 
     {
         // Given
-        let database = CombinedDatabase::open(tmp_dir.path(), capacity).unwrap();
+        let database =
+            CombinedDatabase::open(tmp_dir.path(), capacity, Default::default()).unwrap();
         let service = FuelService::from_combined_database(database, Config::local_node())
             .await
             .unwrap();
@@ -68,7 +337,8 @@ async fn can_restart_node_with_transactions() {
 
     {
         // When
-        let database = CombinedDatabase::open(tmp_dir.path(), capacity).unwrap();
+        let database =
+            CombinedDatabase::open(tmp_dir.path(), capacity, Default::default()).unwrap();
         let service = FuelService::from_combined_database(database, Config::local_node())
             .await
             .unwrap();
@@ -0,0 +1,51 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use reqwest::header::CONTENT_TYPE;
+use serde_json::{
+    json,
+    Value,
+};
+
+const INTROSPECTION_QUERY: &str = "{ __schema { queryType { name } } }";
+
+async fn introspect(enable_introspection: bool) -> Value {
+    let mut config = Config::local_node();
+    config.api_enable_introspection = enable_introspection;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let body = reqwest::Client::new()
+        .post(format!("http://{}/v1/graphql", srv.bound_address))
+        .header(CONTENT_TYPE, "application/json")
+        .body(json!({ "query": INTROSPECTION_QUERY }).to_string())
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+
+    serde_json::from_str(&body).unwrap()
+}
+
+#[tokio::test]
+async fn introspection_works_when_enabled() {
+    // When
+    let response = introspect(true).await;
+
+    // Then
+    assert!(response.get("errors").is_none());
+    assert_eq!(response["data"]["__schema"]["queryType"]["name"], "Query");
+}
+
+#[tokio::test]
+async fn introspection_is_rejected_when_disabled() {
+    // When
+    let response = introspect(false).await;
+
+    // Then
+    let errors = response["errors"].as_array().expect("Expected errors");
+    assert!(!errors.is_empty());
+    assert!(response["data"].is_null());
+}
@@ -1,6 +1,7 @@
 #![deny(unused_must_use)]
 #![deny(warnings)]
 
+mod assets;
 mod balances;
 mod blocks;
 mod chain;
@@ -10,14 +11,18 @@ mod contract;
 mod dap;
 mod debugger;
 mod deployment;
+mod disable_mutations;
 mod fee_collection_contract;
 
 mod gas_price;
 mod health;
 mod helpers;
+mod introspection;
 mod messages;
 mod metrics;
 mod node_info;
+#[cfg(feature = "p2p")]
+mod peer_connections;
 mod poa;
 #[cfg(feature = "relayer")]
 mod relayer;
@@ -9,6 +9,11 @@ use fuel_core_types::{
     fuel_asm::*,
     fuel_tx::*,
 };
+use reqwest::StatusCode;
+use std::net::{
+    Ipv4Addr,
+    SocketAddr,
+};
 use tempfile::TempDir;
 
 #[tokio::test]
@@ -62,3 +67,35 @@ async fn test_metrics_endpoint() {
     // Gt check exists because testing can be weird with multiple instances running
     assert!(categories.len() >= 16);
 }
+
+#[tokio::test]
+async fn metrics_are_served_only_on_the_dedicated_port() {
+    // Given
+    let mut config = Config::local_node();
+    config.metrics_addr = Some(SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let metrics_address = srv
+        .shared
+        .metrics
+        .as_ref()
+        .expect("The metrics listener should be enabled")
+        .bound_address;
+
+    // When
+    let metrics_response = reqwest::get(format!("http://{}/v1/metrics", metrics_address))
+        .await
+        .unwrap();
+    let api_response = reqwest::get(format!("http://{}/v1/metrics", srv.bound_address))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(metrics_response.status(), StatusCode::OK);
+    assert!(!metrics_response.text().await.unwrap().is_empty());
+    assert_eq!(api_response.status(), StatusCode::NOT_FOUND);
+
+    // The shutdown of the node stops the metrics listener.
+    srv.stop_and_await().await.unwrap();
+    let result = reqwest::get(format!("http://{}/v1/metrics", metrics_address)).await;
+    assert!(result.is_err());
+}
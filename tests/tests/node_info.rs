@@ -103,3 +103,42 @@ async fn test_peer_info() {
         .unwrap();
     assert!(time_since_heartbeat < Duration::from_secs(10));
 }
+
+#[cfg(feature = "p2p")]
+#[tokio::test]
+async fn node_info_reports_sync_estimate_and_rejected_blocks() {
+    use reqwest::header::CONTENT_TYPE;
+    use serde_json::{
+        json,
+        Value,
+    };
+
+    // Given
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    assert!(srv.shared.sync.is_some());
+
+    // When
+    let query = "{ nodeInfo { syncEstimate { synced remainingBlocks } rejectedBlocks { height } } }";
+    let body = reqwest::Client::new()
+        .post(format!("http://{}/v1/graphql", srv.bound_address))
+        .header(CONTENT_TYPE, "application/json")
+        .body(json!({ "query": query }).to_string())
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    let response: Value = serde_json::from_str(&body).unwrap();
+
+    // Then
+    // The node without peers has all blocks known to them.
+    assert_eq!(
+        response["data"]["nodeInfo"],
+        json!({
+            "syncEstimate": { "synced": true, "remainingBlocks": "0" },
+            "rejectedBlocks": [],
+        })
+    );
+}
@@ -0,0 +1,85 @@
+use fuel_core::p2p_test_helpers::{
+    make_config,
+    make_node,
+    make_nodes,
+    BootstrapSetup,
+    Nodes,
+    ProducerSetup,
+};
+use fuel_core_poa::Trigger;
+use fuel_core_types::{
+    fuel_tx::Input,
+    fuel_vm::SecretKey,
+    services::p2p::{
+        PeerConnectionEvent,
+        PeerId,
+    },
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::time::Duration;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn connecting_two_nodes_emits_peer_connected_event() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+
+    // Given
+    let Nodes {
+        mut producers,
+        bootstrap_nodes: bootstrap,
+        ..
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(ProducerSetup::new(secret).with_name("producer"))],
+        [],
+        None,
+    )
+    .await;
+    let producer = producers.pop().unwrap();
+    let mut peer_connections = producer
+        .node
+        .shared
+        .network
+        .as_ref()
+        .expect("The p2p is enabled")
+        .subscribe_peer_connections();
+
+    // When
+    let mut validator_config =
+        make_config("validator".to_string(), producer.config.clone());
+    validator_config.block_production = Trigger::Never;
+    validator_config.consensus_key = None;
+    validator_config.p2p.as_mut().unwrap().bootstrap_nodes =
+        bootstrap.iter().flat_map(|b| b.listeners()).collect();
+    let validator = make_node(validator_config, vec![]).await;
+
+    // Then
+    let validator_peer_id: PeerId = validator
+        .config
+        .p2p
+        .as_ref()
+        .unwrap()
+        .keypair
+        .public()
+        .to_peer_id()
+        .to_bytes()
+        .into();
+    tokio::time::timeout(Duration::from_secs(30), async {
+        loop {
+            let event = peer_connections.recv().await.unwrap();
+            if event
+                == (PeerConnectionEvent::PeerConnected {
+                    peer_id: validator_peer_id.clone(),
+                })
+            {
+                break
+            }
+        }
+    })
+    .await
+    .expect("The producer should connect to the validator");
+}
@@ -7,7 +7,11 @@ use ethers::{
     },
 };
 use fuel_core::{
-    database::Database,
+    combined_database::CombinedDatabase,
+    database::{
+        database_description::relayer::Relayer as RelayerDatabase,
+        Database,
+    },
     relayer,
     service::{
         Config,
@@ -20,11 +24,15 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        message::MessageStatus,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_poa::service::Mode;
 use fuel_core_relayer::{
+    storage::EventsHistory,
     test_helpers::{
         middleware::MockMiddleware,
         EvtToLog,
@@ -33,14 +41,17 @@ use fuel_core_relayer::{
     H160,
 };
 use fuel_core_storage::{
+    iter::IteratorOverTable,
     tables::Messages,
     StorageAsRef,
 };
 use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx::*,
     fuel_types::Nonce,
+    services::relayer::Event as RelayerEvent,
 };
 use hyper::{
     service::{
@@ -63,8 +74,10 @@ use std::{
     net::{
         Ipv4Addr,
         SocketAddr,
+        TcpListener,
     },
     sync::Arc,
+    time::Duration,
 };
 use tokio::sync::oneshot::Sender;
 
@@ -134,6 +147,252 @@ async fn relayer_can_download_logs() {
     eth_node_handle.shutdown.send(()).unwrap();
 }
 
+fn relayer_events(
+    database: &Database<RelayerDatabase>,
+) -> Vec<(DaBlockHeight, Vec<RelayerEvent>)> {
+    database
+        .iter_all::<EventsHistory>(None)
+        .map(|result| result.unwrap())
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pruned_relayer_database_is_rebuilt_from_the_da_layer() {
+    let mut config = Config::local_node();
+    config.relayer = Some(relayer::Config::default());
+    let relayer_config = config.relayer.as_mut().expect("Expected relayer config");
+    let eth_node = MockMiddleware::default();
+    let contract_address = relayer_config.eth_v2_listening_contracts[0];
+    let message = |nonce, block_number: u64| {
+        make_message_event(
+            Nonce::from(nonce),
+            block_number,
+            contract_address,
+            None,
+            None,
+            None,
+            None,
+            0,
+        )
+    };
+    let logs = vec![message(1, 3), message(2, 5)];
+    eth_node.update_data(|data| data.logs_batch = vec![logs.clone()]);
+    eth_node.update_data(|data| data.best_block.number = Some(200.into()));
+    let eth_node = Arc::new(eth_node);
+    let eth_node_handle = spawn_eth_node(eth_node).await;
+    relayer_config.relayer = Some(
+        format!("http://{}", eth_node_handle.address)
+            .as_str()
+            .try_into()
+            .unwrap(),
+    );
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let capacity = 1024 * 1024;
+
+    // Given
+    let database =
+        CombinedDatabase::open(tmp_dir.path(), capacity, Default::default()).unwrap();
+    let srv = FuelService::from_combined_database(database.clone(), config.clone())
+        .await
+        .unwrap();
+    srv.await_relayer_synced().await.unwrap();
+    let expected_events = relayer_events(database.relayer());
+    assert!(!expected_events.is_empty());
+    srv.stop_and_await().await.unwrap();
+    drop(srv);
+    drop(database);
+
+    // When
+    CombinedDatabase::prune_relayer(tmp_dir.path()).unwrap();
+    let database =
+        CombinedDatabase::open(tmp_dir.path(), capacity, Default::default()).unwrap();
+    assert!(relayer_events(database.relayer()).is_empty());
+    let srv = FuelService::from_combined_database(database.clone(), config)
+        .await
+        .unwrap();
+    srv.await_relayer_synced().await.unwrap();
+
+    // Then
+    let events = relayer_events(database.relayer());
+    assert_eq!(events, expected_events);
+    srv.stop_and_await().await.unwrap();
+    eth_node_handle.shutdown.send(()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pending_da_events_contains_messages_until_they_are_included() {
+    let mut config = Config::local_node();
+    config.relayer = Some(relayer::Config::default());
+    let relayer_config = config.relayer.as_mut().expect("Expected relayer config");
+    let eth_node = MockMiddleware::default();
+    let contract_address = relayer_config.eth_v2_listening_contracts[0];
+    let message = |nonce, block_number: u64| {
+        make_message_event(
+            Nonce::from(nonce),
+            block_number,
+            contract_address,
+            None,
+            None,
+            None,
+            None,
+            0,
+        )
+    };
+
+    // Given
+    let logs = vec![message(1, 3), message(2, 5)];
+    let expected_ids: Vec<Bytes32> = logs
+        .iter()
+        .map(|l| (*l.to_msg().message_id()).into())
+        .collect();
+    eth_node.update_data(|data| data.logs_batch = vec![logs.clone()]);
+    eth_node.update_data(|data| data.best_block.number = Some(200.into()));
+    let eth_node = Arc::new(eth_node);
+    let eth_node_handle = spawn_eth_node(eth_node).await;
+
+    relayer_config.relayer = Some(
+        format!("http://{}", eth_node_handle.address)
+            .as_str()
+            .try_into()
+            .unwrap(),
+    );
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    srv.await_relayer_synced().await.unwrap();
+
+    // When
+    let pending = client.pending_da_events().await.unwrap();
+
+    // Then
+    let pending_ids: Vec<_> = pending.iter().map(|event| event.id).collect();
+    assert_eq!(pending_ids, expected_ids);
+    assert_eq!(
+        pending
+            .iter()
+            .map(|event| event.da_height)
+            .collect::<Vec<_>>(),
+        vec![3, 5]
+    );
+
+    // When
+    srv.shared
+        .poa_adapter
+        .manually_produce_blocks(
+            None,
+            Mode::Blocks {
+                number_of_blocks: 1,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let pending = client.pending_da_events().await.unwrap();
+    assert!(pending.is_empty());
+
+    srv.stop_and_await().await.unwrap();
+    eth_node_handle.shutdown.send(()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn relayer_status_reports_synced_da_height_as_relayer_progresses() {
+    // Given
+    let mut config = Config::local_node();
+    let mut relayer_config = relayer::Config::default();
+    relayer_config.sync_minimum_duration = Duration::from_millis(100);
+    let da_deploy_height = relayer_config.da_deploy_height;
+    let eth_node = Arc::new(MockMiddleware::default());
+    eth_node.update_data(|data| data.best_block.number = Some(100.into()));
+    let eth_node_handle = spawn_eth_node(eth_node.clone()).await;
+    relayer_config.relayer = Some(
+        format!("http://{}", eth_node_handle.address)
+            .as_str()
+            .try_into()
+            .unwrap(),
+    );
+    config.relayer = Some(relayer_config);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    srv.await_relayer_synced().await.unwrap();
+
+    // When
+    let status = client.relayer_status().await.unwrap();
+
+    // Then
+    let status = status.expect("The relayer is enabled");
+    assert_eq!(status.synced_da_height, 100);
+    assert_eq!(status.latest_known_da_height, Some(100));
+    assert_eq!(status.da_deploy_height, da_deploy_height.0);
+    assert!(status.synced);
+
+    // When
+    eth_node.update_data(|data| data.best_block.number = Some(150.into()));
+    let status = tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let status = client.relayer_status().await.unwrap().unwrap();
+            if status.synced_da_height == 150 {
+                break status
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await;
+
+    // Then
+    let status = status.expect("The synced DA height should advance");
+    assert_eq!(status.latest_known_da_height, Some(150));
+    assert!(status.synced);
+    srv.stop_and_await().await.unwrap();
+    eth_node_handle.shutdown.send(()).unwrap();
+}
+
+#[tokio::test]
+async fn relayer_status_is_null_when_relayer_is_disabled() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let status = client.relayer_status().await.unwrap();
+
+    // Then
+    assert!(status.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn node_starts_with_unreachable_da_layer_and_reports_relayer_as_not_synced() {
+    // Given
+    let mut config = Config::local_node();
+    let mut relayer_config = relayer::Config::default();
+    let unreachable_port = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    relayer_config.relayer = Some(
+        format!("http://127.0.0.1:{unreachable_port}")
+            .as_str()
+            .try_into()
+            .unwrap(),
+    );
+    relayer_config.sync_minimum_duration = Duration::from_millis(100);
+    config.relayer = Some(relayer_config);
+
+    // When
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    // Then
+    let client = FuelClient::from(srv.bound_address);
+    assert!(client.health().await.unwrap());
+    client.chain_info().await.unwrap();
+
+    let relayer = srv.shared.relayer.clone().expect("The relayer is enabled");
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert!(!relayer.is_synced());
+    assert!(!relayer.is_healthy());
+    srv.stop_and_await().await.unwrap();
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn messages_are_spendable_after_relayer_is_synced() {
     let mut rng = StdRng::seed_from_u64(1234);
@@ -252,6 +511,82 @@ async fn messages_are_spendable_after_relayer_is_synced() {
     eth_node_handle.shutdown.send(()).unwrap();
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn message_status_follows_relayed_message_from_import_to_spending() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let mut config = Config::local_node();
+    config.relayer = Some(relayer::Config::default());
+    let relayer_config = config.relayer.as_mut().expect("Expected relayer config");
+    let eth_node = MockMiddleware::default();
+    let contract_address = relayer_config.eth_v2_listening_contracts[0];
+
+    // Given
+    let secret_key: SecretKey = SecretKey::random(&mut rng);
+    let recipient = Input::owner(&secret_key.public_key());
+    let sender = Address::zeroed();
+    let amount = 100;
+    let nonce = Nonce::from(3u64);
+    let logs = vec![make_message_event(
+        nonce,
+        5,
+        contract_address,
+        Some(sender.into()),
+        Some(recipient.into()),
+        Some(amount),
+        None,
+        0,
+    )];
+    eth_node.update_data(|data| data.logs_batch = vec![logs]);
+    eth_node.update_data(|data| data.best_block.number = Some(200.into()));
+    let eth_node_handle = spawn_eth_node(Arc::new(eth_node)).await;
+    relayer_config.relayer = Some(
+        format!("http://{}", eth_node_handle.address)
+            .as_str()
+            .try_into()
+            .unwrap(),
+    );
+    config.utxo_validation = true;
+    let srv = FuelService::from_database(Database::in_memory(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    srv.await_relayer_synced().await.unwrap();
+
+    // When
+    // The relayed message is not a part of the chain until it is imported into a block.
+    let relayed = client.message_status(&nonce).await.unwrap();
+    srv.shared
+        .poa_adapter
+        .manually_produce_blocks(
+            None,
+            Mode::Blocks {
+                number_of_blocks: 1,
+            },
+        )
+        .await
+        .unwrap();
+    let imported = client.message_status(&nonce).await.unwrap();
+    let tx = TransactionBuilder::script(vec![op::ret(0)].into_iter().collect(), vec![])
+        .script_gas_limit(10_000)
+        .add_unsigned_message_input(secret_key, sender, nonce, amount, vec![])
+        .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+        .finalize_as_transaction();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(
+        matches!(status, TransactionStatus::Success { .. }),
+        "{status:?}"
+    );
+    let spent = client.message_status(&nonce).await.unwrap();
+
+    // Then
+    assert_eq!(relayed, MessageStatus::NotFound);
+    assert_eq!(imported, MessageStatus::Unspent);
+    assert_eq!(spent, MessageStatus::Spent);
+
+    srv.stop_and_await().await.unwrap();
+    eth_node_handle.shutdown.send(()).unwrap();
+}
+
 #[allow(clippy::too_many_arguments)]
 fn make_message_event(
     nonce: Nonce,
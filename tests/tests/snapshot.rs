@@ -1,20 +1,31 @@
 use fuel_core::{
     chain_config::{
         Randomize,
+        SnapshotMetadata,
         SnapshotReader,
         StateConfig,
     },
     combined_database::CombinedDatabase,
     service::{
+        config::PeriodicSnapshotConfig,
         Config,
         FuelService,
     },
 };
+use fuel_core_client::client::FuelClient;
 use fuel_core_types::blockchain::primitives::DaBlockHeight;
 use rand::{
     rngs::StdRng,
     SeedableRng,
 };
+use std::{
+    num::{
+        NonZeroU32,
+        NonZeroUsize,
+    },
+    path::Path,
+    time::Duration,
+};
 
 #[tokio::test]
 async fn loads_snapshot() {
@@ -43,3 +54,65 @@ async fn loads_snapshot() {
     // initial state
     pretty_assertions::assert_eq!(starting_state.sorted(), stored_state);
 }
+
+async fn wait_for_snapshot(path: &Path) {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while !path.exists() {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("The snapshot should be created");
+}
+
+#[tokio::test]
+async fn periodic_snapshot_is_importable_into_fresh_database() {
+    let snapshot_dir = tempfile::tempdir().unwrap();
+    let db = CombinedDatabase::default();
+    let config = Config {
+        periodic_snapshot: Some(PeriodicSnapshotConfig {
+            interval: NonZeroU32::new(2).unwrap(),
+            directory: snapshot_dir.path().to_path_buf(),
+            retention: NonZeroUsize::new(1).unwrap(),
+        }),
+        ..Config::local_node()
+    };
+    let srv = FuelService::from_combined_database(db.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    client.produce_blocks(1, None).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+    let first_snapshot = snapshot_dir.path().join("snapshot_2");
+    wait_for_snapshot(&first_snapshot).await;
+
+    // When
+    client.produce_blocks(2, None).await.unwrap();
+    let second_snapshot = snapshot_dir.path().join("snapshot_4");
+    wait_for_snapshot(&second_snapshot).await;
+
+    // Then
+    // Only the most recent snapshot is kept.
+    assert!(!first_snapshot.exists());
+    assert!(!snapshot_dir.path().join("snapshot_1").exists());
+
+    let metadata = SnapshotMetadata::read(&second_snapshot).unwrap();
+    let fresh_db = CombinedDatabase::default();
+    let restored_config = Config {
+        snapshot_reader: SnapshotReader::open(metadata).unwrap(),
+        ..Config::local_node()
+    };
+    let _restored =
+        FuelService::from_combined_database(fresh_db.clone(), restored_config)
+            .await
+            .unwrap();
+
+    let restored_state = fresh_db.read_state_config().unwrap();
+    assert_eq!(u32::from(restored_state.block_height), 4);
+    pretty_assertions::assert_eq!(
+        db.read_state_config().unwrap().sorted(),
+        restored_state.sorted()
+    );
+}
@@ -1,7 +1,16 @@
-use crate::helpers::TestContext;
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
+use base64::prelude::{
+    Engine,
+    BASE64_STANDARD,
+};
 use fuel_core::{
+    fuel_core_graphql_api::SimulationLimitConfig,
     schema::tx::receipt::all_receipts,
     service::{
+        config::Trigger,
         Config,
         FuelService,
     },
@@ -11,7 +20,10 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        StorageAccessKey,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_poa::service::Mode;
@@ -19,7 +31,14 @@ use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::SecretKey,
     fuel_tx::*,
-    fuel_types::ChainId,
+    fuel_types::{
+        canonical::{
+            Deserialize,
+            Serialize,
+        },
+        ChainId,
+    },
+    tai64::Tai64,
 };
 use itertools::Itertools;
 use rand::{
@@ -27,7 +46,16 @@ use rand::{
     Rng,
     SeedableRng,
 };
-use std::io::ErrorKind::NotFound;
+use reqwest::header::CONTENT_TYPE;
+use serde_json::{
+    json,
+    Value,
+};
+use std::{
+    io::ErrorKind::NotFound,
+    num::NonZeroUsize,
+    time::Duration,
+};
 
 mod predicates;
 mod tx_pointer;
@@ -103,6 +131,122 @@ async fn dry_run_script() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn dry_runs_above_the_concurrency_limit_are_rejected() {
+    // Given
+    let mut config = Config::local_node();
+    config.api_simulation_limit = Some(SimulationLimitConfig {
+        max_concurrent: NonZeroUsize::new(1).unwrap(),
+        queue_timeout: Duration::ZERO,
+    });
+    let max_gas_per_tx = config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .tx_params()
+        .max_gas_per_tx();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The script loops until it runs out of gas.
+    let script = [op::noop(), op::jmpb(RegId::ZERO, 0)];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(max_gas_per_tx / 2)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    // When
+    let results = futures::future::join_all(
+        (0..4).map(|_| client.dry_run(std::slice::from_ref(&tx))),
+    )
+    .await;
+
+    // Then
+    let rejected = results
+        .iter()
+        .filter(|result| {
+            matches!(result, Err(err) if err.to_string().contains("Too many concurrent simulations"))
+        })
+        .count();
+    assert!(rejected > 0, "{results:?}");
+    assert!(results.iter().any(Result::is_ok), "{results:?}");
+}
+
+#[tokio::test]
+async fn dry_run_reports_storage_keys_touched_by_contract_call() {
+    // Given
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    test_builder.record_storage_access = true;
+    // The contract writes `42` into the storage slot with the zero key.
+    let contract_code: Vec<u8> = [
+        op::movi(0x10, 32),
+        op::aloc(0x10),
+        op::movi(0x11, 42),
+        op::sww(RegId::HP, 0x12, 0x11),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, vec![], None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let script = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let tx = TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    // When
+    let storage_access = client
+        .dry_run_storage_access(&[tx])
+        .await
+        .unwrap()
+        .pop()
+        .unwrap()
+        .expect("The storage access is recorded");
+
+    // Then
+    let touched = |keys: &[StorageAccessKey], column: &str, key: Vec<u8>| {
+        keys.iter()
+            .any(|access| access.column == column && access.key == key)
+    };
+    let slot_key = [contract_id.as_ref(), Bytes32::zeroed().as_ref()].concat();
+    assert!(
+        touched(&storage_access.writes, "ContractsState", slot_key),
+        "{storage_access:?}"
+    );
+    assert!(
+        touched(
+            &storage_access.reads,
+            "ContractsRawCode",
+            contract_id.to_vec()
+        ),
+        "{storage_access:?}"
+    );
+}
+
 #[tokio::test]
 async fn dry_run_create() {
     let mut rng = StdRng::seed_from_u64(2322);
@@ -140,6 +284,80 @@ async fn dry_run_create() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn simulate_block_matches_produced_block() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let block_time = Tai64::now().0;
+
+    // Given
+    let simulated = client
+        .simulate_block(&[tx.clone()], Some(block_time))
+        .await
+        .unwrap();
+    let chain_info = client.chain_info().await.unwrap();
+    assert_eq!(chain_info.latest_block.header.height, 0);
+
+    // When
+    client.submit(&tx).await.unwrap();
+    client.produce_blocks(1, Some(block_time)).await.unwrap();
+
+    // Then
+    let block = client.block_by_height(1.into()).await.unwrap().unwrap();
+    assert_eq!(simulated.header, block.header);
+    assert!(simulated.skipped_transactions.is_empty());
+    // The submitted transaction and the mint transaction.
+    assert_eq!(simulated.transactions.len(), 2);
+    let tx_id = tx.id(&ChainId::default());
+    let receipts = client.receipts(&tx_id).await.unwrap().unwrap();
+    assert_eq!(simulated.transactions[0].id, tx_id);
+    assert_eq!(
+        simulated.transactions[0].result.receipts(),
+        receipts.as_slice()
+    );
+}
+
+#[tokio::test]
+async fn transaction_finality_grows_with_new_blocks() {
+    let mut config = Config::local_node();
+    config.api_finality_depth = 3;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::default_test_tx();
+    let tx_id = tx.id(&ChainId::default());
+
+    // Given
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let finality = client.transaction_finality(&tx_id).await.unwrap().unwrap();
+    assert_eq!(finality.confirmations, Some(1));
+    assert!(!finality.finalized);
+
+    // When
+    client.produce_blocks(2, None).await.unwrap();
+
+    // Then
+    let finality = client.transaction_finality(&tx_id).await.unwrap().unwrap();
+    assert_eq!(finality.confirmations, Some(3));
+    assert!(finality.finalized);
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -213,6 +431,176 @@ async fn receipts_decoding() {
     assert_eq!(actual_receipts, all_receipts())
 }
 
+#[tokio::test]
+async fn receipts_base64_decodes_to_the_same_receipts() {
+    // Given
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client
+        .submit_and_await_commit(&transaction)
+        .await
+        .expect("transaction should insert");
+
+    // When
+    let query = "query($id: TransactionId!) { transaction(id: $id) { status { \
+        ... on SuccessStatus { receiptsBase64 } } } }";
+    let body = reqwest::Client::new()
+        .post(format!("http://{}/v1/graphql", srv.bound_address))
+        .header(CONTENT_TYPE, "application/json")
+        .body(
+            json!({ "query": query, "variables": { "id": format!("{id:#x}") } })
+                .to_string(),
+        )
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    let response: Value = serde_json::from_str(&body).unwrap();
+
+    // Then
+    let decoded_receipts: Vec<Receipt> = response["data"]["transaction"]["status"]
+        ["receiptsBase64"]
+        .as_array()
+        .expect("Expected receipts")
+        .iter()
+        .map(|encoded| {
+            let bytes = BASE64_STANDARD.decode(encoded.as_str().unwrap()).unwrap();
+            Receipt::from_bytes(&bytes).unwrap()
+        })
+        .collect();
+    let receipts = client.receipts(&id).await.unwrap().unwrap();
+    assert!(!receipts.is_empty());
+    assert_eq!(decoded_receipts, receipts);
+}
+
+#[tokio::test]
+async fn raw_payload_base64_decodes_to_the_same_transactions_as_block_describes() {
+    // Given
+    let transaction = Transaction::default_test_tx();
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client
+        .submit_and_await_commit(&transaction)
+        .await
+        .expect("transaction should insert");
+
+    // When
+    let query =
+        "{ block(height: \"1\") { transactions { id isMint rawPayloadBase64 } } }";
+    let body = reqwest::Client::new()
+        .post(format!("http://{}/v1/graphql", srv.bound_address))
+        .header(CONTENT_TYPE, "application/json")
+        .body(json!({ "query": query }).to_string())
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    let response: Value = serde_json::from_str(&body).unwrap();
+
+    // Then
+    let transactions = response["data"]["block"]["transactions"]
+        .as_array()
+        .expect("Expected transactions");
+    assert_eq!(transactions.len(), 2);
+    for json in transactions {
+        let bytes = BASE64_STANDARD
+            .decode(json["rawPayloadBase64"].as_str().unwrap())
+            .unwrap();
+        let decoded = Transaction::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            json["id"].as_str().unwrap(),
+            format!("{:#x}", decoded.id(&ChainId::default()))
+        );
+        assert_eq!(json["isMint"].as_bool().unwrap(), decoded.is_mint());
+    }
+    let bytes = BASE64_STANDARD
+        .decode(transactions[0]["rawPayloadBase64"].as_str().unwrap())
+        .unwrap();
+    assert_eq!(Transaction::from_bytes(&bytes).unwrap(), transaction);
+}
+
+#[tokio::test]
+async fn receipts_above_the_limit_are_fetched_page_by_page() {
+    // Given
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.api_max_receipts_per_transaction = Some(10);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut script =
+        vec![op::log(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::ZERO); 20];
+    script.push(op::ret(RegId::ONE));
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ChainId::default());
+    client.submit(&tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    // When
+    let status = client.transaction_status(&id).await;
+
+    // Then
+    let error = status.expect_err("Should fail because of too many receipts");
+    assert!(error.to_string().contains("truncated, 12 more"));
+
+    // When
+    let query = "query($id: TransactionId!, $after: String) { \
+        receipts(id: $id, first: 10, after: $after) { \
+        pageInfo { hasNextPage endCursor } nodes { receiptType } } }";
+    let mut pages = vec![];
+    let mut after = Value::Null;
+    loop {
+        let body = reqwest::Client::new()
+            .post(format!("http://{}/v1/graphql", srv.bound_address))
+            .header(CONTENT_TYPE, "application/json")
+            .body(
+                json!({
+                    "query": query,
+                    "variables": { "id": format!("{id:#x}"), "after": after }
+                })
+                .to_string(),
+            )
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        let response: Value = serde_json::from_str(&body).unwrap();
+        let receipts = &response["data"]["receipts"];
+        let page = receipts["nodes"]
+            .as_array()
+            .expect("Expected receipts")
+            .iter()
+            .map(|receipt| receipt["receiptType"].as_str().unwrap().to_string())
+            .collect_vec();
+        pages.push(page);
+        if !receipts["pageInfo"]["hasNextPage"].as_bool().unwrap() {
+            break
+        }
+        after = receipts["pageInfo"]["endCursor"].clone();
+    }
+
+    // Then
+    assert_eq!(pages.iter().map(Vec::len).collect_vec(), vec![10, 10, 2]);
+    let receipt_types = pages.concat();
+    assert!(receipt_types[..20].iter().all(|ty| ty == "LOG"));
+    assert_eq!(receipt_types[20..], ["RETURN", "SCRIPT_RESULT"]);
+}
+
 #[tokio::test]
 async fn get_transaction_by_id() {
     // setup test data in the node
@@ -660,3 +1048,322 @@ fn create_mock_tx(val: u64) -> Transaction {
         )
         .finalize_as_transaction()
 }
+
+// the `seed` makes the script data, and so the transaction, unique
+fn unique_script(seed: u8) -> Transaction {
+    TransactionBuilder::script(vec![], vec![seed])
+        .script_gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction()
+}
+
+mod submit_with_result {
+    use super::*;
+    use cynic::MutationBuilder;
+    use fuel_core_client::client::schema::{
+        schema,
+        tx::TxArg,
+        Bytes,
+        HexString,
+        TransactionId,
+    };
+    use fuel_core_types::fuel_types::canonical::Serialize;
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Mutation",
+        variables = "TxArg"
+    )]
+    pub struct SubmitWithResult {
+        #[arguments(tx: $tx)]
+        pub submit_with_result: SubmissionResult,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "../crates/client/assets/schema.sdl")]
+    pub struct SubmissionResult {
+        pub replaced: bool,
+        pub replaced_transaction_ids: Vec<TransactionId>,
+    }
+
+    async fn submit_with_result(
+        client: &FuelClient,
+        tx: &Transaction,
+    ) -> std::io::Result<SubmissionResult> {
+        let mutation = SubmitWithResult::build(TxArg {
+            tx: HexString(Bytes(tx.clone().to_bytes())),
+        });
+        client
+            .query(mutation)
+            .await
+            .map(|result| result.submit_with_result)
+    }
+
+    #[tokio::test]
+    async fn submit_with_result_reports_replaced_transaction() {
+        // Given
+        let mut config = Config::local_node();
+        config.block_production = Trigger::Never;
+        config.txpool.min_replacement_tip_bump = 5;
+        let srv = FuelService::new_node(config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        let mut rng = StdRng::seed_from_u64(2322);
+        let secret = SecretKey::random(&mut rng);
+        let utxo_id: UtxoId = rng.gen();
+        let tx_with_tip = |tip| {
+            TransactionBuilder::script(vec![], vec![])
+                .tip(tip)
+                .max_fee_limit(tip)
+                .script_gas_limit(10_000)
+                .add_unsigned_coin_input(
+                    secret,
+                    utxo_id,
+                    1000,
+                    AssetId::BASE,
+                    Default::default(),
+                )
+                .finalize_as_transaction()
+        };
+        let original = tx_with_tip(10);
+        client.submit(&original).await.unwrap();
+
+        // When
+        let underpriced = submit_with_result(&client, &tx_with_tip(14)).await;
+
+        // Then
+        let error = underpriced.expect_err("The bump of the tip is not enough");
+        assert!(
+            error.to_string().contains("the required tip is 15"),
+            "{error}"
+        );
+
+        // When
+        let replacement = submit_with_result(&client, &tx_with_tip(15)).await;
+
+        // Then
+        let result = replacement.expect("The replacement should be accepted");
+        assert!(result.replaced);
+        let replaced: Vec<TxId> = result
+            .replaced_transaction_ids
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        assert_eq!(replaced, vec![original.id(&ChainId::default())]);
+    }
+}
+
+mod transaction_labels {
+    use super::*;
+    use cynic::{
+        MutationBuilder,
+        QueryBuilder,
+    };
+    use fuel_core_client::client::schema::{
+        schema,
+        Bytes,
+        HexString,
+        TransactionId,
+    };
+
+    #[derive(cynic::QueryVariables)]
+    pub struct LabeledTxArg {
+        pub tx: HexString,
+        pub label: Option<String>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Mutation",
+        variables = "LabeledTxArg"
+    )]
+    pub struct SubmitWithLabel {
+        #[arguments(tx: $tx, label: $label)]
+        pub submit: LabeledTransaction,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct LabelArg {
+        pub label: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query",
+        variables = "LabelArg"
+    )]
+    pub struct TransactionsByLabel {
+        #[arguments(label: $label)]
+        pub transactions_by_label: Vec<LabeledTransaction>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Transaction"
+    )]
+    pub struct LabeledTransaction {
+        pub id: TransactionId,
+    }
+
+    async fn submit_with_label(
+        client: &FuelClient,
+        tx: &Transaction,
+        label: Option<&str>,
+    ) -> std::io::Result<TxId> {
+        let mutation = SubmitWithLabel::build(LabeledTxArg {
+            tx: HexString(Bytes(tx.clone().to_bytes())),
+            label: label.map(ToString::to_string),
+        });
+        client
+            .query(mutation)
+            .await
+            .map(|result| result.submit.id.into())
+    }
+
+    async fn transactions_by_label(
+        client: &FuelClient,
+        label: &str,
+    ) -> std::io::Result<Vec<TxId>> {
+        let query = TransactionsByLabel::build(LabelArg {
+            label: label.to_string(),
+        });
+        client.query(query).await.map(|result| {
+            result
+                .transactions_by_label
+                .into_iter()
+                .map(|tx| tx.id.into())
+                .collect()
+        })
+    }
+
+    #[tokio::test]
+    async fn transactions_by_label_returns_transactions_submitted_with_label() {
+        // Given
+        let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        let first = submit_with_label(&client, &unique_script(1), Some("first"))
+            .await
+            .unwrap();
+        let second = submit_with_label(&client, &unique_script(2), Some("second"))
+            .await
+            .unwrap();
+        let third = submit_with_label(&client, &unique_script(3), Some("first"))
+            .await
+            .unwrap();
+        submit_with_label(&client, &unique_script(4), None)
+            .await
+            .unwrap();
+
+        // When
+        let first_label = transactions_by_label(&client, "first").await.unwrap();
+        let second_label = transactions_by_label(&client, "second").await.unwrap();
+        let unknown_label = transactions_by_label(&client, "unknown").await.unwrap();
+
+        // Then
+        assert_eq!(first_label, vec![first, third]);
+        assert_eq!(second_label, vec![second]);
+        assert!(unknown_label.is_empty());
+    }
+
+    #[tokio::test]
+    async fn transaction_labels_are_rejected_outside_of_debug_mode() {
+        // Given
+        let mut config = Config::local_node();
+        config.debug = false;
+        let srv = FuelService::new_node(config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // When
+        let submit_result =
+            submit_with_label(&client, &unique_script(1), Some("label")).await;
+        let query_result = transactions_by_label(&client, "label").await;
+
+        // Then
+        assert!(submit_result.is_err());
+        assert!(query_result.is_err());
+    }
+}
+
+mod txpool_stats {
+    use super::*;
+    use cynic::QueryBuilder;
+    use fuel_core_client::client::schema::{
+        schema,
+        U64,
+    };
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../crates/client/assets/schema.sdl",
+        graphql_type = "Query"
+    )]
+    pub struct TxPoolStatsQuery {
+        pub txpool_stats: TxPoolStats,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "../crates/client/assets/schema.sdl")]
+    pub struct TxPoolStats {
+        pub pending_count: U64,
+        pub total_bytes: U64,
+        pub max_tx: U64,
+        pub max_depth: U64,
+        pub utilization: f64,
+    }
+
+    async fn txpool_stats(client: &FuelClient) -> TxPoolStats {
+        client
+            .query(TxPoolStatsQuery::build(()))
+            .await
+            .unwrap()
+            .txpool_stats
+    }
+
+    #[tokio::test]
+    async fn txpool_stats_follow_added_and_removed_transactions() {
+        // Given
+        let mut config = Config::local_node();
+        config.block_production = Trigger::Never;
+        config.txpool.max_tx = 4;
+        config.txpool.max_depth = 3;
+        let srv = FuelService::new_node(config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        let empty = txpool_stats(&client).await;
+        let first = unique_script(1);
+        let second = unique_script(2);
+
+        // When
+        client.submit(&first).await.unwrap();
+        let one_pending = txpool_stats(&client).await;
+        client.submit(&second).await.unwrap();
+        let two_pending = txpool_stats(&client).await;
+        client.produce_blocks(1, None).await.unwrap();
+        let after_block = txpool_stats(&client).await;
+
+        // Then
+        assert_eq!(empty.pending_count.0, 0);
+        assert_eq!(empty.total_bytes.0, 0);
+        assert_eq!(empty.max_tx.0, 4);
+        assert_eq!(empty.max_depth.0, 3);
+        assert_eq!(empty.utilization, 0.0);
+
+        assert_eq!(one_pending.pending_count.0, 1);
+        assert_eq!(one_pending.total_bytes.0, first.size() as u64);
+        assert_eq!(one_pending.utilization, 25.0);
+
+        assert_eq!(two_pending.pending_count.0, 2);
+        assert_eq!(
+            two_pending.total_bytes.0,
+            (first.size() + second.size()) as u64
+        );
+        assert_eq!(two_pending.utilization, 50.0);
+
+        assert_eq!(after_block.pending_count.0, 0);
+        assert_eq!(after_block.total_bytes.0, 0);
+        assert_eq!(after_block.utilization, 0.0);
+    }
+}